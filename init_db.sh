@@ -20,7 +20,7 @@ if command -v sqlx &> /dev/null; then
 elif command -v psql &> /dev/null; then
     # Fallback: chạy tất cả migration theo thứ tự (1., 2., 3....)
     if [ -d "migrations" ]; then
-        for f in $(ls migrations/*.sql 2>/dev/null | sort); do
+        for f in $(ls migrations/*.sql 2>/dev/null | sort -V); do
             echo "Running migration: $f"
             psql "$DATABASE_URL" -f "$f" || exit 1
         done
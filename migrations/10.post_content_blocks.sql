@@ -0,0 +1,18 @@
+-- Nội dung dạng block (JSONB, có version) và text thuần render từ nội dung để index tìm kiếm
+ALTER TABLE posts
+    ADD COLUMN IF NOT EXISTS content_blocks JSONB,
+    ADD COLUMN IF NOT EXISTS content_text TEXT;
+
+ALTER TABLE post_revisions
+    ADD COLUMN IF NOT EXISTS content_blocks JSONB;
+
+-- search_vector index text đã render (bài block không có `content`), fallback về `content` với bài cũ
+DROP INDEX IF EXISTS idx_posts_search_vector;
+ALTER TABLE posts DROP COLUMN IF EXISTS search_vector;
+ALTER TABLE posts
+    ADD COLUMN search_vector tsvector GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple'::regconfig, coalesce(title, '')), 'A') ||
+        setweight(to_tsvector('simple'::regconfig, coalesce(excerpt, '')), 'B') ||
+        setweight(to_tsvector('simple'::regconfig, coalesce(content_text, content, '')), 'C')
+    ) STORED;
+CREATE INDEX IF NOT EXISTS idx_posts_search_vector ON posts USING GIN (search_vector);
@@ -0,0 +1,21 @@
+-- Ảnh đại diện và gallery media cho bài viết
+ALTER TABLE posts
+    ADD COLUMN IF NOT EXISTS featured_media_id UUID REFERENCES media(id);
+
+CREATE TABLE IF NOT EXISTS post_media (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+    post_id UUID NOT NULL,
+    media_id UUID NOT NULL,
+    position INT NOT NULL DEFAULT 0, -- thứ tự hiển thị trong gallery (tăng dần)
+    caption TEXT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP DEFAULT NULL,
+    FOREIGN KEY (post_id) REFERENCES posts(id),
+    FOREIGN KEY (media_id) REFERENCES media(id)
+);
+
+ALTER TABLE post_media
+    ADD CONSTRAINT post_media_post_media_unique UNIQUE (post_id, media_id);
+
+CREATE INDEX IF NOT EXISTS idx_post_media_post_position ON post_media(post_id, position);
@@ -0,0 +1,34 @@
+-- SEO theo từng ngôn ngữ cho post/category (song song với post_translations, category_translations)
+CREATE TABLE IF NOT EXISTS seo_metadata (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+    entity_type TEXT NOT NULL, -- post | category
+    entity_id UUID NOT NULL,
+    language_code TEXT NOT NULL,
+    meta_title TEXT,
+    meta_description TEXT,
+    canonical_url TEXT, -- URL đầy đủ hoặc path, NULL => sinh từ seo_site_url + slug
+    robots_index BOOLEAN NOT NULL DEFAULT TRUE,
+    robots_follow BOOLEAN NOT NULL DEFAULT TRUE,
+    og_image_media_id UUID,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP DEFAULT NULL,
+    FOREIGN KEY (language_code) REFERENCES languages(code),
+    FOREIGN KEY (og_image_media_id) REFERENCES media(id)
+);
+
+ALTER TABLE seo_metadata
+    ADD CONSTRAINT seo_metadata_entity_lang_unique UNIQUE (entity_type, entity_id, language_code);
+
+-- Giá trị mặc định toàn site khi bài/category chưa khai báo SEO
+INSERT INTO settings (key, value, description)
+VALUES
+  ('seo_site_name', '"Rust API"', 'Tên site (og:site_name, publisher trong JSON-LD)'),
+  ('seo_site_url', '"http://localhost:3000"', 'URL gốc của frontend, dùng sinh canonical URL'),
+  ('seo_title_template', '"{title} | {site_name}"', 'Mẫu thẻ title khi không khai báo meta_title'),
+  ('seo_default_description', '""', 'Meta description mặc định'),
+  ('seo_default_og_image', 'null', 'URL ảnh OG mặc định'),
+  ('seo_publisher_logo', 'null', 'URL logo publisher trong JSON-LD'),
+  ('seo_post_path', '"/posts/{slug}"', 'Path bài viết trên frontend'),
+  ('seo_category_path', '"/categories/{slug}"', 'Path category trên frontend')
+ON CONFLICT (key) DO NOTHING;
@@ -0,0 +1,4 @@
+-- Số bài tối đa trong feed RSS/Atom/JSON Feed
+INSERT INTO settings (key, value, description)
+VALUES ('feed_item_limit', '20', 'Số bài tối đa trong feed RSS/Atom/JSON Feed')
+ON CONFLICT (key) DO NOTHING;
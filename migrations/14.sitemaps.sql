@@ -0,0 +1,6 @@
+-- URL của tag và của các bản ngôn ngữ khác mặc định (dùng cho sitemap + hreflang)
+INSERT INTO settings (key, value, description)
+VALUES
+  ('seo_tag_path', '"/tags/{slug}"', 'Path tag trên frontend'),
+  ('seo_language_path', '"/{lang}{path}"', 'Path trang ở ngôn ngữ khác mặc định, {path} là path gốc')
+ON CONFLICT (key) DO NOTHING;
@@ -0,0 +1,4 @@
+-- Slug bản dịch unique theo ngôn ngữ (bỏ qua bản dịch đã xoá mềm)
+CREATE UNIQUE INDEX IF NOT EXISTS post_translations_lang_slug_unique
+    ON post_translations (language_code, slug)
+    WHERE deleted_at IS NULL AND slug IS NOT NULL;
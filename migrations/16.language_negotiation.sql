@@ -0,0 +1,9 @@
+-- Ngôn ngữ ưu tiên của user (dùng khi request không có ?lang=)
+ALTER TABLE user_profiles
+    ADD COLUMN IF NOT EXISTS preferred_language TEXT;
+
+-- Chuỗi ngôn ngữ dự phòng khi thiếu bản dịch, ngôn ngữ mặc định luôn đứng cuối
+INSERT INTO settings (key, value, description)
+VALUES
+  ('language_fallbacks', '{}', 'Ngôn ngữ dự phòng theo mã, ví dụ {"en-GB": ["en"], "ja": ["en"]}')
+ON CONFLICT (key) DO NOTHING;
@@ -0,0 +1,6 @@
+-- Chỉ một ngôn ngữ mặc định (bỏ qua ngôn ngữ đã xoá mềm)
+UPDATE languages SET is_default = FALSE WHERE is_default IS NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS languages_single_default
+    ON languages ((is_default))
+    WHERE is_default AND deleted_at IS NULL;
@@ -0,0 +1,108 @@
+-- Thời điểm nội dung cần dịch của bản ghi gốc thay đổi (khác updated_at: publish/archive không tính)
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS source_changed_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP;
+ALTER TABLE categories ADD COLUMN IF NOT EXISTS source_changed_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP;
+ALTER TABLE tags ADD COLUMN IF NOT EXISTS source_changed_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP;
+ALTER TABLE banners ADD COLUMN IF NOT EXISTS source_changed_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP;
+
+-- Dữ liệu cũ: lấy theo updated_at (chạy lại migration không ghi đè giá trị đã có)
+UPDATE posts SET source_changed_at = COALESCE(updated_at, created_at)
+    WHERE source_changed_at > COALESCE(updated_at, created_at);
+UPDATE categories SET source_changed_at = COALESCE(updated_at, created_at)
+    WHERE source_changed_at > COALESCE(updated_at, created_at);
+UPDATE tags SET source_changed_at = COALESCE(updated_at, created_at)
+    WHERE source_changed_at > COALESCE(updated_at, created_at);
+UPDATE banners SET source_changed_at = COALESCE(updated_at, created_at)
+    WHERE source_changed_at > COALESCE(updated_at, created_at);
+
+-- Cập nhật source_changed_at khi một trong các cột truyền vào (TG_ARGV) đổi giá trị
+CREATE OR REPLACE FUNCTION touch_source_changed_at()
+RETURNS trigger
+LANGUAGE plpgsql
+AS $$
+DECLARE
+    col TEXT;
+BEGIN
+    FOREACH col IN ARRAY TG_ARGV LOOP
+        IF to_jsonb(NEW) -> col IS DISTINCT FROM to_jsonb(OLD) -> col THEN
+            NEW.source_changed_at := NOW();
+            EXIT;
+        END IF;
+    END LOOP;
+    RETURN NEW;
+END;
+$$;
+
+DROP TRIGGER IF EXISTS posts_source_changed_at ON posts;
+CREATE TRIGGER posts_source_changed_at BEFORE UPDATE ON posts
+    FOR EACH ROW EXECUTE FUNCTION touch_source_changed_at('title', 'excerpt', 'content');
+
+DROP TRIGGER IF EXISTS categories_source_changed_at ON categories;
+CREATE TRIGGER categories_source_changed_at BEFORE UPDATE ON categories
+    FOR EACH ROW EXECUTE FUNCTION touch_source_changed_at('name', 'description');
+
+DROP TRIGGER IF EXISTS tags_source_changed_at ON tags;
+CREATE TRIGGER tags_source_changed_at BEFORE UPDATE ON tags
+    FOR EACH ROW EXECUTE FUNCTION touch_source_changed_at('name', 'description');
+
+DROP TRIGGER IF EXISTS banners_source_changed_at ON banners;
+CREATE TRIGGER banners_source_changed_at BEFORE UPDATE ON banners
+    FOR EACH ROW EXECUTE FUNCTION touch_source_changed_at('title', 'description');
+
+-- Trạng thái dịch của từng bản ghi gốc theo mỗi ngôn ngữ khác mặc định:
+-- missing (chưa có bản dịch) | outdated (bản gốc đổi sau lần cập nhật bản dịch cuối) | up_to_date
+CREATE OR REPLACE VIEW translation_status AS
+SELECT 'post'::TEXT AS entity_type, p.id, NULL::TEXT AS type, p.slug, p.title AS label,
+       l.code AS language_code, p.source_changed_at,
+       COALESCE(t.updated_at, t.created_at) AS translation_updated_at,
+       CASE
+           WHEN t.id IS NULL THEN 'missing'
+           WHEN COALESCE(t.updated_at, t.created_at) < p.source_changed_at THEN 'outdated'
+           ELSE 'up_to_date'
+       END AS status
+FROM posts p
+CROSS JOIN languages l
+LEFT JOIN post_translations t
+    ON t.post_id = p.id AND t.language_code = l.code AND t.deleted_at IS NULL
+WHERE p.deleted_at IS NULL AND l.deleted_at IS NULL AND l.is_default IS NOT TRUE
+UNION ALL
+SELECT 'category', c.id, c.type, c.slug, c.name,
+       l.code, c.source_changed_at,
+       COALESCE(t.updated_at, t.created_at),
+       CASE
+           WHEN t.id IS NULL THEN 'missing'
+           WHEN COALESCE(t.updated_at, t.created_at) < c.source_changed_at THEN 'outdated'
+           ELSE 'up_to_date'
+       END
+FROM categories c
+CROSS JOIN languages l
+LEFT JOIN category_translations t
+    ON t.category_id = c.id AND t.language_code = l.code AND t.deleted_at IS NULL
+WHERE c.deleted_at IS NULL AND l.deleted_at IS NULL AND l.is_default IS NOT TRUE
+UNION ALL
+SELECT 'tag', g.id, g.type, g.slug, g.name,
+       l.code, g.source_changed_at,
+       COALESCE(t.updated_at, t.created_at),
+       CASE
+           WHEN t.id IS NULL THEN 'missing'
+           WHEN COALESCE(t.updated_at, t.created_at) < g.source_changed_at THEN 'outdated'
+           ELSE 'up_to_date'
+       END
+FROM tags g
+CROSS JOIN languages l
+LEFT JOIN tag_translations t
+    ON t.tag_id = g.id AND t.language_code = l.code AND t.deleted_at IS NULL
+WHERE g.deleted_at IS NULL AND l.deleted_at IS NULL AND l.is_default IS NOT TRUE
+UNION ALL
+SELECT 'banner', b.id, NULL, b.slug, b.title,
+       l.code, b.source_changed_at,
+       COALESCE(t.updated_at, t.created_at),
+       CASE
+           WHEN t.id IS NULL THEN 'missing'
+           WHEN COALESCE(t.updated_at, t.created_at) < b.source_changed_at THEN 'outdated'
+           ELSE 'up_to_date'
+       END
+FROM banners b
+CROSS JOIN languages l
+LEFT JOIN banner_translations t
+    ON t.banner_id = b.id AND t.language_code = l.code AND t.deleted_at IS NULL
+WHERE b.deleted_at IS NULL AND l.deleted_at IS NULL AND l.is_default IS NOT TRUE;
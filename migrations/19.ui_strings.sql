@@ -0,0 +1,30 @@
+-- Chuỗi giao diện (i18n message key) frontend lấy qua `/i18n/:lang/:namespace`
+CREATE TABLE IF NOT EXISTS translation_keys (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+    namespace TEXT NOT NULL, -- ví dụ: 'common', 'checkout'
+    key TEXT NOT NULL, -- ví dụ: 'cart.items_count'
+    description TEXT, -- ngữ cảnh cho người dịch
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP DEFAULT NULL
+);
+
+-- Key unique trong namespace (bỏ qua key đã xoá mềm)
+CREATE UNIQUE INDEX IF NOT EXISTS translation_keys_namespace_key_unique
+    ON translation_keys (namespace, key) WHERE deleted_at IS NULL;
+
+-- Nội dung theo ngôn ngữ, cú pháp ICU MessageFormat
+CREATE TABLE IF NOT EXISTS translation_values (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+    key_id UUID NOT NULL,
+    language_code TEXT NOT NULL,
+    value TEXT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP DEFAULT NULL,
+    FOREIGN KEY (key_id) REFERENCES translation_keys(id),
+    FOREIGN KEY (language_code) REFERENCES languages(code)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS translation_values_key_lang_unique
+    ON translation_values (key_id, language_code);
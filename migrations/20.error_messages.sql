@@ -0,0 +1,126 @@
+-- Message lỗi API theo ngôn ngữ: namespace 'errors', key = mã lỗi trong `error.code`.
+-- Tham số `{name}` lấy từ `error.details.params`; chỉ seed key chưa có để không ghi đè bản đã sửa
+WITH messages (code, description, en, vi) AS (
+  VALUES
+  ('AUTH_INVALID_CREDENTIALS', 'Đăng nhập sai email/mật khẩu',
+    'Invalid credentials', 'Email hoặc mật khẩu không đúng'),
+  ('AUTH_TOKEN_MISSING', 'Thiếu header Authorization: Bearer',
+    'Missing or invalid token', 'Bạn chưa đăng nhập hoặc token không hợp lệ'),
+  ('AUTH_TOKEN_INVALID', 'Token sai chữ ký, hết hạn hoặc user id không hợp lệ',
+    'Invalid token', 'Phiên đăng nhập không hợp lệ hoặc đã hết hạn'),
+  ('FORBIDDEN_ADMIN_ROLE', 'Route chỉ dành cho Admin',
+    'This action requires the Admin role', 'Chỉ quản trị viên mới được thực hiện thao tác này'),
+  ('FORBIDDEN_EDITOR_ROLE', 'Route dành cho Admin/Editor',
+    'This action requires the Admin or Editor role', 'Chỉ quản trị viên hoặc biên tập viên mới được thực hiện thao tác này'),
+  ('FORBIDDEN_PERMISSION', 'Thiếu permission; {permission}',
+    'You need the {permission} permission to do this', 'Bạn cần quyền {permission} để thực hiện thao tác này'),
+  ('FORBIDDEN', 'Không có quyền; {reason} (tiếng Anh, có thể thiếu)',
+    'You are not allowed to do this', 'Bạn không có quyền thực hiện thao tác này'),
+  ('INVALID_CURSOR', 'Cursor phân trang hỏng/hết hạn; {reason}',
+    'This page link is no longer valid, please reload from the first page', 'Liên kết phân trang không còn hợp lệ, vui lòng tải lại từ trang đầu'),
+  ('SEARCH_QUERY_REQUIRED', 'Tìm kiếm thiếu tham số q',
+    'Please enter a search query', 'Vui lòng nhập từ khoá tìm kiếm'),
+  ('NO_FIELDS_TO_UPDATE', 'Body cập nhật rỗng',
+    'There is nothing to update', 'Không có thông tin nào để cập nhật'),
+  ('INVALID_JSON_BODY', 'Body JSON sai kiểu dữ liệu; {reason}',
+    'The request body is invalid: {reason}', 'Dữ liệu gửi lên không hợp lệ: {reason}'),
+  ('MALFORMED_JSON_BODY', 'Body không phải JSON; {reason}',
+    'The request body is not valid JSON', 'Dữ liệu gửi lên không đúng định dạng JSON'),
+  ('UNSUPPORTED_MEDIA_TYPE', 'Thiếu Content-Type: application/json',
+    'The request must be sent as JSON', 'Dữ liệu phải được gửi dưới dạng JSON'),
+  ('INVALID_QUERY', 'Query string sai kiểu; {reason}',
+    'The query parameters are invalid: {reason}', 'Tham số truy vấn không hợp lệ: {reason}'),
+  ('INVALID_PATH', 'Tham số trên URL sai kiểu (ví dụ id không phải UUID); {reason}',
+    'The URL is invalid', 'Đường dẫn không hợp lệ'),
+  ('ROUTE_NOT_FOUND', 'Không có route',
+    'The requested page does not exist', 'Trang bạn yêu cầu không tồn tại'),
+  ('METHOD_NOT_ALLOWED', 'Sai HTTP method',
+    'This method is not allowed here', 'Phương thức này không được hỗ trợ'),
+  ('LANGUAGE_IS_DEFAULT', 'Ghi bản dịch cho ngôn ngữ mặc định; {code}',
+    '{code} is the default language, update the original content instead', '{code} là ngôn ngữ mặc định, hãy sửa nội dung gốc'),
+  ('LANGUAGE_DUPLICATED', 'Ngôn ngữ bị lặp; {code}',
+    'Language {code} is given twice', 'Ngôn ngữ {code} bị khai báo hai lần'),
+  ('LANGUAGE_INVALID', 'Mã ngôn ngữ không hợp lệ/chưa bật; {code}',
+    'Language {code} is not supported', 'Ngôn ngữ {code} chưa được hỗ trợ'),
+  ('LANGUAGE_NOT_FOUND', 'Không có ngôn ngữ; {code}',
+    'Language {code} not found', 'Không tìm thấy ngôn ngữ {code}'),
+  ('DEFAULT_LANGUAGE_NOT_FOUND', 'Chưa cấu hình ngôn ngữ mặc định',
+    'No default language is configured', 'Chưa cấu hình ngôn ngữ mặc định'),
+  ('LANGUAGE_EXISTS', 'Thêm ngôn ngữ đã có; {code}',
+    'Language {code} already exists', 'Ngôn ngữ {code} đã tồn tại'),
+  ('LANGUAGE_DELETE_DEFAULT', 'Xoá ngôn ngữ mặc định; {code}',
+    '{code} is the default language, set another default language first', '{code} là ngôn ngữ mặc định, hãy chọn ngôn ngữ mặc định khác trước'),
+  ('LANGUAGE_HAS_TRANSLATIONS', 'Xoá ngôn ngữ còn bản dịch; {code}, {count}',
+    '{count, plural, one {Language {code} still has # translation} other {Language {code} still has # translations}}, migrate or delete them first',
+    '{count, plural, other {Ngôn ngữ {code} vẫn còn # bản dịch}}, hãy chuyển hoặc xoá chúng trước'),
+  ('POST_NOT_FOUND', 'Không có bài viết', 'Post not found', 'Không tìm thấy bài viết'),
+  ('CATEGORY_NOT_FOUND', 'Không có danh mục', 'Category not found', 'Không tìm thấy danh mục'),
+  ('TAG_NOT_FOUND', 'Không có tag', 'Tag not found', 'Không tìm thấy thẻ'),
+  ('BANNER_NOT_FOUND', 'Không có banner', 'Banner not found', 'Không tìm thấy banner'),
+  ('BANNER_ITEM_NOT_FOUND', 'Không có item banner', 'Banner item not found', 'Không tìm thấy mục banner'),
+  ('MEDIA_NOT_FOUND', 'Không có file media', 'Media not found', 'Không tìm thấy tệp media'),
+  ('POST_MEDIA_NOT_FOUND', 'Bài viết không gắn media này', 'Post media not found', 'Không tìm thấy media của bài viết'),
+  ('USER_NOT_FOUND', 'Không có user', 'User not found', 'Không tìm thấy người dùng'),
+  ('ROLE_NOT_FOUND', 'Không có role', 'Role not found', 'Không tìm thấy vai trò'),
+  ('PERMISSION_NOT_FOUND', 'Không có permission', 'Permission not found', 'Không tìm thấy quyền'),
+  ('REDIRECT_NOT_FOUND', 'Không có redirect', 'Redirect not found', 'Không tìm thấy chuyển hướng'),
+  ('REVISION_NOT_FOUND', 'Không có phiên bản bài viết; {number}',
+    'Revision {number} not found', 'Không tìm thấy phiên bản {number}'),
+  ('POST_TRANSLATION_NOT_FOUND', 'Bài viết chưa có bản dịch này',
+    'Post translation not found', 'Không tìm thấy bản dịch bài viết'),
+  ('CATEGORY_TRANSLATION_NOT_FOUND', 'Danh mục chưa có bản dịch này',
+    'Category translation not found', 'Không tìm thấy bản dịch danh mục'),
+  ('TAG_TRANSLATION_NOT_FOUND', 'Tag chưa có bản dịch này',
+    'Tag translation not found', 'Không tìm thấy bản dịch thẻ'),
+  ('BANNER_TRANSLATION_NOT_FOUND', 'Banner chưa có bản dịch này',
+    'Banner translation not found', 'Không tìm thấy bản dịch banner'),
+  ('TRANSLATION_KEY_NOT_FOUND', 'Không có message key',
+    'Translation key not found', 'Không tìm thấy khoá chuỗi giao diện'),
+  ('TRANSLATION_VALUE_NOT_FOUND', 'Message key chưa có bản dịch này',
+    'Translation value not found', 'Không tìm thấy bản dịch của chuỗi giao diện'),
+  ('NAMESPACE_NOT_FOUND', 'Không có namespace chuỗi giao diện; {namespace}',
+    'Namespace {namespace} not found', 'Không tìm thấy namespace {namespace}'),
+  ('FEED_NOT_FOUND', 'Không có feed', 'Feed not found', 'Không tìm thấy feed'),
+  ('SITEMAP_NOT_FOUND', 'Không có sitemap', 'Sitemap not found', 'Không tìm thấy sitemap'),
+  ('POST_STATUS_TRANSITION_INVALID', 'Chuyển trạng thái bài viết không hợp lệ; {from}, {to}',
+    'A post cannot move from {from} to {to}', 'Không thể chuyển bài viết từ {from} sang {to}'),
+  ('POST_SCHEDULE_PUBLISH_AT_REQUIRED', 'Hẹn giờ đăng thiếu published_at',
+    'Scheduled posts need a publish time', 'Bài viết hẹn giờ cần có thời điểm đăng'),
+  ('POST_SCHEDULE_UNPUBLISH_BEFORE_PUBLISH', 'unpublish_at không sau published_at',
+    'The unpublish time must be after the publish time', 'Thời điểm gỡ bài phải sau thời điểm đăng'),
+  ('POST_REVIEWER_MISSING_PERMISSION', 'Người duyệt thiếu quyền; {id}, {permission}',
+    'Reviewer {id} does not have the {permission} permission', 'Người duyệt {id} không có quyền {permission}'),
+  ('POST_MEDIA_LIMIT', 'Gắn quá nhiều media; {max}',
+    'A post can have at most {max} media items', 'Mỗi bài viết có tối đa {max} media'),
+  ('REDIRECT_SOURCE_EXISTS', 'Trùng source_path; {source_path}',
+    'A redirect from {source_path} already exists', 'Đã có chuyển hướng từ {source_path}'),
+  ('TRANSLATION_KEY_EXISTS', 'Trùng message key; {key}, {namespace}',
+    'Key {key} already exists in namespace {namespace}', 'Khoá {key} đã tồn tại trong namespace {namespace}'),
+  ('FIELD_REQUIRED', 'Thiếu trường bắt buộc; {field}',
+    '{field} is required', 'Vui lòng nhập {field}'),
+  ('FIELD_TOO_LONG', 'Trường quá dài; {field}, {max}',
+    '{field} must be at most {max} characters', '{field} không được dài quá {max} ký tự'),
+  ('FIELD_INVALID', 'Giá trị không hợp lệ; {field}',
+    '{field} is invalid', '{field} không hợp lệ'),
+  ('VALIDATION_ERROR', 'Dữ liệu không hợp lệ; {field}, {reason} (tiếng Anh, có thể thiếu)',
+    'Invalid {field}: {reason}', 'Giá trị {field} không hợp lệ: {reason}'),
+  ('CONFLICT', 'Xung đột dữ liệu; {reason}',
+    'The request conflicts with the current data: {reason}', 'Yêu cầu xung đột với dữ liệu hiện tại: {reason}'),
+  ('NOT_FOUND', 'Không có tài nguyên; {resource}',
+    '{resource} not found', 'Không tìm thấy {resource}'),
+  ('INTERNAL_SERVER_ERROR', 'Lỗi hệ thống; {detail} (chỉ để debug)',
+    'Something went wrong, please try again later', 'Đã có lỗi xảy ra, vui lòng thử lại sau')
+),
+keys AS (
+  INSERT INTO translation_keys (namespace, key, description)
+  SELECT 'errors', code, description FROM messages
+  ON CONFLICT (namespace, key) WHERE deleted_at IS NULL DO NOTHING
+  RETURNING id, key
+)
+INSERT INTO translation_values (key_id, language_code, value)
+SELECT k.id, v.language_code, v.value
+FROM keys k
+JOIN messages m ON m.code = k.key
+CROSS JOIN LATERAL (VALUES ('en', m.en), ('vi', m.vi)) AS v (language_code, value)
+JOIN languages l ON l.code = v.language_code
+ON CONFLICT (key_id, language_code) DO NOTHING;
@@ -0,0 +1,4 @@
+-- Bài viết dạng block: sửa `content_blocks` cũng làm bản dịch lỗi thời (trigger ở migration 18 chỉ theo dõi `content`)
+DROP TRIGGER IF EXISTS posts_source_changed_at ON posts;
+CREATE TRIGGER posts_source_changed_at BEFORE UPDATE ON posts
+    FOR EACH ROW EXECUTE FUNCTION touch_source_changed_at('title', 'excerpt', 'content', 'content_blocks');
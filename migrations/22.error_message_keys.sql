@@ -0,0 +1,45 @@
+-- Mã message lỗi nay gắn ở nơi phát sinh lỗi (`error.details.messageKey`), không suy ra từ text:
+-- thêm mã mới, bỏ mã chỉ dùng để khớp text rejection của axum (nay gộp theo status)
+WITH messages (code, description, en, vi) AS (
+  VALUES
+  ('SLUG_EXISTS', 'Slug đã được dùng trong cùng phạm vi',
+    'This slug is already in use', 'Slug này đã được sử dụng'),
+  ('SETTING_NOT_FOUND', 'Không có setting',
+    'Setting not found', 'Không tìm thấy cài đặt'),
+  ('LANGUAGE_MIGRATE_SLUG_CONFLICT', 'Chuyển bản dịch khi xoá ngôn ngữ bị trùng slug; {slugs}',
+    'These slugs are already used in the target language: {slugs}', 'Các slug sau đã được dùng ở ngôn ngữ đích: {slugs}'),
+  ('INVALID_REQUEST', 'Request sai định dạng (JSON hỏng, query/path sai kiểu); {reason}',
+    'The request is invalid: {reason}', 'Yêu cầu không hợp lệ: {reason}')
+),
+keys AS (
+  INSERT INTO translation_keys (namespace, key, description)
+  SELECT 'errors', code, description FROM messages
+  ON CONFLICT (namespace, key) WHERE deleted_at IS NULL DO NOTHING
+  RETURNING id, key
+)
+INSERT INTO translation_values (key_id, language_code, value)
+SELECT k.id, v.language_code, v.value
+FROM keys k
+JOIN messages m ON m.code = k.key
+CROSS JOIN LATERAL (VALUES ('en', m.en), ('vi', m.vi)) AS v (language_code, value)
+JOIN languages l ON l.code = v.language_code
+ON CONFLICT (key_id, language_code) DO NOTHING;
+
+-- Lỗi 5xx không còn trả message gốc trong tham số `detail`
+UPDATE translation_keys
+SET description = 'Lỗi hệ thống (message gốc chỉ ghi log)', updated_at = NOW()
+WHERE namespace = 'errors' AND key = 'INTERNAL_SERVER_ERROR' AND deleted_at IS NULL;
+
+WITH removed AS (
+  UPDATE translation_keys
+  SET deleted_at = NOW(), updated_at = NOW()
+  WHERE namespace = 'errors' AND deleted_at IS NULL
+    AND key IN (
+      'MALFORMED_JSON_BODY', 'INVALID_QUERY', 'INVALID_PATH', 'FIELD_INVALID', 'FORBIDDEN', 'NOT_FOUND',
+      'CONFLICT'
+    )
+  RETURNING id
+)
+UPDATE translation_values
+SET deleted_at = NOW(), updated_at = NOW()
+WHERE key_id IN (SELECT id FROM removed) AND deleted_at IS NULL;
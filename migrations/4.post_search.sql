@@ -0,0 +1,43 @@
+-- Full-text search cho bài viết (posts + post_translations)
+-- Dùng tsvector generated column + GIN index, pg_trgm làm fallback khi gõ sai chính tả.
+
+-- Cấu hình text search theo ngôn ngữ của bản dịch.
+-- Ngôn ngữ không có stemmer riêng (vd: tiếng Việt) dùng 'simple'.
+CREATE OR REPLACE FUNCTION post_search_config(lang TEXT)
+RETURNS regconfig
+LANGUAGE sql
+IMMUTABLE
+AS $$
+    SELECT CASE lang
+        WHEN 'en' THEN 'english'::regconfig
+        WHEN 'fr' THEN 'french'::regconfig
+        WHEN 'de' THEN 'german'::regconfig
+        WHEN 'es' THEN 'spanish'::regconfig
+        ELSE 'simple'::regconfig
+    END
+$$;
+
+-- posts: title (A) > excerpt (B) > content (C)
+ALTER TABLE posts
+    ADD COLUMN IF NOT EXISTS search_vector tsvector GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple'::regconfig, coalesce(title, '')), 'A') ||
+        setweight(to_tsvector('simple'::regconfig, coalesce(excerpt, '')), 'B') ||
+        setweight(to_tsvector('simple'::regconfig, coalesce(content, '')), 'C')
+    ) STORED;
+
+-- post_translations: cấu hình theo language_code
+ALTER TABLE post_translations
+    ADD COLUMN IF NOT EXISTS search_vector tsvector GENERATED ALWAYS AS (
+        setweight(to_tsvector(post_search_config(language_code), coalesce(title, '')), 'A') ||
+        setweight(to_tsvector(post_search_config(language_code), coalesce(excerpt, '')), 'B') ||
+        setweight(to_tsvector(post_search_config(language_code), coalesce(content, '')), 'C')
+    ) STORED;
+
+CREATE INDEX IF NOT EXISTS idx_posts_search_vector ON posts USING GIN (search_vector);
+CREATE INDEX IF NOT EXISTS idx_post_translations_search_vector
+    ON post_translations USING GIN (search_vector);
+
+-- Trigram index cho fallback typo-tolerant trên title
+CREATE INDEX IF NOT EXISTS idx_posts_title_trgm ON posts USING GIN (title gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_post_translations_title_trgm
+    ON post_translations USING GIN (title gin_trgm_ops);
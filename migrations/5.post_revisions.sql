@@ -0,0 +1,26 @@
+-- Lịch sử chỉnh sửa bài viết: mỗi lần lưu (create/update/restore) tạo 1 snapshot
+CREATE TABLE IF NOT EXISTS post_revisions (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+    post_id UUID NOT NULL,
+    revision_number INT NOT NULL,
+    saved_by UUID, -- user thực hiện lần lưu
+    title TEXT NOT NULL,
+    slug TEXT,
+    excerpt TEXT,
+    content TEXT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP DEFAULT NULL,
+    FOREIGN KEY (post_id) REFERENCES posts(id),
+    FOREIGN KEY (saved_by) REFERENCES users(id)
+);
+
+ALTER TABLE post_revisions
+    ADD CONSTRAINT post_revisions_post_number_unique UNIQUE (post_id, revision_number);
+
+CREATE INDEX IF NOT EXISTS idx_post_revisions_post_id ON post_revisions(post_id);
+
+-- Số revision tối đa giữ lại cho mỗi bài viết (<= 0: không giới hạn)
+INSERT INTO settings (key, value, description)
+VALUES ('post_revision_retention', '50', 'Số revision tối đa giữ lại cho mỗi bài viết')
+ON CONFLICT (key) DO NOTHING;
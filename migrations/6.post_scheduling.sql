@@ -0,0 +1,12 @@
+-- Lên lịch publish / gỡ bài viết
+-- `scheduled`: bài chờ tới `published_at` để worker chuyển sang `published`
+-- `unpublish_at`: thời điểm worker chuyển bài `published` sang `archived`
+ALTER TYPE post_status ADD VALUE IF NOT EXISTS 'scheduled';
+
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS unpublish_at TIMESTAMP;
+
+-- Index cho worker quét bài tới hạn
+CREATE INDEX IF NOT EXISTS idx_posts_status_published_at
+    ON posts (status, published_at) WHERE deleted_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_posts_unpublish_at
+    ON posts (unpublish_at) WHERE unpublish_at IS NOT NULL AND deleted_at IS NULL;
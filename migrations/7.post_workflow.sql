@@ -0,0 +1,67 @@
+-- Quy trình biên tập: draft -> in_review -> (changes_requested | published/scheduled)
+ALTER TYPE post_status ADD VALUE IF NOT EXISTS 'in_review';
+ALTER TYPE post_status ADD VALUE IF NOT EXISTS 'changes_requested';
+
+-- Người review được gán cho bài viết
+CREATE TABLE IF NOT EXISTS post_reviewers (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+    post_id UUID NOT NULL,
+    reviewer_id UUID NOT NULL,
+    assigned_by UUID, -- user thực hiện gán
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP DEFAULT NULL,
+    FOREIGN KEY (post_id) REFERENCES posts(id),
+    FOREIGN KEY (reviewer_id) REFERENCES users(id),
+    FOREIGN KEY (assigned_by) REFERENCES users(id)
+);
+
+ALTER TABLE post_reviewers
+    ADD CONSTRAINT post_reviewers_post_reviewer_unique UNIQUE (post_id, reviewer_id);
+
+CREATE INDEX IF NOT EXISTS idx_post_reviewers_reviewer_id ON post_reviewers(reviewer_id);
+
+-- Nhận xét review, `decision` ghi lại kết quả (approved / changes_requested) nếu có
+CREATE TABLE IF NOT EXISTS post_review_comments (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+    post_id UUID NOT NULL,
+    author_id UUID NOT NULL,
+    decision TEXT,
+    body TEXT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP DEFAULT NULL,
+    FOREIGN KEY (post_id) REFERENCES posts(id),
+    FOREIGN KEY (author_id) REFERENCES users(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_post_review_comments_post_id ON post_review_comments(post_id);
+
+-- Quyền cho quy trình biên tập
+INSERT INTO permissions (name)
+VALUES
+  ('post:review'),
+  ('post:publish')
+ON CONFLICT (name) DO NOTHING;
+
+-- Admin có toàn bộ quyền
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+JOIN permissions p ON p.name IN ('post:review', 'post:publish')
+WHERE r.name = 'admin'
+  AND NOT EXISTS (
+    SELECT 1 FROM role_permissions rp WHERE rp.role_id = r.id AND rp.permission_id = p.id
+  );
+
+-- Editor: viết, review và publish bài
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+JOIN permissions p ON p.name IN (
+  'post:create', 'post:read', 'post:read_all', 'post:update', 'post:review', 'post:publish'
+)
+WHERE r.name = 'editor'
+  AND NOT EXISTS (
+    SELECT 1 FROM role_permissions rp WHERE rp.role_id = r.id AND rp.permission_id = p.id
+  );
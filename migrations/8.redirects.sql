@@ -0,0 +1,52 @@
+-- Lịch sử slug: slug cũ của post/category trỏ về entity để trả 301 tới slug hiện tại
+CREATE TABLE IF NOT EXISTS slug_redirects (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+    entity_type TEXT NOT NULL, -- post | category | ...
+    scope TEXT NOT NULL DEFAULT '', -- type của category/tag, rỗng với post
+    entity_id UUID NOT NULL,
+    old_slug TEXT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP DEFAULT NULL
+);
+
+ALTER TABLE slug_redirects
+    ADD CONSTRAINT slug_redirects_entity_slug_unique UNIQUE (entity_type, scope, old_slug);
+
+CREATE INDEX IF NOT EXISTS idx_slug_redirects_entity ON slug_redirects(entity_type, entity_id);
+
+-- Redirect do admin quản lý (source path -> target), frontend tra cứu khi gặp 404
+CREATE TABLE IF NOT EXISTS redirects (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v7(),
+    source_path TEXT NOT NULL,
+    target TEXT NOT NULL, -- path nội bộ hoặc URL đầy đủ
+    status_code INT NOT NULL DEFAULT 301 CHECK (status_code IN (301, 302)),
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    hit_count BIGINT NOT NULL DEFAULT 0,
+    last_hit_at TIMESTAMP,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP DEFAULT NULL
+);
+
+-- source_path unique trong các redirect chưa xoá
+CREATE UNIQUE INDEX IF NOT EXISTS redirects_source_path_unique
+    ON redirects(source_path) WHERE deleted_at IS NULL;
+
+-- Quyền quản lý redirect
+INSERT INTO permissions (name)
+VALUES
+  ('redirect:create'),
+  ('redirect:read'),
+  ('redirect:update'),
+  ('redirect:delete')
+ON CONFLICT (name) DO NOTHING;
+
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+JOIN permissions p ON p.name LIKE 'redirect:%'
+WHERE r.name = 'admin'
+  AND NOT EXISTS (
+    SELECT 1 FROM role_permissions rp WHERE rp.role_id = r.id AND rp.permission_id = p.id
+  );
@@ -0,0 +1,18 @@
+-- Định dạng nội dung bài viết và kết quả render (HTML đã sanitize, mục lục, thống kê)
+DO $$ BEGIN
+    CREATE TYPE post_content_format AS ENUM ('markdown', 'html', 'plain');
+EXCEPTION
+    WHEN duplicate_object THEN null;
+END $$;
+
+-- Bài cũ giữ 'html' vì frontend vẫn render `content` như HTML
+ALTER TABLE posts
+    ADD COLUMN IF NOT EXISTS content_format post_content_format NOT NULL DEFAULT 'html',
+    ADD COLUMN IF NOT EXISTS content_html TEXT, -- HTML đã sanitize, NULL với bài chưa render lại
+    ADD COLUMN IF NOT EXISTS toc JSONB, -- mục lục [{level, text, anchor}]
+    ADD COLUMN IF NOT EXISTS word_count INT NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS reading_time_minutes INT NOT NULL DEFAULT 0;
+
+-- Revision lưu kèm định dạng để restore render đúng
+ALTER TABLE post_revisions
+    ADD COLUMN IF NOT EXISTS content_format post_content_format;
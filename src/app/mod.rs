@@ -1 +1,2 @@
+pub mod post_scheduler;
 pub mod state;
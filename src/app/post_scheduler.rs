@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::app::state::AppState;
+use crate::application::post::run_post_schedule::RunPostScheduleUseCase;
+
+/// Chu kỳ quét mặc định (giây), đổi qua env `POST_SCHEDULER_INTERVAL_SECS`
+const DEFAULT_INTERVAL_SECS: u64 = 60;
+
+/// Chạy worker publish/gỡ bài theo lịch trong background.
+/// An toàn khi chạy nhiều instance: mỗi bài chỉ được 1 instance khoá và xử lý.
+pub fn spawn(state: Arc<AppState>) -> tokio::task::JoinHandle<()> {
+    let interval_secs = std::env::var("POST_SCHEDULER_INTERVAL_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|v| *v > 0)
+        .unwrap_or(DEFAULT_INTERVAL_SECS);
+
+    tokio::spawn(async move {
+        let usecase = RunPostScheduleUseCase::new(state.post_repo.clone());
+        let mut ticker = tokio::time::interval(Duration::from_secs(interval_secs));
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match usecase.execute().await {
+                Ok(transitions) if !transitions.is_empty() => {
+                    tracing::info!(transitions = transitions.len(), "post scheduler ran");
+                }
+                Ok(_) => {}
+                Err(e) => tracing::error!(error = %e, "post scheduler failed"),
+            }
+        }
+    })
+}
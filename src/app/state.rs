@@ -1,11 +1,23 @@
 use crate::domain::repositories::{
     audit_log_repository::AuditLogRepository, banner_repository::BannerRepository,
-    category_repository::CategoryRepository, language_repository::LanguageRepository,
-    media_repository::MediaRepository, permission_repository::PermissionRepository,
-    post_repository::PostRepository, role_repository::RoleRepository,
-    setting_repository::SettingRepository, tag_repository::TagRepository,
+    banner_translation_repository::BannerTranslationRepository,
+    category_repository::CategoryRepository,
+    category_translation_repository::CategoryTranslationRepository,
+    language_repository::LanguageRepository, media_repository::MediaRepository,
+    permission_repository::PermissionRepository, post_media_repository::PostMediaRepository,
+    post_repository::PostRepository, post_review_repository::PostReviewRepository,
+    post_revision_repository::PostRevisionRepository,
+    post_translation_repository::PostTranslationRepository,
+    redirect_repository::RedirectRepository, role_repository::RoleRepository,
+    seo_metadata_repository::SeoMetadataRepository, setting_repository::SettingRepository,
+    sitemap_repository::SitemapRepository, slug_redirect_repository::SlugRedirectRepository,
+    slug_repository::SlugRepository, tag_repository::TagRepository,
+    tag_translation_repository::TagTranslationRepository,
+    translation_key_repository::TranslationKeyRepository,
+    translation_status_repository::TranslationStatusRepository,
     user_profile_repository::UserProfileRepository, user_repository::UserRepository,
 };
+use crate::shared::sitemap_cache::SitemapCache;
 use std::sync::Arc;
 
 pub struct AppState {
@@ -19,6 +31,24 @@ pub struct AppState {
     pub category_repo: Arc<dyn CategoryRepository>,
     pub tag_repo: Arc<dyn TagRepository>,
     pub post_repo: Arc<dyn PostRepository>,
+    pub post_revision_repo: Arc<dyn PostRevisionRepository>,
+    pub post_review_repo: Arc<dyn PostReviewRepository>,
+    pub post_media_repo: Arc<dyn PostMediaRepository>,
     pub language_repo: Arc<dyn LanguageRepository>,
     pub audit_log_repo: Arc<dyn AuditLogRepository>,
+    pub slug_repo: Arc<dyn SlugRepository>,
+    pub slug_redirect_repo: Arc<dyn SlugRedirectRepository>,
+    pub redirect_repo: Arc<dyn RedirectRepository>,
+    pub seo_metadata_repo: Arc<dyn SeoMetadataRepository>,
+    pub sitemap_repo: Arc<dyn SitemapRepository>,
+    pub post_translation_repo: Arc<dyn PostTranslationRepository>,
+    pub category_translation_repo: Arc<dyn CategoryTranslationRepository>,
+    pub tag_translation_repo: Arc<dyn TagTranslationRepository>,
+    pub banner_translation_repo: Arc<dyn BannerTranslationRepository>,
+    /// Độ phủ bản dịch / hàng đợi cần dịch (view `translation_status`)
+    pub translation_status_repo: Arc<dyn TranslationStatusRepository>,
+    /// Chuỗi giao diện (message key) cho frontend
+    pub translation_key_repo: Arc<dyn TranslationKeyRepository>,
+    /// Cache sitemap XML, được repo post/category/tag invalidate khi ghi
+    pub sitemap_cache: Arc<SitemapCache>,
 }
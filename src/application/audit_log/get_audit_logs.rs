@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::domain::entities::audit_log::AuditLog;
 use crate::domain::repositories::audit_log_repository::{AuditLogFilter, AuditLogRepository};
+use crate::shared::error::AppError;
 
 pub struct GetAuditLogsUseCase {
     repo: Arc<dyn AuditLogRepository>,
@@ -12,10 +13,10 @@ impl GetAuditLogsUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, filter: AuditLogFilter) -> Result<Vec<AuditLog>, String> {
+    pub async fn execute(&self, filter: AuditLogFilter) -> Result<Vec<AuditLog>, AppError> {
         self.repo
             .search(&filter)
             .await
-            .map_err(|e| format!("Failed to search audit logs: {}", e))
+            .map_err(|e| format!("Failed to search audit logs: {}", e).into())
     }
 }
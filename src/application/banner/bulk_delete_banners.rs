@@ -3,6 +3,7 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::domain::repositories::banner_repository::BannerRepository;
+use crate::shared::error::AppError;
 
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct BulkDeleteBannersInput {
@@ -18,7 +19,7 @@ impl BulkDeleteBannersUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, input: BulkDeleteBannersInput) -> Result<(), String> {
-        self.repo.soft_delete_many(&input.ids).await
+    pub async fn execute(&self, input: BulkDeleteBannersInput) -> Result<(), AppError> {
+        Ok(self.repo.soft_delete_many(&input.ids).await?)
     }
 }
@@ -2,8 +2,11 @@ use std::sync::Arc;
 
 use uuid::Uuid;
 
+use crate::application::common::resolve_slug::save_with_slug;
 use crate::domain::entities::banner::Banner;
 use crate::domain::repositories::banner_repository::BannerRepository;
+use crate::domain::repositories::slug_repository::{SlugRepository, SlugScope};
+use crate::shared::error::AppError;
 
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct CreateBannerInput {
@@ -18,18 +21,19 @@ pub struct CreateBannerInput {
 
 pub struct CreateBannerUseCase {
     repo: Arc<dyn BannerRepository>,
+    slug_repo: Arc<dyn SlugRepository>,
 }
 
 impl CreateBannerUseCase {
-    pub fn new(repo: Arc<dyn BannerRepository>) -> Self {
-        Self { repo }
+    pub fn new(repo: Arc<dyn BannerRepository>, slug_repo: Arc<dyn SlugRepository>) -> Self {
+        Self { repo, slug_repo }
     }
 
-    pub async fn execute(&self, input: CreateBannerInput) -> Result<Banner, String> {
+    pub async fn execute(&self, input: CreateBannerInput) -> Result<Banner, AppError> {
         let banner = Banner {
             id: Uuid::new_v4(),
             title: input.title,
-            slug: input.slug,
+            slug: None,
             key: input.key,
             description: input.description,
             is_active: input.is_active.or(Some(true)),
@@ -40,6 +44,20 @@ impl CreateBannerUseCase {
             deleted_at: None,
         };
 
-        self.repo.create(banner).await
+        let (repo, banner) = (&self.repo, &banner);
+        save_with_slug(
+            self.slug_repo.as_ref(),
+            SlugScope::Banner,
+            input.slug.as_deref(),
+            &banner.title,
+            None,
+            move |slug| {
+                repo.create(Banner {
+                    slug: Some(slug),
+                    ..banner.clone()
+                })
+            },
+        )
+        .await
     }
 }
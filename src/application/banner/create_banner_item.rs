@@ -4,6 +4,7 @@ use uuid::Uuid;
 
 use crate::domain::entities::banner_item::BannerItem;
 use crate::domain::repositories::banner_repository::BannerRepository;
+use crate::shared::error::AppError;
 
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct CreateBannerItemInput {
@@ -24,7 +25,7 @@ impl CreateBannerItemUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, input: CreateBannerItemInput) -> Result<BannerItem, String> {
+    pub async fn execute(&self, input: CreateBannerItemInput) -> Result<BannerItem, AppError> {
         let item = BannerItem {
             id: Uuid::new_v4(),
             banner_id: input.banner_id,
@@ -38,6 +39,6 @@ impl CreateBannerItemUseCase {
             deleted_at: None,
         };
 
-        self.repo.create_item(item).await
+        Ok(self.repo.create_item(item).await?)
     }
 }
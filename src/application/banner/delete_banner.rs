@@ -3,6 +3,7 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::domain::repositories::banner_repository::BannerRepository;
+use crate::shared::error::AppError;
 
 pub struct DeleteBannerUseCase {
     repo: Arc<dyn BannerRepository>,
@@ -13,7 +14,7 @@ impl DeleteBannerUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, id: Uuid) -> Result<(), String> {
-        self.repo.soft_delete(id).await
+    pub async fn execute(&self, id: Uuid) -> Result<(), AppError> {
+        Ok(self.repo.soft_delete(id).await?)
     }
 }
@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::entities::banner::Banner;
+use crate::domain::entities::banner_item::BannerItem;
+use crate::domain::repositories::banner_repository::BannerRepository;
+use crate::shared::error::AppError;
+
+/// Các quan hệ có thể include cho banner
+pub const BANNER_INCLUDES: &[&str] = &["items"];
+
+/// Banner kèm item khi có `include=items`
+#[derive(Debug, Clone, Serialize)]
+pub struct BannerView {
+    #[serde(flatten)]
+    pub banner: Banner,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<BannerItem>>,
+}
+
+pub struct EmbedBannerItemsUseCase {
+    repo: Arc<dyn BannerRepository>,
+}
+
+impl EmbedBannerItemsUseCase {
+    pub fn new(repo: Arc<dyn BannerRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Nhúng item cho danh sách banner bằng một query duy nhất
+    pub async fn execute(
+        &self,
+        banners: Vec<Banner>,
+        includes: &[String],
+    ) -> Result<Vec<BannerView>, AppError> {
+        let items = if includes.iter().any(|i| i == "items") {
+            let ids: Vec<Uuid> = banners.iter().map(|b| b.id).collect();
+            let mut grouped: HashMap<Uuid, Vec<BannerItem>> = HashMap::new();
+            for item in self.repo.list_items_by_banner_ids(&ids).await? {
+                grouped.entry(item.banner_id).or_default().push(item);
+            }
+            Some(grouped)
+        } else {
+            None
+        };
+
+        Ok(banners
+            .into_iter()
+            .map(|banner| BannerView {
+                items: items
+                    .as_ref()
+                    .map(|m| m.get(&banner.id).cloned().unwrap_or_default()),
+                banner,
+            })
+            .collect())
+    }
+}
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::domain::entities::banner::Banner;
 use crate::domain::entities::banner_item::BannerItem;
 use crate::domain::repositories::banner_repository::BannerRepository;
+use crate::shared::error::AppError;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BannerWithItems {
@@ -21,7 +22,7 @@ impl GetBannerByKeyUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, key: &str) -> Result<Option<BannerWithItems>, String> {
+    pub async fn execute(&self, key: &str) -> Result<Option<BannerWithItems>, AppError> {
         if let Some(banner) = self.repo.get_by_key(key).await? {
             let items = self.repo.list_items(banner.id).await?;
             Ok(Some(BannerWithItems { banner, items }))
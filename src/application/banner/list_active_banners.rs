@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::domain::entities::banner::Banner;
 use crate::domain::repositories::banner_repository::BannerRepository;
+use crate::shared::error::AppError;
 
 pub struct ListActiveBannersUseCase {
     repo: Arc<dyn BannerRepository>,
@@ -12,7 +13,7 @@ impl ListActiveBannersUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self) -> Result<Vec<Banner>, String> {
-        self.repo.list_active().await
+    pub async fn execute(&self) -> Result<Vec<Banner>, AppError> {
+        Ok(self.repo.list_active().await?)
     }
 }
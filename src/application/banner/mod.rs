@@ -3,6 +3,7 @@ pub mod create_banner;
 pub mod create_banner_item;
 pub mod delete_banner;
 pub mod delete_banner_item;
+pub mod embed_banner_items;
 pub mod get_banner_by_key;
 pub mod list_active_banners;
 pub mod update_banner;
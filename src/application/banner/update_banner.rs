@@ -2,8 +2,11 @@ use std::sync::Arc;
 
 use uuid::Uuid;
 
+use crate::application::common::resolve_slug::{save_with_slug, slug_conflict_error};
 use crate::domain::entities::banner::Banner;
 use crate::domain::repositories::banner_repository::BannerRepository;
+use crate::domain::repositories::slug_repository::{SlugRepository, SlugScope};
+use crate::shared::error::AppError;
 
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct UpdateBannerInput {
@@ -18,24 +21,34 @@ pub struct UpdateBannerInput {
 
 pub struct UpdateBannerUseCase {
     repo: Arc<dyn BannerRepository>,
+    slug_repo: Arc<dyn SlugRepository>,
 }
 
 impl UpdateBannerUseCase {
-    pub fn new(repo: Arc<dyn BannerRepository>) -> Self {
-        Self { repo }
+    pub fn new(repo: Arc<dyn BannerRepository>, slug_repo: Arc<dyn SlugRepository>) -> Self {
+        Self { repo, slug_repo }
     }
 
-    pub async fn execute(&self, id: Uuid, input: UpdateBannerInput) -> Result<Banner, String> {
+    pub async fn execute(&self, id: Uuid, input: UpdateBannerInput) -> Result<Banner, AppError> {
         let existing = self
             .repo
             .get_by_id(id)
             .await?
-            .ok_or_else(|| "Banner not found".to_string())?;
+            .ok_or_else(|| AppError::not_found("BANNER_NOT_FOUND", "Banner"))?;
+
+        let title = input.title.unwrap_or(existing.title);
+        // Giữ slug hiện tại, chỉ sinh lại khi client gửi slug mới hoặc bản ghi chưa có slug
+        let current_slug = match existing.slug.as_deref() {
+            Some(current) if input.slug.is_none() && !current.is_empty() => {
+                Some(current.to_string())
+            }
+            _ => None,
+        };
 
         let banner = Banner {
             id: existing.id,
-            title: input.title.unwrap_or(existing.title),
-            slug: input.slug.or(existing.slug),
+            title,
+            slug: None,
             key: input.key.or(existing.key),
             description: input.description.or(existing.description),
             is_active: input.is_active.or(existing.is_active),
@@ -46,6 +59,26 @@ impl UpdateBannerUseCase {
             deleted_at: existing.deleted_at,
         };
 
-        self.repo.update(banner).await
+        let (repo, banner) = (&self.repo, &banner);
+        let save = move |slug: String| {
+            repo.update(Banner {
+                slug: Some(slug),
+                ..banner.clone()
+            })
+        };
+        match current_slug {
+            Some(current) => save(current).await.map_err(slug_conflict_error),
+            None => {
+                save_with_slug(
+                    self.slug_repo.as_ref(),
+                    SlugScope::Banner,
+                    input.slug.as_deref(),
+                    &banner.title,
+                    Some(existing.id),
+                    save,
+                )
+                .await
+            }
+        }
     }
 }
@@ -4,6 +4,7 @@ use uuid::Uuid;
 
 use crate::domain::entities::banner_item::BannerItem;
 use crate::domain::repositories::banner_repository::BannerRepository;
+use crate::shared::error::AppError;
 
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct UpdateBannerItemInput {
@@ -28,12 +29,12 @@ impl UpdateBannerItemUseCase {
         &self,
         id: Uuid,
         input: UpdateBannerItemInput,
-    ) -> Result<BannerItem, String> {
+    ) -> Result<BannerItem, AppError> {
         let existing = self
             .repo
             .find_item_by_id(id)
             .await?
-            .ok_or_else(|| "Banner item not found".to_string())?;
+            .ok_or_else(|| AppError::not_found("BANNER_ITEM_NOT_FOUND", "Banner item"))?;
 
         let item = BannerItem {
             id: existing.id,
@@ -48,6 +49,6 @@ impl UpdateBannerItemUseCase {
             deleted_at: existing.deleted_at,
         };
 
-        self.repo.update_item(item).await
+        Ok(self.repo.update_item(item).await?)
     }
 }
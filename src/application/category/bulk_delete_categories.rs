@@ -3,6 +3,7 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::domain::repositories::category_repository::CategoryRepository;
+use crate::shared::error::AppError;
 
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct BulkDeleteCategoriesInput {
@@ -18,7 +19,7 @@ impl BulkDeleteCategoriesUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, input: BulkDeleteCategoriesInput) -> Result<(), String> {
-        self.repo.soft_delete_many(&input.ids).await
+    pub async fn execute(&self, input: BulkDeleteCategoriesInput) -> Result<(), AppError> {
+        Ok(self.repo.soft_delete_many(&input.ids).await?)
     }
 }
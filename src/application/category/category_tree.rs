@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::entities::category::Category;
+use crate::domain::repositories::category_repository::CategoryRepository;
+use crate::shared::error::AppError;
+
+/// Số tầng lồng tối đa của cây category (cũng là giới hạn `?depth=`)
+pub const MAX_CATEGORY_DEPTH: i32 = 32;
+
+/// Category kèm các category con, `depth` = 1 với tầng trên cùng của kết quả
+#[derive(Debug, Clone, Serialize)]
+pub struct CategoryTreeNode {
+    #[serde(flatten)]
+    pub category: Category,
+    pub depth: i32,
+    pub children: Vec<CategoryTreeNode>,
+}
+
+/// Phần tử của danh sách con cháu (duyệt theo chiều sâu), `depth` = 1 với con trực tiếp
+#[derive(Debug, Clone, Serialize)]
+pub struct CategoryDescendant {
+    #[serde(flatten)]
+    pub category: Category,
+    pub depth: i32,
+}
+
+/// `?depth=`: số tầng trả về, mặc định toàn bộ cây
+pub fn parse_depth(depth: Option<i32>) -> Result<i32, AppError> {
+    match depth {
+        None => Ok(MAX_CATEGORY_DEPTH),
+        Some(depth) if (1..=MAX_CATEGORY_DEPTH).contains(&depth) => Ok(depth),
+        Some(_) => Err(AppError::invalid(
+            "depth",
+            format!("must be between 1 and {MAX_CATEGORY_DEPTH}"),
+        )),
+    }
+}
+
+/// Dựng cây từ danh sách phẳng, bắt đầu từ các con của `root` (`None` = category gốc).
+/// Gọi sau khi đã dịch để anh em được sắp theo tên hiển thị
+pub fn build_category_tree(categories: Vec<Category>, root: Option<Uuid>) -> Vec<CategoryTreeNode> {
+    let mut children: HashMap<Option<Uuid>, Vec<Category>> = HashMap::new();
+    for category in categories {
+        children
+            .entry(category.parent_id)
+            .or_default()
+            .push(category);
+    }
+    for siblings in children.values_mut() {
+        siblings.sort_by_cached_key(|c| c.name.to_lowercase());
+    }
+    attach_children(&mut children, root, 1)
+}
+
+fn attach_children(
+    children: &mut HashMap<Option<Uuid>, Vec<Category>>,
+    parent: Option<Uuid>,
+    depth: i32,
+) -> Vec<CategoryTreeNode> {
+    // `remove` => mỗi category chỉ được gắn 1 lần, dữ liệu trỏ vòng cũng không lặp vô hạn
+    let Some(siblings) = children.remove(&parent) else {
+        return Vec::new();
+    };
+    siblings
+        .into_iter()
+        .map(|category| {
+            let children = attach_children(children, Some(category.id), depth + 1);
+            CategoryTreeNode {
+                category,
+                depth,
+                children,
+            }
+        })
+        .collect()
+}
+
+/// Làm phẳng cây theo thứ tự duyệt chiều sâu (cha đứng trước con)
+pub fn flatten_category_tree(nodes: Vec<CategoryTreeNode>) -> Vec<CategoryDescendant> {
+    let mut flat = Vec::new();
+    for node in nodes {
+        flat.push(CategoryDescendant {
+            category: node.category,
+            depth: node.depth,
+        });
+        flat.extend(flatten_category_tree(node.children));
+    }
+    flat
+}
+
+/// Category cha phải tồn tại, cùng `type`, không phải chính nó hoặc con cháu của nó
+/// (tránh cây bị trỏ vòng) và cả nhánh sau khi chuyển không vượt quá `MAX_CATEGORY_DEPTH` tầng
+pub async fn ensure_valid_parent(
+    repo: &dyn CategoryRepository,
+    id: Option<Uuid>,
+    parent_id: Option<Uuid>,
+    r#type: &str,
+) -> Result<(), AppError> {
+    let Some(parent_id) = parent_id else {
+        return Ok(());
+    };
+    if Some(parent_id) == id {
+        return Err(AppError::invalid(
+            "parent_id",
+            "a category cannot be its own parent",
+        ));
+    }
+    let parent = repo
+        .find_by_id(parent_id)
+        .await?
+        .ok_or_else(|| AppError::invalid("parent_id", format!("category {parent_id} not found")))?;
+    if parent.r#type != r#type {
+        return Err(AppError::invalid(
+            "parent_id",
+            format!("parent has type `{}`, expected `{type}`", parent.r#type),
+        ));
+    }
+
+    let ancestors = repo.find_ancestors(parent_id).await?;
+    if let Some(id) = id
+        && ancestors.iter().any(|c| c.id == id)
+    {
+        return Err(AppError::invalid(
+            "parent_id",
+            "a category cannot be moved under its own descendant",
+        ));
+    }
+    // Cả nhánh con của category được chuyển theo: tầng sâu nhất = tầng của nó + chiều cao nhánh
+    let height = match id {
+        Some(id) => subtree_height(repo, id).await?,
+        None => 0,
+    };
+    if ancestors.len() as i32 + 1 + height > MAX_CATEGORY_DEPTH {
+        return Err(AppError::invalid(
+            "parent_id",
+            format!("categories can be nested at most {MAX_CATEGORY_DEPTH} levels"),
+        ));
+    }
+    Ok(())
+}
+
+/// Số tầng con cháu bên dưới category `id` (0 nếu không có con)
+async fn subtree_height(repo: &dyn CategoryRepository, id: Uuid) -> Result<i32, AppError> {
+    let descendants = repo.find_descendants(id, MAX_CATEGORY_DEPTH).await?;
+    let tree = build_category_tree(descendants, Some(id));
+    Ok(flatten_category_tree(tree)
+        .iter()
+        .map(|c| c.depth)
+        .max()
+        .unwrap_or(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(n: u128) -> Uuid {
+        Uuid::from_u128(n)
+    }
+
+    fn category(n: u128, parent: Option<u128>, name: &str) -> Category {
+        Category {
+            id: id(n),
+            parent_id: parent.map(id),
+            name: name.to_string(),
+            slug: None,
+            r#type: "post".to_string(),
+            description: None,
+            created_at: None,
+            updated_at: None,
+            deleted_at: None,
+        }
+    }
+
+    /// Tin tức > (Thể thao > Bóng đá, Kinh tế); Giải trí
+    fn categories() -> Vec<Category> {
+        vec![
+            category(4, Some(2), "Bóng đá"),
+            category(1, None, "tin tức"),
+            category(3, Some(1), "Kinh tế"),
+            category(5, None, "Giải trí"),
+            category(2, Some(1), "Thể thao"),
+        ]
+    }
+
+    fn names(flat: &[CategoryDescendant]) -> Vec<(&str, i32)> {
+        flat.iter()
+            .map(|c| (c.category.name.as_str(), c.depth))
+            .collect()
+    }
+
+    #[test]
+    fn builds_nested_tree_with_siblings_sorted_by_name() {
+        let tree = build_category_tree(categories(), None);
+        let roots: Vec<&str> = tree.iter().map(|n| n.category.name.as_str()).collect();
+        assert_eq!(roots, vec!["Giải trí", "tin tức"]);
+        let news = &tree[1];
+        assert_eq!(news.depth, 1);
+        let children: Vec<(&str, i32)> = news
+            .children
+            .iter()
+            .map(|n| (n.category.name.as_str(), n.depth))
+            .collect();
+        assert_eq!(children, vec![("Kinh tế", 2), ("Thể thao", 2)]);
+        assert_eq!(news.children[1].children[0].depth, 3);
+    }
+
+    #[test]
+    fn flattens_depth_first_with_parents_before_children() {
+        let flat = flatten_category_tree(build_category_tree(categories(), None));
+        assert_eq!(
+            names(&flat),
+            vec![
+                ("Giải trí", 1),
+                ("tin tức", 1),
+                ("Kinh tế", 2),
+                ("Thể thao", 2),
+                ("Bóng đá", 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn subtree_starts_at_depth_one_below_root() {
+        let flat = flatten_category_tree(build_category_tree(categories(), Some(id(1))));
+        assert_eq!(
+            names(&flat),
+            vec![("Kinh tế", 1), ("Thể thao", 1), ("Bóng đá", 2)]
+        );
+    }
+
+    #[test]
+    fn cyclic_parents_do_not_loop() {
+        let cyclic = vec![category(1, Some(2), "A"), category(2, Some(1), "B")];
+        assert!(build_category_tree(cyclic.clone(), None).is_empty());
+        let flat = flatten_category_tree(build_category_tree(cyclic, Some(id(1))));
+        assert_eq!(names(&flat), vec![("B", 1), ("A", 2)]);
+    }
+
+    #[test]
+    fn depth_is_bounded() {
+        assert_eq!(parse_depth(None).unwrap(), MAX_CATEGORY_DEPTH);
+        assert_eq!(parse_depth(Some(2)).unwrap(), 2);
+        assert!(parse_depth(Some(0)).is_err());
+        assert!(parse_depth(Some(MAX_CATEGORY_DEPTH + 1)).is_err());
+    }
+}
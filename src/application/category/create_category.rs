@@ -2,8 +2,12 @@ use std::sync::Arc;
 
 use uuid::Uuid;
 
+use crate::application::category::category_tree::ensure_valid_parent;
+use crate::application::common::resolve_slug::save_with_slug;
 use crate::domain::entities::category::Category;
 use crate::domain::repositories::category_repository::CategoryRepository;
+use crate::domain::repositories::slug_repository::{SlugRepository, SlugScope};
+use crate::shared::error::AppError;
 
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct CreateCategoryInput {
@@ -16,19 +20,22 @@ pub struct CreateCategoryInput {
 
 pub struct CreateCategoryUseCase {
     repo: Arc<dyn CategoryRepository>,
+    slug_repo: Arc<dyn SlugRepository>,
 }
 
 impl CreateCategoryUseCase {
-    pub fn new(repo: Arc<dyn CategoryRepository>) -> Self {
-        Self { repo }
+    pub fn new(repo: Arc<dyn CategoryRepository>, slug_repo: Arc<dyn SlugRepository>) -> Self {
+        Self { repo, slug_repo }
     }
 
-    pub async fn execute(&self, input: CreateCategoryInput) -> Result<Category, String> {
+    pub async fn execute(&self, input: CreateCategoryInput) -> Result<Category, AppError> {
+        ensure_valid_parent(self.repo.as_ref(), None, input.parent_id, &input.r#type).await?;
+
         let category = Category {
             id: Uuid::new_v4(),
             parent_id: input.parent_id,
             name: input.name,
-            slug: input.slug,
+            slug: None,
             r#type: input.r#type,
             description: input.description,
             created_at: None,
@@ -36,6 +43,22 @@ impl CreateCategoryUseCase {
             deleted_at: None,
         };
 
-        self.repo.create(category).await
+        let (repo, category) = (&self.repo, &category);
+        save_with_slug(
+            self.slug_repo.as_ref(),
+            SlugScope::Category {
+                r#type: &category.r#type,
+            },
+            input.slug.as_deref(),
+            &category.name,
+            None,
+            move |slug| {
+                repo.create(Category {
+                    slug: Some(slug),
+                    ..category.clone()
+                })
+            },
+        )
+        .await
     }
 }
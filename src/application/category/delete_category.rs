@@ -3,6 +3,7 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::domain::repositories::category_repository::CategoryRepository;
+use crate::shared::error::AppError;
 
 pub struct DeleteCategoryUseCase {
     repo: Arc<dyn CategoryRepository>,
@@ -13,7 +14,7 @@ impl DeleteCategoryUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, id: Uuid) -> Result<(), String> {
-        self.repo.soft_delete(id).await
+    pub async fn execute(&self, id: Uuid) -> Result<(), AppError> {
+        Ok(self.repo.soft_delete(id).await?)
     }
 }
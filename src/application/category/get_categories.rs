@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::domain::entities::category::Category;
 use crate::domain::repositories::category_repository::CategoryRepository;
+use crate::shared::error::AppError;
 
 pub struct GetCategoriesUseCase {
     repo: Arc<dyn CategoryRepository>,
@@ -12,7 +13,7 @@ impl GetCategoriesUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, r#type: &str) -> Result<Vec<Category>, String> {
-        self.repo.list_by_type(r#type).await
+    pub async fn execute(&self, r#type: &str) -> Result<Vec<Category>, AppError> {
+        Ok(self.repo.list_by_type(r#type).await?)
     }
 }
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::domain::entities::category::Category;
 use crate::domain::repositories::category_repository::CategoryRepository;
+use crate::shared::error::AppError;
 
 pub struct GetCategoryUseCase {
     repo: Arc<dyn CategoryRepository>,
@@ -12,7 +13,7 @@ impl GetCategoryUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, slug: &str, r#type: &str) -> Result<Option<Category>, String> {
-        self.repo.find_by_slug_and_type(slug, r#type).await
+    pub async fn execute(&self, slug: &str, r#type: &str) -> Result<Option<Category>, AppError> {
+        Ok(self.repo.find_by_slug_and_type(slug, r#type).await?)
     }
 }
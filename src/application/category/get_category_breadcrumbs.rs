@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use crate::domain::entities::category::Category;
+use crate::domain::repositories::category_repository::CategoryRepository;
+use crate::shared::error::AppError;
+
+/// Chuỗi category từ gốc tới category có `slug` (bao gồm chính nó), `None` nếu không có
+/// category mang slug này (handler tra slug cũ để redirect)
+pub struct GetCategoryBreadcrumbsUseCase {
+    repo: Arc<dyn CategoryRepository>,
+}
+
+impl GetCategoryBreadcrumbsUseCase {
+    pub fn new(repo: Arc<dyn CategoryRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        slug: &str,
+        r#type: &str,
+    ) -> Result<Option<Vec<Category>>, AppError> {
+        let Some(category) = self.repo.find_by_slug_and_type(slug, r#type).await? else {
+            return Ok(None);
+        };
+        Ok(Some(self.repo.find_ancestors(category.id).await?))
+    }
+}
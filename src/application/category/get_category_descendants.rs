@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use crate::application::category::category_tree::parse_depth;
+use crate::domain::entities::category::Category;
+use crate::domain::repositories::category_repository::CategoryRepository;
+use crate::shared::error::AppError;
+
+/// Category có `slug` và các category con cháu của nó (tối đa `depth` tầng)
+pub struct CategorySubtree {
+    pub root: Category,
+    pub descendants: Vec<Category>,
+}
+
+/// `None` nếu không có category mang slug này (handler tra slug cũ để redirect)
+pub struct GetCategoryDescendantsUseCase {
+    repo: Arc<dyn CategoryRepository>,
+}
+
+impl GetCategoryDescendantsUseCase {
+    pub fn new(repo: Arc<dyn CategoryRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        slug: &str,
+        r#type: &str,
+        depth: Option<i32>,
+    ) -> Result<Option<CategorySubtree>, AppError> {
+        let depth = parse_depth(depth)?;
+        let Some(root) = self.repo.find_by_slug_and_type(slug, r#type).await? else {
+            return Ok(None);
+        };
+        let descendants = self.repo.find_descendants(root.id, depth).await?;
+        Ok(Some(CategorySubtree { root, descendants }))
+    }
+}
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::application::category::category_tree::parse_depth;
+use crate::domain::entities::category::Category;
+use crate::domain::repositories::category_repository::CategoryRepository;
+use crate::shared::error::AppError;
+
+/// Toàn bộ category của `type` theo cây (danh sách phẳng, dựng cây bằng `build_category_tree`)
+pub struct GetCategoryTreeUseCase {
+    repo: Arc<dyn CategoryRepository>,
+}
+
+impl GetCategoryTreeUseCase {
+    pub fn new(repo: Arc<dyn CategoryRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        r#type: &str,
+        depth: Option<i32>,
+    ) -> Result<Vec<Category>, AppError> {
+        let depth = parse_depth(depth)?;
+        Ok(self.repo.list_tree(r#type, depth).await?)
+    }
+}
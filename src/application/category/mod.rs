@@ -1,6 +1,10 @@
 pub mod bulk_delete_categories;
+pub mod category_tree;
 pub mod create_category;
 pub mod delete_category;
 pub mod get_categories;
 pub mod get_category;
+pub mod get_category_breadcrumbs;
+pub mod get_category_descendants;
+pub mod get_category_tree;
 pub mod update_category;
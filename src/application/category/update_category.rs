@@ -2,8 +2,12 @@ use std::sync::Arc;
 
 use uuid::Uuid;
 
+use crate::application::category::category_tree::ensure_valid_parent;
+use crate::application::common::resolve_slug::{save_with_slug, slug_conflict_error};
 use crate::domain::entities::category::Category;
 use crate::domain::repositories::category_repository::CategoryRepository;
+use crate::domain::repositories::slug_repository::{SlugRepository, SlugScope};
+use crate::shared::error::AppError;
 
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct UpdateCategoryInput {
@@ -16,32 +20,84 @@ pub struct UpdateCategoryInput {
 
 pub struct UpdateCategoryUseCase {
     repo: Arc<dyn CategoryRepository>,
+    slug_repo: Arc<dyn SlugRepository>,
 }
 
 impl UpdateCategoryUseCase {
-    pub fn new(repo: Arc<dyn CategoryRepository>) -> Self {
-        Self { repo }
+    pub fn new(repo: Arc<dyn CategoryRepository>, slug_repo: Arc<dyn SlugRepository>) -> Self {
+        Self { repo, slug_repo }
     }
 
-    pub async fn execute(&self, id: Uuid, input: UpdateCategoryInput) -> Result<Category, String> {
+    pub async fn execute(
+        &self,
+        id: Uuid,
+        input: UpdateCategoryInput,
+    ) -> Result<Category, AppError> {
         let existing = self
             .repo
             .find_by_id(id)
             .await?
-            .ok_or_else(|| "Category not found".to_string())?;
+            .ok_or_else(|| AppError::not_found("CATEGORY_NOT_FOUND", "Category"))?;
+
+        let old_type = existing.r#type.clone();
+        let name = input.name.unwrap_or(existing.name);
+        let changes_tree = input.parent_id.is_some() || input.r#type.is_some();
+        let parent_id = input.parent_id.or(existing.parent_id);
+        let r#type = input.r#type.unwrap_or(existing.r#type);
+        // Category con phải cùng `type` với cha => chỉ đổi `type` khi chưa có category con
+        if r#type != old_type && !self.repo.find_descendants(id, 1).await?.is_empty() {
+            return Err(AppError::invalid(
+                "type",
+                "a category that has subcategories cannot change its type",
+            ));
+        }
+        if changes_tree {
+            ensure_valid_parent(self.repo.as_ref(), Some(existing.id), parent_id, &r#type).await?;
+        }
+        // Giữ slug hiện tại, chỉ sinh lại khi client gửi slug mới hoặc bản ghi chưa có slug
+        let current_slug = match existing.slug.as_deref() {
+            Some(current) if input.slug.is_none() && !current.is_empty() => {
+                Some(current.to_string())
+            }
+            _ => None,
+        };
 
         let category = Category {
             id: existing.id,
-            parent_id: input.parent_id.or(existing.parent_id),
-            name: input.name.unwrap_or(existing.name),
-            slug: input.slug.or(existing.slug),
-            r#type: input.r#type.unwrap_or(existing.r#type),
+            parent_id,
+            name,
+            slug: None,
+            r#type,
             description: input.description.or(existing.description),
             created_at: existing.created_at,
             updated_at: existing.updated_at,
             deleted_at: existing.deleted_at,
         };
 
-        self.repo.update(category).await
+        let (repo, category) = (&self.repo, &category);
+        let save = move |slug: String| {
+            repo.update(Category {
+                slug: Some(slug),
+                ..category.clone()
+            })
+        };
+        let updated = match current_slug {
+            Some(current) => save(current).await.map_err(slug_conflict_error)?,
+            None => {
+                save_with_slug(
+                    self.slug_repo.as_ref(),
+                    SlugScope::Category {
+                        r#type: &category.r#type,
+                    },
+                    input.slug.as_deref(),
+                    &category.name,
+                    Some(existing.id),
+                    save,
+                )
+                .await?
+            }
+        };
+
+        Ok(updated)
     }
 }
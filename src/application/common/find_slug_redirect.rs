@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::domain::repositories::slug_redirect_repository::{
+    SlugRedirectRepository, SlugRedirectTarget,
+};
+use crate::domain::repositories::slug_repository::SlugScope;
+use crate::shared::error::AppError;
+
+/// Tra slug cũ trong lịch sử, trả slug (và type) hiện tại để handler redirect 301
+pub struct FindSlugRedirectUseCase {
+    repo: Arc<dyn SlugRedirectRepository>,
+}
+
+impl FindSlugRedirectUseCase {
+    pub fn new(repo: Arc<dyn SlugRedirectRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        scope: SlugScope<'_>,
+        old_slug: &str,
+    ) -> Result<Option<SlugRedirectTarget>, AppError> {
+        Ok(self.repo.find_current_slug(scope, old_slug).await?)
+    }
+}
@@ -1,5 +1,9 @@
 use serde::Deserialize;
 
+/// Tham số list dùng chung
+/// - `fields`: các cột dùng để tìm kiếm với `search`
+/// - `select`: các cột trả về trong response (projection), vd `id,title,slug`
+/// - `include`: các quan hệ cần nhúng, vd `author,category,tags`
 #[derive(Debug, Clone, Deserialize, Default)]
 pub struct ListParams {
     pub search: Option<String>,
@@ -7,6 +11,55 @@ pub struct ListParams {
     pub sort_by: Option<String>,
     pub cursor: Option<String>,
     pub limit: Option<i64>,
+    pub select: Option<String>,
+    pub include: Option<String>,
+}
+
+impl ListParams {
+    pub fn read_params(&self) -> ReadParams {
+        ReadParams {
+            select: self.select.clone(),
+            include: self.include.clone(),
+        }
+    }
+}
+
+/// Tham số đọc cho endpoint chi tiết (không phân trang)
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ReadParams {
+    pub select: Option<String>,
+    pub include: Option<String>,
+}
+
+impl ReadParams {
+    /// Danh sách cột được chọn, `None` nghĩa là trả về toàn bộ
+    pub fn selected(&self) -> Option<Vec<String>> {
+        let fields = split_csv(self.select.as_deref());
+        if fields.is_empty() {
+            None
+        } else {
+            Some(fields)
+        }
+    }
+
+    /// Danh sách quan hệ được include, bỏ qua tên không nằm trong `allowed`
+    pub fn includes(&self, allowed: &[&str]) -> Vec<String> {
+        split_csv(self.include.as_deref())
+            .into_iter()
+            .filter(|name| allowed.contains(&name.as_str()))
+            .collect()
+    }
+}
+
+fn split_csv(value: Option<&str>) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for part in value.unwrap_or_default().split(',') {
+        let part = part.trim();
+        if !part.is_empty() && !out.iter().any(|p| p == part) {
+            out.push(part.to_string());
+        }
+    }
+    out
 }
 
 #[derive(Debug, Clone)]
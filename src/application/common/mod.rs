@@ -1 +1,3 @@
+pub mod find_slug_redirect;
 pub mod list_params;
+pub mod resolve_slug;
@@ -0,0 +1,202 @@
+use std::future::Future;
+
+use uuid::Uuid;
+
+use crate::domain::repositories::slug_repository::{SlugRepository, SlugScope};
+use crate::shared::error::{AppError, ErrorKind};
+use crate::shared::utils::slug::slugify;
+
+/// Lỗi khi slug client gửi đã được dùng trong scope
+pub fn slug_conflict() -> AppError {
+    AppError::new(
+        ErrorKind::Conflict,
+        "SLUG_EXISTS",
+        "Conflict: slug already exists",
+    )
+}
+
+/// Số lần thử lưu với slug sinh tự động khi bị ghi đồng thời chiếm mất
+const SLUG_SAVE_ATTEMPTS: usize = 3;
+
+/// Slug cuối cùng để lưu:
+/// - `requested` (client gửi) được chuẩn hoá, đã bị dùng trong `scope` => `Conflict`,
+///   trùng slug dành riêng của `scope` (route tĩnh) => `Invalid`
+/// - bỏ trống thì sinh từ `source` (title/name), trùng thì thêm hậu tố số:
+///   `chao-mung`, `chao-mung-2`, `chao-mung-3`, ...
+/// - `exclude_id`: bản ghi đang cập nhật (không tính là trùng với chính nó)
+pub async fn resolve_slug(
+    slug_repo: &dyn SlugRepository,
+    scope: SlugScope<'_>,
+    requested: Option<&str>,
+    source: &str,
+    exclude_id: Option<Uuid>,
+) -> Result<String, AppError> {
+    let requested = requested.map(slugify).unwrap_or_default();
+    let explicit = !requested.is_empty();
+    let mut base = requested;
+    if base.is_empty() {
+        base = slugify(source);
+    }
+    if base.is_empty() {
+        base = scope.fallback().to_string();
+    }
+
+    let reserved = scope.reserved();
+    if explicit && reserved.contains(&base.as_str()) {
+        return Err(AppError::invalid("slug", format!("`{base}` is reserved")));
+    }
+
+    let mut taken = slug_repo.find_taken(scope, &base, exclude_id).await?;
+    taken.extend(reserved.iter().map(|s| s.to_string()));
+    if !taken.contains(&base) {
+        return Ok(base);
+    }
+    if explicit {
+        return Err(slug_conflict());
+    }
+
+    let mut n = 2;
+    loop {
+        let candidate = format!("{base}-{n}");
+        if !taken.contains(&candidate) {
+            return Ok(candidate);
+        }
+        n += 1;
+    }
+}
+
+/// Resolve slug rồi gọi `save`. Slug sinh tự động bị request khác chiếm giữa lúc kiểm tra
+/// và lúc ghi (vi phạm unique) thì resolve lại và thử lại; slug client gửi thì trả `Conflict`
+pub async fn save_with_slug<T, F, Fut>(
+    slug_repo: &dyn SlugRepository,
+    scope: SlugScope<'_>,
+    requested: Option<&str>,
+    source: &str,
+    exclude_id: Option<Uuid>,
+    mut save: F,
+) -> Result<T, AppError>
+where
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = Result<T, String>>,
+{
+    let explicit = requested.is_some_and(|s| !slugify(s).is_empty());
+    let mut attempt = 1;
+    loop {
+        let slug = resolve_slug(slug_repo, scope, requested, source, exclude_id).await?;
+        match save(slug).await {
+            Err(e) if is_slug_unique_violation(&e) => {
+                if explicit || attempt >= SLUG_SAVE_ATTEMPTS {
+                    return Err(slug_conflict());
+                }
+                attempt += 1;
+            }
+            result => return Ok(result?),
+        }
+    }
+}
+
+/// Đổi lỗi vi phạm unique của slug (khi giữ slug hiện tại) thành `Conflict`
+pub fn slug_conflict_error(e: String) -> AppError {
+    if is_slug_unique_violation(&e) {
+        slug_conflict()
+    } else {
+        e.into()
+    }
+}
+
+/// Lỗi từ DB khi vi phạm constraint unique có chứa slug (`*_slug_unique`, `*_slug_type_unique`...)
+fn is_slug_unique_violation(e: &str) -> bool {
+    e.contains("duplicate key value violates unique constraint") && e.contains("slug")
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+
+    use super::*;
+
+    /// Slug đã dùng cố định, lọc theo `base` giống repo thật
+    struct TakenSlugs(&'static [&'static str]);
+
+    #[async_trait]
+    impl SlugRepository for TakenSlugs {
+        async fn find_taken(
+            &self,
+            _scope: SlugScope<'_>,
+            base: &str,
+            _exclude_id: Option<Uuid>,
+        ) -> Result<Vec<String>, String> {
+            Ok(self
+                .0
+                .iter()
+                .filter(|s| **s == base || s.starts_with(&format!("{base}-")))
+                .map(|s| s.to_string())
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn generated_slug_is_kept_when_free() {
+        let slug = resolve_slug(&TakenSlugs(&[]), SlugScope::Post, None, "Chào mừng", None)
+            .await
+            .unwrap();
+        assert_eq!(slug, "chao-mung");
+    }
+
+    #[tokio::test]
+    async fn generated_slug_gets_next_free_suffix() {
+        let repo = TakenSlugs(&["chao-mung", "chao-mung-2", "chao-mung-4"]);
+        let slug = resolve_slug(&repo, SlugScope::Post, None, "Chào mừng", None)
+            .await
+            .unwrap();
+        assert_eq!(slug, "chao-mung-3");
+    }
+
+    #[tokio::test]
+    async fn blank_source_falls_back_to_scope_name() {
+        let repo = TakenSlugs(&["post"]);
+        let slug = resolve_slug(&repo, SlugScope::Post, Some("  "), "???", None)
+            .await
+            .unwrap();
+        assert_eq!(slug, "post-2");
+    }
+
+    #[tokio::test]
+    async fn requested_slug_is_normalized_and_not_suffixed() {
+        let repo = TakenSlugs(&["chao-mung"]);
+        let slug = resolve_slug(&repo, SlugScope::Post, Some("Xin Chào"), "Chào mừng", None)
+            .await
+            .unwrap();
+        assert_eq!(slug, "xin-chao");
+
+        let err = resolve_slug(&repo, SlugScope::Post, Some("Chào mừng"), "x", None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.key(), Some("SLUG_EXISTS"));
+        assert_eq!(err.kind(), ErrorKind::Conflict);
+    }
+
+    #[tokio::test]
+    async fn reserved_slug_is_rejected_when_requested() {
+        let scope = SlugScope::Category { r#type: "post" };
+        let err = resolve_slug(&TakenSlugs(&[]), scope, Some("Tree"), "x", None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Invalid);
+        assert_eq!(err.params()["field"], "slug");
+    }
+
+    #[tokio::test]
+    async fn reserved_slug_is_skipped_when_generated() {
+        let scope = SlugScope::Category { r#type: "post" };
+        let slug = resolve_slug(&TakenSlugs(&[]), scope, None, "Tree", None)
+            .await
+            .unwrap();
+        assert_eq!(slug, "tree-2");
+
+        let slug = resolve_slug(&TakenSlugs(&[]), SlugScope::Post, Some("tree"), "x", None)
+            .await
+            .unwrap();
+        assert_eq!(slug, "tree");
+    }
+}
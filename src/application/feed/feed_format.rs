@@ -0,0 +1,233 @@
+use chrono::NaiveDateTime;
+use serde_json::{Value, json};
+
+use crate::application::feed::get_feed::Feed;
+use crate::shared::utils::content::escape_html;
+
+/// Định dạng xuất feed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedFormat {
+    /// RSS 2.0 (`feed.xml`)
+    Rss,
+    /// Atom 1.0 (`feed.atom`)
+    Atom,
+    /// JSON Feed 1.1 (`feed.json`)
+    Json,
+}
+
+impl FeedFormat {
+    /// Định dạng theo tên file cuối path: `feed.xml` | `feed.atom` | `feed.json`
+    pub fn from_file_name(name: &str) -> Option<Self> {
+        match name {
+            "feed.xml" => Some(FeedFormat::Rss),
+            "feed.atom" => Some(FeedFormat::Atom),
+            "feed.json" => Some(FeedFormat::Json),
+            _ => None,
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "application/rss+xml; charset=utf-8",
+            FeedFormat::Atom => "application/atom+xml; charset=utf-8",
+            FeedFormat::Json => "application/feed+json; charset=utf-8",
+        }
+    }
+
+    /// `self_url`: URL của chính feed (link `rel="self"` / `feed_url`)
+    pub fn render(&self, feed: &Feed, self_url: &str) -> String {
+        match self {
+            FeedFormat::Rss => render_rss(feed, self_url),
+            FeedFormat::Atom => render_atom(feed, self_url),
+            FeedFormat::Json => render_json_feed(feed, self_url),
+        }
+    }
+}
+
+fn render_rss(feed: &Feed, self_url: &str) -> String {
+    let mut out = String::with_capacity(4096);
+    out.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    out.push('\n');
+    out.push_str(
+        r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom" xmlns:content="http://purl.org/rss/1.0/modules/content/" xmlns:dc="http://purl.org/dc/elements/1.1/">"#,
+    );
+    out.push_str("\n<channel>\n");
+    push_element(&mut out, "title", &feed.title);
+    push_element(&mut out, "link", &feed.home_url);
+    push_element(
+        &mut out,
+        "description",
+        feed.description.as_deref().unwrap_or(&feed.title),
+    );
+    push_element(&mut out, "language", &feed.language_code);
+    if let Some(updated) = feed.updated {
+        push_element(&mut out, "lastBuildDate", &rfc2822(updated));
+    }
+    out.push_str(&format!(
+        "<atom:link href=\"{}\" rel=\"self\" type=\"application/rss+xml\"/>\n",
+        escape_html(self_url)
+    ));
+
+    for item in &feed.items {
+        out.push_str("<item>\n");
+        push_element(&mut out, "title", &item.title);
+        push_element(&mut out, "link", &item.url);
+        out.push_str(&format!(
+            "<guid isPermaLink=\"false\">urn:uuid:{}</guid>\n",
+            item.id
+        ));
+        if let Some(published) = item.published {
+            push_element(&mut out, "pubDate", &rfc2822(published));
+        }
+        if let Some(author) = &item.author {
+            push_element(&mut out, "dc:creator", author);
+        }
+        if let Some(category) = &item.category {
+            push_element(&mut out, "category", category);
+        }
+        if let Some(summary) = &item.summary {
+            push_element(&mut out, "description", summary);
+        }
+        if let Some(html) = &item.content_html {
+            push_element(&mut out, "content:encoded", html);
+        }
+        out.push_str("</item>\n");
+    }
+
+    out.push_str("</channel>\n</rss>\n");
+    out
+}
+
+fn render_atom(feed: &Feed, self_url: &str) -> String {
+    let mut out = String::with_capacity(4096);
+    out.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    out.push('\n');
+    out.push_str(&format!(
+        "<feed xmlns=\"http://www.w3.org/2005/Atom\" xml:lang=\"{}\">\n",
+        escape_html(&feed.language_code)
+    ));
+    push_element(&mut out, "id", self_url);
+    push_element(&mut out, "title", &feed.title);
+    if let Some(description) = &feed.description {
+        push_element(&mut out, "subtitle", description);
+    }
+    // `updated` là bắt buộc trong Atom, feed rỗng dùng epoch
+    push_element(
+        &mut out,
+        "updated",
+        &rfc3339(feed.updated.unwrap_or_default()),
+    );
+    out.push_str(&format!(
+        "<link rel=\"self\" type=\"application/atom+xml\" href=\"{}\"/>\n",
+        escape_html(self_url)
+    ));
+    out.push_str(&format!(
+        "<link rel=\"alternate\" type=\"text/html\" href=\"{}\"/>\n",
+        escape_html(&feed.home_url)
+    ));
+
+    for item in &feed.items {
+        out.push_str("<entry>\n");
+        push_element(&mut out, "id", &format!("urn:uuid:{}", item.id));
+        push_element(&mut out, "title", &item.title);
+        out.push_str(&format!(
+            "<link rel=\"alternate\" type=\"text/html\" href=\"{}\"/>\n",
+            escape_html(&item.url)
+        ));
+        if let Some(published) = item.published {
+            push_element(&mut out, "published", &rfc3339(published));
+        }
+        push_element(
+            &mut out,
+            "updated",
+            &rfc3339(item.updated.or(item.published).unwrap_or_default()),
+        );
+        if let Some(author) = &item.author {
+            out.push_str("<author>");
+            push_element(&mut out, "name", author);
+            out.push_str("</author>\n");
+        }
+        if let Some(category) = &item.category {
+            out.push_str(&format!("<category term=\"{}\"/>\n", escape_html(category)));
+        }
+        if let Some(summary) = &item.summary {
+            out.push_str(&format!(
+                "<summary type=\"text\">{}</summary>\n",
+                escape_html(summary)
+            ));
+        }
+        if let Some(html) = &item.content_html {
+            out.push_str(&format!(
+                "<content type=\"html\">{}</content>\n",
+                escape_html(html)
+            ));
+        }
+        out.push_str("</entry>\n");
+    }
+
+    out.push_str("</feed>\n");
+    out
+}
+
+fn render_json_feed(feed: &Feed, self_url: &str) -> String {
+    let items: Vec<Value> = feed
+        .items
+        .iter()
+        .map(|item| {
+            let mut entry = json!({
+                "id": item.id.to_string(),
+                "url": item.url,
+                "title": item.title,
+            });
+            if let Some(html) = &item.content_html {
+                entry["content_html"] = json!(html);
+            } else {
+                // JSON Feed yêu cầu có content_html hoặc content_text
+                entry["content_text"] = json!(item.summary.clone().unwrap_or_default());
+            }
+            if let Some(summary) = &item.summary {
+                entry["summary"] = json!(summary);
+            }
+            if let Some(published) = item.published {
+                entry["date_published"] = json!(rfc3339(published));
+            }
+            if let Some(updated) = item.updated {
+                entry["date_modified"] = json!(rfc3339(updated));
+            }
+            if let Some(author) = &item.author {
+                entry["authors"] = json!([{ "name": author }]);
+            }
+            if let Some(category) = &item.category {
+                entry["tags"] = json!([category]);
+            }
+            entry
+        })
+        .collect();
+
+    let mut doc = json!({
+        "version": "https://jsonfeed.org/version/1.1",
+        "title": feed.title,
+        "home_page_url": feed.home_url,
+        "feed_url": self_url,
+        "language": feed.language_code,
+        "items": items,
+    });
+    if let Some(description) = &feed.description {
+        doc["description"] = json!(description);
+    }
+    doc.to_string()
+}
+
+fn push_element(out: &mut String, name: &str, text: &str) {
+    out.push_str(&format!("<{name}>{}</{name}>\n", escape_html(text)));
+}
+
+fn rfc2822(value: NaiveDateTime) -> String {
+    value.and_utc().to_rfc2822()
+}
+
+fn rfc3339(value: NaiveDateTime) -> String {
+    value
+        .and_utc()
+        .to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
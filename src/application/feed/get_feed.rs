@@ -0,0 +1,212 @@
+use std::sync::Arc;
+
+use chrono::NaiveDateTime;
+use uuid::Uuid;
+
+use crate::application::post::post_content::render_content_html;
+use crate::application::post::resolve_post_tags::POST_TAG_TYPE;
+use crate::application::seo::seo_defaults::SeoDefaults;
+use crate::domain::repositories::category_repository::CategoryRepository;
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::domain::repositories::post_repository::{PostFeedQuery, PostRepository};
+use crate::domain::repositories::setting_repository::SettingRepository;
+use crate::domain::repositories::tag_repository::TagRepository;
+use crate::shared::error::{AppError, ErrorKind};
+
+/// Key setting: số bài tối đa trong feed
+pub const FEED_ITEM_LIMIT_KEY: &str = "feed_item_limit";
+pub const DEFAULT_FEED_ITEM_LIMIT: i64 = 20;
+const MAX_FEED_ITEM_LIMIT: i64 = 100;
+
+/// Phạm vi của feed
+#[derive(Debug, Clone, Copy)]
+pub enum FeedScope<'a> {
+    /// Toàn site
+    Site,
+    Category {
+        slug: &'a str,
+        r#type: &'a str,
+    },
+    Tag {
+        slug: &'a str,
+    },
+}
+
+/// Feed đã dựng, độc lập với định dạng xuất (RSS/Atom/JSON Feed)
+#[derive(Debug, Clone)]
+pub struct Feed {
+    pub title: String,
+    pub description: Option<String>,
+    /// Trang HTML tương ứng trên frontend
+    pub home_url: String,
+    /// URL của chính feed, dựng theo `seo_site_url` (không tin header Host của request)
+    pub self_url: String,
+    pub language_code: String,
+    /// Thời điểm cập nhật mới nhất trong các bài (dùng cho `Last-Modified`)
+    pub updated: Option<NaiveDateTime>,
+    pub items: Vec<FeedEntry>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FeedEntry {
+    pub id: Uuid,
+    pub title: String,
+    pub url: String,
+    pub summary: Option<String>,
+    pub content_html: Option<String>,
+    pub author: Option<String>,
+    pub category: Option<String>,
+    pub published: Option<NaiveDateTime>,
+    pub updated: Option<NaiveDateTime>,
+}
+
+pub struct GetFeedUseCase {
+    post_repo: Arc<dyn PostRepository>,
+    category_repo: Arc<dyn CategoryRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+    setting_repo: Arc<dyn SettingRepository>,
+    language_repo: Arc<dyn LanguageRepository>,
+}
+
+impl GetFeedUseCase {
+    pub fn new(
+        post_repo: Arc<dyn PostRepository>,
+        category_repo: Arc<dyn CategoryRepository>,
+        tag_repo: Arc<dyn TagRepository>,
+        setting_repo: Arc<dyn SettingRepository>,
+        language_repo: Arc<dyn LanguageRepository>,
+    ) -> Self {
+        Self {
+            post_repo,
+            category_repo,
+            tag_repo,
+            setting_repo,
+            language_repo,
+        }
+    }
+
+    /// Feed các bài đã publish mới nhất. `lang` khác ngôn ngữ mặc định => chỉ bài có bản dịch,
+    /// link bài theo `seo_language_path` như sitemap.
+    /// `self_path`: path + query của request (feed phục vụ ở cùng host với `seo_site_url`)
+    pub async fn execute(
+        &self,
+        scope: FeedScope<'_>,
+        lang: Option<&str>,
+        self_path: &str,
+    ) -> Result<Feed, AppError> {
+        let defaults = SeoDefaults::load(self.setting_repo.as_ref()).await?;
+        let default_language = self
+            .language_repo
+            .get_default()
+            .await?
+            .map(|l| l.code)
+            .ok_or_else(|| AppError::not_found("DEFAULT_LANGUAGE_NOT_FOUND", "Default language"))?;
+        let language_code = match lang.map(str::trim).filter(|l| !l.is_empty()) {
+            Some(code) => self
+                .language_repo
+                .find_by_code(code)
+                .await?
+                .map(|l| l.code)
+                .ok_or_else(|| {
+                    AppError::new(
+                        ErrorKind::Invalid,
+                        "LANGUAGE_INVALID",
+                        format!("Invalid language: {code}"),
+                    )
+                    .with("code", code)
+                })?,
+            None => default_language.clone(),
+        };
+
+        let mut query = PostFeedQuery {
+            language_code: Some(language_code.clone()).filter(|l| *l != default_language),
+            limit: self.item_limit().await?,
+            ..Default::default()
+        };
+        let (title, description, home_url) = match scope {
+            FeedScope::Site => (
+                defaults.site_name.clone(),
+                defaults.default_description.clone(),
+                defaults.home_url(),
+            ),
+            FeedScope::Category { slug, r#type } => {
+                let category = self
+                    .category_repo
+                    .find_by_slug_and_type(slug, r#type)
+                    .await?
+                    .ok_or_else(|| AppError::not_found("CATEGORY_NOT_FOUND", "Category"))?;
+                query.category_id = Some(category.id);
+                (
+                    format!("{} - {}", defaults.site_name, category.name),
+                    category.description.filter(|d| !d.trim().is_empty()),
+                    defaults.category_url(slug, r#type),
+                )
+            }
+            FeedScope::Tag { slug } => {
+                let tag = self
+                    .tag_repo
+                    .find_by_slug_and_type(slug, POST_TAG_TYPE)
+                    .await?
+                    .ok_or_else(|| AppError::not_found("TAG_NOT_FOUND", "Tag"))?;
+                query.tag_slug = Some(tag.slug.clone().unwrap_or_else(|| slug.to_string()));
+                (
+                    format!("{} - #{}", defaults.site_name, tag.name),
+                    tag.description.filter(|d| !d.trim().is_empty()),
+                    defaults.tag_url(slug, POST_TAG_TYPE),
+                )
+            }
+        };
+
+        let items: Vec<FeedEntry> = self
+            .post_repo
+            .list_feed(&query)
+            .await?
+            .into_iter()
+            .map(|item| {
+                let content_html = item.content_html.or_else(|| {
+                    item.content
+                        .as_deref()
+                        .and_then(|raw| render_content_html(&item.content_format, raw))
+                });
+                FeedEntry {
+                    id: item.id,
+                    url: defaults.localized_post_url(
+                        item.slug.as_deref().unwrap_or_default(),
+                        query.language_code.as_deref(),
+                    ),
+                    title: item.title,
+                    summary: item.excerpt.filter(|e| !e.trim().is_empty()),
+                    content_html,
+                    author: item.author_name,
+                    category: item.category_name,
+                    published: item.published_at,
+                    updated: item.updated_at.or(item.published_at),
+                }
+            })
+            .collect();
+
+        Ok(Feed {
+            title,
+            description,
+            home_url,
+            self_url: defaults.absolute_url(self_path),
+            language_code,
+            updated: items.iter().filter_map(|i| i.updated).max(),
+            items,
+        })
+    }
+
+    async fn item_limit(&self) -> Result<i64, AppError> {
+        let limit = self
+            .setting_repo
+            .get_by_key(FEED_ITEM_LIMIT_KEY)
+            .await?
+            .and_then(|s| s.value)
+            .and_then(|v| {
+                v.as_i64()
+                    .or_else(|| v.as_str().and_then(|s| s.parse().ok()))
+            })
+            .unwrap_or(DEFAULT_FEED_ITEM_LIMIT);
+        Ok(limit.clamp(1, MAX_FEED_ITEM_LIMIT))
+    }
+}
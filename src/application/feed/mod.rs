@@ -0,0 +1,2 @@
+pub mod feed_format;
+pub mod get_feed;
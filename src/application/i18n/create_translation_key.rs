@@ -0,0 +1,70 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::i18n::get_translation_key::{TranslationKeyView, with_values};
+use crate::application::i18n::message_rules::{
+    ensure_unique_key, normalize_key, normalize_namespace, validate_values,
+};
+use crate::application::translation::translation_language::optional_text;
+use crate::domain::entities::translation_key::TranslationKey;
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::domain::repositories::translation_key_repository::TranslationKeyRepository;
+use crate::shared::error::AppError;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct CreateTranslationKeyInput {
+    pub namespace: String,
+    pub key: String,
+    pub description: Option<String>,
+    /// `language_code` => message (ICU MessageFormat)
+    pub values: Option<BTreeMap<String, String>>,
+}
+
+pub struct CreateTranslationKeyUseCase {
+    repo: Arc<dyn TranslationKeyRepository>,
+    language_repo: Arc<dyn LanguageRepository>,
+}
+
+impl CreateTranslationKeyUseCase {
+    pub fn new(
+        repo: Arc<dyn TranslationKeyRepository>,
+        language_repo: Arc<dyn LanguageRepository>,
+    ) -> Self {
+        Self {
+            repo,
+            language_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        input: CreateTranslationKeyInput,
+    ) -> Result<TranslationKeyView, AppError> {
+        let namespace = normalize_namespace(&input.namespace)?;
+        let key = normalize_key(&input.key)?;
+        ensure_unique_key(self.repo.as_ref(), None, &namespace, &key).await?;
+        let languages = self.language_repo.list_all().await?;
+        let values = validate_values(&languages, input.values.unwrap_or_default())?;
+
+        let created = self
+            .repo
+            .create(
+                TranslationKey {
+                    id: Uuid::now_v7(),
+                    namespace,
+                    key,
+                    description: optional_text(input.description),
+                    created_at: None,
+                    updated_at: None,
+                    deleted_at: None,
+                },
+                values,
+            )
+            .await?;
+        with_values(self.repo.as_ref(), vec![created])
+            .await
+            .map(|mut views| views.remove(0))
+    }
+}
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::repositories::translation_key_repository::TranslationKeyRepository;
+use crate::shared::error::AppError;
+
+pub struct DeleteTranslationKeyUseCase {
+    repo: Arc<dyn TranslationKeyRepository>,
+}
+
+impl DeleteTranslationKeyUseCase {
+    pub fn new(repo: Arc<dyn TranslationKeyRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(&self, id: Uuid) -> Result<(), AppError> {
+        self.repo.soft_delete(id).await
+    }
+}
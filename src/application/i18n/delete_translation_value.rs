@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::i18n::message_rules::find_language;
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::domain::repositories::translation_key_repository::TranslationKeyRepository;
+use crate::shared::error::AppError;
+
+pub struct DeleteTranslationValueUseCase {
+    repo: Arc<dyn TranslationKeyRepository>,
+    language_repo: Arc<dyn LanguageRepository>,
+}
+
+impl DeleteTranslationValueUseCase {
+    pub fn new(
+        repo: Arc<dyn TranslationKeyRepository>,
+        language_repo: Arc<dyn LanguageRepository>,
+    ) -> Self {
+        Self {
+            repo,
+            language_repo,
+        }
+    }
+
+    /// Trả về mã ngôn ngữ chuẩn (client có thể gửi `EN`, `en`...)
+    pub async fn execute(&self, id: Uuid, lang: &str) -> Result<String, AppError> {
+        let key =
+            self.repo.find_by_id(id).await?.ok_or_else(|| {
+                AppError::not_found("TRANSLATION_KEY_NOT_FOUND", "Translation key")
+            })?;
+        let languages = self.language_repo.list_all().await?;
+        let code = find_language(&languages, lang)?.code.clone();
+        self.repo.delete_value(key.id, &code).await?;
+        Ok(code)
+    }
+}
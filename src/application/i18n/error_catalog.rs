@@ -0,0 +1,4 @@
+/// Namespace chứa message lỗi theo ngôn ngữ trong `translation_keys` (key = mã message của
+/// `AppError`, trả về ở `error.details.messageKey`).
+/// Frontend muốn tự render message thì lấy qua `/i18n/:lang/errors`
+pub const ERROR_NAMESPACE: &str = "errors";
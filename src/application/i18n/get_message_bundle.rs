@@ -0,0 +1,74 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::application::i18n::message_rules::normalize_namespace;
+use crate::application::translation::negotiate_language::ContentLanguage;
+use crate::domain::repositories::translation_key_repository::TranslationKeyRepository;
+use crate::shared::error::{AppError, ErrorKind};
+
+/// Bundle chuỗi giao diện của 1 namespace: `key` => message
+#[derive(Debug, Clone)]
+pub struct MessageBundle {
+    pub language_code: String,
+    pub messages: BTreeMap<String, String>,
+}
+
+/// Message theo ngôn ngữ yêu cầu; key chưa dịch lấy theo chuỗi dự phòng
+/// (ngôn ngữ dự phòng rồi tới ngôn ngữ mặc định), chưa có ở đâu thì bỏ qua
+pub struct GetMessageBundleUseCase {
+    repo: Arc<dyn TranslationKeyRepository>,
+}
+
+impl GetMessageBundleUseCase {
+    pub fn new(repo: Arc<dyn TranslationKeyRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        language: &ContentLanguage,
+        namespace: &str,
+    ) -> Result<MessageBundle, AppError> {
+        let namespace = normalize_namespace(namespace).map_err(|_| {
+            AppError::new(
+                ErrorKind::NotFound,
+                "NAMESPACE_NOT_FOUND",
+                format!("Namespace {namespace} not found"),
+            )
+            .with("namespace", namespace)
+        })?;
+        if !self.repo.namespace_exists(&namespace).await? {
+            return Err(AppError::new(
+                ErrorKind::NotFound,
+                "NAMESPACE_NOT_FOUND",
+                format!("Namespace {namespace} not found"),
+            )
+            .with("namespace", namespace));
+        }
+
+        let mut best: BTreeMap<String, (usize, String)> = BTreeMap::new();
+        for message in self.repo.bundle(&namespace, &language.chain).await? {
+            let Some(rank) = language
+                .chain
+                .iter()
+                .position(|code| *code == message.language_code)
+            else {
+                continue;
+            };
+            match best.get(&message.key) {
+                Some((current, _)) if *current <= rank => {}
+                _ => {
+                    best.insert(message.key, (rank, message.value));
+                }
+            }
+        }
+
+        Ok(MessageBundle {
+            language_code: language.code.clone(),
+            messages: best
+                .into_iter()
+                .map(|(key, (_, value))| (key, value))
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,60 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::entities::translation_key::TranslationKey;
+use crate::domain::repositories::translation_key_repository::TranslationKeyRepository;
+use crate::shared::error::AppError;
+
+/// Key kèm message theo từng ngôn ngữ
+#[derive(Debug, Clone, Serialize)]
+pub struct TranslationKeyView {
+    #[serde(flatten)]
+    pub key: TranslationKey,
+    /// `language_code` => message
+    pub values: BTreeMap<String, String>,
+}
+
+/// Ghép message của nhiều key bằng 1 truy vấn
+pub async fn with_values(
+    repo: &dyn TranslationKeyRepository,
+    keys: Vec<TranslationKey>,
+) -> Result<Vec<TranslationKeyView>, AppError> {
+    let ids: Vec<Uuid> = keys.iter().map(|k| k.id).collect();
+    let mut values: HashMap<Uuid, BTreeMap<String, String>> = HashMap::new();
+    for value in repo.list_values(&ids).await? {
+        values
+            .entry(value.key_id)
+            .or_default()
+            .insert(value.language_code, value.value);
+    }
+    Ok(keys
+        .into_iter()
+        .map(|key| TranslationKeyView {
+            values: values.remove(&key.id).unwrap_or_default(),
+            key,
+        })
+        .collect())
+}
+
+pub struct GetTranslationKeyUseCase {
+    repo: Arc<dyn TranslationKeyRepository>,
+}
+
+impl GetTranslationKeyUseCase {
+    pub fn new(repo: Arc<dyn TranslationKeyRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(&self, id: Uuid) -> Result<TranslationKeyView, AppError> {
+        let key =
+            self.repo.find_by_id(id).await?.ok_or_else(|| {
+                AppError::not_found("TRANSLATION_KEY_NOT_FOUND", "Translation key")
+            })?;
+        with_values(self.repo.as_ref(), vec![key])
+            .await
+            .map(|mut views| views.remove(0))
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use crate::application::common::list_params::{ListParams, PaginatedResult};
+use crate::application::i18n::get_translation_key::{TranslationKeyView, with_values};
+use crate::application::i18n::message_rules::normalize_namespace;
+use crate::domain::repositories::translation_key_repository::{
+    TranslationKeyRepository, TranslationKeySearchFilter,
+};
+use crate::shared::error::AppError;
+
+pub struct GetTranslationKeysUseCase {
+    repo: Arc<dyn TranslationKeyRepository>,
+}
+
+impl GetTranslationKeysUseCase {
+    pub fn new(repo: Arc<dyn TranslationKeyRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        params: &ListParams,
+        namespace: Option<String>,
+    ) -> Result<PaginatedResult<TranslationKeyView>, AppError> {
+        let namespace = namespace
+            .filter(|n| !n.trim().is_empty())
+            .map(|n| normalize_namespace(&n))
+            .transpose()?;
+        let filter = TranslationKeySearchFilter {
+            namespace,
+            search: params.search.clone(),
+        };
+        let limit = params.limit.unwrap_or(20).clamp(1, 100);
+        let result = self
+            .repo
+            .search(
+                &filter,
+                params.sort_by.clone(),
+                params.cursor.clone(),
+                limit,
+            )
+            .await?;
+
+        Ok(PaginatedResult {
+            items: with_values(self.repo.as_ref(), result.items).await?,
+            next_cursor: result.next_cursor,
+            limit: result.limit,
+        })
+    }
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use crate::application::i18n::error_catalog::ERROR_NAMESPACE;
+use crate::application::translation::negotiate_language::ContentLanguage;
+use crate::domain::repositories::translation_key_repository::TranslationKeyRepository;
+use crate::shared::error::AppError;
+use crate::shared::utils::icu_message::format_icu_message;
+
+/// Message lỗi theo ngôn ngữ của request: tra mã message của lỗi trong namespace `errors`
+/// theo chuỗi dự phòng rồi điền tham số. Lỗi không có mã hoặc chưa có bản dịch => `None`
+pub struct LocalizeErrorUseCase {
+    repo: Arc<dyn TranslationKeyRepository>,
+}
+
+impl LocalizeErrorUseCase {
+    pub fn new(repo: Arc<dyn TranslationKeyRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        language: &ContentLanguage,
+        error: &AppError,
+    ) -> Result<Option<String>, AppError> {
+        let Some(key) = error.key() else {
+            return Ok(None);
+        };
+        let message = self
+            .repo
+            .bundle(ERROR_NAMESPACE, &language.chain)
+            .await?
+            .into_iter()
+            .filter(|m| m.key == key)
+            .filter_map(|m| {
+                language
+                    .chain
+                    .iter()
+                    .position(|code| *code == m.language_code)
+                    .map(|rank| (rank, m))
+            })
+            .min_by_key(|(rank, _)| *rank)
+            .map(|(_, m)| format_icu_message(&m.value, error.params(), &m.language_code));
+        Ok(message)
+    }
+}
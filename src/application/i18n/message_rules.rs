@@ -0,0 +1,183 @@
+use std::collections::{BTreeMap, HashMap};
+
+use uuid::Uuid;
+
+use crate::domain::entities::language::Language;
+use crate::domain::repositories::translation_key_repository::TranslationKeyRepository;
+use crate::shared::error::{AppError, ErrorKind};
+use crate::shared::utils::icu_message::{IcuArgumentKind, parse_icu_message};
+
+/// Namespace: chữ thường, số, `-`, `_` (`common`, `checkout-v2`)
+pub fn normalize_namespace(raw: &str) -> Result<String, AppError> {
+    let namespace = raw.trim().to_ascii_lowercase();
+    let valid = namespace
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+    if namespace.is_empty() || namespace.len() > 100 || !valid {
+        return Err(AppError::invalid(
+            "namespace",
+            format!("'{}' (letters, digits, '-' and '_' only)", raw.trim()),
+        ));
+    }
+    Ok(namespace)
+}
+
+/// Key: chữ, số, `.`, `-`, `_`, không bắt đầu/kết thúc bằng `.` (`cart.items_count`)
+pub fn normalize_key(raw: &str) -> Result<String, AppError> {
+    let key = raw.trim();
+    let valid = key
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'));
+    if key.is_empty()
+        || key.len() > 255
+        || !valid
+        || key.starts_with('.')
+        || key.ends_with('.')
+        || key.contains("..")
+    {
+        return Err(AppError::invalid(
+            "key",
+            format!("'{key}' (letters, digits, '.', '-' and '_' only)"),
+        ));
+    }
+    Ok(key.to_string())
+}
+
+/// Chặn trùng `namespace` + `key` với key khác
+pub async fn ensure_unique_key(
+    repo: &dyn TranslationKeyRepository,
+    id: Option<Uuid>,
+    namespace: &str,
+    key: &str,
+) -> Result<(), AppError> {
+    if let Some(existing) = repo.find_by_name(namespace, key).await?
+        && Some(existing.id) != id
+    {
+        return Err(AppError::new(
+            ErrorKind::Invalid,
+            "TRANSLATION_KEY_EXISTS",
+            format!("Invalid key: '{key}' already exists in namespace '{namespace}'"),
+        )
+        .with("key", key)
+        .with("namespace", namespace));
+    }
+    Ok(())
+}
+
+/// Mã ngôn ngữ đang bật (không phân biệt hoa thường) => mã chuẩn
+pub fn find_language<'a>(languages: &'a [Language], code: &str) -> Result<&'a Language, AppError> {
+    let code = code.trim();
+    languages
+        .iter()
+        .find(|l| l.code.eq_ignore_ascii_case(code))
+        .ok_or_else(|| {
+            AppError::new(
+                ErrorKind::Invalid,
+                "LANGUAGE_INVALID",
+                format!("Invalid language: {code}"),
+            )
+            .with("code", code)
+        })
+}
+
+/// Kiểm tra message rồi gắn lỗi với `field` (`Invalid {field}: ...`)
+pub fn validate_message(
+    field: &str,
+    value: &str,
+    source: Option<&str>,
+) -> Result<String, AppError> {
+    if value.trim().is_empty() {
+        return Err(AppError::new(
+            ErrorKind::Invalid,
+            "FIELD_REQUIRED",
+            format!("Invalid {field}: must not be empty"),
+        )
+        .with("field", field));
+    }
+    match message_problem(value, source) {
+        Some(reason) => Err(AppError::invalid(field, reason)),
+        None => Ok(value.to_string()),
+    }
+}
+
+/// Kiểm tra cú pháp ICU MessageFormat. Có `source` (bản ngôn ngữ mặc định) thì bản dịch
+/// chỉ được dùng placeholder có trong bản gốc, và plural/select phải giữ đúng loại
+pub fn message_problem(value: &str, source: Option<&str>) -> Option<String> {
+    let arguments = match parse_icu_message(value) {
+        Ok(arguments) => arguments,
+        Err(e) => return Some(e.to_string()),
+    };
+
+    // Bản gốc lỗi cú pháp (dữ liệu cũ) => chỉ kiểm tra cú pháp bản dịch
+    let source_arguments = parse_icu_message(source?).ok()?;
+    let mut expected: HashMap<&str, IcuArgumentKind> = HashMap::new();
+    for argument in &source_arguments {
+        let kind = expected.entry(&argument.name).or_insert(argument.kind);
+        if argument.kind.is_branching() {
+            *kind = argument.kind;
+        }
+    }
+
+    arguments
+        .iter()
+        .find_map(|argument| match expected.get(argument.name.as_str()) {
+            None => Some(format!(
+                "placeholder {{{}}} is not in the default language message",
+                argument.name
+            )),
+            Some(kind)
+                if (kind.is_branching() || argument.kind.is_branching())
+                    && *kind != argument.kind =>
+            {
+                Some(format!(
+                    "placeholder {{{}}} must be {} (as in the default language message), got {}",
+                    argument.name,
+                    kind.as_str(),
+                    argument.kind.as_str()
+                ))
+            }
+            Some(_) => None,
+        })
+}
+
+/// Kiểm tra bộ giá trị theo ngôn ngữ: ngôn ngữ mặc định làm bản gốc cho các ngôn ngữ khác
+pub fn validate_values(
+    languages: &[Language],
+    values: BTreeMap<String, String>,
+) -> Result<Vec<(String, String)>, AppError> {
+    let mut resolved: BTreeMap<String, String> = BTreeMap::new();
+    for (code, value) in values {
+        let language = find_language(languages, &code)?;
+        if resolved.contains_key(&language.code) {
+            return Err(AppError::new(
+                ErrorKind::Invalid,
+                "LANGUAGE_DUPLICATED",
+                format!("Invalid language: {code} is given twice"),
+            )
+            .with("code", code));
+        }
+        resolved.insert(language.code.clone(), value);
+    }
+
+    let default_code = languages
+        .iter()
+        .find(|l| l.is_default == Some(true))
+        .map(|l| l.code.clone());
+    let source = default_code
+        .as_ref()
+        .and_then(|code| resolved.get(code))
+        .cloned();
+
+    resolved
+        .into_iter()
+        .map(|(code, value)| {
+            let is_source = Some(&code) == default_code.as_ref();
+            let value = validate_message(
+                &format!("value ({code})"),
+                &value,
+                if is_source { None } else { source.as_deref() },
+            )?;
+            Ok((code, value))
+        })
+        .collect()
+}
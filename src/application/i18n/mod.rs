@@ -0,0 +1,11 @@
+pub mod create_translation_key;
+pub mod delete_translation_key;
+pub mod delete_translation_value;
+pub mod error_catalog;
+pub mod get_message_bundle;
+pub mod get_translation_key;
+pub mod get_translation_keys;
+pub mod localize_error;
+pub mod message_rules;
+pub mod update_translation_key;
+pub mod upsert_translation_value;
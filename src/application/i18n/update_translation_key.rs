@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::i18n::get_translation_key::{TranslationKeyView, with_values};
+use crate::application::i18n::message_rules::{
+    ensure_unique_key, normalize_key, normalize_namespace,
+};
+use crate::application::translation::translation_language::optional_text;
+use crate::domain::entities::translation_key::TranslationKey;
+use crate::domain::repositories::translation_key_repository::TranslationKeyRepository;
+use crate::shared::error::AppError;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct UpdateTranslationKeyInput {
+    pub namespace: Option<String>,
+    pub key: Option<String>,
+    /// Chuỗi rỗng => xoá mô tả
+    pub description: Option<String>,
+}
+
+pub struct UpdateTranslationKeyUseCase {
+    repo: Arc<dyn TranslationKeyRepository>,
+}
+
+impl UpdateTranslationKeyUseCase {
+    pub fn new(repo: Arc<dyn TranslationKeyRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        id: Uuid,
+        input: UpdateTranslationKeyInput,
+    ) -> Result<TranslationKeyView, AppError> {
+        let existing =
+            self.repo.find_by_id(id).await?.ok_or_else(|| {
+                AppError::not_found("TRANSLATION_KEY_NOT_FOUND", "Translation key")
+            })?;
+
+        let namespace = match input.namespace.as_deref() {
+            Some(raw) => normalize_namespace(raw)?,
+            None => existing.namespace,
+        };
+        let key = match input.key.as_deref() {
+            Some(raw) => normalize_key(raw)?,
+            None => existing.key,
+        };
+        ensure_unique_key(self.repo.as_ref(), Some(existing.id), &namespace, &key).await?;
+        let description = match input.description {
+            Some(description) => optional_text(Some(description)),
+            None => existing.description,
+        };
+
+        let updated = self
+            .repo
+            .update(TranslationKey {
+                id: existing.id,
+                namespace,
+                key,
+                description,
+                created_at: existing.created_at,
+                updated_at: existing.updated_at,
+                deleted_at: existing.deleted_at,
+            })
+            .await?;
+        with_values(self.repo.as_ref(), vec![updated])
+            .await
+            .map(|mut views| views.remove(0))
+    }
+}
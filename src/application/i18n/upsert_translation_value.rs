@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::i18n::message_rules::{find_language, message_problem, validate_message};
+use crate::domain::entities::translation_key::TranslationValue;
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::domain::repositories::translation_key_repository::TranslationKeyRepository;
+use crate::shared::error::AppError;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct UpsertTranslationValueInput {
+    /// Message theo cú pháp ICU MessageFormat
+    pub value: String,
+}
+
+/// Ghi message của 1 key theo 1 ngôn ngữ. Ngôn ngữ khác mặc định được đối chiếu
+/// placeholder với message của ngôn ngữ mặc định (nếu đã có); sửa message ngôn ngữ mặc định
+/// thì các bản dịch sẵn có được đối chiếu lại với message mới
+pub struct UpsertTranslationValueUseCase {
+    repo: Arc<dyn TranslationKeyRepository>,
+    language_repo: Arc<dyn LanguageRepository>,
+}
+
+impl UpsertTranslationValueUseCase {
+    pub fn new(
+        repo: Arc<dyn TranslationKeyRepository>,
+        language_repo: Arc<dyn LanguageRepository>,
+    ) -> Self {
+        Self {
+            repo,
+            language_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        id: Uuid,
+        lang: &str,
+        input: UpsertTranslationValueInput,
+    ) -> Result<TranslationValue, AppError> {
+        let key =
+            self.repo.find_by_id(id).await?.ok_or_else(|| {
+                AppError::not_found("TRANSLATION_KEY_NOT_FOUND", "Translation key")
+            })?;
+        let languages = self.language_repo.list_all().await?;
+        let language = find_language(&languages, lang)?;
+        let default_code = languages
+            .iter()
+            .find(|l| l.is_default == Some(true))
+            .map(|l| l.code.as_str());
+        let existing = self.repo.list_values(&[key.id]).await?;
+
+        let value = if Some(language.code.as_str()) == default_code {
+            let value = validate_message("value", &input.value, None)?;
+            // Bản gốc đổi placeholder => bản dịch sẵn có phải vẫn hợp lệ với bản gốc mới
+            for translation in existing.iter().filter(|v| v.language_code != language.code) {
+                if let Some(reason) = message_problem(&translation.value, Some(&value)) {
+                    return Err(AppError::invalid(
+                        "value",
+                        format!(
+                            "{} translation no longer matches: {reason}",
+                            translation.language_code
+                        ),
+                    ));
+                }
+            }
+            value
+        } else {
+            let source = existing
+                .into_iter()
+                .find(|v| Some(v.language_code.as_str()) == default_code)
+                .map(|v| v.value);
+            validate_message("value", &input.value, source.as_deref())?
+        };
+
+        Ok(self
+            .repo
+            .upsert_value(key.id, &language.code, &value)
+            .await?)
+    }
+}
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use crate::application::translation::translation_language::required_text;
+use crate::domain::entities::language::Language;
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::shared::error::{AppError, ErrorKind};
+use crate::shared::utils::language_tag::canonicalize_language_tag;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct CreateLanguageInput {
+    /// Language tag BCP 47 (`ja`, `en-GB`, `zh-Hant`), lưu ở dạng chuẩn hoá
+    pub code: String,
+    pub name: String,
+}
+
+pub struct CreateLanguageUseCase {
+    repo: Arc<dyn LanguageRepository>,
+}
+
+impl CreateLanguageUseCase {
+    pub fn new(repo: Arc<dyn LanguageRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Ngôn ngữ mới không bao giờ là mặc định (dùng `SetDefaultLanguageUseCase`)
+    pub async fn execute(&self, input: CreateLanguageInput) -> Result<Language, AppError> {
+        let code = canonicalize_language_tag(&input.code).ok_or_else(|| {
+            AppError::invalid(
+                "code",
+                format!("{} is not a valid BCP 47 language tag", input.code.trim()),
+            )
+        })?;
+        let name = required_text(&input.name, "name")?;
+
+        // Mã cũ có thể lưu khác hoa/thường
+        if let Some(existing) = self
+            .repo
+            .list_all()
+            .await?
+            .into_iter()
+            .find(|l| l.code.eq_ignore_ascii_case(&code))
+        {
+            return Err(language_exists(&existing.code));
+        }
+
+        self.repo
+            .create(&code, &name)
+            .await?
+            .ok_or_else(|| language_exists(&code))
+    }
+}
+
+fn language_exists(code: &str) -> AppError {
+    AppError::new(
+        ErrorKind::Conflict,
+        "LANGUAGE_EXISTS",
+        format!("Conflict: language {code} already exists"),
+    )
+    .with("code", code)
+}
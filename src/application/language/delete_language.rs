@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+
+use crate::application::language::update_language::find_code;
+use crate::domain::repositories::language_repository::{
+    LanguageDeletion, LanguageRepository, TranslationDisposal,
+};
+use crate::shared::error::{AppError, ErrorKind};
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DeleteLanguageInput {
+    /// `migrate` | `cascade`; bỏ trống => từ chối nếu còn bản dịch
+    pub on_translations: Option<String>,
+    /// Ngôn ngữ nhận bản dịch khi `on_translations=migrate`
+    pub migrate_to: Option<String>,
+}
+
+/// Số bản dịch đã chuyển / đã xoá mềm cùng ngôn ngữ
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DeleteLanguageOutput {
+    pub code: String,
+    pub migrated: i64,
+    pub removed: i64,
+}
+
+pub struct DeleteLanguageUseCase {
+    repo: Arc<dyn LanguageRepository>,
+}
+
+impl DeleteLanguageUseCase {
+    pub fn new(repo: Arc<dyn LanguageRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        code: &str,
+        input: DeleteLanguageInput,
+    ) -> Result<DeleteLanguageOutput, AppError> {
+        let code = find_code(self.repo.as_ref(), code).await?;
+
+        let disposal = match input.on_translations.as_deref().map(str::trim) {
+            None | Some("") => TranslationDisposal::Refuse,
+            Some("cascade") => TranslationDisposal::Cascade,
+            Some("migrate") => {
+                let target = input
+                    .migrate_to
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|t| !t.is_empty())
+                    .ok_or_else(|| {
+                        AppError::invalid("migrate_to", "required when on_translations=migrate")
+                    })?;
+                let target = self
+                    .repo
+                    .list_all()
+                    .await?
+                    .into_iter()
+                    .find(|l| l.code.eq_ignore_ascii_case(target))
+                    .ok_or_else(|| {
+                        AppError::invalid("migrate_to", format!("unknown language {target}"))
+                    })?;
+                if target.code == code {
+                    return Err(AppError::invalid(
+                        "migrate_to",
+                        "must differ from the deleted language",
+                    ));
+                }
+                // Ngôn ngữ mặc định không có bản dịch (nội dung nằm ở bản ghi gốc)
+                if target.is_default == Some(true) {
+                    return Err(AppError::invalid(
+                        "migrate_to",
+                        format!(
+                            "{} is the default language and has no translations",
+                            target.code
+                        ),
+                    ));
+                }
+                TranslationDisposal::Migrate { to: target.code }
+            }
+            Some(other) => {
+                return Err(AppError::invalid(
+                    "on_translations",
+                    format!("{other} (expected migrate or cascade)"),
+                ));
+            }
+        };
+
+        match self.repo.soft_delete(&code, disposal).await? {
+            LanguageDeletion::Deleted { migrated, removed } => Ok(DeleteLanguageOutput {
+                code,
+                migrated,
+                removed,
+            }),
+            LanguageDeletion::NotFound => Err(AppError::new(
+                ErrorKind::NotFound,
+                "LANGUAGE_NOT_FOUND",
+                format!("Language {code} not found"),
+            )
+            .with("code", code)),
+            LanguageDeletion::IsDefault => Err(AppError::new(
+                ErrorKind::Conflict,
+                "LANGUAGE_DELETE_DEFAULT",
+                format!("Conflict: {code} is the default language, set another default first"),
+            )
+            .with("code", code)),
+            LanguageDeletion::HasTranslations { count } => Err(AppError::new(
+                ErrorKind::Conflict,
+                "LANGUAGE_HAS_TRANSLATIONS",
+                format!(
+                    "Conflict: language {code} still has {count} translations, \
+                     use on_translations=migrate&migrate_to=<code> or on_translations=cascade"
+                ),
+            )
+            .with("code", code)
+            .with("count", count)),
+            LanguageDeletion::SlugConflicts { slugs } => {
+                let slugs = slugs.join(", ");
+                Err(AppError::new(
+                    ErrorKind::Conflict, "LANGUAGE_MIGRATE_SLUG_CONFLICT",
+                    format!(
+                        "Conflict: post translation slugs already used in migrate_to language: {slugs}"
+                    ),
+                )
+                .with("slugs", slugs))
+            }
+        }
+    }
+}
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::domain::entities::language::Language;
 use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::shared::error::AppError;
 
 pub struct GetDefaultLanguageUseCase {
     repo: Arc<dyn LanguageRepository + Send + Sync>,
@@ -12,10 +13,11 @@ impl GetDefaultLanguageUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self) -> Result<Option<Language>, String> {
-        self.repo
+    pub async fn execute(&self) -> Result<Option<Language>, AppError> {
+        Ok(self
+            .repo
             .get_default()
             .await
-            .map_err(|e| format!("Failed to get default language: {}", e))
+            .map_err(|e| format!("Failed to get default language: {}", e))?)
     }
 }
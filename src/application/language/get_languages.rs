@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::domain::entities::language::Language;
 use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::shared::error::AppError;
 
 pub struct GetLanguagesUseCase {
     repo: Arc<dyn LanguageRepository + Send + Sync>,
@@ -12,10 +13,11 @@ impl GetLanguagesUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self) -> Result<Vec<Language>, String> {
-        self.repo
+    pub async fn execute(&self) -> Result<Vec<Language>, AppError> {
+        Ok(self
+            .repo
             .list_all()
             .await
-            .map_err(|e| format!("Failed to list languages: {}", e))
+            .map_err(|e| format!("Failed to list languages: {}", e))?)
     }
 }
@@ -1,2 +1,6 @@
+pub mod create_language;
+pub mod delete_language;
 pub mod get_default_language;
 pub mod get_languages;
+pub mod set_default_language;
+pub mod update_language;
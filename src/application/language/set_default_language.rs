@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use crate::application::language::update_language::find_code;
+use crate::domain::entities::language::Language;
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::shared::error::{AppError, ErrorKind};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct SetDefaultLanguageInput {
+    pub code: String,
+}
+
+/// Đổi ngôn ngữ mặc định. Lưu ý: nội dung gốc (posts, categories, ...) không được dịch lại,
+/// bản dịch của ngôn ngữ mới vẫn nằm ở các bảng `*_translations`
+pub struct SetDefaultLanguageUseCase {
+    repo: Arc<dyn LanguageRepository>,
+}
+
+impl SetDefaultLanguageUseCase {
+    pub fn new(repo: Arc<dyn LanguageRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(&self, input: SetDefaultLanguageInput) -> Result<Language, AppError> {
+        let code = find_code(self.repo.as_ref(), &input.code).await?;
+        self.repo.set_default(&code).await?.ok_or_else(|| {
+            AppError::new(
+                ErrorKind::NotFound,
+                "LANGUAGE_NOT_FOUND",
+                format!("Language {code} not found"),
+            )
+            .with("code", code)
+        })
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use crate::application::translation::translation_language::required_text;
+use crate::domain::entities::language::Language;
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::shared::error::{AppError, ErrorKind};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct UpdateLanguageInput {
+    pub name: String,
+}
+
+/// Chỉ đổi tên hiển thị; `code` là khoá của bản dịch nên không đổi được
+pub struct UpdateLanguageUseCase {
+    repo: Arc<dyn LanguageRepository>,
+}
+
+impl UpdateLanguageUseCase {
+    pub fn new(repo: Arc<dyn LanguageRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        code: &str,
+        input: UpdateLanguageInput,
+    ) -> Result<Language, AppError> {
+        let name = required_text(&input.name, "name")?;
+        let code = find_code(self.repo.as_ref(), code).await?;
+        self.repo.update_name(&code, &name).await?.ok_or_else(|| {
+            AppError::new(
+                ErrorKind::NotFound,
+                "LANGUAGE_NOT_FOUND",
+                format!("Language {code} not found"),
+            )
+            .with("code", code)
+        })
+    }
+}
+
+/// Mã ngôn ngữ đúng như trong DB (URL có thể khác hoa/thường)
+pub async fn find_code(repo: &dyn LanguageRepository, code: &str) -> Result<String, AppError> {
+    let code = code.trim();
+    repo.list_all()
+        .await?
+        .into_iter()
+        .find(|l| l.code.eq_ignore_ascii_case(code))
+        .map(|l| l.code)
+        .ok_or_else(|| {
+            AppError::new(
+                ErrorKind::NotFound,
+                "LANGUAGE_NOT_FOUND",
+                format!("Language {code} not found"),
+            )
+            .with("code", code)
+        })
+}
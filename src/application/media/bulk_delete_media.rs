@@ -1,4 +1,5 @@
 use crate::domain::repositories::media_repository::MediaRepository;
+use crate::shared::error::AppError;
 use uuid::Uuid;
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -15,7 +16,7 @@ impl<R: MediaRepository> BulkDeleteMediaUseCase<R> {
         Self { repo }
     }
 
-    pub async fn execute(&self, input: BulkDeleteMediaInput) -> Result<(), String> {
-        self.repo.soft_delete_many(&input.ids).await
+    pub async fn execute(&self, input: BulkDeleteMediaInput) -> Result<(), AppError> {
+        Ok(self.repo.soft_delete_many(&input.ids).await?)
     }
 }
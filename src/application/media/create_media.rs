@@ -2,6 +2,7 @@ use crate::domain::{
     entities::media::{Media, NewMedia},
     repositories::media_repository::MediaRepository,
 };
+use crate::shared::error::AppError;
 
 pub struct CreateMediaUseCase<R: MediaRepository> {
     repo: R,
@@ -12,7 +13,7 @@ impl<R: MediaRepository> CreateMediaUseCase<R> {
         Self { repo }
     }
 
-    pub async fn execute(&self, media: NewMedia) -> Result<Media, String> {
-        self.repo.create(media).await
+    pub async fn execute(&self, media: NewMedia) -> Result<Media, AppError> {
+        Ok(self.repo.create(media).await?)
     }
 }
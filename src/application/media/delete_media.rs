@@ -1,4 +1,5 @@
 use crate::domain::repositories::media_repository::MediaRepository;
+use crate::shared::error::AppError;
 use uuid::Uuid;
 
 pub struct DeleteMediaUseCase<R: MediaRepository> {
@@ -10,7 +11,7 @@ impl<R: MediaRepository> DeleteMediaUseCase<R> {
         Self { repo }
     }
 
-    pub async fn execute(&self, id: Uuid) -> Result<(), String> {
-        self.repo.soft_delete(id).await
+    pub async fn execute(&self, id: Uuid) -> Result<(), AppError> {
+        Ok(self.repo.soft_delete(id).await?)
     }
 }
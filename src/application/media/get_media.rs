@@ -3,6 +3,7 @@ use crate::domain::{
     entities::media::Media,
     repositories::media_repository::{MediaRepository, MediaSearchFilter},
 };
+use crate::shared::error::AppError;
 
 pub struct GetMediaUseCase<R: MediaRepository> {
     repo: R,
@@ -15,20 +16,20 @@ impl<R: MediaRepository> GetMediaUseCase<R> {
         Self { repo }
     }
 
-    pub async fn get_by_id(&self, id: Uuid) -> Result<Option<Media>, String> {
-        self.repo.find_by_id(id).await
+    pub async fn get_by_id(&self, id: Uuid) -> Result<Option<Media>, AppError> {
+        Ok(self.repo.find_by_id(id).await?)
     }
 
     #[allow(dead_code)]
-    pub async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<Media>, String> {
-        self.repo.find_by_user_id(user_id).await
+    pub async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<Media>, AppError> {
+        Ok(self.repo.find_by_user_id(user_id).await?)
     }
 
     pub async fn get_by_user_paginated(
         &self,
         user_id: Uuid,
         params: &ListParams,
-    ) -> Result<PaginatedResult<Media>, String> {
+    ) -> Result<PaginatedResult<Media>, AppError> {
         let filter = MediaSearchFilter {
             search: params.search.clone(),
             user_id: Some(user_id),
@@ -48,7 +49,7 @@ impl<R: MediaRepository> GetMediaUseCase<R> {
     pub async fn get_all_paginated(
         &self,
         params: &ListParams,
-    ) -> Result<PaginatedResult<Media>, String> {
+    ) -> Result<PaginatedResult<Media>, AppError> {
         let filter = MediaSearchFilter {
             search: params.search.clone(),
             user_id: None,
@@ -0,0 +1,29 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::entities::media::Media;
+use crate::shared::utils::media::{media_url, media_variants};
+
+/// Media đã resolve URL public, nhúng vào response (bài viết, gallery, ...)
+#[derive(Debug, Clone, Serialize)]
+pub struct MediaView {
+    pub id: Uuid,
+    pub media_type: String,
+    pub url: String,
+    /// `tên biến thể => URL`, chỉ có với ảnh
+    pub variants: BTreeMap<String, String>,
+}
+
+impl From<&Media> for MediaView {
+    fn from(media: &Media) -> Self {
+        let url = media_url(&media.file_path);
+        Self {
+            id: media.id,
+            media_type: media.media_type.clone(),
+            variants: media_variants(&media.media_type, &url),
+            url,
+        }
+    }
+}
@@ -2,4 +2,5 @@ pub mod bulk_delete_media;
 pub mod create_media;
 pub mod delete_media;
 pub mod get_media;
+pub mod media_view;
 pub mod update_media;
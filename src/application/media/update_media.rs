@@ -1,4 +1,5 @@
 use crate::domain::{entities::media::Media, repositories::media_repository::MediaRepository};
+use crate::shared::error::AppError;
 use uuid::Uuid;
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -16,12 +17,12 @@ impl<R: MediaRepository> UpdateMediaUseCase<R> {
         Self { repo }
     }
 
-    pub async fn execute(&self, id: Uuid, input: UpdateMediaInput) -> Result<Media, String> {
+    pub async fn execute(&self, id: Uuid, input: UpdateMediaInput) -> Result<Media, AppError> {
         let existing = self
             .repo
             .find_by_id(id)
             .await?
-            .ok_or_else(|| "Media not found".to_string())?;
+            .ok_or_else(|| AppError::not_found("MEDIA_NOT_FOUND", "Media"))?;
 
         let media = Media {
             id: existing.id,
@@ -33,6 +34,6 @@ impl<R: MediaRepository> UpdateMediaUseCase<R> {
             deleted_at: existing.deleted_at,
         };
 
-        self.repo.update(media).await
+        Ok(self.repo.update(media).await?)
     }
 }
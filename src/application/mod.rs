@@ -2,12 +2,18 @@ pub mod audit_log;
 pub mod banner;
 pub mod category;
 pub mod common;
+pub mod feed;
+pub mod i18n;
 pub mod language;
 pub mod media;
 pub mod permission;
 pub mod post;
+pub mod redirect;
 pub mod role;
+pub mod seo;
 pub mod settings;
+pub mod sitemap;
 pub mod tag;
+pub mod translation;
 pub mod user;
 pub mod user_profile;
@@ -1,5 +1,6 @@
 use crate::domain::entities::permission::{NewPermission, Permission};
 use crate::domain::repositories::permission_repository::PermissionRepository;
+use crate::shared::error::AppError;
 use std::sync::Arc;
 
 pub struct CreatePermissionUseCase {
@@ -11,7 +12,7 @@ impl CreatePermissionUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, permission: NewPermission) -> Result<Permission, String> {
-        self.repo.create(permission).await
+    pub async fn execute(&self, permission: NewPermission) -> Result<Permission, AppError> {
+        Ok(self.repo.create(permission).await?)
     }
 }
@@ -1,4 +1,5 @@
 use crate::domain::repositories::permission_repository::PermissionRepository;
+use crate::shared::error::AppError;
 use std::sync::Arc;
 
 pub struct DeletePermissionUseCase {
@@ -12,7 +13,7 @@ impl DeletePermissionUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, id: Uuid) -> Result<(), String> {
+    pub async fn execute(&self, id: Uuid) -> Result<(), AppError> {
         self.repo.delete(id).await
     }
 }
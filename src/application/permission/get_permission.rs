@@ -1,5 +1,6 @@
 use crate::domain::entities::permission::Permission;
 use crate::domain::repositories::permission_repository::PermissionRepository;
+use crate::shared::error::AppError;
 use std::sync::Arc;
 
 pub struct GetPermissionUseCase {
@@ -13,7 +14,7 @@ impl GetPermissionUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, id: Uuid) -> Result<Option<Permission>, String> {
-        self.repo.find_by_id(id).await
+    pub async fn execute(&self, id: Uuid) -> Result<Option<Permission>, AppError> {
+        Ok(self.repo.find_by_id(id).await?)
     }
 }
@@ -3,6 +3,7 @@ use crate::domain::entities::permission::Permission;
 use crate::domain::repositories::permission_repository::{
     PermissionRepository, PermissionSearchFilter,
 };
+use crate::shared::error::AppError;
 use std::sync::Arc;
 
 pub struct GetPermissionsUseCase {
@@ -17,7 +18,7 @@ impl GetPermissionsUseCase {
     pub async fn execute(
         &self,
         params: &ListParams,
-    ) -> Result<PaginatedResult<Permission>, String> {
+    ) -> Result<PaginatedResult<Permission>, AppError> {
         let filter = PermissionSearchFilter {
             search: params.search.clone(),
         };
@@ -1,5 +1,6 @@
 use crate::domain::entities::permission::Permission;
 use crate::domain::repositories::permission_repository::PermissionRepository;
+use crate::shared::error::AppError;
 use std::sync::Arc;
 
 pub struct GetPermissionsByRoleUseCase {
@@ -13,7 +14,7 @@ impl GetPermissionsByRoleUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, role_id: Uuid) -> Result<Vec<Permission>, String> {
-        self.repo.find_by_role_id(role_id).await
+    pub async fn execute(&self, role_id: Uuid) -> Result<Vec<Permission>, AppError> {
+        Ok(self.repo.find_by_role_id(role_id).await?)
     }
 }
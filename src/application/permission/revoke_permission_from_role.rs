@@ -1,4 +1,5 @@
 use crate::domain::repositories::permission_repository::PermissionRepository;
+use crate::shared::error::AppError;
 use std::sync::Arc;
 
 pub struct RevokePermissionFromRoleUseCase {
@@ -12,7 +13,7 @@ impl RevokePermissionFromRoleUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, role_id: Uuid, permission_id: Uuid) -> Result<(), String> {
-        self.repo.revoke_from_role(role_id, permission_id).await
+    pub async fn execute(&self, role_id: Uuid, permission_id: Uuid) -> Result<(), AppError> {
+        Ok(self.repo.revoke_from_role(role_id, permission_id).await?)
     }
 }
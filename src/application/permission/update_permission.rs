@@ -1,5 +1,6 @@
 use crate::domain::entities::permission::{Permission, UpdatePermission};
 use crate::domain::repositories::permission_repository::PermissionRepository;
+use crate::shared::error::{AppError, ErrorKind};
 use std::sync::Arc;
 
 pub struct UpdatePermissionUseCase {
@@ -17,7 +18,14 @@ impl UpdatePermissionUseCase {
         &self,
         id: Uuid,
         permission: UpdatePermission,
-    ) -> Result<Permission, String> {
-        self.repo.update(id, permission).await
+    ) -> Result<Permission, AppError> {
+        if permission.name.is_none() {
+            return Err(AppError::new(
+                ErrorKind::Invalid,
+                "NO_FIELDS_TO_UPDATE",
+                "No fields to update",
+            ));
+        }
+        Ok(self.repo.update(id, permission).await?)
     }
 }
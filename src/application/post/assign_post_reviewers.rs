@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::post::post_workflow::{
+    POST_PUBLISH_PERMISSION, POST_REVIEW_PERMISSION, ensure_permission,
+};
+use crate::domain::entities::post_review::PostReviewer;
+use crate::domain::repositories::permission_repository::PermissionRepository;
+use crate::domain::repositories::post_repository::PostRepository;
+use crate::domain::repositories::post_review_repository::PostReviewRepository;
+use crate::shared::error::{AppError, ErrorKind};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct AssignPostReviewersInput {
+    /// Thay thế toàn bộ reviewer, mỗi người phải có quyền `post:review`
+    pub reviewer_ids: Vec<Uuid>,
+}
+
+pub struct AssignPostReviewersUseCase {
+    post_repo: Arc<dyn PostRepository>,
+    review_repo: Arc<dyn PostReviewRepository>,
+    permission_repo: Arc<dyn PermissionRepository>,
+}
+
+impl AssignPostReviewersUseCase {
+    pub fn new(
+        post_repo: Arc<dyn PostRepository>,
+        review_repo: Arc<dyn PostReviewRepository>,
+        permission_repo: Arc<dyn PermissionRepository>,
+    ) -> Self {
+        Self {
+            post_repo,
+            review_repo,
+            permission_repo,
+        }
+    }
+
+    /// `assigned_by`: người gán, phải có quyền `post:publish` (editor)
+    pub async fn execute(
+        &self,
+        slug: &str,
+        input: AssignPostReviewersInput,
+        assigned_by: Option<Uuid>,
+    ) -> Result<Vec<PostReviewer>, AppError> {
+        ensure_permission(
+            self.permission_repo.as_ref(),
+            assigned_by,
+            POST_PUBLISH_PERMISSION,
+        )
+        .await?;
+
+        let post = self
+            .post_repo
+            .find_by_slug(slug)
+            .await?
+            .ok_or_else(|| AppError::not_found("POST_NOT_FOUND", "Post"))?;
+
+        let mut reviewer_ids: Vec<Uuid> = Vec::new();
+        for id in input.reviewer_ids {
+            if !reviewer_ids.contains(&id) {
+                reviewer_ids.push(id);
+            }
+        }
+
+        for id in &reviewer_ids {
+            if !self
+                .permission_repo
+                .user_has_permission(*id, POST_REVIEW_PERMISSION)
+                .await?
+            {
+                return Err(AppError::new(
+                    ErrorKind::Invalid,
+                    "POST_REVIEWER_MISSING_PERMISSION",
+                    format!(
+                        "Invalid reviewer: {id} does not have {POST_REVIEW_PERMISSION} permission"
+                    ),
+                )
+                .with("id", id.to_string())
+                .with("permission", POST_REVIEW_PERMISSION));
+            }
+        }
+
+        self.review_repo
+            .set_reviewers(post.id, &reviewer_ids, assigned_by)
+            .await?;
+        Ok(self.review_repo.list_reviewers(post.id).await?)
+    }
+}
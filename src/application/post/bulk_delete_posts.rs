@@ -3,6 +3,7 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::domain::repositories::post_repository::PostRepository;
+use crate::shared::error::AppError;
 
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct BulkDeletePostsInput {
@@ -18,7 +19,7 @@ impl BulkDeletePostsUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, input: BulkDeletePostsInput) -> Result<(), String> {
-        self.repo.soft_delete_many(&input.ids).await
+    pub async fn execute(&self, input: BulkDeletePostsInput) -> Result<(), AppError> {
+        Ok(self.repo.soft_delete_many(&input.ids).await?)
     }
 }
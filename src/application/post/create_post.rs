@@ -2,46 +2,159 @@ use std::sync::Arc;
 
 use uuid::Uuid;
 
-use crate::domain::entities::post::{Post, PostStatus};
+use crate::application::common::resolve_slug::save_with_slug;
+use crate::application::post::post_content::render_post_content;
+use crate::application::post::post_content_blocks::resolve_content_blocks;
+use crate::application::post::post_media::ensure_featured_media;
+use crate::application::post::post_schedule::apply_post_schedule;
+use crate::application::post::post_workflow::ensure_status_transition;
+use crate::application::post::record_post_revision::post_revision_options;
+use crate::application::post::resolve_post_tags::resolve_post_tag_ids;
+use crate::domain::entities::post::{ContentFormat, Post, PostStatus};
+use crate::domain::repositories::banner_repository::BannerRepository;
+use crate::domain::repositories::media_repository::MediaRepository;
+use crate::domain::repositories::permission_repository::PermissionRepository;
 use crate::domain::repositories::post_repository::PostRepository;
+use crate::domain::repositories::setting_repository::SettingRepository;
+use crate::domain::repositories::slug_repository::{SlugRepository, SlugScope};
+use crate::domain::repositories::tag_repository::TagRepository;
+use crate::shared::error::AppError;
 
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct CreatePostInput {
     pub author_id: Uuid,
     pub category_id: Option<Uuid>,
+    /// Ảnh đại diện (media loại ảnh của chính user hoặc cần quyền `media:read`)
+    pub featured_media_id: Option<Uuid>,
     pub title: String,
     pub slug: Option<String>,
     pub excerpt: Option<String>,
     pub content: Option<String>,
+    /// `markdown`, `html` (mặc định) hoặc `plain`
+    pub content_format: Option<ContentFormat>,
+    /// Nội dung dạng block `{"version": 1, "blocks": [...]}`, `blocks` rỗng => bỏ block
+    pub content_blocks: Option<serde_json::Value>,
     pub status: Option<PostStatus>,
+    /// `published_at` ở tương lai => bài được lên lịch (`scheduled`)
     pub published_at: Option<chrono::NaiveDateTime>,
+    /// Tự động gỡ bài (chuyển sang `archived`) tại thời điểm này
+    pub unpublish_at: Option<chrono::NaiveDateTime>,
+    /// Gán tag theo id hoặc slug (chỉ tag type `post`)
+    pub tag_ids: Option<Vec<Uuid>>,
+    pub tag_slugs: Option<Vec<String>>,
 }
 
 pub struct CreatePostUseCase {
     repo: Arc<dyn PostRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+    setting_repo: Arc<dyn SettingRepository>,
+    permission_repo: Arc<dyn PermissionRepository>,
+    slug_repo: Arc<dyn SlugRepository>,
+    media_repo: Arc<dyn MediaRepository>,
+    banner_repo: Arc<dyn BannerRepository>,
 }
 
 impl CreatePostUseCase {
-    pub fn new(repo: Arc<dyn PostRepository>) -> Self {
-        Self { repo }
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        repo: Arc<dyn PostRepository>,
+        tag_repo: Arc<dyn TagRepository>,
+        setting_repo: Arc<dyn SettingRepository>,
+        permission_repo: Arc<dyn PermissionRepository>,
+        slug_repo: Arc<dyn SlugRepository>,
+        media_repo: Arc<dyn MediaRepository>,
+        banner_repo: Arc<dyn BannerRepository>,
+    ) -> Self {
+        Self {
+            repo,
+            tag_repo,
+            setting_repo,
+            permission_repo,
+            slug_repo,
+            media_repo,
+            banner_repo,
+        }
     }
 
-    pub async fn execute(&self, input: CreatePostInput) -> Result<Post, String> {
-        let post = Post {
+    /// `saved_by`: user thực hiện lần lưu (ghi vào revision, kiểm tra quyền chuyển trạng thái)
+    pub async fn execute(
+        &self,
+        input: CreatePostInput,
+        saved_by: Option<Uuid>,
+    ) -> Result<Post, AppError> {
+        let status = input.status.unwrap_or(PostStatus::Draft);
+        ensure_status_transition(self.permission_repo.as_ref(), saved_by, None, &status).await?;
+
+        let tag_ids =
+            resolve_post_tag_ids(self.tag_repo.as_ref(), input.tag_ids, input.tag_slugs).await?;
+
+        if let Some(media_id) = input.featured_media_id {
+            ensure_featured_media(
+                self.media_repo.as_ref(),
+                self.permission_repo.as_ref(),
+                saved_by,
+                media_id,
+            )
+            .await?;
+        }
+
+        let content_blocks = match input.content_blocks {
+            Some(value) => {
+                resolve_content_blocks(
+                    self.media_repo.as_ref(),
+                    self.banner_repo.as_ref(),
+                    self.permission_repo.as_ref(),
+                    saved_by,
+                    value,
+                )
+                .await?
+            }
+            None => None,
+        };
+
+        let mut post = Post {
             id: Uuid::new_v4(),
             author_id: input.author_id,
             category_id: input.category_id,
+            featured_media_id: input.featured_media_id,
             title: input.title,
-            slug: input.slug,
+            slug: None,
             excerpt: input.excerpt,
             content: input.content,
-            status: input.status.or(Some(PostStatus::Draft)),
+            content_format: input.content_format.unwrap_or_default(),
+            content_blocks,
+            content_html: None,
+            content_text: None,
+            toc: None,
+            word_count: 0,
+            reading_time_minutes: 0,
+            status: Some(status),
             published_at: input.published_at,
+            unpublish_at: input.unpublish_at,
             created_at: None,
             updated_at: None,
             deleted_at: None,
         };
 
-        self.repo.create(post).await
+        apply_post_schedule(&mut post)?;
+        render_post_content(&mut post, false);
+
+        let revision = post_revision_options(self.setting_repo.as_ref(), saved_by).await?;
+        let (repo, post, tag_ids, revision) = (&self.repo, &post, tag_ids.as_deref(), &revision);
+        save_with_slug(
+            self.slug_repo.as_ref(),
+            SlugScope::Post,
+            input.slug.as_deref(),
+            &post.title,
+            None,
+            move |slug| {
+                let post = Post {
+                    slug: Some(slug),
+                    ..post.clone()
+                };
+                repo.create(post, tag_ids, Some(revision))
+            },
+        )
+        .await
     }
 }
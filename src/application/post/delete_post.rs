@@ -3,6 +3,7 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::domain::repositories::post_repository::PostRepository;
+use crate::shared::error::AppError;
 
 pub struct DeletePostUseCase {
     repo: Arc<dyn PostRepository>,
@@ -13,7 +14,7 @@ impl DeletePostUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, id: Uuid) -> Result<(), String> {
-        self.repo.soft_delete(id).await
+    pub async fn execute(&self, id: Uuid) -> Result<(), AppError> {
+        Ok(self.repo.soft_delete(id).await?)
     }
 }
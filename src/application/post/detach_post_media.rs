@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::post::post_media::{PostMediaItem, ensure_media_access, load_post_media};
+use crate::domain::repositories::media_repository::MediaRepository;
+use crate::domain::repositories::permission_repository::PermissionRepository;
+use crate::domain::repositories::post_media_repository::PostMediaRepository;
+use crate::domain::repositories::post_repository::PostRepository;
+use crate::shared::error::AppError;
+
+pub struct DetachPostMediaUseCase {
+    post_repo: Arc<dyn PostRepository>,
+    post_media_repo: Arc<dyn PostMediaRepository>,
+    media_repo: Arc<dyn MediaRepository>,
+    permission_repo: Arc<dyn PermissionRepository>,
+}
+
+impl DetachPostMediaUseCase {
+    pub fn new(
+        post_repo: Arc<dyn PostRepository>,
+        post_media_repo: Arc<dyn PostMediaRepository>,
+        media_repo: Arc<dyn MediaRepository>,
+        permission_repo: Arc<dyn PermissionRepository>,
+    ) -> Self {
+        Self {
+            post_repo,
+            post_media_repo,
+            media_repo,
+            permission_repo,
+        }
+    }
+
+    /// Gỡ 1 media khỏi gallery, trả về gallery còn lại.
+    /// `actor` cần cùng quyền như khi gắn media (chủ media hoặc `media:read`)
+    pub async fn execute(
+        &self,
+        slug: &str,
+        media_id: Uuid,
+        actor: Option<Uuid>,
+    ) -> Result<Vec<PostMediaItem>, AppError> {
+        let post = self
+            .post_repo
+            .find_by_slug(slug)
+            .await?
+            .ok_or_else(|| AppError::not_found("POST_NOT_FOUND", "Post"))?;
+
+        ensure_media_access(
+            self.media_repo.as_ref(),
+            self.permission_repo.as_ref(),
+            actor,
+            &[media_id],
+        )
+        .await?;
+
+        self.post_media_repo.remove(post.id, media_id).await?;
+
+        let mut gallery = load_post_media(
+            self.post_media_repo.as_ref(),
+            self.media_repo.as_ref(),
+            &[post.id],
+        )
+        .await?;
+        Ok(gallery.remove(&post.id).unwrap_or_default())
+    }
+}
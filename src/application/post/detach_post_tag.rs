@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use crate::application::post::resolve_post_tags::POST_TAG_TYPE;
+use crate::domain::entities::tag::Tag;
+use crate::domain::repositories::post_repository::PostRepository;
+use crate::domain::repositories::tag_repository::TagRepository;
+use crate::shared::error::AppError;
+
+pub struct DetachPostTagUseCase {
+    post_repo: Arc<dyn PostRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+}
+
+impl DetachPostTagUseCase {
+    pub fn new(post_repo: Arc<dyn PostRepository>, tag_repo: Arc<dyn TagRepository>) -> Self {
+        Self {
+            post_repo,
+            tag_repo,
+        }
+    }
+
+    /// Gỡ 1 tag (theo slug) khỏi bài viết, trả về danh sách tag còn lại
+    pub async fn execute(&self, slug: &str, tag_slug: &str) -> Result<Vec<Tag>, AppError> {
+        let post = self
+            .post_repo
+            .find_by_slug(slug)
+            .await?
+            .ok_or_else(|| AppError::not_found("POST_NOT_FOUND", "Post"))?;
+        let tag = self
+            .tag_repo
+            .find_by_slug_and_type(tag_slug, POST_TAG_TYPE)
+            .await?
+            .ok_or_else(|| AppError::not_found("TAG_NOT_FOUND", "Tag"))?;
+
+        self.post_repo.detach_tags(post.id, &[tag.id]).await?;
+
+        let tags = self.tag_repo.find_by_post_ids(&[post.id]).await?;
+        Ok(tags.into_iter().map(|(_, tag)| tag).collect())
+    }
+}
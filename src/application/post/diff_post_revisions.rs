@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::domain::repositories::post_repository::PostRepository;
+use crate::domain::repositories::post_revision_repository::PostRevisionRepository;
+use crate::shared::error::{AppError, ErrorKind};
+use crate::shared::utils::diff::{DiffLine, line_diff};
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct DiffPostRevisionsInput {
+    pub from: i32,
+    pub to: i32,
+}
+
+/// Diff theo dòng giữa 2 revision, tách theo từng field
+#[derive(Debug, Clone, Serialize)]
+pub struct PostRevisionDiff {
+    pub from: i32,
+    pub to: i32,
+    pub title: Vec<DiffLine>,
+    pub excerpt: Vec<DiffLine>,
+    pub content: Vec<DiffLine>,
+}
+
+pub struct DiffPostRevisionsUseCase {
+    post_repo: Arc<dyn PostRepository>,
+    revision_repo: Arc<dyn PostRevisionRepository>,
+}
+
+impl DiffPostRevisionsUseCase {
+    pub fn new(
+        post_repo: Arc<dyn PostRepository>,
+        revision_repo: Arc<dyn PostRevisionRepository>,
+    ) -> Self {
+        Self {
+            post_repo,
+            revision_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        slug: &str,
+        input: DiffPostRevisionsInput,
+    ) -> Result<PostRevisionDiff, AppError> {
+        let post = self
+            .post_repo
+            .find_by_slug(slug)
+            .await?
+            .ok_or_else(|| AppError::not_found("POST_NOT_FOUND", "Post"))?;
+
+        let mut revisions = Vec::with_capacity(2);
+        for number in [input.from, input.to] {
+            let revision = self
+                .revision_repo
+                .find_by_number(post.id, number)
+                .await?
+                .ok_or_else(|| {
+                    AppError::new(
+                        ErrorKind::NotFound,
+                        "REVISION_NOT_FOUND",
+                        format!("Revision {number} not found"),
+                    )
+                    .with("number", number)
+                })?;
+            revisions.push(revision);
+        }
+        let (old, new) = (&revisions[0], &revisions[1]);
+
+        Ok(PostRevisionDiff {
+            from: old.revision_number,
+            to: new.revision_number,
+            title: line_diff(&old.title, &new.title),
+            excerpt: line_diff(
+                old.excerpt.as_deref().unwrap_or_default(),
+                new.excerpt.as_deref().unwrap_or_default(),
+            ),
+            content: line_diff(
+                old.content.as_deref().unwrap_or_default(),
+                new.content.as_deref().unwrap_or_default(),
+            ),
+        })
+    }
+}
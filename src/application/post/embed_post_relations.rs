@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::application::media::media_view::MediaView;
+use crate::application::post::post_media::{PostMediaItem, load_post_media};
+use crate::domain::entities::category::Category;
+use crate::domain::entities::post::Post;
+use crate::domain::entities::tag::Tag;
+use crate::domain::repositories::category_repository::CategoryRepository;
+use crate::domain::repositories::media_repository::MediaRepository;
+use crate::domain::repositories::post_media_repository::PostMediaRepository;
+use crate::domain::repositories::tag_repository::TagRepository;
+use crate::domain::repositories::user_repository::UserRepository;
+use crate::shared::error::AppError;
+
+/// Các quan hệ có thể include cho bài viết
+pub const POST_INCLUDES: &[&str] = &["author", "category", "tags", "featured_media", "media"];
+
+/// Thông tin tác giả rút gọn khi nhúng vào bài viết
+#[derive(Debug, Clone, Serialize)]
+pub struct PostAuthor {
+    pub id: Uuid,
+    pub name: String,
+}
+
+/// Bài viết kèm các quan hệ đã include (field `None` sẽ không xuất hiện trong JSON)
+#[derive(Debug, Clone, Serialize)]
+pub struct PostView {
+    #[serde(flatten)]
+    pub post: Post,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<Option<PostAuthor>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<Option<Category>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<Tag>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub featured_media: Option<Option<MediaView>>,
+    /// Gallery theo thứ tự hiển thị
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media: Option<Vec<PostMediaItem>>,
+}
+
+pub struct EmbedPostRelationsUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    category_repo: Arc<dyn CategoryRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+    media_repo: Arc<dyn MediaRepository>,
+    post_media_repo: Arc<dyn PostMediaRepository>,
+}
+
+impl EmbedPostRelationsUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        category_repo: Arc<dyn CategoryRepository>,
+        tag_repo: Arc<dyn TagRepository>,
+        media_repo: Arc<dyn MediaRepository>,
+        post_media_repo: Arc<dyn PostMediaRepository>,
+    ) -> Self {
+        Self {
+            user_repo,
+            category_repo,
+            tag_repo,
+            media_repo,
+            post_media_repo,
+        }
+    }
+
+    /// Nhúng quan hệ cho danh sách bài viết, mỗi quan hệ chỉ tốn một query
+    pub async fn execute(
+        &self,
+        posts: Vec<Post>,
+        includes: &[String],
+    ) -> Result<Vec<PostView>, AppError> {
+        let has = |name: &str| includes.iter().any(|i| i == name);
+
+        let authors = if has("author") {
+            let mut ids: Vec<Uuid> = posts.iter().map(|p| p.author_id).collect();
+            ids.sort();
+            ids.dedup();
+            let users = self.user_repo.find_by_ids(&ids).await?;
+            Some(
+                users
+                    .into_iter()
+                    .map(|u| {
+                        (
+                            u.id,
+                            PostAuthor {
+                                id: u.id,
+                                name: u.name,
+                            },
+                        )
+                    })
+                    .collect::<HashMap<_, _>>(),
+            )
+        } else {
+            None
+        };
+
+        let categories = if has("category") {
+            let mut ids: Vec<Uuid> = posts.iter().filter_map(|p| p.category_id).collect();
+            ids.sort();
+            ids.dedup();
+            let categories = if ids.is_empty() {
+                Vec::new()
+            } else {
+                self.category_repo.find_by_ids(&ids).await?
+            };
+            Some(
+                categories
+                    .into_iter()
+                    .map(|c| (c.id, c))
+                    .collect::<HashMap<_, _>>(),
+            )
+        } else {
+            None
+        };
+
+        let tags = if has("tags") {
+            let ids: Vec<Uuid> = posts.iter().map(|p| p.id).collect();
+            let mut grouped: HashMap<Uuid, Vec<Tag>> = HashMap::new();
+            for (post_id, tag) in self.tag_repo.find_by_post_ids(&ids).await? {
+                grouped.entry(post_id).or_default().push(tag);
+            }
+            Some(grouped)
+        } else {
+            None
+        };
+
+        let featured_media = if has("featured_media") {
+            let mut ids: Vec<Uuid> = posts.iter().filter_map(|p| p.featured_media_id).collect();
+            ids.sort();
+            ids.dedup();
+            let media = self.media_repo.find_by_ids(&ids).await?;
+            Some(
+                media
+                    .iter()
+                    .map(|m| (m.id, MediaView::from(m)))
+                    .collect::<HashMap<_, _>>(),
+            )
+        } else {
+            None
+        };
+
+        let gallery = if has("media") {
+            let ids: Vec<Uuid> = posts.iter().map(|p| p.id).collect();
+            Some(
+                load_post_media(
+                    self.post_media_repo.as_ref(),
+                    self.media_repo.as_ref(),
+                    &ids,
+                )
+                .await?,
+            )
+        } else {
+            None
+        };
+
+        Ok(posts
+            .into_iter()
+            .map(|post| PostView {
+                author: authors.as_ref().map(|m| m.get(&post.author_id).cloned()),
+                category: categories
+                    .as_ref()
+                    .map(|m| post.category_id.and_then(|id| m.get(&id).cloned())),
+                tags: tags
+                    .as_ref()
+                    .map(|m| m.get(&post.id).cloned().unwrap_or_default()),
+                featured_media: featured_media
+                    .as_ref()
+                    .map(|m| post.featured_media_id.and_then(|id| m.get(&id).cloned())),
+                media: gallery
+                    .as_ref()
+                    .map(|m| m.get(&post.id).cloned().unwrap_or_default()),
+                post,
+            })
+            .collect())
+    }
+}
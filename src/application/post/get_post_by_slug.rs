@@ -1,18 +1,64 @@
 use std::sync::Arc;
 
+use crate::application::post::post_content::render_post_content;
 use crate::domain::entities::post::Post;
 use crate::domain::repositories::post_repository::PostRepository;
+use crate::domain::repositories::post_translation_repository::PostTranslationRepository;
+use crate::shared::error::AppError;
+
+/// Bài tìm được theo slug; `language_code` có giá trị khi slug là slug của bản dịch
+pub struct PostBySlug {
+    pub post: Post,
+    pub language_code: Option<String>,
+}
 
 pub struct GetPostBySlugUseCase {
     repo: Arc<dyn PostRepository>,
+    translation_repo: Arc<dyn PostTranslationRepository>,
 }
 
 impl GetPostBySlugUseCase {
-    pub fn new(repo: Arc<dyn PostRepository>) -> Self {
-        Self { repo }
+    pub fn new(
+        repo: Arc<dyn PostRepository>,
+        translation_repo: Arc<dyn PostTranslationRepository>,
+    ) -> Self {
+        Self {
+            repo,
+            translation_repo,
+        }
     }
 
-    pub async fn execute(&self, slug: &str) -> Result<Option<Post>, String> {
-        self.repo.find_by_slug(slug).await
+    /// Tìm theo slug gốc trước, sau đó tới slug đã dịch
+    /// (`lang` có giá trị => chỉ xét bản dịch của ngôn ngữ đó)
+    pub async fn execute(
+        &self,
+        slug: &str,
+        lang: Option<&str>,
+    ) -> Result<Option<PostBySlug>, AppError> {
+        let mut found = match self.repo.find_by_slug(slug).await? {
+            Some(post) => Some(PostBySlug {
+                post,
+                language_code: None,
+            }),
+            None => match self.translation_repo.find_by_slug(slug, lang).await? {
+                Some(translation) => self
+                    .repo
+                    .find_by_id(translation.post_id)
+                    .await?
+                    .map(|post| PostBySlug {
+                        post,
+                        language_code: Some(translation.language_code),
+                    }),
+                None => None,
+            },
+        };
+        // Bài lưu trước khi có pipeline chưa có `content_html`: render tạm khi đọc
+        if let Some(found) = found.as_mut()
+            && found.post.content_html.is_none()
+            && found.post.content.is_some()
+        {
+            render_post_content(&mut found.post, false);
+        }
+        Ok(found)
     }
 }
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use crate::application::post::post_media::{PostMediaItem, load_post_media};
+use crate::domain::repositories::media_repository::MediaRepository;
+use crate::domain::repositories::post_media_repository::PostMediaRepository;
+use crate::domain::repositories::post_repository::PostRepository;
+use crate::shared::error::AppError;
+
+pub struct GetPostMediaUseCase {
+    post_repo: Arc<dyn PostRepository>,
+    post_media_repo: Arc<dyn PostMediaRepository>,
+    media_repo: Arc<dyn MediaRepository>,
+}
+
+impl GetPostMediaUseCase {
+    pub fn new(
+        post_repo: Arc<dyn PostRepository>,
+        post_media_repo: Arc<dyn PostMediaRepository>,
+        media_repo: Arc<dyn MediaRepository>,
+    ) -> Self {
+        Self {
+            post_repo,
+            post_media_repo,
+            media_repo,
+        }
+    }
+
+    pub async fn execute(&self, slug: &str) -> Result<Vec<PostMediaItem>, AppError> {
+        let post = self
+            .post_repo
+            .find_by_slug(slug)
+            .await?
+            .ok_or_else(|| AppError::not_found("POST_NOT_FOUND", "Post"))?;
+
+        let mut gallery = load_post_media(
+            self.post_media_repo.as_ref(),
+            self.media_repo.as_ref(),
+            &[post.id],
+        )
+        .await?;
+        Ok(gallery.remove(&post.id).unwrap_or_default())
+    }
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::domain::entities::post::PostStatus;
+use crate::domain::entities::post_review::{PostReviewComment, PostReviewer};
+use crate::domain::repositories::post_repository::PostRepository;
+use crate::domain::repositories::post_review_repository::PostReviewRepository;
+use crate::shared::error::AppError;
+
+/// Tình trạng review của bài viết: trạng thái, reviewer và nhận xét
+#[derive(Debug, Clone, Serialize)]
+pub struct PostReviewSummary {
+    pub status: Option<PostStatus>,
+    pub reviewers: Vec<PostReviewer>,
+    pub comments: Vec<PostReviewComment>,
+}
+
+pub struct GetPostReviewUseCase {
+    post_repo: Arc<dyn PostRepository>,
+    review_repo: Arc<dyn PostReviewRepository>,
+}
+
+impl GetPostReviewUseCase {
+    pub fn new(
+        post_repo: Arc<dyn PostRepository>,
+        review_repo: Arc<dyn PostReviewRepository>,
+    ) -> Self {
+        Self {
+            post_repo,
+            review_repo,
+        }
+    }
+
+    pub async fn execute(&self, slug: &str) -> Result<PostReviewSummary, AppError> {
+        let post = self
+            .post_repo
+            .find_by_slug(slug)
+            .await?
+            .ok_or_else(|| AppError::not_found("POST_NOT_FOUND", "Post"))?;
+
+        Ok(PostReviewSummary {
+            status: post.status,
+            reviewers: self.review_repo.list_reviewers(post.id).await?,
+            comments: self.review_repo.list_comments(post.id).await?,
+        })
+    }
+}
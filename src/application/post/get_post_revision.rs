@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use crate::domain::entities::post_revision::PostRevision;
+use crate::domain::repositories::post_repository::PostRepository;
+use crate::domain::repositories::post_revision_repository::PostRevisionRepository;
+use crate::shared::error::{AppError, ErrorKind};
+
+pub struct GetPostRevisionUseCase {
+    post_repo: Arc<dyn PostRepository>,
+    revision_repo: Arc<dyn PostRevisionRepository>,
+}
+
+impl GetPostRevisionUseCase {
+    pub fn new(
+        post_repo: Arc<dyn PostRepository>,
+        revision_repo: Arc<dyn PostRevisionRepository>,
+    ) -> Self {
+        Self {
+            post_repo,
+            revision_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        slug: &str,
+        revision_number: i32,
+    ) -> Result<PostRevision, AppError> {
+        let post = self
+            .post_repo
+            .find_by_slug(slug)
+            .await?
+            .ok_or_else(|| AppError::not_found("POST_NOT_FOUND", "Post"))?;
+        self.revision_repo
+            .find_by_number(post.id, revision_number)
+            .await?
+            .ok_or_else(|| {
+                AppError::new(
+                    ErrorKind::NotFound,
+                    "REVISION_NOT_FOUND",
+                    format!("Revision {revision_number} not found"),
+                )
+                .with("number", revision_number)
+            })
+    }
+}
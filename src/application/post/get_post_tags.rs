@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use crate::domain::entities::tag::Tag;
+use crate::domain::repositories::post_repository::PostRepository;
+use crate::domain::repositories::tag_repository::TagRepository;
+use crate::shared::error::AppError;
+
+pub struct GetPostTagsUseCase {
+    post_repo: Arc<dyn PostRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+}
+
+impl GetPostTagsUseCase {
+    pub fn new(post_repo: Arc<dyn PostRepository>, tag_repo: Arc<dyn TagRepository>) -> Self {
+        Self {
+            post_repo,
+            tag_repo,
+        }
+    }
+
+    pub async fn execute(&self, slug: &str) -> Result<Vec<Tag>, AppError> {
+        let post = self
+            .post_repo
+            .find_by_slug(slug)
+            .await?
+            .ok_or_else(|| AppError::not_found("POST_NOT_FOUND", "Post"))?;
+
+        let tags = self.tag_repo.find_by_post_ids(&[post.id]).await?;
+        Ok(tags.into_iter().map(|(_, tag)| tag).collect())
+    }
+}
@@ -1,7 +1,37 @@
 use std::sync::Arc;
 
-use crate::domain::entities::post::Post;
+use chrono::NaiveDateTime;
+use uuid::Uuid;
+
+use crate::application::common::list_params::{ListParams, PaginatedResult};
+use crate::domain::entities::post::{Post, PostStatus};
 use crate::domain::repositories::post_repository::{PostRepository, PostSearchFilter};
+use crate::shared::error::AppError;
+
+/// Filter riêng cho danh sách bài viết (đi kèm `ListParams`)
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct PostListFilterInput {
+    /// Bao gồm cả bài thuộc category con
+    pub category_id: Option<Uuid>,
+    /// Slug của tag
+    pub tag: Option<String>,
+    pub author_id: Option<Uuid>,
+    /// Bị bỏ qua ở chế độ public
+    pub status: Option<String>,
+    pub published_from: Option<NaiveDateTime>,
+    pub published_to: Option<NaiveDateTime>,
+}
+
+/// Chế độ xem danh sách bài viết
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PostListMode {
+    /// Chỉ bài đã publish
+    Public,
+    /// Admin/editor: mọi trạng thái, lọc được theo `status`
+    Editorial,
+    /// Bài `in_review` đang chờ reviewer này duyệt
+    AwaitingReview(Uuid),
+}
 
 pub struct GetPostsUseCase {
     repo: Arc<dyn PostRepository>,
@@ -12,13 +42,49 @@ impl GetPostsUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self) -> Result<Vec<Post>, String> {
-        // Tạm thời ignore filter chi tiết, PostRepository::search sẽ trả về bài viết đã publish.
+    pub async fn execute(
+        &self,
+        params: &ListParams,
+        input: PostListFilterInput,
+        mode: PostListMode,
+    ) -> Result<PaginatedResult<Post>, AppError> {
+        let public_only = mode == PostListMode::Public;
+        let (status, reviewer_id) = match mode {
+            PostListMode::AwaitingReview(reviewer_id) => {
+                (Some(PostStatus::InReview), Some(reviewer_id))
+            }
+            PostListMode::Public => (None, None),
+            PostListMode::Editorial => match input.status.as_deref() {
+                Some(s) => (
+                    Some(
+                        PostStatus::parse(s)
+                            .ok_or_else(|| AppError::invalid("status", s.to_string()))?,
+                    ),
+                    None,
+                ),
+                None => (None, None),
+            },
+        };
+
         let filter = PostSearchFilter {
-            search: None,
-            category_id: None,
-            status: None,
+            search: params.search.clone(),
+            category_id: input.category_id,
+            tag_slug: input.tag.filter(|t| !t.is_empty()),
+            author_id: input.author_id,
+            reviewer_id,
+            status,
+            published_from: input.published_from,
+            published_to: input.published_to,
+            public_only,
         };
-        self.repo.search(&filter).await
+        let limit = params.limit.unwrap_or(20).clamp(1, 100);
+        self.repo
+            .search(
+                &filter,
+                params.sort_by.clone(),
+                params.cursor.clone(),
+                limit,
+            )
+            .await
     }
 }
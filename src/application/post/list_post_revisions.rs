@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use crate::domain::entities::post_revision::PostRevision;
+use crate::domain::repositories::post_repository::PostRepository;
+use crate::domain::repositories::post_revision_repository::PostRevisionRepository;
+use crate::shared::error::AppError;
+
+pub struct ListPostRevisionsUseCase {
+    post_repo: Arc<dyn PostRepository>,
+    revision_repo: Arc<dyn PostRevisionRepository>,
+}
+
+impl ListPostRevisionsUseCase {
+    pub fn new(
+        post_repo: Arc<dyn PostRepository>,
+        revision_repo: Arc<dyn PostRevisionRepository>,
+    ) -> Self {
+        Self {
+            post_repo,
+            revision_repo,
+        }
+    }
+
+    pub async fn execute(&self, slug: &str) -> Result<Vec<PostRevision>, AppError> {
+        let post = self
+            .post_repo
+            .find_by_slug(slug)
+            .await?
+            .ok_or_else(|| AppError::not_found("POST_NOT_FOUND", "Post"))?;
+        Ok(self.revision_repo.list_by_post(post.id).await?)
+    }
+}
@@ -1,6 +1,29 @@
+pub mod assign_post_reviewers;
 pub mod bulk_delete_posts;
 pub mod create_post;
 pub mod delete_post;
+pub mod detach_post_media;
+pub mod detach_post_tag;
+pub mod diff_post_revisions;
+pub mod embed_post_relations;
 pub mod get_post_by_slug;
+pub mod get_post_media;
+pub mod get_post_review;
+pub mod get_post_revision;
+pub mod get_post_tags;
 pub mod get_posts;
+pub mod list_post_revisions;
+pub mod post_content;
+pub mod post_content_blocks;
+pub mod post_media;
+pub mod post_schedule;
+pub mod post_workflow;
+pub mod record_post_revision;
+pub mod resolve_post_tags;
+pub mod restore_post_revision;
+pub mod review_post;
+pub mod run_post_schedule;
+pub mod search_posts;
+pub mod set_post_media;
+pub mod set_post_tags;
 pub mod update_post;
@@ -0,0 +1,81 @@
+use crate::application::post::post_content_blocks::render_content_blocks;
+use crate::domain::entities::content_block::ContentBlocks;
+use crate::domain::entities::post::{ContentFormat, Post};
+use crate::shared::utils::content::{
+    AUTO_EXCERPT_LENGTH, add_heading_anchors, derive_excerpt, html_to_text, markdown_to_html,
+    plain_to_html, reading_time_minutes, sanitize_html, word_count,
+};
+
+/// Pipeline render nội dung trước khi lưu:
+/// `content_blocks` (nếu có) hoặc `content` (markdown/html/plain) → HTML đã sanitize + anchor heading,
+/// mục lục, text thuần (index tìm kiếm), số từ, thời gian đọc.
+/// `auto_excerpt = true` hoặc bài chưa có excerpt => sinh excerpt từ nội dung
+pub fn render_post_content(post: &mut Post, auto_excerpt: bool) {
+    let blocks = post
+        .content_blocks
+        .as_ref()
+        .and_then(|v| serde_json::from_value::<ContentBlocks>(v.clone()).ok());
+    let raw = post.content.as_deref().unwrap_or_default();
+
+    let unsafe_html = match blocks {
+        Some(doc) => render_content_blocks(&doc),
+        None if raw.trim().is_empty() => String::new(),
+        None => format_to_html(&post.content_format, raw),
+    };
+    if unsafe_html.trim().is_empty() {
+        post.content_html = None;
+        post.content_text = None;
+        post.toc = None;
+        post.word_count = 0;
+        post.reading_time_minutes = 0;
+        if auto_excerpt {
+            post.excerpt = None;
+        }
+        return;
+    }
+
+    let (html, toc) = add_heading_anchors(&sanitize_html(&unsafe_html));
+    let text = html_to_text(&html);
+    let words = word_count(&text);
+
+    let missing_excerpt = post.excerpt.as_deref().is_none_or(|e| e.trim().is_empty());
+    if auto_excerpt || missing_excerpt {
+        post.excerpt = Some(derive_excerpt(&text, AUTO_EXCERPT_LENGTH)).filter(|e| !e.is_empty());
+    }
+    post.content_html = Some(html);
+    post.content_text = Some(text);
+    post.toc = Some(serde_json::json!(toc));
+    post.word_count = i32::try_from(words).unwrap_or(i32::MAX);
+    post.reading_time_minutes = i32::try_from(reading_time_minutes(words)).unwrap_or(i32::MAX);
+}
+
+/// HTML đã sanitize của nội dung ngoài bài gốc (vd: bản dịch) theo `format` của bài
+pub fn render_content_html(format: &ContentFormat, raw: &str) -> Option<String> {
+    if raw.trim().is_empty() {
+        return None;
+    }
+    let (html, _) = add_heading_anchors(&sanitize_html(&format_to_html(format, raw)));
+    Some(html)
+}
+
+/// Nội dung thô → HTML chưa sanitize
+fn format_to_html(format: &ContentFormat, raw: &str) -> String {
+    match format {
+        ContentFormat::Markdown => markdown_to_html(raw),
+        ContentFormat::Html => raw.to_string(),
+        ContentFormat::Plain => plain_to_html(raw),
+    }
+}
+
+/// Excerpt hiện tại do pipeline sinh ra (không phải do người viết nhập),
+/// dùng để sinh lại khi nội dung thay đổi
+pub fn has_derived_excerpt(post: &Post) -> bool {
+    match (post.excerpt.as_deref(), post.content_html.as_deref()) {
+        (None, _) => true,
+        (Some(excerpt), _) if excerpt.trim().is_empty() => true,
+        (Some(excerpt), Some(html)) => {
+            derive_excerpt(&html_to_text(html), AUTO_EXCERPT_LENGTH) == excerpt
+        }
+        (Some(_), None) => false,
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::application::post::post_media::ensure_media_access;
+use crate::domain::entities::content_block::{CONTENT_BLOCKS_VERSION, ContentBlock, ContentBlocks};
+use crate::domain::entities::media::Media;
+use crate::domain::repositories::banner_repository::BannerRepository;
+use crate::domain::repositories::media_repository::MediaRepository;
+use crate::domain::repositories::permission_repository::PermissionRepository;
+use crate::shared::error::AppError;
+use crate::shared::utils::content::{escape_html, markdown_to_html};
+use crate::shared::utils::media::media_url;
+
+/// Số block tối đa trong 1 bài
+pub const MAX_CONTENT_BLOCKS: usize = 500;
+
+/// Validate `content_blocks` do client gửi lên theo schema phiên bản hiện tại:
+/// image phải trỏ tới media `actor` được dùng (như gallery, server điền `src`),
+/// banner phải tồn tại theo `key`. Trả `None` khi không có block nào (bài dùng lại `content`)
+pub async fn resolve_content_blocks(
+    media_repo: &dyn MediaRepository,
+    banner_repo: &dyn BannerRepository,
+    permission_repo: &dyn PermissionRepository,
+    actor: Option<Uuid>,
+    value: serde_json::Value,
+) -> Result<Option<serde_json::Value>, AppError> {
+    let mut doc: ContentBlocks = serde_json::from_value(value)
+        .map_err(|e| AppError::invalid("content_blocks", format!("{e}")))?;
+    if doc.version != CONTENT_BLOCKS_VERSION {
+        return Err(AppError::invalid(
+            "content_blocks",
+            format!(
+                "unsupported version {} (expected {CONTENT_BLOCKS_VERSION})",
+                doc.version
+            ),
+        ));
+    }
+    if doc.blocks.is_empty() {
+        return Ok(None);
+    }
+    if doc.blocks.len() > MAX_CONTENT_BLOCKS {
+        return Err(AppError::invalid(
+            "content_blocks",
+            format!("at most {MAX_CONTENT_BLOCKS} blocks allowed"),
+        ));
+    }
+
+    // 1 media có thể xuất hiện ở nhiều block
+    let mut media_ids: Vec<Uuid> = Vec::new();
+    for block in &doc.blocks {
+        if let ContentBlock::Image { media_id, .. } = block
+            && !media_ids.contains(media_id)
+        {
+            media_ids.push(*media_id);
+        }
+    }
+    let media: HashMap<Uuid, Media> = if media_ids.is_empty() {
+        HashMap::new()
+    } else {
+        ensure_media_access(media_repo, permission_repo, actor, &media_ids)
+            .await?
+            .into_iter()
+            .map(|m| (m.id, m))
+            .collect()
+    };
+
+    for (index, block) in doc.blocks.iter_mut().enumerate() {
+        let invalid =
+            |reason: &str| AppError::invalid("content_blocks", format!("block {index} {reason}"));
+        match block {
+            ContentBlock::Paragraph { text } | ContentBlock::Quote { text, .. } => {
+                if text.trim().is_empty() {
+                    return Err(invalid("text must not be empty"));
+                }
+            }
+            ContentBlock::Heading { level, text } => {
+                if !(1..=6).contains(level) {
+                    return Err(invalid("heading level must be between 1 and 6"));
+                }
+                if text.trim().is_empty() {
+                    return Err(invalid("text must not be empty"));
+                }
+            }
+            ContentBlock::Image { media_id, src, .. } => {
+                let media = media
+                    .get(media_id)
+                    .ok_or_else(|| invalid(&format!("references unknown media {media_id}")))?;
+                *src = Some(media_url(&media.file_path));
+            }
+            ContentBlock::Code { code, language } => {
+                if code.trim().is_empty() {
+                    return Err(invalid("code must not be empty"));
+                }
+                if let Some(lang) = language.as_deref()
+                    && !lang
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || "+#-_".contains(c))
+                {
+                    return Err(invalid("language contains invalid characters"));
+                }
+            }
+            ContentBlock::Embed { url, .. } => {
+                if !(url.starts_with("https://") || url.starts_with("http://")) {
+                    return Err(invalid("embed url must be http(s)"));
+                }
+            }
+            ContentBlock::Banner { key } => {
+                if banner_repo.get_by_key(key).await?.is_none() {
+                    return Err(invalid(&format!("references unknown banner '{key}'")));
+                }
+            }
+        }
+    }
+
+    Ok(serde_json::to_value(&doc)
+        .map(Some)
+        .map_err(|e| e.to_string())?)
+}
+
+/// Render block sang HTML (chưa sanitize, text đã escape). Banner không sinh HTML
+/// vì nội dung banner do frontend tải theo `key`
+pub fn render_content_blocks(doc: &ContentBlocks) -> String {
+    let mut out = String::new();
+    for block in &doc.blocks {
+        match block {
+            ContentBlock::Paragraph { text } => out.push_str(&markdown_to_html(text)),
+            ContentBlock::Heading { level, text } => {
+                out.push_str(&format!("<h{level}>{}</h{level}>\n", escape_html(text)));
+            }
+            ContentBlock::Image {
+                src, alt, caption, ..
+            } => {
+                let Some(src) = src else { continue };
+                out.push_str(&format!(
+                    "<figure><img src=\"{}\" alt=\"{}\">",
+                    escape_html(src),
+                    escape_html(alt.as_deref().unwrap_or_default())
+                ));
+                if let Some(caption) = caption {
+                    out.push_str(&format!(
+                        "<figcaption>{}</figcaption>",
+                        escape_html(caption)
+                    ));
+                }
+                out.push_str("</figure>\n");
+            }
+            ContentBlock::Quote { text, cite } => {
+                out.push_str(&format!("<blockquote><p>{}</p>", escape_html(text)));
+                if let Some(cite) = cite {
+                    out.push_str(&format!("<p>— {}</p>", escape_html(cite)));
+                }
+                out.push_str("</blockquote>\n");
+            }
+            ContentBlock::Code { code, language } => {
+                match language {
+                    Some(lang) => out.push_str(&format!(
+                        "<pre><code class=\"language-{}\">",
+                        escape_html(lang)
+                    )),
+                    None => out.push_str("<pre><code>"),
+                }
+                out.push_str(&escape_html(code));
+                out.push_str("</code></pre>\n");
+            }
+            ContentBlock::Embed { url, caption } => {
+                out.push_str(&format!(
+                    "<p><a href=\"{}\">{}</a></p>\n",
+                    escape_html(url),
+                    escape_html(caption.as_deref().unwrap_or(url))
+                ));
+            }
+            ContentBlock::Banner { .. } => {}
+        }
+    }
+    out
+}
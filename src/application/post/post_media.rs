@@ -0,0 +1,99 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::application::media::media_view::MediaView;
+use crate::application::post::post_workflow::ensure_permission;
+use crate::domain::entities::media::Media;
+use crate::domain::repositories::media_repository::MediaRepository;
+use crate::domain::repositories::permission_repository::PermissionRepository;
+use crate::domain::repositories::post_media_repository::PostMediaRepository;
+use crate::shared::error::AppError;
+
+/// Quyền dùng media của user khác (ảnh đại diện, gallery)
+pub const MEDIA_READ_PERMISSION: &str = "media:read";
+
+/// Số media tối đa trong gallery của 1 bài
+pub const MAX_POST_MEDIA: usize = 100;
+
+/// 1 media trong gallery kèm URL đã resolve
+#[derive(Debug, Clone, Serialize)]
+pub struct PostMediaItem {
+    #[serde(flatten)]
+    pub media: MediaView,
+    pub position: i32,
+    pub caption: Option<String>,
+}
+
+/// Kiểm tra `actor` được gắn các media `ids` vào bài viết: media phải tồn tại và
+/// thuộc về `actor`, hoặc `actor` có quyền `media:read`
+pub async fn ensure_media_access(
+    media_repo: &dyn MediaRepository,
+    permission_repo: &dyn PermissionRepository,
+    actor: Option<Uuid>,
+    ids: &[Uuid],
+) -> Result<Vec<Media>, AppError> {
+    let mut seen = HashSet::new();
+    if let Some(dup) = ids.iter().find(|id| !seen.insert(**id)) {
+        return Err(AppError::invalid("media", format!("{dup} is duplicated")));
+    }
+
+    let media = media_repo.find_by_ids(ids).await?;
+    let found: HashSet<Uuid> = media.iter().map(|m| m.id).collect();
+    if let Some(missing) = ids.iter().find(|id| !found.contains(id)) {
+        return Err(AppError::invalid("media", format!("{missing} not found")));
+    }
+
+    if media.iter().any(|m| Some(m.user_id) != actor) {
+        ensure_permission(permission_repo, actor, MEDIA_READ_PERMISSION).await?;
+    }
+    Ok(media)
+}
+
+/// Gallery của các bài viết (theo `position`), chỉ tốn 2 query
+pub async fn load_post_media(
+    post_media_repo: &dyn PostMediaRepository,
+    media_repo: &dyn MediaRepository,
+    post_ids: &[Uuid],
+) -> Result<HashMap<Uuid, Vec<PostMediaItem>>, AppError> {
+    let rows = post_media_repo.list_by_post_ids(post_ids).await?;
+    let mut media_ids: Vec<Uuid> = rows.iter().map(|r| r.media_id).collect();
+    media_ids.sort();
+    media_ids.dedup();
+    let media: HashMap<Uuid, Media> = media_repo
+        .find_by_ids(&media_ids)
+        .await?
+        .into_iter()
+        .map(|m| (m.id, m))
+        .collect();
+
+    let mut grouped: HashMap<Uuid, Vec<PostMediaItem>> = HashMap::new();
+    for row in rows {
+        if let Some(m) = media.get(&row.media_id) {
+            grouped.entry(row.post_id).or_default().push(PostMediaItem {
+                media: MediaView::from(m),
+                position: row.position,
+                caption: row.caption,
+            });
+        }
+    }
+    Ok(grouped)
+}
+
+/// Kiểm tra ảnh đại diện: media phải là ảnh và `actor` được phép dùng
+pub async fn ensure_featured_media(
+    media_repo: &dyn MediaRepository,
+    permission_repo: &dyn PermissionRepository,
+    actor: Option<Uuid>,
+    media_id: Uuid,
+) -> Result<(), AppError> {
+    let media = ensure_media_access(media_repo, permission_repo, actor, &[media_id]).await?;
+    if media.iter().any(|m| m.media_type != "images") {
+        return Err(AppError::invalid(
+            "featured media",
+            format!("{media_id} is not an image"),
+        ));
+    }
+    Ok(())
+}
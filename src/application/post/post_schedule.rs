@@ -0,0 +1,158 @@
+use chrono::{NaiveDateTime, Utc};
+
+use crate::domain::entities::post::{Post, PostStatus};
+use crate::shared::error::{AppError, ErrorKind};
+
+/// Chuẩn hoá trạng thái theo lịch trước khi lưu:
+/// - `published` với `published_at` ở tương lai => `scheduled`
+/// - `scheduled` đã tới `published_at` => `published` ngay
+/// - `scheduled` bắt buộc có `published_at`, `unpublish_at` phải sau `published_at`
+pub fn apply_post_schedule(post: &mut Post) -> Result<(), AppError> {
+    normalize_schedule(post, Utc::now().naive_utc())
+}
+
+fn normalize_schedule(post: &mut Post, now: NaiveDateTime) -> Result<(), AppError> {
+    match (&post.status, post.published_at) {
+        (Some(PostStatus::Scheduled), None) => {
+            return Err(AppError::new(
+                ErrorKind::Invalid,
+                "POST_SCHEDULE_PUBLISH_AT_REQUIRED",
+                "Invalid schedule: published_at is required for scheduled posts",
+            ));
+        }
+        (Some(PostStatus::Scheduled), Some(at)) if at <= now => {
+            post.status = Some(PostStatus::Published);
+        }
+        (Some(PostStatus::Published), Some(at)) if at > now => {
+            post.status = Some(PostStatus::Scheduled);
+        }
+        _ => {}
+    }
+
+    if let (Some(publish), Some(unpublish)) = (post.published_at, post.unpublish_at)
+        && unpublish <= publish
+    {
+        return Err(AppError::new(
+            ErrorKind::Invalid,
+            "POST_SCHEDULE_UNPUBLISH_BEFORE_PUBLISH",
+            "Invalid schedule: unpublish_at must be after published_at",
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, NaiveDate};
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::entities::post::ContentFormat;
+
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 1, 15)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap()
+    }
+
+    fn post(
+        status: PostStatus,
+        published_at: Option<NaiveDateTime>,
+        unpublish_at: Option<NaiveDateTime>,
+    ) -> Post {
+        Post {
+            id: Uuid::nil(),
+            author_id: Uuid::nil(),
+            category_id: None,
+            featured_media_id: None,
+            title: "Lịch đăng".to_string(),
+            slug: None,
+            excerpt: None,
+            content: None,
+            content_format: ContentFormat::default(),
+            content_blocks: None,
+            content_html: None,
+            content_text: None,
+            toc: None,
+            word_count: 0,
+            reading_time_minutes: 0,
+            status: Some(status),
+            published_at,
+            unpublish_at,
+            created_at: None,
+            updated_at: None,
+            deleted_at: None,
+        }
+    }
+
+    fn normalized(mut post: Post) -> Result<Option<PostStatus>, Option<&'static str>> {
+        normalize_schedule(&mut post, now())
+            .map(|_| post.status)
+            .map_err(|e| e.key())
+    }
+
+    #[test]
+    fn published_in_the_future_becomes_scheduled() {
+        let at = Some(now() + Duration::hours(1));
+        assert_eq!(
+            normalized(post(PostStatus::Published, at, None)),
+            Ok(Some(PostStatus::Scheduled))
+        );
+    }
+
+    #[test]
+    fn scheduled_that_is_due_becomes_published() {
+        for at in [now(), now() - Duration::minutes(1)] {
+            assert_eq!(
+                normalized(post(PostStatus::Scheduled, Some(at), None)),
+                Ok(Some(PostStatus::Published))
+            );
+        }
+    }
+
+    #[test]
+    fn other_statuses_are_left_alone() {
+        let future = Some(now() + Duration::days(1));
+        assert_eq!(
+            normalized(post(PostStatus::Draft, future, None)),
+            Ok(Some(PostStatus::Draft))
+        );
+        assert_eq!(
+            normalized(post(PostStatus::Published, None, None)),
+            Ok(Some(PostStatus::Published))
+        );
+        assert_eq!(
+            normalized(post(PostStatus::Scheduled, future, None)),
+            Ok(Some(PostStatus::Scheduled))
+        );
+    }
+
+    #[test]
+    fn scheduled_requires_published_at() {
+        assert_eq!(
+            normalized(post(PostStatus::Scheduled, None, None)),
+            Err(Some("POST_SCHEDULE_PUBLISH_AT_REQUIRED"))
+        );
+    }
+
+    #[test]
+    fn unpublish_at_must_follow_published_at() {
+        let at = now() + Duration::hours(1);
+        for unpublish in [at, at - Duration::minutes(1)] {
+            assert_eq!(
+                normalized(post(PostStatus::Published, Some(at), Some(unpublish))),
+                Err(Some("POST_SCHEDULE_UNPUBLISH_BEFORE_PUBLISH"))
+            );
+        }
+        assert_eq!(
+            normalized(post(
+                PostStatus::Published,
+                Some(at),
+                Some(at + Duration::days(7))
+            )),
+            Ok(Some(PostStatus::Scheduled))
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use uuid::Uuid;
+
+use crate::domain::entities::post::PostStatus;
+use crate::domain::repositories::permission_repository::PermissionRepository;
+use crate::shared::error::{AppError, ErrorKind};
+
+/// Quyền chuyển bài sang `published` / `scheduled`
+pub const POST_PUBLISH_PERMISSION: &str = "post:publish";
+/// Quyền review bài (yêu cầu chỉnh sửa, được gán làm reviewer)
+pub const POST_REVIEW_PERMISSION: &str = "post:review";
+
+/// Kiểm tra chuyển trạng thái theo quy trình biên tập và quyền của `actor`
+/// (`from = None`: bài mới tạo)
+pub async fn ensure_status_transition(
+    permission_repo: &dyn PermissionRepository,
+    actor: Option<Uuid>,
+    from: Option<&PostStatus>,
+    to: &PostStatus,
+) -> Result<(), AppError> {
+    if from == Some(to) {
+        return Ok(());
+    }
+    if !PostStatus::can_transition(from, to) {
+        let from = from.map(PostStatus::as_str).unwrap_or("new");
+        return Err(AppError::new(
+            ErrorKind::Invalid,
+            "POST_STATUS_TRANSITION_INVALID",
+            format!("Invalid status transition: {from} -> {}", to.as_str()),
+        )
+        .with("from", from)
+        .with("to", to.as_str()));
+    }
+
+    let required = if to.is_publication() {
+        Some(POST_PUBLISH_PERMISSION)
+    } else if *to == PostStatus::ChangesRequested {
+        Some(POST_REVIEW_PERMISSION)
+    } else {
+        None
+    };
+
+    if let Some(permission) = required {
+        ensure_permission(permission_repo, actor, permission).await?;
+    }
+    Ok(())
+}
+
+/// Lỗi `Forbidden: ...` nếu `actor` không có `permission`
+pub async fn ensure_permission(
+    permission_repo: &dyn PermissionRepository,
+    actor: Option<Uuid>,
+    permission: &str,
+) -> Result<(), AppError> {
+    let allowed = match actor {
+        Some(user_id) => {
+            permission_repo
+                .user_has_permission(user_id, permission)
+                .await?
+        }
+        None => false,
+    };
+    if allowed {
+        Ok(())
+    } else {
+        Err(missing_permission(permission))
+    }
+}
+
+/// `Forbidden` do thiếu permission
+pub fn missing_permission(permission: &str) -> AppError {
+    AppError::new(
+        ErrorKind::Forbidden,
+        "FORBIDDEN_PERMISSION",
+        format!("Forbidden: requires {permission} permission"),
+    )
+    .with("permission", permission)
+}
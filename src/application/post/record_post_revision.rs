@@ -0,0 +1,31 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::post_repository::PostRevisionOptions;
+use crate::domain::repositories::setting_repository::SettingRepository;
+use crate::shared::error::AppError;
+
+/// Key setting: số revision tối đa giữ lại cho mỗi bài viết (<= 0: không giới hạn)
+pub const REVISION_RETENTION_KEY: &str = "post_revision_retention";
+pub const DEFAULT_REVISION_RETENTION: i64 = 50;
+
+/// Tham số revision cho lần lưu bài viết: repo ghi snapshot và prune theo setting retention
+/// trong cùng transaction với bài viết
+pub async fn post_revision_options(
+    setting_repo: &dyn SettingRepository,
+    saved_by: Option<Uuid>,
+) -> Result<PostRevisionOptions, AppError> {
+    let retention = setting_repo
+        .get_by_key(REVISION_RETENTION_KEY)
+        .await?
+        .and_then(|s| s.value)
+        .and_then(|v| {
+            v.as_i64()
+                .or_else(|| v.as_str().and_then(|s| s.parse().ok()))
+        })
+        .unwrap_or(DEFAULT_REVISION_RETENTION);
+
+    Ok(PostRevisionOptions {
+        saved_by,
+        retention,
+    })
+}
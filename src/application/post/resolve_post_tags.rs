@@ -0,0 +1,60 @@
+use uuid::Uuid;
+
+use crate::domain::repositories::tag_repository::TagRepository;
+use crate::shared::error::AppError;
+
+pub const POST_TAG_TYPE: &str = "post";
+
+/// Gộp `tag_ids` + `tag_slugs` thành danh sách id tag (chỉ nhận tag `type = 'post'`).
+/// Trả về `None` khi client không gửi cả hai field (giữ nguyên tag hiện tại).
+pub async fn resolve_post_tag_ids(
+    tag_repo: &dyn TagRepository,
+    tag_ids: Option<Vec<Uuid>>,
+    tag_slugs: Option<Vec<String>>,
+) -> Result<Option<Vec<Uuid>>, AppError> {
+    if tag_ids.is_none() && tag_slugs.is_none() {
+        return Ok(None);
+    }
+
+    let mut resolved: Vec<Uuid> = Vec::new();
+
+    if let Some(ids) = tag_ids.filter(|ids| !ids.is_empty()) {
+        let tags = tag_repo.find_by_ids(&ids).await?;
+        for id in ids {
+            match tags.iter().find(|t| t.id == id) {
+                None => return Err(AppError::invalid("tag", format!("{id} not found"))),
+                Some(tag) if tag.r#type != POST_TAG_TYPE => {
+                    return Err(AppError::invalid(
+                        "tag",
+                        format!("{id} has type `{}`, expected `{POST_TAG_TYPE}`", tag.r#type),
+                    ));
+                }
+                Some(_) => resolved.push(id),
+            }
+        }
+    }
+
+    if let Some(slugs) = tag_slugs.filter(|slugs| !slugs.is_empty()) {
+        let tags = tag_repo
+            .find_by_slugs_and_type(&slugs, POST_TAG_TYPE)
+            .await?;
+        for slug in slugs {
+            match tags
+                .iter()
+                .find(|t| t.slug.as_deref() == Some(slug.as_str()))
+            {
+                Some(tag) => resolved.push(tag.id),
+                None => {
+                    return Err(AppError::invalid(
+                        "tag",
+                        format!("`{slug}` is not a post tag"),
+                    ));
+                }
+            }
+        }
+    }
+
+    resolved.sort();
+    resolved.dedup();
+    Ok(Some(resolved))
+}
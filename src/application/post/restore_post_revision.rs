@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::post::post_content::render_post_content;
+use crate::application::post::post_content_blocks::resolve_content_blocks;
+use crate::application::post::record_post_revision::post_revision_options;
+use crate::domain::entities::post::Post;
+use crate::domain::repositories::banner_repository::BannerRepository;
+use crate::domain::repositories::media_repository::MediaRepository;
+use crate::domain::repositories::permission_repository::PermissionRepository;
+use crate::domain::repositories::post_repository::PostRepository;
+use crate::domain::repositories::post_revision_repository::PostRevisionRepository;
+use crate::domain::repositories::setting_repository::SettingRepository;
+use crate::shared::error::{AppError, ErrorKind};
+
+pub struct RestorePostRevisionUseCase {
+    post_repo: Arc<dyn PostRepository>,
+    revision_repo: Arc<dyn PostRevisionRepository>,
+    setting_repo: Arc<dyn SettingRepository>,
+    permission_repo: Arc<dyn PermissionRepository>,
+    media_repo: Arc<dyn MediaRepository>,
+    banner_repo: Arc<dyn BannerRepository>,
+}
+
+impl RestorePostRevisionUseCase {
+    pub fn new(
+        post_repo: Arc<dyn PostRepository>,
+        revision_repo: Arc<dyn PostRevisionRepository>,
+        setting_repo: Arc<dyn SettingRepository>,
+        permission_repo: Arc<dyn PermissionRepository>,
+        media_repo: Arc<dyn MediaRepository>,
+        banner_repo: Arc<dyn BannerRepository>,
+    ) -> Self {
+        Self {
+            post_repo,
+            revision_repo,
+            setting_repo,
+            permission_repo,
+            media_repo,
+            banner_repo,
+        }
+    }
+
+    /// Khôi phục title/excerpt/content từ revision, lưu thành 1 revision mới.
+    /// Slug hiện tại được giữ nguyên để không làm hỏng URL. `content_blocks` được kiểm tra lại
+    /// như khi cập nhật (media trong revision có thể đã bị xoá hoặc không thuộc quyền người khôi phục)
+    pub async fn execute(
+        &self,
+        slug: &str,
+        revision_number: i32,
+        saved_by: Option<Uuid>,
+    ) -> Result<Post, AppError> {
+        let post = self
+            .post_repo
+            .find_by_slug(slug)
+            .await?
+            .ok_or_else(|| AppError::not_found("POST_NOT_FOUND", "Post"))?;
+        let revision = self
+            .revision_repo
+            .find_by_number(post.id, revision_number)
+            .await?
+            .ok_or_else(|| {
+                AppError::new(
+                    ErrorKind::NotFound,
+                    "REVISION_NOT_FOUND",
+                    format!("Revision {revision_number} not found"),
+                )
+                .with("number", revision_number)
+            })?;
+
+        let content_blocks = match revision.content_blocks {
+            Some(value) => {
+                resolve_content_blocks(
+                    self.media_repo.as_ref(),
+                    self.banner_repo.as_ref(),
+                    self.permission_repo.as_ref(),
+                    saved_by,
+                    value,
+                )
+                .await?
+            }
+            None => None,
+        };
+
+        let mut restored = Post {
+            title: revision.title,
+            excerpt: revision.excerpt,
+            content: revision.content,
+            content_format: revision.content_format.unwrap_or(post.content_format),
+            content_blocks,
+            ..post
+        };
+        render_post_content(&mut restored, false);
+
+        let revision = post_revision_options(self.setting_repo.as_ref(), saved_by).await?;
+        Ok(self
+            .post_repo
+            .update(restored, None, Some(&revision))
+            .await?)
+    }
+}
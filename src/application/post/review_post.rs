@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::application::post::post_schedule::apply_post_schedule;
+use crate::application::post::post_workflow::{
+    POST_REVIEW_PERMISSION, ensure_status_transition, missing_permission,
+};
+use crate::domain::entities::post::{Post, PostStatus};
+use crate::domain::entities::post_review::PostReviewComment;
+use crate::domain::repositories::permission_repository::PermissionRepository;
+use crate::domain::repositories::post_repository::PostRepository;
+use crate::domain::repositories::post_review_repository::PostReviewRepository;
+use crate::shared::error::AppError;
+
+/// Kết quả review
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewDecision {
+    /// Duyệt và publish (cần `post:publish`, `published_at` tương lai => `scheduled`)
+    Approve,
+    /// Trả bài về cho tác giả chỉnh sửa
+    RequestChanges,
+}
+
+impl ReviewDecision {
+    /// Giá trị lưu ở `post_review_comments.decision`
+    fn as_str(&self) -> &'static str {
+        match self {
+            ReviewDecision::Approve => "approved",
+            ReviewDecision::RequestChanges => "changes_requested",
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ReviewPostInput {
+    /// Bỏ trống: chỉ thêm nhận xét
+    pub decision: Option<ReviewDecision>,
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReviewPostResult {
+    pub post: Post,
+    pub comment: PostReviewComment,
+}
+
+pub struct ReviewPostUseCase {
+    post_repo: Arc<dyn PostRepository>,
+    review_repo: Arc<dyn PostReviewRepository>,
+    permission_repo: Arc<dyn PermissionRepository>,
+}
+
+impl ReviewPostUseCase {
+    pub fn new(
+        post_repo: Arc<dyn PostRepository>,
+        review_repo: Arc<dyn PostReviewRepository>,
+        permission_repo: Arc<dyn PermissionRepository>,
+    ) -> Self {
+        Self {
+            post_repo,
+            review_repo,
+            permission_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        slug: &str,
+        input: ReviewPostInput,
+        reviewer_id: Uuid,
+    ) -> Result<ReviewPostResult, AppError> {
+        let post = self
+            .post_repo
+            .find_by_slug(slug)
+            .await?
+            .ok_or_else(|| AppError::not_found("POST_NOT_FOUND", "Post"))?;
+
+        let body = input.body.unwrap_or_default().trim().to_string();
+        let is_reviewer = self.review_repo.is_reviewer(post.id, reviewer_id).await?
+            || self
+                .permission_repo
+                .user_has_permission(reviewer_id, POST_REVIEW_PERMISSION)
+                .await?;
+
+        let Some(decision) = input.decision else {
+            if body.is_empty() {
+                return Err(AppError::invalid("review", "body is required"));
+            }
+            // Tác giả được trả lời nhận xét trên bài của mình
+            if !is_reviewer && post.author_id != reviewer_id {
+                return Err(missing_permission(POST_REVIEW_PERMISSION));
+            }
+            let comment = self
+                .review_repo
+                .add_comment(post.id, reviewer_id, None, &body)
+                .await?;
+            return Ok(ReviewPostResult { post, comment });
+        };
+
+        let target = match decision {
+            ReviewDecision::Approve => PostStatus::Published,
+            ReviewDecision::RequestChanges => {
+                if body.is_empty() {
+                    return Err(AppError::invalid(
+                        "review",
+                        "body is required when requesting changes",
+                    ));
+                }
+                PostStatus::ChangesRequested
+            }
+        };
+        if post.status != Some(PostStatus::InReview) {
+            return Err(AppError::invalid(
+                "review",
+                format!(
+                    "post is {}, expected in_review",
+                    post.status
+                        .as_ref()
+                        .map(PostStatus::as_str)
+                        .unwrap_or("draft")
+                ),
+            ));
+        }
+        ensure_status_transition(
+            self.permission_repo.as_ref(),
+            Some(reviewer_id),
+            post.status.as_ref(),
+            &target,
+        )
+        .await?;
+
+        let mut reviewed = Post {
+            status: Some(target),
+            ..post
+        };
+        apply_post_schedule(&mut reviewed)?;
+        let (post, comment) = self
+            .post_repo
+            .save_review_decision(reviewed, reviewer_id, decision.as_str(), &body)
+            .await?;
+
+        Ok(ReviewPostResult { post, comment })
+    }
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use crate::domain::entities::post::PostScheduleTransition;
+use crate::domain::repositories::post_repository::PostRepository;
+use crate::shared::error::AppError;
+
+/// Số bài tối đa xử lý mỗi lượt query (lặp tới khi hết bài tới hạn)
+const SCHEDULE_BATCH_SIZE: i64 = 100;
+
+/// Publish bài `scheduled` tới hạn và gỡ bài tới `unpublish_at`.
+/// Repo ghi audit log cho từng lần chuyển trạng thái trong cùng transaction
+pub struct RunPostScheduleUseCase {
+    post_repo: Arc<dyn PostRepository>,
+}
+
+impl RunPostScheduleUseCase {
+    pub fn new(post_repo: Arc<dyn PostRepository>) -> Self {
+        Self { post_repo }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<PostScheduleTransition>, AppError> {
+        let mut transitions = Vec::new();
+
+        loop {
+            let batch = self.post_repo.publish_due(SCHEDULE_BATCH_SIZE).await?;
+            let done = (batch.len() as i64) < SCHEDULE_BATCH_SIZE;
+            transitions.extend(batch);
+            if done {
+                break;
+            }
+        }
+
+        loop {
+            let batch = self.post_repo.unpublish_due(SCHEDULE_BATCH_SIZE).await?;
+            let done = (batch.len() as i64) < SCHEDULE_BATCH_SIZE;
+            transitions.extend(batch);
+            if done {
+                break;
+            }
+        }
+
+        Ok(transitions)
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::post::post_workflow::{POST_PUBLISH_PERMISSION, ensure_permission};
+use crate::domain::entities::post::{PostSearchHit, PostStatus};
+use crate::domain::repositories::permission_repository::PermissionRepository;
+use crate::domain::repositories::post_repository::{PostFullTextQuery, PostRepository};
+use crate::shared::error::{AppError, ErrorKind};
+
+const DEFAULT_LIMIT: i64 = 20;
+const MAX_LIMIT: i64 = 50;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct SearchPostsInput {
+    pub q: Option<String>,
+    pub lang: Option<String>,
+    pub category_id: Option<Uuid>,
+    /// Slug của tag
+    pub tag: Option<String>,
+    pub status: Option<String>,
+    pub limit: Option<i64>,
+}
+
+pub struct SearchPostsUseCase {
+    repo: Arc<dyn PostRepository>,
+    permission_repo: Arc<dyn PermissionRepository>,
+}
+
+impl SearchPostsUseCase {
+    pub fn new(
+        repo: Arc<dyn PostRepository>,
+        permission_repo: Arc<dyn PermissionRepository>,
+    ) -> Self {
+        Self {
+            repo,
+            permission_repo,
+        }
+    }
+
+    /// Mặc định chỉ tìm bài `published`; tìm theo trạng thái khác cần quyền `post:publish`
+    pub async fn execute(
+        &self,
+        actor: Option<Uuid>,
+        input: SearchPostsInput,
+    ) -> Result<Vec<PostSearchHit>, AppError> {
+        let query = input
+            .q
+            .map(|q| q.trim().to_string())
+            .filter(|q| !q.is_empty())
+            .ok_or_else(|| {
+                AppError::new(
+                    ErrorKind::Invalid,
+                    "SEARCH_QUERY_REQUIRED",
+                    "Search query `q` is required",
+                )
+            })?;
+
+        let status = match input.status.as_deref() {
+            Some(s) => Some(
+                PostStatus::parse(s).ok_or_else(|| AppError::invalid("status", s.to_string()))?,
+            ),
+            None => None,
+        };
+        if status.as_ref().is_some_and(|s| *s != PostStatus::Published) {
+            ensure_permission(
+                self.permission_repo.as_ref(),
+                actor,
+                POST_PUBLISH_PERMISSION,
+            )
+            .await?;
+        }
+
+        let query = PostFullTextQuery {
+            query,
+            language_code: input.lang.filter(|l| !l.is_empty()),
+            category_id: input.category_id,
+            tag_slug: input.tag.filter(|t| !t.is_empty()),
+            status,
+            limit: input.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT),
+        };
+
+        Ok(self.repo.search_fulltext(&query).await?)
+    }
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::post::post_media::{
+    MAX_POST_MEDIA, PostMediaItem, ensure_media_access, load_post_media,
+};
+use crate::domain::entities::post_media::NewPostMedia;
+use crate::domain::repositories::media_repository::MediaRepository;
+use crate::domain::repositories::permission_repository::PermissionRepository;
+use crate::domain::repositories::post_media_repository::PostMediaRepository;
+use crate::domain::repositories::post_repository::PostRepository;
+use crate::shared::error::{AppError, ErrorKind};
+
+/// Danh sách media theo thứ tự hiển thị
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct PostMediaInput {
+    pub items: Vec<NewPostMedia>,
+}
+
+/// Cách áp dụng danh sách media lên gallery
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PostMediaMode {
+    /// Thay thế toàn bộ, sắp xếp lại theo thứ tự gửi lên (PUT)
+    Replace,
+    /// Thêm vào cuối gallery (POST)
+    Append,
+}
+
+pub struct SetPostMediaUseCase {
+    post_repo: Arc<dyn PostRepository>,
+    post_media_repo: Arc<dyn PostMediaRepository>,
+    media_repo: Arc<dyn MediaRepository>,
+    permission_repo: Arc<dyn PermissionRepository>,
+}
+
+impl SetPostMediaUseCase {
+    pub fn new(
+        post_repo: Arc<dyn PostRepository>,
+        post_media_repo: Arc<dyn PostMediaRepository>,
+        media_repo: Arc<dyn MediaRepository>,
+        permission_repo: Arc<dyn PermissionRepository>,
+    ) -> Self {
+        Self {
+            post_repo,
+            post_media_repo,
+            media_repo,
+            permission_repo,
+        }
+    }
+
+    /// Trả về gallery của bài viết sau khi cập nhật
+    pub async fn execute(
+        &self,
+        slug: &str,
+        input: PostMediaInput,
+        mode: PostMediaMode,
+        actor: Option<Uuid>,
+    ) -> Result<Vec<PostMediaItem>, AppError> {
+        let post = self
+            .post_repo
+            .find_by_slug(slug)
+            .await?
+            .ok_or_else(|| AppError::not_found("POST_NOT_FOUND", "Post"))?;
+
+        if input.items.len() > MAX_POST_MEDIA {
+            return Err(AppError::new(
+                ErrorKind::Invalid,
+                "POST_MEDIA_LIMIT",
+                format!("Invalid media: at most {MAX_POST_MEDIA} items per post"),
+            )
+            .with("max", MAX_POST_MEDIA));
+        }
+        let items: Vec<NewPostMedia> = input
+            .items
+            .into_iter()
+            .map(|item| NewPostMedia {
+                media_id: item.media_id,
+                caption: item
+                    .caption
+                    .map(|c| c.trim().to_string())
+                    .filter(|c| !c.is_empty()),
+            })
+            .collect();
+
+        let ids: Vec<Uuid> = items.iter().map(|i| i.media_id).collect();
+        ensure_media_access(
+            self.media_repo.as_ref(),
+            self.permission_repo.as_ref(),
+            actor,
+            &ids,
+        )
+        .await?;
+
+        match mode {
+            PostMediaMode::Replace => self.post_media_repo.replace(post.id, &items).await?,
+            PostMediaMode::Append if items.is_empty() => {}
+            PostMediaMode::Append => self.post_media_repo.append(post.id, &items).await?,
+        }
+
+        let mut gallery = load_post_media(
+            self.post_media_repo.as_ref(),
+            self.media_repo.as_ref(),
+            &[post.id],
+        )
+        .await?;
+        Ok(gallery.remove(&post.id).unwrap_or_default())
+    }
+}
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::post::resolve_post_tags::resolve_post_tag_ids;
+use crate::domain::entities::tag::Tag;
+use crate::domain::repositories::post_repository::PostRepository;
+use crate::domain::repositories::tag_repository::TagRepository;
+use crate::shared::error::AppError;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct PostTagsInput {
+    pub tag_ids: Option<Vec<Uuid>>,
+    pub tag_slugs: Option<Vec<String>>,
+}
+
+/// Cách áp dụng danh sách tag lên bài viết
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PostTagsMode {
+    /// Thay thế toàn bộ (PUT)
+    Replace,
+    /// Gán thêm (POST)
+    Attach,
+}
+
+pub struct SetPostTagsUseCase {
+    post_repo: Arc<dyn PostRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+}
+
+impl SetPostTagsUseCase {
+    pub fn new(post_repo: Arc<dyn PostRepository>, tag_repo: Arc<dyn TagRepository>) -> Self {
+        Self {
+            post_repo,
+            tag_repo,
+        }
+    }
+
+    /// Trả về danh sách tag của bài viết sau khi cập nhật
+    pub async fn execute(
+        &self,
+        slug: &str,
+        input: PostTagsInput,
+        mode: PostTagsMode,
+    ) -> Result<Vec<Tag>, AppError> {
+        let post = self
+            .post_repo
+            .find_by_slug(slug)
+            .await?
+            .ok_or_else(|| AppError::not_found("POST_NOT_FOUND", "Post"))?;
+
+        let tag_ids = resolve_post_tag_ids(self.tag_repo.as_ref(), input.tag_ids, input.tag_slugs)
+            .await?
+            .unwrap_or_default();
+
+        match mode {
+            PostTagsMode::Replace => self.post_repo.set_tags(post.id, &tag_ids).await?,
+            PostTagsMode::Attach => self.post_repo.attach_tags(post.id, &tag_ids).await?,
+        }
+
+        let tags = self.tag_repo.find_by_post_ids(&[post.id]).await?;
+        Ok(tags.into_iter().map(|(_, tag)| tag).collect())
+    }
+}
@@ -2,51 +2,223 @@ use std::sync::Arc;
 
 use uuid::Uuid;
 
-use crate::domain::entities::post::{Post, PostStatus};
+use crate::application::common::resolve_slug::{save_with_slug, slug_conflict_error};
+use crate::application::post::post_content::{has_derived_excerpt, render_post_content};
+use crate::application::post::post_content_blocks::resolve_content_blocks;
+use crate::application::post::post_media::ensure_featured_media;
+use crate::application::post::post_schedule::apply_post_schedule;
+use crate::application::post::post_workflow::ensure_status_transition;
+use crate::application::post::record_post_revision::post_revision_options;
+use crate::application::post::resolve_post_tags::resolve_post_tag_ids;
+use crate::domain::entities::post::{ContentFormat, Post, PostStatus};
+use crate::domain::repositories::banner_repository::BannerRepository;
+use crate::domain::repositories::media_repository::MediaRepository;
+use crate::domain::repositories::permission_repository::PermissionRepository;
 use crate::domain::repositories::post_repository::PostRepository;
+use crate::domain::repositories::setting_repository::SettingRepository;
+use crate::domain::repositories::slug_repository::{SlugRepository, SlugScope};
+use crate::domain::repositories::tag_repository::TagRepository;
+use crate::shared::error::AppError;
 
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct UpdatePostInput {
     pub category_id: Option<Uuid>,
+    /// Ảnh đại diện, gửi `null` để bỏ
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub featured_media_id: Option<Option<Uuid>>,
     pub title: Option<String>,
     pub slug: Option<String>,
     pub excerpt: Option<String>,
     pub content: Option<String>,
+    /// `markdown`, `html` (mặc định) hoặc `plain`
+    pub content_format: Option<ContentFormat>,
+    /// Nội dung dạng block `{"version": 1, "blocks": [...]}`, `blocks` rỗng => bỏ block
+    pub content_blocks: Option<serde_json::Value>,
     pub status: Option<PostStatus>,
+    /// `published_at` ở tương lai => bài được lên lịch (`scheduled`)
     pub published_at: Option<chrono::NaiveDateTime>,
+    /// Tự động gỡ bài (chuyển sang `archived`) tại thời điểm này
+    pub unpublish_at: Option<chrono::NaiveDateTime>,
+    /// Gán tag theo id hoặc slug (chỉ tag type `post`)
+    pub tag_ids: Option<Vec<Uuid>>,
+    pub tag_slugs: Option<Vec<String>>,
 }
 
 pub struct UpdatePostUseCase {
     repo: Arc<dyn PostRepository>,
+    tag_repo: Arc<dyn TagRepository>,
+    setting_repo: Arc<dyn SettingRepository>,
+    permission_repo: Arc<dyn PermissionRepository>,
+    slug_repo: Arc<dyn SlugRepository>,
+    media_repo: Arc<dyn MediaRepository>,
+    banner_repo: Arc<dyn BannerRepository>,
 }
 
 impl UpdatePostUseCase {
-    pub fn new(repo: Arc<dyn PostRepository>) -> Self {
-        Self { repo }
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        repo: Arc<dyn PostRepository>,
+        tag_repo: Arc<dyn TagRepository>,
+        setting_repo: Arc<dyn SettingRepository>,
+        permission_repo: Arc<dyn PermissionRepository>,
+        slug_repo: Arc<dyn SlugRepository>,
+        media_repo: Arc<dyn MediaRepository>,
+        banner_repo: Arc<dyn BannerRepository>,
+    ) -> Self {
+        Self {
+            repo,
+            tag_repo,
+            setting_repo,
+            permission_repo,
+            slug_repo,
+            media_repo,
+            banner_repo,
+        }
     }
 
-    pub async fn execute(&self, id: Uuid, input: UpdatePostInput) -> Result<Post, String> {
+    /// `saved_by`: user thực hiện lần lưu (ghi vào revision, kiểm tra quyền chuyển trạng thái)
+    pub async fn execute(
+        &self,
+        id: Uuid,
+        input: UpdatePostInput,
+        saved_by: Option<Uuid>,
+    ) -> Result<Post, AppError> {
         let existing = self
             .repo
             .find_by_id(id)
             .await?
-            .ok_or_else(|| "Post not found".to_string())?;
+            .ok_or_else(|| AppError::not_found("POST_NOT_FOUND", "Post"))?;
 
-        let post = Post {
+        let existing_status = existing.status.clone();
+        if let Some(status) = &input.status {
+            ensure_status_transition(
+                self.permission_repo.as_ref(),
+                saved_by,
+                existing_status.as_ref(),
+                status,
+            )
+            .await?;
+        }
+
+        let tag_ids =
+            resolve_post_tag_ids(self.tag_repo.as_ref(), input.tag_ids, input.tag_slugs).await?;
+
+        // Excerpt tự sinh được sinh lại theo nội dung mới, excerpt người viết nhập thì giữ nguyên
+        let auto_excerpt = match input.excerpt.as_deref() {
+            Some(excerpt) => excerpt.trim().is_empty(),
+            None => has_derived_excerpt(&existing),
+        };
+        if let Some(Some(media_id)) = input.featured_media_id
+            && existing.featured_media_id != Some(media_id)
+        {
+            ensure_featured_media(
+                self.media_repo.as_ref(),
+                self.permission_repo.as_ref(),
+                saved_by,
+                media_id,
+            )
+            .await?;
+        }
+
+        let content_blocks = match input.content_blocks {
+            Some(value) => {
+                resolve_content_blocks(
+                    self.media_repo.as_ref(),
+                    self.banner_repo.as_ref(),
+                    self.permission_repo.as_ref(),
+                    saved_by,
+                    value,
+                )
+                .await?
+            }
+            None => existing.content_blocks,
+        };
+
+        let title = input.title.unwrap_or(existing.title);
+        // Giữ slug hiện tại, chỉ sinh lại khi client gửi slug mới hoặc bài chưa có slug
+        let current_slug = match existing.slug.as_deref() {
+            Some(current) if input.slug.is_none() && !current.is_empty() => {
+                Some(current.to_string())
+            }
+            _ => None,
+        };
+
+        let mut post = Post {
             id: existing.id,
             author_id: existing.author_id,
             category_id: input.category_id.or(existing.category_id),
-            title: input.title.unwrap_or(existing.title),
-            slug: input.slug.or(existing.slug),
+            featured_media_id: input
+                .featured_media_id
+                .unwrap_or(existing.featured_media_id),
+            title,
+            slug: None,
             excerpt: input.excerpt.or(existing.excerpt),
             content: input.content.or(existing.content),
+            content_format: input.content_format.unwrap_or(existing.content_format),
+            content_blocks,
+            content_html: existing.content_html,
+            content_text: existing.content_text,
+            toc: existing.toc,
+            word_count: existing.word_count,
+            reading_time_minutes: existing.reading_time_minutes,
             status: input.status.or(existing.status),
             published_at: input.published_at.or(existing.published_at),
+            unpublish_at: input.unpublish_at.or(existing.unpublish_at),
             created_at: existing.created_at,
             updated_at: existing.updated_at,
             deleted_at: existing.deleted_at,
         };
 
-        self.repo.update(post).await
+        let requested_status = post.status.clone();
+        apply_post_schedule(&mut post)?;
+        // Lịch đổi trạng thái (`scheduled` tới giờ => `published`...) cũng phải theo quy trình
+        // và quyền như khi client gửi `status`
+        if post.status != requested_status
+            && let Some(status) = &post.status
+        {
+            ensure_status_transition(
+                self.permission_repo.as_ref(),
+                saved_by,
+                existing_status.as_ref(),
+                status,
+            )
+            .await?;
+        }
+        render_post_content(&mut post, auto_excerpt);
+
+        let revision = post_revision_options(self.setting_repo.as_ref(), saved_by).await?;
+        let (repo, post, tag_ids, revision) = (&self.repo, &post, tag_ids.as_deref(), &revision);
+        let save = move |slug: String| {
+            let post = Post {
+                slug: Some(slug),
+                ..post.clone()
+            };
+            repo.update(post, tag_ids, Some(revision))
+        };
+        let updated = match current_slug {
+            Some(current) => save(current).await.map_err(slug_conflict_error)?,
+            None => {
+                save_with_slug(
+                    self.slug_repo.as_ref(),
+                    SlugScope::Post,
+                    input.slug.as_deref(),
+                    &post.title,
+                    Some(existing.id),
+                    save,
+                )
+                .await?
+            }
+        };
+
+        Ok(updated)
     }
 }
+
+/// Phân biệt field vắng mặt (`None`) với `null` (`Some(None)`)
+fn deserialize_some<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
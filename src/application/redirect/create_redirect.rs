@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::redirect::redirect_rules::{
+    DEFAULT_REDIRECT_STATUS, ensure_redirect_target, normalize_source_path, normalize_target,
+    validate_status_code,
+};
+use crate::domain::entities::redirect::Redirect;
+use crate::domain::repositories::redirect_repository::RedirectRepository;
+use crate::shared::error::AppError;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct CreateRedirectInput {
+    pub source_path: String,
+    pub target: String,
+    /// 301 (mặc định) hoặc 302
+    pub status_code: Option<i32>,
+    pub is_active: Option<bool>,
+}
+
+pub struct CreateRedirectUseCase {
+    repo: Arc<dyn RedirectRepository>,
+}
+
+impl CreateRedirectUseCase {
+    pub fn new(repo: Arc<dyn RedirectRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(&self, input: CreateRedirectInput) -> Result<Redirect, AppError> {
+        let source_path = normalize_source_path(&input.source_path)?;
+        let target = normalize_target(&input.target)?;
+        let status_code =
+            validate_status_code(input.status_code.unwrap_or(DEFAULT_REDIRECT_STATUS))?;
+        ensure_redirect_target(self.repo.as_ref(), None, &source_path, &target).await?;
+
+        let redirect = Redirect {
+            id: Uuid::now_v7(),
+            source_path,
+            target,
+            status_code,
+            is_active: input.is_active.unwrap_or(true),
+            hit_count: 0,
+            last_hit_at: None,
+            created_at: None,
+            updated_at: None,
+            deleted_at: None,
+        };
+
+        Ok(self.repo.create(redirect).await?)
+    }
+}
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::repositories::redirect_repository::RedirectRepository;
+use crate::shared::error::AppError;
+
+pub struct DeleteRedirectUseCase {
+    repo: Arc<dyn RedirectRepository>,
+}
+
+impl DeleteRedirectUseCase {
+    pub fn new(repo: Arc<dyn RedirectRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(&self, id: Uuid) -> Result<(), AppError> {
+        self.repo.soft_delete(id).await
+    }
+}
@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::entities::redirect::Redirect;
+use crate::domain::repositories::redirect_repository::RedirectRepository;
+use crate::shared::error::AppError;
+
+pub struct GetRedirectUseCase {
+    repo: Arc<dyn RedirectRepository>,
+}
+
+impl GetRedirectUseCase {
+    pub fn new(repo: Arc<dyn RedirectRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(&self, id: Uuid) -> Result<Option<Redirect>, AppError> {
+        Ok(self.repo.find_by_id(id).await?)
+    }
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use crate::application::common::list_params::{ListParams, PaginatedResult};
+use crate::domain::entities::redirect::Redirect;
+use crate::domain::repositories::redirect_repository::{RedirectRepository, RedirectSearchFilter};
+use crate::shared::error::AppError;
+
+pub struct GetRedirectsUseCase {
+    repo: Arc<dyn RedirectRepository>,
+}
+
+impl GetRedirectsUseCase {
+    pub fn new(repo: Arc<dyn RedirectRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        params: &ListParams,
+        is_active: Option<bool>,
+    ) -> Result<PaginatedResult<Redirect>, AppError> {
+        let filter = RedirectSearchFilter {
+            search: params.search.clone(),
+            is_active,
+        };
+        let limit = params.limit.unwrap_or(20).clamp(1, 100);
+        self.repo
+            .search(
+                &filter,
+                params.sort_by.clone(),
+                params.cursor.clone(),
+                limit,
+            )
+            .await
+    }
+}
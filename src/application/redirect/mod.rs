@@ -0,0 +1,7 @@
+pub mod create_redirect;
+pub mod delete_redirect;
+pub mod get_redirect;
+pub mod get_redirects;
+pub mod redirect_rules;
+pub mod resolve_redirect;
+pub mod update_redirect;
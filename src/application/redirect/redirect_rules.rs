@@ -0,0 +1,89 @@
+use crate::domain::repositories::redirect_repository::RedirectRepository;
+use crate::shared::error::{AppError, ErrorKind};
+use uuid::Uuid;
+
+pub const DEFAULT_REDIRECT_STATUS: i32 = 301;
+
+/// Chuẩn hoá path nguồn: bắt buộc bắt đầu bằng `/`, bỏ `/` cuối (trừ root)
+pub fn normalize_source_path(raw: &str) -> Result<String, AppError> {
+    let path = raw.trim();
+    if !path.starts_with('/') {
+        return Err(AppError::invalid(
+            "redirect",
+            "source_path must start with '/'",
+        ));
+    }
+    if path.chars().any(char::is_whitespace) {
+        return Err(AppError::invalid(
+            "redirect",
+            "source_path must not contain whitespace",
+        ));
+    }
+    let trimmed = path.trim_end_matches('/');
+    Ok(if trimmed.is_empty() {
+        "/".to_string()
+    } else {
+        trimmed.to_string()
+    })
+}
+
+/// Target là path nội bộ (`/...`) hoặc URL `http(s)://`
+pub fn normalize_target(raw: &str) -> Result<String, AppError> {
+    let target = raw.trim();
+    if target.starts_with("http://") || target.starts_with("https://") {
+        if target.chars().any(char::is_whitespace) {
+            return Err(AppError::invalid(
+                "redirect",
+                "target must not contain whitespace",
+            ));
+        }
+        return Ok(target.to_string());
+    }
+    normalize_source_path(target)
+        .map_err(|_| AppError::invalid("redirect", "target must be a path or http(s) URL"))
+}
+
+pub fn validate_status_code(status_code: i32) -> Result<i32, AppError> {
+    match status_code {
+        301 | 302 => Ok(status_code),
+        _ => Err(AppError::invalid(
+            "redirect",
+            "status_code must be 301 or 302",
+        )),
+    }
+}
+
+/// Chặn trùng `source_path`, redirect về chính nó và vòng lặp 2 bước (A -> B -> A)
+pub async fn ensure_redirect_target(
+    repo: &dyn RedirectRepository,
+    id: Option<Uuid>,
+    source_path: &str,
+    target: &str,
+) -> Result<(), AppError> {
+    if source_path == target {
+        return Err(AppError::invalid(
+            "redirect",
+            "target must differ from source_path",
+        ));
+    }
+    if let Some(existing) = repo.find_by_source_path(source_path).await?
+        && Some(existing.id) != id
+    {
+        return Err(AppError::new(
+            ErrorKind::Invalid,
+            "REDIRECT_SOURCE_EXISTS",
+            format!("Invalid redirect: source_path '{source_path}' already exists"),
+        )
+        .with("source_path", source_path));
+    }
+    if let Some(next) = repo.find_by_source_path(target).await?
+        && next.is_active
+        && next.target == source_path
+    {
+        return Err(AppError::invalid(
+            "redirect",
+            "target redirects back to source_path",
+        ));
+    }
+    Ok(())
+}
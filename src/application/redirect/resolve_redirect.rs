@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::application::redirect::redirect_rules::normalize_source_path;
+use crate::domain::repositories::redirect_repository::RedirectRepository;
+use crate::shared::error::AppError;
+
+/// Kết quả tra cứu cho frontend: chuyển hướng tới `target` với `status_code`
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolvedRedirect {
+    pub source_path: String,
+    pub target: String,
+    pub status_code: i32,
+}
+
+pub struct ResolveRedirectUseCase {
+    repo: Arc<dyn RedirectRepository>,
+}
+
+impl ResolveRedirectUseCase {
+    pub fn new(repo: Arc<dyn RedirectRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Tra redirect đang bật cho `path` và ghi nhận lượt hit
+    pub async fn execute(&self, path: &str) -> Result<Option<ResolvedRedirect>, AppError> {
+        let source_path = normalize_source_path(path)?;
+        let redirect = self.repo.hit(&source_path).await?;
+        Ok(redirect.map(|r| ResolvedRedirect {
+            source_path: r.source_path,
+            target: r.target,
+            status_code: r.status_code,
+        }))
+    }
+}
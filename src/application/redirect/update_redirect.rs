@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::redirect::redirect_rules::{
+    ensure_redirect_target, normalize_source_path, normalize_target, validate_status_code,
+};
+use crate::domain::entities::redirect::Redirect;
+use crate::domain::repositories::redirect_repository::RedirectRepository;
+use crate::shared::error::AppError;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct UpdateRedirectInput {
+    pub source_path: Option<String>,
+    pub target: Option<String>,
+    pub status_code: Option<i32>,
+    pub is_active: Option<bool>,
+}
+
+pub struct UpdateRedirectUseCase {
+    repo: Arc<dyn RedirectRepository>,
+}
+
+impl UpdateRedirectUseCase {
+    pub fn new(repo: Arc<dyn RedirectRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        id: Uuid,
+        input: UpdateRedirectInput,
+    ) -> Result<Redirect, AppError> {
+        let existing = self
+            .repo
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::not_found("REDIRECT_NOT_FOUND", "Redirect"))?;
+
+        let source_path = match input.source_path.as_deref() {
+            Some(raw) => normalize_source_path(raw)?,
+            None => existing.source_path,
+        };
+        let target = match input.target.as_deref() {
+            Some(raw) => normalize_target(raw)?,
+            None => existing.target,
+        };
+        let status_code = validate_status_code(input.status_code.unwrap_or(existing.status_code))?;
+        ensure_redirect_target(self.repo.as_ref(), Some(existing.id), &source_path, &target)
+            .await?;
+
+        let redirect = Redirect {
+            id: existing.id,
+            source_path,
+            target,
+            status_code,
+            is_active: input.is_active.unwrap_or(existing.is_active),
+            hit_count: existing.hit_count,
+            last_hit_at: existing.last_hit_at,
+            created_at: existing.created_at,
+            updated_at: existing.updated_at,
+            deleted_at: existing.deleted_at,
+        };
+
+        Ok(self.repo.update(redirect).await?)
+    }
+}
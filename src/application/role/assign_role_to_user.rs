@@ -1,4 +1,5 @@
 use crate::domain::repositories::role_repository::RoleRepository;
+use crate::shared::error::AppError;
 use std::sync::Arc;
 
 pub struct AssignRoleToUserUseCase {
@@ -12,7 +13,7 @@ impl AssignRoleToUserUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, user_id: Uuid, role_id: Uuid) -> Result<(), String> {
-        self.repo.assign_to_user(user_id, role_id).await
+    pub async fn execute(&self, user_id: Uuid, role_id: Uuid) -> Result<(), AppError> {
+        Ok(self.repo.assign_to_user(user_id, role_id).await?)
     }
 }
@@ -1,5 +1,6 @@
 use crate::domain::entities::role::{NewRole, Role};
 use crate::domain::repositories::role_repository::RoleRepository;
+use crate::shared::error::AppError;
 use std::sync::Arc;
 
 pub struct CreateRoleUseCase {
@@ -11,7 +12,7 @@ impl CreateRoleUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, role: NewRole) -> Result<Role, String> {
-        self.repo.create(role).await
+    pub async fn execute(&self, role: NewRole) -> Result<Role, AppError> {
+        Ok(self.repo.create(role).await?)
     }
 }
@@ -1,4 +1,5 @@
 use crate::domain::repositories::role_repository::RoleRepository;
+use crate::shared::error::AppError;
 use std::sync::Arc;
 
 pub struct DeleteRoleUseCase {
@@ -12,7 +13,7 @@ impl DeleteRoleUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, id: Uuid) -> Result<(), String> {
+    pub async fn execute(&self, id: Uuid) -> Result<(), AppError> {
         self.repo.delete(id).await
     }
 }
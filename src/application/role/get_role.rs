@@ -1,5 +1,6 @@
 use crate::domain::entities::role::Role;
 use crate::domain::repositories::role_repository::RoleRepository;
+use crate::shared::error::AppError;
 use std::sync::Arc;
 
 pub struct GetRoleUseCase {
@@ -13,7 +14,7 @@ impl GetRoleUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, id: Uuid) -> Result<Option<Role>, String> {
-        self.repo.find_by_id(id).await
+    pub async fn execute(&self, id: Uuid) -> Result<Option<Role>, AppError> {
+        Ok(self.repo.find_by_id(id).await?)
     }
 }
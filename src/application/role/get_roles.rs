@@ -1,6 +1,7 @@
 use crate::application::common::list_params::{ListParams, PaginatedResult};
 use crate::domain::entities::role::Role;
 use crate::domain::repositories::role_repository::{RoleRepository, RoleSearchFilter};
+use crate::shared::error::AppError;
 use std::sync::Arc;
 
 pub struct GetRolesUseCase {
@@ -12,7 +13,7 @@ impl GetRolesUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, params: &ListParams) -> Result<PaginatedResult<Role>, String> {
+    pub async fn execute(&self, params: &ListParams) -> Result<PaginatedResult<Role>, AppError> {
         let filter = RoleSearchFilter {
             search: params.search.clone(),
         };
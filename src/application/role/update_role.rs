@@ -1,5 +1,6 @@
 use crate::domain::entities::role::{Role, UpdateRole};
 use crate::domain::repositories::role_repository::RoleRepository;
+use crate::shared::error::AppError;
 use std::sync::Arc;
 
 pub struct UpdateRoleUseCase {
@@ -13,7 +14,7 @@ impl UpdateRoleUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, id: Uuid, role: UpdateRole) -> Result<Role, String> {
-        self.repo.update(id, role).await
+    pub async fn execute(&self, id: Uuid, role: UpdateRole) -> Result<Role, AppError> {
+        Ok(self.repo.update(id, role).await?)
     }
 }
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use crate::application::seo::seo_defaults::{SeoDefaults, resolve_seo_language};
+use crate::application::seo::seo_payload::{CategorySeoSource, SeoPayload, build_category_seo};
+use crate::domain::repositories::category_repository::CategoryRepository;
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::domain::repositories::media_repository::MediaRepository;
+use crate::domain::repositories::seo_metadata_repository::{SeoEntity, SeoMetadataRepository};
+use crate::domain::repositories::setting_repository::SettingRepository;
+use crate::shared::error::AppError;
+use crate::shared::utils::media::media_url;
+
+pub struct GetCategorySeoUseCase {
+    category_repo: Arc<dyn CategoryRepository>,
+    media_repo: Arc<dyn MediaRepository>,
+    seo_repo: Arc<dyn SeoMetadataRepository>,
+    setting_repo: Arc<dyn SettingRepository>,
+    language_repo: Arc<dyn LanguageRepository>,
+}
+
+impl GetCategorySeoUseCase {
+    pub fn new(
+        category_repo: Arc<dyn CategoryRepository>,
+        media_repo: Arc<dyn MediaRepository>,
+        seo_repo: Arc<dyn SeoMetadataRepository>,
+        setting_repo: Arc<dyn SettingRepository>,
+        language_repo: Arc<dyn LanguageRepository>,
+    ) -> Self {
+        Self {
+            category_repo,
+            media_repo,
+            seo_repo,
+            setting_repo,
+            language_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        slug: &str,
+        r#type: &str,
+        lang: Option<&str>,
+    ) -> Result<SeoPayload, AppError> {
+        let category = self
+            .category_repo
+            .find_by_slug_and_type(slug, r#type)
+            .await?
+            .ok_or_else(|| AppError::not_found("CATEGORY_NOT_FOUND", "Category"))?;
+        let language_code = resolve_seo_language(self.language_repo.as_ref(), lang).await?;
+        let defaults = SeoDefaults::load(self.setting_repo.as_ref()).await?;
+
+        let metadata = self
+            .seo_repo
+            .find(SeoEntity::Category, category.id, &language_code)
+            .await?;
+        let image = match metadata.as_ref().and_then(|m| m.og_image_media_id) {
+            Some(id) => self
+                .media_repo
+                .find_by_id(id)
+                .await?
+                .map(|m| media_url(&m.file_path)),
+            None => None,
+        };
+        let categories = self.category_repo.find_ancestors(category.id).await?;
+
+        Ok(build_category_seo(
+            &defaults,
+            &category,
+            CategorySeoSource {
+                categories: &categories,
+                language_code,
+                metadata,
+                image,
+            },
+        ))
+    }
+}
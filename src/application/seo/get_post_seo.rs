@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use crate::application::seo::seo_defaults::{SeoDefaults, resolve_seo_language};
+use crate::application::seo::seo_payload::{PostSeoSource, SeoPayload, build_post_seo};
+use crate::domain::repositories::category_repository::CategoryRepository;
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::domain::repositories::media_repository::MediaRepository;
+use crate::domain::repositories::post_repository::PostRepository;
+use crate::domain::repositories::seo_metadata_repository::{SeoEntity, SeoMetadataRepository};
+use crate::domain::repositories::setting_repository::SettingRepository;
+use crate::domain::repositories::user_repository::UserRepository;
+use crate::shared::error::AppError;
+use crate::shared::utils::media::media_url;
+
+pub struct GetPostSeoUseCase {
+    post_repo: Arc<dyn PostRepository>,
+    category_repo: Arc<dyn CategoryRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    media_repo: Arc<dyn MediaRepository>,
+    seo_repo: Arc<dyn SeoMetadataRepository>,
+    setting_repo: Arc<dyn SettingRepository>,
+    language_repo: Arc<dyn LanguageRepository>,
+}
+
+impl GetPostSeoUseCase {
+    pub fn new(
+        post_repo: Arc<dyn PostRepository>,
+        category_repo: Arc<dyn CategoryRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        media_repo: Arc<dyn MediaRepository>,
+        seo_repo: Arc<dyn SeoMetadataRepository>,
+        setting_repo: Arc<dyn SettingRepository>,
+        language_repo: Arc<dyn LanguageRepository>,
+    ) -> Self {
+        Self {
+            post_repo,
+            category_repo,
+            user_repo,
+            media_repo,
+            seo_repo,
+            setting_repo,
+            language_repo,
+        }
+    }
+
+    /// SEO của bài viết theo ngôn ngữ `lang` (bỏ trống => ngôn ngữ mặc định)
+    pub async fn execute(&self, slug: &str, lang: Option<&str>) -> Result<SeoPayload, AppError> {
+        let post = self
+            .post_repo
+            .find_by_slug(slug)
+            .await?
+            .ok_or_else(|| AppError::not_found("POST_NOT_FOUND", "Post"))?;
+        let language_code = resolve_seo_language(self.language_repo.as_ref(), lang).await?;
+        let defaults = SeoDefaults::load(self.setting_repo.as_ref()).await?;
+
+        let metadata = self
+            .seo_repo
+            .find(SeoEntity::Post, post.id, &language_code)
+            .await?;
+
+        let image_id = metadata
+            .as_ref()
+            .and_then(|m| m.og_image_media_id)
+            .or(post.featured_media_id);
+        let image = match image_id {
+            Some(id) => self
+                .media_repo
+                .find_by_id(id)
+                .await?
+                .map(|m| media_url(&m.file_path)),
+            None => None,
+        };
+
+        let author_name = self
+            .user_repo
+            .find_by_id(post.author_id)
+            .await?
+            .map(|u| u.name);
+        let categories = match post.category_id {
+            Some(id) => self.category_repo.find_ancestors(id).await?,
+            None => Vec::new(),
+        };
+
+        Ok(build_post_seo(
+            &defaults,
+            PostSeoSource {
+                post: &post,
+                language_code,
+                metadata,
+                image,
+                author_name,
+                categories: &categories,
+            },
+        ))
+    }
+}
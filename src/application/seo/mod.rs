@@ -0,0 +1,5 @@
+pub mod get_category_seo;
+pub mod get_post_seo;
+pub mod seo_defaults;
+pub mod seo_payload;
+pub mod update_seo;
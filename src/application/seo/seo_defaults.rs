@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::domain::repositories::setting_repository::SettingRepository;
+use crate::shared::error::{AppError, ErrorKind};
+
+/// Cấu hình SEO toàn site, đọc từ các setting `seo_*` (thiếu key => giá trị mặc định)
+#[derive(Debug, Clone)]
+pub struct SeoDefaults {
+    pub site_name: String,
+    /// URL gốc của frontend (không có `/` cuối)
+    pub site_url: String,
+    /// Mẫu title, hỗ trợ `{title}` và `{site_name}`
+    pub title_template: String,
+    pub default_description: Option<String>,
+    pub default_og_image: Option<String>,
+    pub publisher_logo: Option<String>,
+    /// Path bài viết, hỗ trợ `{slug}`
+    pub post_path: String,
+    /// Path category, hỗ trợ `{slug}` và `{type}`
+    pub category_path: String,
+    /// Path tag, hỗ trợ `{slug}` và `{type}`
+    pub tag_path: String,
+    /// Path trang ở ngôn ngữ khác mặc định, hỗ trợ `{lang}` và `{path}` (path gốc)
+    pub language_path: String,
+}
+
+impl SeoDefaults {
+    pub async fn load(setting_repo: &dyn SettingRepository) -> Result<Self, AppError> {
+        let values: HashMap<String, String> = setting_repo
+            .list()
+            .await?
+            .into_iter()
+            .filter(|s| s.key.starts_with("seo_"))
+            .filter_map(|s| {
+                let value = match s.value? {
+                    serde_json::Value::String(v) => v,
+                    serde_json::Value::Null => return None,
+                    other => other.to_string(),
+                };
+                Some((s.key, value.trim().to_string())).filter(|(_, v)| !v.is_empty())
+            })
+            .collect();
+        let get = |key: &str| values.get(key).cloned();
+
+        Ok(Self {
+            site_name: get("seo_site_name").unwrap_or_else(|| "Rust API".to_string()),
+            site_url: get("seo_site_url")
+                .unwrap_or_default()
+                .trim_end_matches('/')
+                .to_string(),
+            title_template: get("seo_title_template")
+                .unwrap_or_else(|| "{title} | {site_name}".to_string()),
+            default_description: get("seo_default_description"),
+            default_og_image: get("seo_default_og_image"),
+            publisher_logo: get("seo_publisher_logo"),
+            post_path: get("seo_post_path").unwrap_or_else(|| "/posts/{slug}".to_string()),
+            category_path: get("seo_category_path")
+                .unwrap_or_else(|| "/categories/{slug}".to_string()),
+            tag_path: get("seo_tag_path").unwrap_or_else(|| "/tags/{slug}".to_string()),
+            language_path: get("seo_language_path").unwrap_or_else(|| "/{lang}{path}".to_string()),
+        })
+    }
+
+    /// Title theo mẫu khi không khai báo `meta_title`
+    pub fn title(&self, title: &str) -> String {
+        self.title_template
+            .replace("{title}", title)
+            .replace("{site_name}", &self.site_name)
+    }
+
+    /// Path/URL tương đối => URL tuyệt đối theo `site_url`
+    pub fn absolute_url(&self, path_or_url: &str) -> String {
+        if path_or_url.starts_with("http://") || path_or_url.starts_with("https://") {
+            path_or_url.to_string()
+        } else {
+            format!("{}/{}", self.site_url, path_or_url.trim_start_matches('/'))
+        }
+    }
+
+    pub fn home_url(&self) -> String {
+        format!("{}/", self.site_url)
+    }
+
+    pub fn post_url(&self, slug: &str) -> String {
+        self.localized_post_url(slug, None)
+    }
+
+    pub fn category_url(&self, slug: &str, r#type: &str) -> String {
+        self.localized_category_url(slug, r#type, None)
+    }
+
+    pub fn tag_url(&self, slug: &str, r#type: &str) -> String {
+        self.localized_tag_url(slug, r#type, None)
+    }
+
+    /// URL bài viết ở ngôn ngữ `lang` (`None` => ngôn ngữ mặc định)
+    pub fn localized_post_url(&self, slug: &str, lang: Option<&str>) -> String {
+        self.localized_url(&self.post_path.replace("{slug}", slug), lang)
+    }
+
+    pub fn localized_category_url(&self, slug: &str, r#type: &str, lang: Option<&str>) -> String {
+        let path = self
+            .category_path
+            .replace("{slug}", slug)
+            .replace("{type}", r#type);
+        self.localized_url(&path, lang)
+    }
+
+    pub fn localized_tag_url(&self, slug: &str, r#type: &str, lang: Option<&str>) -> String {
+        let path = self
+            .tag_path
+            .replace("{slug}", slug)
+            .replace("{type}", r#type);
+        self.localized_url(&path, lang)
+    }
+
+    fn localized_url(&self, path: &str, lang: Option<&str>) -> String {
+        match lang {
+            Some(lang) => self.absolute_url(
+                &self
+                    .language_path
+                    .replace("{lang}", lang)
+                    .replace("{path}", path),
+            ),
+            None => self.absolute_url(path),
+        }
+    }
+}
+
+/// Ngôn ngữ của SEO: `lang` phải tồn tại, bỏ trống => ngôn ngữ mặc định
+pub async fn resolve_seo_language(
+    language_repo: &dyn LanguageRepository,
+    lang: Option<&str>,
+) -> Result<String, AppError> {
+    match lang.map(str::trim).filter(|l| !l.is_empty()) {
+        Some(code) => language_repo
+            .find_by_code(code)
+            .await?
+            .map(|l| l.code)
+            .ok_or_else(|| {
+                AppError::new(
+                    ErrorKind::Invalid,
+                    "LANGUAGE_INVALID",
+                    format!("Invalid language: {code}"),
+                )
+                .with("code", code)
+            }),
+        None => language_repo
+            .get_default()
+            .await?
+            .map(|l| l.code)
+            .ok_or_else(|| AppError::not_found("DEFAULT_LANGUAGE_NOT_FOUND", "Default language")),
+    }
+}
@@ -0,0 +1,269 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use serde_json::{Value, json};
+
+use crate::application::seo::seo_defaults::SeoDefaults;
+use crate::domain::entities::category::Category;
+use crate::domain::entities::post::{Post, PostStatus};
+use crate::domain::entities::seo_metadata::SeoMetadata;
+
+/// Độ dài tối đa `headline` của Article theo khuyến nghị schema.org/Google
+const ARTICLE_HEADLINE_MAX: usize = 110;
+
+/// Dữ liệu SEO dựng sẵn cho frontend: thẻ meta, Open Graph và JSON-LD
+#[derive(Debug, Clone, Serialize)]
+pub struct SeoPayload {
+    pub language_code: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub canonical_url: String,
+    /// Giá trị thẻ `<meta name="robots">`, vd `index,follow`
+    pub robots: String,
+    pub open_graph: OpenGraph,
+    /// Các object schema.org, mỗi object là 1 thẻ `<script type="application/ld+json">`
+    pub json_ld: Vec<Value>,
+    /// SEO đã lưu cho ngôn ngữ này (`null` => toàn bộ là giá trị mặc định)
+    pub metadata: Option<SeoMetadata>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OpenGraph {
+    /// `article` | `website`
+    pub r#type: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub url: String,
+    pub image: Option<String>,
+    pub site_name: String,
+    pub locale: String,
+}
+
+/// Dữ liệu đã load sẵn để dựng SEO cho bài viết
+pub struct PostSeoSource<'a> {
+    pub post: &'a Post,
+    pub language_code: String,
+    pub metadata: Option<SeoMetadata>,
+    /// URL ảnh OG (ảnh khai báo trong SEO, nếu không có thì ảnh đại diện)
+    pub image: Option<String>,
+    pub author_name: Option<String>,
+    /// Chuỗi category từ gốc tới category của bài
+    pub categories: &'a [Category],
+}
+
+pub fn build_post_seo(defaults: &SeoDefaults, source: PostSeoSource<'_>) -> SeoPayload {
+    let post = source.post;
+    let meta = source.metadata.as_ref();
+    let slug = post.slug.as_deref().unwrap_or_default();
+
+    let title = meta
+        .and_then(|m| m.meta_title.clone())
+        .unwrap_or_else(|| defaults.title(&post.title));
+    let description = meta
+        .and_then(|m| m.meta_description.clone())
+        .or_else(|| post.excerpt.clone().filter(|e| !e.trim().is_empty()))
+        .or_else(|| defaults.default_description.clone());
+    let canonical_url = meta
+        .and_then(|m| m.canonical_url.as_deref())
+        .map(|url| defaults.absolute_url(url))
+        .unwrap_or_else(|| defaults.post_url(slug));
+    let image = source
+        .image
+        .or_else(|| defaults.default_og_image.clone())
+        .map(|url| defaults.absolute_url(&url));
+    // Bài chưa publish luôn noindex
+    let published = post.status == Some(PostStatus::Published);
+    let robots = robots(
+        published && meta.is_none_or(|m| m.robots_index),
+        meta.is_none_or(|m| m.robots_follow),
+    );
+
+    let mut article = json!({
+        "@context": "https://schema.org",
+        "@type": "Article",
+        "headline": truncate(&post.title, ARTICLE_HEADLINE_MAX),
+        "mainEntityOfPage": { "@type": "WebPage", "@id": canonical_url },
+        "inLanguage": source.language_code,
+        "publisher": publisher(defaults),
+    });
+    if let Some(description) = &description {
+        article["description"] = json!(description);
+    }
+    if let Some(image) = &image {
+        article["image"] = json!([image]);
+    }
+    if let Some(published_at) = post.published_at {
+        article["datePublished"] = json!(iso_datetime(published_at));
+    }
+    if let Some(updated_at) = post.updated_at.or(post.published_at) {
+        article["dateModified"] = json!(iso_datetime(updated_at));
+    }
+    if let Some(name) = &source.author_name {
+        article["author"] = json!({ "@type": "Person", "name": name });
+    }
+
+    let mut crumbs = vec![(defaults.site_name.clone(), defaults.home_url())];
+    crumbs.extend(source.categories.iter().filter_map(|c| {
+        let slug = c.slug.as_deref()?;
+        Some((c.name.clone(), defaults.category_url(slug, &c.r#type)))
+    }));
+    crumbs.push((post.title.clone(), canonical_url.clone()));
+
+    SeoPayload {
+        open_graph: OpenGraph {
+            r#type: "article".to_string(),
+            title: title.clone(),
+            description: description.clone(),
+            url: canonical_url.clone(),
+            image,
+            site_name: defaults.site_name.clone(),
+            locale: source.language_code.clone(),
+        },
+        json_ld: vec![article, breadcrumb_list(&crumbs)],
+        language_code: source.language_code,
+        title,
+        description,
+        canonical_url,
+        robots,
+        metadata: source.metadata,
+    }
+}
+
+/// Dữ liệu đã load sẵn để dựng SEO cho category
+pub struct CategorySeoSource<'a> {
+    /// Chuỗi category từ gốc tới category đang xem (phần tử cuối)
+    pub categories: &'a [Category],
+    pub language_code: String,
+    pub metadata: Option<SeoMetadata>,
+    pub image: Option<String>,
+}
+
+pub fn build_category_seo(
+    defaults: &SeoDefaults,
+    category: &Category,
+    source: CategorySeoSource<'_>,
+) -> SeoPayload {
+    let meta = source.metadata.as_ref();
+    let slug = category.slug.as_deref().unwrap_or_default();
+
+    let title = meta
+        .and_then(|m| m.meta_title.clone())
+        .unwrap_or_else(|| defaults.title(&category.name));
+    let description = meta
+        .and_then(|m| m.meta_description.clone())
+        .or_else(|| {
+            category
+                .description
+                .clone()
+                .filter(|d| !d.trim().is_empty())
+        })
+        .or_else(|| defaults.default_description.clone());
+    let canonical_url = meta
+        .and_then(|m| m.canonical_url.as_deref())
+        .map(|url| defaults.absolute_url(url))
+        .unwrap_or_else(|| defaults.category_url(slug, &category.r#type));
+    let image = source
+        .image
+        .or_else(|| defaults.default_og_image.clone())
+        .map(|url| defaults.absolute_url(&url));
+    let robots = robots(
+        meta.is_none_or(|m| m.robots_index),
+        meta.is_none_or(|m| m.robots_follow),
+    );
+
+    let mut page = json!({
+        "@context": "https://schema.org",
+        "@type": "CollectionPage",
+        "name": category.name,
+        "url": canonical_url,
+        "inLanguage": source.language_code,
+        "publisher": publisher(defaults),
+    });
+    if let Some(description) = &description {
+        page["description"] = json!(description);
+    }
+
+    let mut crumbs = vec![(defaults.site_name.clone(), defaults.home_url())];
+    crumbs.extend(
+        source
+            .categories
+            .iter()
+            .filter(|c| c.id != category.id)
+            .filter_map(|c| {
+                let slug = c.slug.as_deref()?;
+                Some((c.name.clone(), defaults.category_url(slug, &c.r#type)))
+            }),
+    );
+    crumbs.push((category.name.clone(), canonical_url.clone()));
+
+    SeoPayload {
+        open_graph: OpenGraph {
+            r#type: "website".to_string(),
+            title: title.clone(),
+            description: description.clone(),
+            url: canonical_url.clone(),
+            image,
+            site_name: defaults.site_name.clone(),
+            locale: source.language_code.clone(),
+        },
+        json_ld: vec![page, breadcrumb_list(&crumbs)],
+        language_code: source.language_code,
+        title,
+        description,
+        canonical_url,
+        robots,
+        metadata: source.metadata,
+    }
+}
+
+/// schema.org `BreadcrumbList` từ danh sách `(tên, URL)` theo thứ tự từ trang chủ
+fn breadcrumb_list(items: &[(String, String)]) -> Value {
+    let elements: Vec<Value> = items
+        .iter()
+        .enumerate()
+        .map(|(i, (name, url))| {
+            json!({
+                "@type": "ListItem",
+                "position": i + 1,
+                "name": name,
+                "item": url,
+            })
+        })
+        .collect();
+    json!({
+        "@context": "https://schema.org",
+        "@type": "BreadcrumbList",
+        "itemListElement": elements,
+    })
+}
+
+fn publisher(defaults: &SeoDefaults) -> Value {
+    let mut publisher = json!({ "@type": "Organization", "name": defaults.site_name });
+    if let Some(logo) = &defaults.publisher_logo {
+        publisher["logo"] = json!({
+            "@type": "ImageObject",
+            "url": defaults.absolute_url(logo),
+        });
+    }
+    publisher
+}
+
+fn robots(index: bool, follow: bool) -> String {
+    format!(
+        "{},{}",
+        if index { "index" } else { "noindex" },
+        if follow { "follow" } else { "nofollow" }
+    )
+}
+
+fn iso_datetime(value: NaiveDateTime) -> String {
+    value.and_utc().to_rfc3339()
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        text.to_string()
+    } else {
+        let cut: String = text.chars().take(max_chars - 1).collect();
+        format!("{}…", cut.trim_end())
+    }
+}
@@ -0,0 +1,174 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::application::post::post_media::ensure_featured_media;
+use crate::application::seo::seo_defaults::resolve_seo_language;
+use crate::domain::entities::seo_metadata::{NewSeoMetadata, SeoMetadata};
+use crate::domain::repositories::category_repository::CategoryRepository;
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::domain::repositories::media_repository::MediaRepository;
+use crate::domain::repositories::permission_repository::PermissionRepository;
+use crate::domain::repositories::post_repository::PostRepository;
+use crate::domain::repositories::seo_metadata_repository::{SeoEntity, SeoMetadataRepository};
+use crate::shared::error::{AppError, ErrorKind};
+
+/// Độ dài tối đa (ký tự) của `meta_title`
+pub const META_TITLE_MAX: usize = 200;
+/// Độ dài tối đa (ký tự) của `meta_description`
+pub const META_DESCRIPTION_MAX: usize = 500;
+
+/// Ghi đè toàn bộ SEO của 1 ngôn ngữ, field bỏ trống => dùng giá trị mặc định
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct UpdateSeoInput {
+    /// Bỏ trống => ngôn ngữ mặc định
+    pub language_code: Option<String>,
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    /// URL `http(s)://...` hoặc path bắt đầu bằng `/`
+    pub canonical_url: Option<String>,
+    pub robots_index: Option<bool>,
+    pub robots_follow: Option<bool>,
+    /// Ảnh OG (media loại ảnh), bỏ trống => dùng ảnh đại diện của bài
+    pub og_image_media_id: Option<Uuid>,
+}
+
+/// Đối tượng cần cập nhật SEO
+#[derive(Debug, Clone, Copy)]
+pub enum SeoTarget<'a> {
+    Post { slug: &'a str },
+    Category { slug: &'a str, r#type: &'a str },
+}
+
+pub struct UpdateSeoUseCase {
+    post_repo: Arc<dyn PostRepository>,
+    category_repo: Arc<dyn CategoryRepository>,
+    seo_repo: Arc<dyn SeoMetadataRepository>,
+    language_repo: Arc<dyn LanguageRepository>,
+    media_repo: Arc<dyn MediaRepository>,
+    permission_repo: Arc<dyn PermissionRepository>,
+}
+
+impl UpdateSeoUseCase {
+    pub fn new(
+        post_repo: Arc<dyn PostRepository>,
+        category_repo: Arc<dyn CategoryRepository>,
+        seo_repo: Arc<dyn SeoMetadataRepository>,
+        language_repo: Arc<dyn LanguageRepository>,
+        media_repo: Arc<dyn MediaRepository>,
+        permission_repo: Arc<dyn PermissionRepository>,
+    ) -> Self {
+        Self {
+            post_repo,
+            category_repo,
+            seo_repo,
+            language_repo,
+            media_repo,
+            permission_repo,
+        }
+    }
+
+    /// `actor`: user thực hiện (kiểm tra quyền dùng ảnh OG)
+    pub async fn execute(
+        &self,
+        target: SeoTarget<'_>,
+        input: UpdateSeoInput,
+        actor: Option<Uuid>,
+    ) -> Result<SeoMetadata, AppError> {
+        let (entity, entity_id) = match target {
+            SeoTarget::Post { slug } => {
+                let post = self
+                    .post_repo
+                    .find_by_slug(slug)
+                    .await?
+                    .ok_or_else(|| AppError::not_found("POST_NOT_FOUND", "Post"))?;
+                (SeoEntity::Post, post.id)
+            }
+            SeoTarget::Category { slug, r#type } => {
+                let category = self
+                    .category_repo
+                    .find_by_slug_and_type(slug, r#type)
+                    .await?
+                    .ok_or_else(|| AppError::not_found("CATEGORY_NOT_FOUND", "Category"))?;
+                (SeoEntity::Category, category.id)
+            }
+        };
+
+        let language_code =
+            resolve_seo_language(self.language_repo.as_ref(), input.language_code.as_deref())
+                .await?;
+        let meta_title = normalize_text(input.meta_title, "meta_title", META_TITLE_MAX)?;
+        let meta_description = normalize_text(
+            input.meta_description,
+            "meta_description",
+            META_DESCRIPTION_MAX,
+        )?;
+        let canonical_url = normalize_canonical_url(input.canonical_url)?;
+
+        if let Some(media_id) = input.og_image_media_id {
+            ensure_featured_media(
+                self.media_repo.as_ref(),
+                self.permission_repo.as_ref(),
+                actor,
+                media_id,
+            )
+            .await?;
+        }
+
+        Ok(self
+            .seo_repo
+            .upsert(NewSeoMetadata {
+                entity_type: entity.as_str().to_string(),
+                entity_id,
+                language_code,
+                meta_title,
+                meta_description,
+                canonical_url,
+                robots_index: input.robots_index.unwrap_or(true),
+                robots_follow: input.robots_follow.unwrap_or(true),
+                og_image_media_id: input.og_image_media_id,
+            })
+            .await?)
+    }
+}
+
+/// Trim, rỗng => `None`, quá `max` ký tự => lỗi
+fn normalize_text(
+    value: Option<String>,
+    field: &str,
+    max: usize,
+) -> Result<Option<String>, AppError> {
+    let Some(value) = value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+    else {
+        return Ok(None);
+    };
+    if value.chars().count() > max {
+        return Err(AppError::new(
+            ErrorKind::Invalid,
+            "FIELD_TOO_LONG",
+            format!("Invalid {field}: at most {max} characters"),
+        )
+        .with("field", field)
+        .with("max", max));
+    }
+    Ok(Some(value))
+}
+
+fn normalize_canonical_url(value: Option<String>) -> Result<Option<String>, AppError> {
+    let Some(url) = value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+    else {
+        return Ok(None);
+    };
+    let valid = url.starts_with("https://") || url.starts_with("http://") || url.starts_with('/');
+    if !valid || url.chars().any(char::is_whitespace) {
+        return Err(AppError::invalid(
+            "canonical_url",
+            "must be an http(s) URL or a path starting with /",
+        ));
+    }
+    Ok(Some(url))
+}
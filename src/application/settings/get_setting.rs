@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::domain::entities::setting::Setting;
 use crate::domain::repositories::setting_repository::SettingRepository;
+use crate::shared::error::AppError;
 
 pub struct GetSettingUseCase {
     repo: Arc<dyn SettingRepository>,
@@ -12,7 +13,7 @@ impl GetSettingUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, key: &str) -> Result<Option<Setting>, String> {
-        self.repo.get_by_key(key).await
+    pub async fn execute(&self, key: &str) -> Result<Option<Setting>, AppError> {
+        Ok(self.repo.get_by_key(key).await?)
     }
 }
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::domain::entities::setting::Setting;
 use crate::domain::repositories::setting_repository::SettingRepository;
+use crate::shared::error::AppError;
 
 pub struct GetSettingsUseCase {
     repo: Arc<dyn SettingRepository>,
@@ -12,7 +13,7 @@ impl GetSettingsUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self) -> Result<Vec<Setting>, String> {
-        self.repo.list().await
+    pub async fn execute(&self) -> Result<Vec<Setting>, AppError> {
+        Ok(self.repo.list().await?)
     }
 }
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::domain::entities::setting::Setting;
 use crate::domain::repositories::setting_repository::SettingRepository;
+use crate::shared::error::AppError;
 
 pub struct UpdateSettingUseCase {
     repo: Arc<dyn SettingRepository>,
@@ -16,7 +17,7 @@ impl UpdateSettingUseCase {
         &self,
         key: &str,
         value: Option<serde_json::Value>,
-    ) -> Result<Setting, String> {
-        self.repo.set(key, value).await
+    ) -> Result<Setting, AppError> {
+        Ok(self.repo.set(key, value).await?)
     }
 }
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use crate::application::seo::seo_defaults::SeoDefaults;
+use crate::application::sitemap::sitemap_xml::{entries_per_page, render_index, sitemap_page_path};
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::domain::repositories::setting_repository::SettingRepository;
+use crate::domain::repositories::sitemap_repository::{SitemapRepository, SitemapSection};
+use crate::shared::error::AppError;
+use crate::shared::sitemap_cache::SitemapCache;
+
+const INDEX_CACHE_KEY: &str = "index";
+
+pub struct GetSitemapIndexUseCase {
+    sitemap_repo: Arc<dyn SitemapRepository>,
+    setting_repo: Arc<dyn SettingRepository>,
+    language_repo: Arc<dyn LanguageRepository>,
+    cache: Arc<SitemapCache>,
+}
+
+impl GetSitemapIndexUseCase {
+    pub fn new(
+        sitemap_repo: Arc<dyn SitemapRepository>,
+        setting_repo: Arc<dyn SettingRepository>,
+        language_repo: Arc<dyn LanguageRepository>,
+        cache: Arc<SitemapCache>,
+    ) -> Self {
+        Self {
+            sitemap_repo,
+            setting_repo,
+            language_repo,
+            cache,
+        }
+    }
+
+    /// `<sitemapindex>` liệt kê các sitemap con, mỗi file tối đa `SITEMAP_MAX_URLS` URL
+    pub async fn execute(&self) -> Result<Arc<String>, AppError> {
+        if let Some(cached) = self.cache.get(INDEX_CACHE_KEY) {
+            return Ok(cached);
+        }
+        let generation = self.cache.generation();
+        let expires_at = self.sitemap_repo.next_schedule_change().await?;
+        let defaults = SeoDefaults::load(self.setting_repo.as_ref()).await?;
+        let per_page = entries_per_page(self.language_repo.list_all().await?.len());
+
+        let mut sitemaps = Vec::new();
+        for section in SitemapSection::ALL {
+            let stats = self.sitemap_repo.stats(section).await?;
+            let pages = (stats.total + per_page - 1) / per_page;
+            for page in 1..=pages {
+                let path = sitemap_page_path(section.as_str(), page);
+                sitemaps.push((defaults.absolute_url(&path), stats.last_modified));
+            }
+        }
+
+        Ok(self.cache.put(
+            INDEX_CACHE_KEY,
+            generation,
+            render_index(&sitemaps),
+            expires_at,
+        ))
+    }
+}
@@ -0,0 +1,120 @@
+use std::sync::Arc;
+
+use crate::application::seo::seo_defaults::SeoDefaults;
+use crate::application::sitemap::sitemap_xml::{SitemapUrl, entries_per_page, render_urlset};
+use crate::domain::entities::sitemap::SitemapEntry;
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::domain::repositories::setting_repository::SettingRepository;
+use crate::domain::repositories::sitemap_repository::{SitemapRepository, SitemapSection};
+use crate::shared::error::AppError;
+use crate::shared::sitemap_cache::SitemapCache;
+
+pub struct GetSitemapPageUseCase {
+    sitemap_repo: Arc<dyn SitemapRepository>,
+    setting_repo: Arc<dyn SettingRepository>,
+    language_repo: Arc<dyn LanguageRepository>,
+    cache: Arc<SitemapCache>,
+}
+
+impl GetSitemapPageUseCase {
+    pub fn new(
+        sitemap_repo: Arc<dyn SitemapRepository>,
+        setting_repo: Arc<dyn SettingRepository>,
+        language_repo: Arc<dyn LanguageRepository>,
+        cache: Arc<SitemapCache>,
+    ) -> Self {
+        Self {
+            sitemap_repo,
+            setting_repo,
+            language_repo,
+            cache,
+        }
+    }
+
+    /// `<urlset>` của 1 trang sitemap con (`page` từ 1), kèm `xhtml:link` hreflang của bản dịch
+    pub async fn execute(
+        &self,
+        section: SitemapSection,
+        page: i64,
+    ) -> Result<Arc<String>, AppError> {
+        let cache_key = format!("{}-{}", section.as_str(), page);
+        if let Some(cached) = self.cache.get(&cache_key) {
+            return Ok(cached);
+        }
+        let generation = self.cache.generation();
+        let expires_at = self.sitemap_repo.next_schedule_change().await?;
+
+        let languages = self.language_repo.list_all().await?;
+        let default_language = languages
+            .iter()
+            .find(|l| l.is_default == Some(true))
+            .map(|l| l.code.clone())
+            .ok_or_else(|| AppError::not_found("DEFAULT_LANGUAGE_NOT_FOUND", "Default language"))?;
+        let per_page = entries_per_page(languages.len());
+
+        let stats = self.sitemap_repo.stats(section).await?;
+        let pages = ((stats.total + per_page - 1) / per_page).max(1);
+        if page < 1 || page > pages {
+            return Err(AppError::not_found("SITEMAP_NOT_FOUND", "Sitemap"));
+        }
+
+        let defaults = SeoDefaults::load(self.setting_repo.as_ref()).await?;
+        let urls: Vec<SitemapUrl> = self
+            .sitemap_repo
+            .list(section, (page - 1) * per_page, per_page)
+            .await?
+            .into_iter()
+            .flat_map(|entry| to_sitemap_urls(&defaults, section, &default_language, entry))
+            .collect();
+
+        Ok(self
+            .cache
+            .put(&cache_key, generation, render_urlset(&urls), expires_at))
+    }
+}
+
+/// 1 entity => 1 `<url>` cho mỗi bản ngôn ngữ, mỗi `<url>` liệt kê đủ mọi bản (kể cả chính nó)
+fn to_sitemap_urls(
+    defaults: &SeoDefaults,
+    section: SitemapSection,
+    default_language: &str,
+    entry: SitemapEntry,
+) -> Vec<SitemapUrl> {
+    let r#type = entry.r#type.as_deref().unwrap_or("post");
+    let url = |slug: &str, lang: Option<&str>| match section {
+        SitemapSection::Posts => defaults.localized_post_url(slug, lang),
+        SitemapSection::Categories => defaults.localized_category_url(slug, r#type, lang),
+        SitemapSection::Tags => defaults.localized_tag_url(slug, r#type, lang),
+    };
+    let loc = url(&entry.slug, None);
+
+    let translations: Vec<(String, String)> = entry
+        .translation_languages
+        .iter()
+        .zip(&entry.translation_slugs)
+        .filter(|(lang, _)| lang.as_str() != default_language)
+        .map(|(lang, slug)| (lang.clone(), url(slug, Some(lang))))
+        .collect();
+    if translations.is_empty() {
+        return vec![SitemapUrl {
+            loc,
+            lastmod: entry.updated_at,
+            alternates: Vec::new(),
+        }];
+    }
+
+    let mut alternates = vec![
+        (default_language.to_string(), loc.clone()),
+        ("x-default".to_string(), loc.clone()),
+    ];
+    alternates.extend(translations.iter().cloned());
+
+    std::iter::once(loc)
+        .chain(translations.into_iter().map(|(_, href)| href))
+        .map(|loc| SitemapUrl {
+            loc,
+            lastmod: entry.updated_at,
+            alternates: alternates.clone(),
+        })
+        .collect()
+}
@@ -0,0 +1,3 @@
+pub mod get_sitemap_index;
+pub mod get_sitemap_page;
+pub mod sitemap_xml;
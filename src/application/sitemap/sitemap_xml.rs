@@ -0,0 +1,73 @@
+use chrono::NaiveDateTime;
+
+use crate::shared::utils::content::escape_html;
+
+/// Số URL tối đa trong 1 file sitemap (giới hạn của giao thức sitemaps.org)
+pub const SITEMAP_MAX_URLS: i64 = 50_000;
+
+/// Số entity mỗi trang sao cho kể cả khi mọi entity có đủ bản dịch (mỗi bản 1 `<url>`)
+/// thì 1 file vẫn không vượt `SITEMAP_MAX_URLS`
+pub fn entries_per_page(language_count: usize) -> i64 {
+    (SITEMAP_MAX_URLS / language_count.max(1) as i64).max(1)
+}
+
+/// Path sitemap con (phục vụ ở cùng host với `seo_site_url`, frontend proxy về API)
+pub fn sitemap_page_path(section: &str, page: i64) -> String {
+    format!("/sitemaps/{section}-{page}.xml")
+}
+
+/// `lastmod` theo định dạng W3C Datetime
+pub fn lastmod(value: NaiveDateTime) -> String {
+    value
+        .and_utc()
+        .to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+/// 1 `<url>` kèm các bản ngôn ngữ `(hreflang, URL)`
+pub struct SitemapUrl {
+    pub loc: String,
+    pub lastmod: Option<NaiveDateTime>,
+    pub alternates: Vec<(String, String)>,
+}
+
+pub fn render_index(sitemaps: &[(String, Option<NaiveDateTime>)]) -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <sitemapindex xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+    for (loc, modified) in sitemaps {
+        out.push_str("<sitemap>");
+        out.push_str(&format!("<loc>{}</loc>", escape_html(loc)));
+        if let Some(modified) = modified {
+            out.push_str(&format!("<lastmod>{}</lastmod>", lastmod(*modified)));
+        }
+        out.push_str("</sitemap>\n");
+    }
+    out.push_str("</sitemapindex>\n");
+    out
+}
+
+pub fn render_urlset(urls: &[SitemapUrl]) -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\" \
+         xmlns:xhtml=\"http://www.w3.org/1999/xhtml\">\n",
+    );
+    for url in urls {
+        out.push_str("<url>");
+        out.push_str(&format!("<loc>{}</loc>", escape_html(&url.loc)));
+        if let Some(modified) = url.lastmod {
+            out.push_str(&format!("<lastmod>{}</lastmod>", lastmod(modified)));
+        }
+        for (hreflang, href) in &url.alternates {
+            out.push_str(&format!(
+                "<xhtml:link rel=\"alternate\" hreflang=\"{}\" href=\"{}\"/>",
+                escape_html(hreflang),
+                escape_html(href)
+            ));
+        }
+        out.push_str("</url>\n");
+    }
+    out.push_str("</urlset>\n");
+    out
+}
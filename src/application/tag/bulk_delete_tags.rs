@@ -3,6 +3,7 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::domain::repositories::tag_repository::TagRepository;
+use crate::shared::error::AppError;
 
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct BulkDeleteTagsInput {
@@ -18,7 +19,7 @@ impl BulkDeleteTagsUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, input: BulkDeleteTagsInput) -> Result<(), String> {
-        self.repo.soft_delete_many(&input.ids).await
+    pub async fn execute(&self, input: BulkDeleteTagsInput) -> Result<(), AppError> {
+        Ok(self.repo.soft_delete_many(&input.ids).await?)
     }
 }
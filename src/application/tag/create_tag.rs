@@ -2,8 +2,11 @@ use std::sync::Arc;
 
 use uuid::Uuid;
 
+use crate::application::common::resolve_slug::save_with_slug;
 use crate::domain::entities::tag::Tag;
+use crate::domain::repositories::slug_repository::{SlugRepository, SlugScope};
 use crate::domain::repositories::tag_repository::TagRepository;
+use crate::shared::error::AppError;
 
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct CreateTagInput {
@@ -15,18 +18,19 @@ pub struct CreateTagInput {
 
 pub struct CreateTagUseCase {
     repo: Arc<dyn TagRepository>,
+    slug_repo: Arc<dyn SlugRepository>,
 }
 
 impl CreateTagUseCase {
-    pub fn new(repo: Arc<dyn TagRepository>) -> Self {
-        Self { repo }
+    pub fn new(repo: Arc<dyn TagRepository>, slug_repo: Arc<dyn SlugRepository>) -> Self {
+        Self { repo, slug_repo }
     }
 
-    pub async fn execute(&self, input: CreateTagInput) -> Result<Tag, String> {
+    pub async fn execute(&self, input: CreateTagInput) -> Result<Tag, AppError> {
         let tag = Tag {
             id: Uuid::new_v4(),
             name: input.name,
-            slug: input.slug,
+            slug: None,
             r#type: input.r#type,
             description: input.description,
             created_at: None,
@@ -34,6 +38,22 @@ impl CreateTagUseCase {
             deleted_at: None,
         };
 
-        self.repo.create(tag).await
+        let (repo, tag) = (&self.repo, &tag);
+        save_with_slug(
+            self.slug_repo.as_ref(),
+            SlugScope::Tag {
+                r#type: &tag.r#type,
+            },
+            input.slug.as_deref(),
+            &tag.name,
+            None,
+            move |slug| {
+                repo.create(Tag {
+                    slug: Some(slug),
+                    ..tag.clone()
+                })
+            },
+        )
+        .await
     }
 }
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::domain::repositories::tag_repository::TagRepository;
+use crate::shared::error::AppError;
 
 pub struct DeleteTagUseCase {
     repo: Arc<dyn TagRepository>,
@@ -13,7 +14,7 @@ impl DeleteTagUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, id: Uuid) -> Result<(), String> {
-        self.repo.soft_delete(id).await
+    pub async fn execute(&self, id: Uuid) -> Result<(), AppError> {
+        Ok(self.repo.soft_delete(id).await?)
     }
 }
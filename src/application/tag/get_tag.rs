@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::domain::entities::tag::Tag;
 use crate::domain::repositories::tag_repository::TagRepository;
+use crate::shared::error::AppError;
 
 pub struct GetTagUseCase {
     repo: Arc<dyn TagRepository>,
@@ -12,7 +13,7 @@ impl GetTagUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, slug: &str, r#type: &str) -> Result<Option<Tag>, String> {
-        self.repo.find_by_slug_and_type(slug, r#type).await
+    pub async fn execute(&self, slug: &str, r#type: &str) -> Result<Option<Tag>, AppError> {
+        Ok(self.repo.find_by_slug_and_type(slug, r#type).await?)
     }
 }
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::domain::entities::tag::Tag;
 use crate::domain::repositories::tag_repository::TagRepository;
+use crate::shared::error::AppError;
 
 pub struct GetTagsUseCase {
     repo: Arc<dyn TagRepository>,
@@ -12,7 +13,7 @@ impl GetTagsUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, r#type: &str) -> Result<Vec<Tag>, String> {
-        self.repo.list_by_type(r#type).await
+    pub async fn execute(&self, r#type: &str) -> Result<Vec<Tag>, AppError> {
+        Ok(self.repo.list_by_type(r#type).await?)
     }
 }
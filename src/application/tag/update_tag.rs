@@ -2,8 +2,11 @@ use std::sync::Arc;
 
 use uuid::Uuid;
 
+use crate::application::common::resolve_slug::{save_with_slug, slug_conflict_error};
 use crate::domain::entities::tag::Tag;
+use crate::domain::repositories::slug_repository::{SlugRepository, SlugScope};
 use crate::domain::repositories::tag_repository::TagRepository;
+use crate::shared::error::AppError;
 
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct UpdateTagInput {
@@ -15,31 +18,64 @@ pub struct UpdateTagInput {
 
 pub struct UpdateTagUseCase {
     repo: Arc<dyn TagRepository>,
+    slug_repo: Arc<dyn SlugRepository>,
 }
 
 impl UpdateTagUseCase {
-    pub fn new(repo: Arc<dyn TagRepository>) -> Self {
-        Self { repo }
+    pub fn new(repo: Arc<dyn TagRepository>, slug_repo: Arc<dyn SlugRepository>) -> Self {
+        Self { repo, slug_repo }
     }
 
-    pub async fn execute(&self, id: Uuid, input: UpdateTagInput) -> Result<Tag, String> {
+    pub async fn execute(&self, id: Uuid, input: UpdateTagInput) -> Result<Tag, AppError> {
         let existing = self
             .repo
             .find_by_id(id)
             .await?
-            .ok_or_else(|| "Tag not found".to_string())?;
+            .ok_or_else(|| AppError::not_found("TAG_NOT_FOUND", "Tag"))?;
+
+        let name = input.name.unwrap_or(existing.name);
+        let r#type = input.r#type.unwrap_or(existing.r#type);
+        // Giữ slug hiện tại, chỉ sinh lại khi client gửi slug mới hoặc bản ghi chưa có slug
+        let current_slug = match existing.slug.as_deref() {
+            Some(current) if input.slug.is_none() && !current.is_empty() => {
+                Some(current.to_string())
+            }
+            _ => None,
+        };
 
         let tag = Tag {
             id: existing.id,
-            name: input.name.unwrap_or(existing.name),
-            slug: input.slug.or(existing.slug),
-            r#type: input.r#type.unwrap_or(existing.r#type),
+            name,
+            slug: None,
+            r#type,
             description: input.description.or(existing.description),
             created_at: existing.created_at,
             updated_at: existing.updated_at,
             deleted_at: existing.deleted_at,
         };
 
-        self.repo.update(tag).await
+        let (repo, tag) = (&self.repo, &tag);
+        let save = move |slug: String| {
+            repo.update(Tag {
+                slug: Some(slug),
+                ..tag.clone()
+            })
+        };
+        match current_slug {
+            Some(current) => save(current).await.map_err(slug_conflict_error),
+            None => {
+                save_with_slug(
+                    self.slug_repo.as_ref(),
+                    SlugScope::Tag {
+                        r#type: &tag.r#type,
+                    },
+                    input.slug.as_deref(),
+                    &tag.name,
+                    Some(existing.id),
+                    save,
+                )
+                .await
+            }
+        }
     }
 }
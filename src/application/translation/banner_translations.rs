@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::application::translation::translation_language::{
+    optional_text, required_text, resolve_translation_language,
+};
+use crate::domain::entities::banner::Banner;
+use crate::domain::entities::banner_translation::{BannerTranslation, NewBannerTranslation};
+use crate::domain::repositories::banner_repository::BannerRepository;
+use crate::domain::repositories::banner_translation_repository::BannerTranslationRepository;
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::shared::error::AppError;
+
+/// Ghi đè toàn bộ bản dịch của 1 ngôn ngữ
+#[derive(Debug, Clone, Deserialize)]
+pub struct BannerTranslationInput {
+    pub title: String,
+    pub description: Option<String>,
+}
+
+/// CRUD bản dịch banner: `/banners/:id/translations[/:lang]`
+pub struct BannerTranslationsUseCase {
+    banner_repo: Arc<dyn BannerRepository>,
+    translation_repo: Arc<dyn BannerTranslationRepository>,
+    language_repo: Arc<dyn LanguageRepository>,
+}
+
+impl BannerTranslationsUseCase {
+    pub fn new(
+        banner_repo: Arc<dyn BannerRepository>,
+        translation_repo: Arc<dyn BannerTranslationRepository>,
+        language_repo: Arc<dyn LanguageRepository>,
+    ) -> Self {
+        Self {
+            banner_repo,
+            translation_repo,
+            language_repo,
+        }
+    }
+
+    pub async fn list(&self, id: Uuid) -> Result<Vec<BannerTranslation>, AppError> {
+        let banner = self.find_banner(id).await?;
+        Ok(self.translation_repo.list_by_banner(banner.id).await?)
+    }
+
+    pub async fn get(&self, id: Uuid, lang: &str) -> Result<BannerTranslation, AppError> {
+        let banner = self.find_banner(id).await?;
+        self.translation_repo
+            .find(banner.id, lang.trim())
+            .await?
+            .ok_or_else(|| {
+                AppError::not_found("BANNER_TRANSLATION_NOT_FOUND", "Banner translation")
+            })
+    }
+
+    pub async fn upsert(
+        &self,
+        id: Uuid,
+        lang: &str,
+        input: BannerTranslationInput,
+    ) -> Result<BannerTranslation, AppError> {
+        let banner = self.find_banner(id).await?;
+        let language_code = resolve_translation_language(self.language_repo.as_ref(), lang).await?;
+
+        Ok(self
+            .translation_repo
+            .upsert(NewBannerTranslation {
+                banner_id: banner.id,
+                language_code,
+                title: required_text(&input.title, "title")?,
+                description: optional_text(input.description),
+            })
+            .await?)
+    }
+
+    pub async fn delete(&self, id: Uuid, lang: &str) -> Result<(), AppError> {
+        let banner = self.find_banner(id).await?;
+        self.translation_repo.delete(banner.id, lang.trim()).await
+    }
+
+    async fn find_banner(&self, id: Uuid) -> Result<Banner, AppError> {
+        self.banner_repo
+            .get_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::not_found("BANNER_NOT_FOUND", "Banner"))
+    }
+}
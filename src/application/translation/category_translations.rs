@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+
+use crate::application::translation::translation_language::{
+    optional_text, required_text, resolve_translation_language,
+};
+use crate::domain::entities::category::Category;
+use crate::domain::entities::category_translation::{CategoryTranslation, NewCategoryTranslation};
+use crate::domain::repositories::category_repository::CategoryRepository;
+use crate::domain::repositories::category_translation_repository::CategoryTranslationRepository;
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::shared::error::AppError;
+
+/// Ghi đè toàn bộ bản dịch của 1 ngôn ngữ (dùng chung cho category và tag)
+#[derive(Debug, Clone, Deserialize)]
+pub struct NameTranslationInput {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// CRUD bản dịch category: `/categories/:slug/translations[/:lang]?type=`
+pub struct CategoryTranslationsUseCase {
+    category_repo: Arc<dyn CategoryRepository>,
+    translation_repo: Arc<dyn CategoryTranslationRepository>,
+    language_repo: Arc<dyn LanguageRepository>,
+}
+
+impl CategoryTranslationsUseCase {
+    pub fn new(
+        category_repo: Arc<dyn CategoryRepository>,
+        translation_repo: Arc<dyn CategoryTranslationRepository>,
+        language_repo: Arc<dyn LanguageRepository>,
+    ) -> Self {
+        Self {
+            category_repo,
+            translation_repo,
+            language_repo,
+        }
+    }
+
+    pub async fn list(
+        &self,
+        slug: &str,
+        r#type: &str,
+    ) -> Result<Vec<CategoryTranslation>, AppError> {
+        let category = self.find_category(slug, r#type).await?;
+        Ok(self.translation_repo.list_by_category(category.id).await?)
+    }
+
+    pub async fn get(
+        &self,
+        slug: &str,
+        r#type: &str,
+        lang: &str,
+    ) -> Result<CategoryTranslation, AppError> {
+        let category = self.find_category(slug, r#type).await?;
+        self.translation_repo
+            .find(category.id, lang.trim())
+            .await?
+            .ok_or_else(|| {
+                AppError::not_found("CATEGORY_TRANSLATION_NOT_FOUND", "Category translation")
+            })
+    }
+
+    pub async fn upsert(
+        &self,
+        slug: &str,
+        r#type: &str,
+        lang: &str,
+        input: NameTranslationInput,
+    ) -> Result<CategoryTranslation, AppError> {
+        let category = self.find_category(slug, r#type).await?;
+        let language_code = resolve_translation_language(self.language_repo.as_ref(), lang).await?;
+
+        Ok(self
+            .translation_repo
+            .upsert(NewCategoryTranslation {
+                category_id: category.id,
+                language_code,
+                name: required_text(&input.name, "name")?,
+                description: optional_text(input.description),
+            })
+            .await?)
+    }
+
+    pub async fn delete(&self, slug: &str, r#type: &str, lang: &str) -> Result<(), AppError> {
+        let category = self.find_category(slug, r#type).await?;
+        self.translation_repo.delete(category.id, lang.trim()).await
+    }
+
+    async fn find_category(&self, slug: &str, r#type: &str) -> Result<Category, AppError> {
+        self.category_repo
+            .find_by_slug_and_type(slug, r#type)
+            .await?
+            .ok_or_else(|| AppError::not_found("CATEGORY_NOT_FOUND", "Category"))
+    }
+}
@@ -0,0 +1,633 @@
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+
+use crate::shared::error::AppError;
+use crate::shared::utils::content::escape_html;
+
+/// Định dạng file trao đổi bản dịch với bên dịch thuật
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExchangeFormat {
+    /// XLIFF 2.0 (`.xlf`)
+    Xliff,
+    /// gettext PO (`.po`)
+    Po,
+}
+
+impl ExchangeFormat {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "xliff" | "xlf" | "xliff2" => Some(Self::Xliff),
+            "po" | "gettext" => Some(Self::Po),
+            _ => None,
+        }
+    }
+
+    /// Không chỉ định `format` khi import => đoán theo nội dung
+    pub fn detect(body: &str) -> Self {
+        if body
+            .trim_start_matches('\u{feff}')
+            .trim_start()
+            .starts_with('<')
+        {
+            Self::Xliff
+        } else {
+            Self::Po
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Xliff => "xliff",
+            Self::Po => "po",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Xliff => "application/xliff+xml; charset=utf-8",
+            Self::Po => "text/x-gettext-translation; charset=utf-8",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Xliff => "xlf",
+            Self::Po => "po",
+        }
+    }
+}
+
+/// 1 đơn vị dịch: `entity` = `post:<uuid>`, key đầy đủ = `post:<uuid>:title`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExchangeUnit {
+    pub entity: String,
+    pub field: String,
+    /// `post/<slug>` để người dịch biết ngữ cảnh
+    pub reference: Option<String>,
+    pub source: String,
+    pub target: Option<String>,
+    /// `false` => chưa dịch xong (XLIFF `state="initial"`, PO `#, fuzzy`), bỏ qua khi import
+    pub translated: bool,
+    /// Ghi chú cho người dịch (vd: bản gốc đã đổi)
+    pub note: Option<String>,
+}
+
+impl ExchangeUnit {
+    pub fn key(&self) -> String {
+        format!("{}:{}", self.entity, self.field)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ExchangeDocument {
+    pub source_language: Option<String>,
+    pub target_language: Option<String>,
+    pub units: Vec<ExchangeUnit>,
+}
+
+pub fn render(format: ExchangeFormat, doc: &ExchangeDocument) -> String {
+    match format {
+        ExchangeFormat::Xliff => render_xliff(doc),
+        ExchangeFormat::Po => render_po(doc),
+    }
+}
+
+pub fn parse(format: ExchangeFormat, body: &str) -> Result<ExchangeDocument, AppError> {
+    match format {
+        ExchangeFormat::Xliff => parse_xliff(body),
+        ExchangeFormat::Po => parse_po(body),
+    }
+}
+
+/// Mỗi bản ghi gốc là 1 `<file>`, mỗi trường là 1 `<unit>`.
+/// Mọi giá trị đều qua `escape_html` (bỏ luôn ký tự điều khiển không hợp lệ trong XML 1.0)
+fn render_xliff(doc: &ExchangeDocument) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str(&format!(
+        "<xliff xmlns=\"urn:oasis:names:tc:xliff:document:2.0\" version=\"2.0\" srcLang=\"{}\" trgLang=\"{}\">\n",
+        escape_html(doc.source_language.as_deref().unwrap_or_default()),
+        escape_html(doc.target_language.as_deref().unwrap_or_default()),
+    ));
+
+    let mut current: Option<&str> = None;
+    for unit in &doc.units {
+        if current != Some(unit.entity.as_str()) {
+            if current.is_some() {
+                out.push_str("  </file>\n");
+            }
+            out.push_str(&format!("  <file id=\"{}\"", escape_html(&unit.entity)));
+            if let Some(reference) = &unit.reference {
+                out.push_str(&format!(" original=\"{}\"", escape_html(reference)));
+            }
+            out.push_str(">\n");
+            current = Some(unit.entity.as_str());
+        }
+
+        out.push_str(&format!("    <unit id=\"{}\">\n", escape_html(&unit.field)));
+        if let Some(note) = &unit.note {
+            out.push_str(&format!(
+                "      <notes>\n        <note>{}</note>\n      </notes>\n",
+                escape_html(note)
+            ));
+        }
+        let state = if unit.translated {
+            "translated"
+        } else {
+            "initial"
+        };
+        out.push_str(&format!("      <segment state=\"{state}\">\n"));
+        out.push_str(&format!(
+            "        <source>{}</source>\n",
+            escape_html(&unit.source)
+        ));
+        if let Some(target) = &unit.target {
+            out.push_str(&format!(
+                "        <target>{}</target>\n",
+                escape_html(target)
+            ));
+        }
+        out.push_str("      </segment>\n    </unit>\n");
+    }
+    if current.is_some() {
+        out.push_str("  </file>\n");
+    }
+    out.push_str("</xliff>\n");
+    out
+}
+
+fn parse_xliff(body: &str) -> Result<ExchangeDocument, AppError> {
+    let mut reader = Reader::from_str(body);
+    let mut doc = ExchangeDocument::default();
+    let mut seen_root = false;
+
+    let mut file: Option<(String, Option<String>)> = None;
+    let mut unit: Option<ExchangeUnit> = None;
+    // text đang gom của <source>/<target>: (là target?, nội dung)
+    let mut capture: Option<(bool, String)> = None;
+    let mut depth_in_capture = 0usize;
+
+    loop {
+        let event = reader.read_event().map_err(|e| {
+            AppError::invalid(
+                "file",
+                format!("XML error at byte {}: {e}", reader.error_position()),
+            )
+        })?;
+        match event {
+            Event::Start(e) => {
+                if capture.is_some() {
+                    // inline markup (<ph>, <pc>, ...) => chỉ giữ text bên trong
+                    depth_in_capture += 1;
+                    continue;
+                }
+                match e.local_name().as_ref() {
+                    b"xliff" => {
+                        seen_root = true;
+                        if let Some(version) = attr(&e, b"version")?
+                            && !version.starts_with('2')
+                        {
+                            return Err(AppError::invalid(
+                                "file",
+                                format!("XLIFF version {version} is not supported (expected 2.0)"),
+                            ));
+                        }
+                        doc.source_language = attr(&e, b"srcLang")?;
+                        doc.target_language = attr(&e, b"trgLang")?;
+                    }
+                    b"file" => {
+                        let id = attr(&e, b"id")?.ok_or_else(|| {
+                            AppError::invalid("file", "<file> without id attribute")
+                        })?;
+                        file = Some((id, attr(&e, b"original")?));
+                    }
+                    b"unit" => {
+                        let (entity, reference) = file
+                            .clone()
+                            .ok_or_else(|| AppError::invalid("file", "<unit> outside <file>"))?;
+                        let field = attr(&e, b"id")?.ok_or_else(|| {
+                            AppError::invalid("file", "<unit> without id attribute")
+                        })?;
+                        unit = Some(ExchangeUnit {
+                            entity,
+                            field,
+                            reference,
+                            source: String::new(),
+                            target: None,
+                            translated: true,
+                            note: None,
+                        });
+                    }
+                    b"segment" => {
+                        // XLIFF 2.0: thiếu `state` => `initial`
+                        if let Some(unit) = unit.as_mut()
+                            && attr(&e, b"state")?.is_none_or(|state| state == "initial")
+                        {
+                            unit.translated = false;
+                        }
+                    }
+                    b"source" if unit.is_some() => capture = Some((false, String::new())),
+                    b"target" if unit.is_some() => capture = Some((true, String::new())),
+                    _ => {}
+                }
+            }
+            Event::Empty(e) => {
+                if capture.is_none()
+                    && e.local_name().as_ref() == b"target"
+                    && let Some(unit) = unit.as_mut()
+                {
+                    unit.target.get_or_insert_with(String::new);
+                }
+            }
+            Event::Text(e) => {
+                if let Some((_, text)) = capture.as_mut() {
+                    let value = e
+                        .unescape()
+                        .map_err(|e| AppError::invalid("file", format!("XML error: {e}")))?;
+                    text.push_str(&value);
+                }
+            }
+            Event::CData(e) => {
+                if let Some((_, text)) = capture.as_mut() {
+                    text.push_str(&String::from_utf8_lossy(&e));
+                }
+            }
+            Event::End(e) => {
+                if capture.is_some() && depth_in_capture > 0 {
+                    depth_in_capture -= 1;
+                    continue;
+                }
+                match e.local_name().as_ref() {
+                    b"source" | b"target" => {
+                        if let (Some((is_target, text)), Some(unit)) =
+                            (capture.take(), unit.as_mut())
+                        {
+                            // Nhiều <segment> trong 1 unit => nối lại theo thứ tự
+                            if is_target {
+                                unit.target.get_or_insert_with(String::new).push_str(&text);
+                            } else {
+                                unit.source.push_str(&text);
+                            }
+                        }
+                    }
+                    b"unit" => {
+                        if let Some(unit) = unit.take() {
+                            doc.units.push(unit);
+                        }
+                    }
+                    b"file" => file = None,
+                    _ => {}
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    if !seen_root {
+        return Err(AppError::invalid("file", "missing <xliff> root element"));
+    }
+    Ok(doc)
+}
+
+fn attr(e: &BytesStart, name: &[u8]) -> Result<Option<String>, AppError> {
+    for attribute in e.attributes() {
+        let attribute =
+            attribute.map_err(|e| AppError::invalid("file", format!("XML error: {e}")))?;
+        if attribute.key.local_name().as_ref() == name {
+            return attribute
+                .unescape_value()
+                .map(|v| Some(v.into_owned()))
+                .map_err(|e| AppError::invalid("file", format!("XML error: {e}")));
+        }
+    }
+    Ok(None)
+}
+
+/// `msgctxt` = key của unit, `#:` = reference, `#, fuzzy` = chưa dịch xong
+fn render_po(doc: &ExchangeDocument) -> String {
+    let mut out = String::new();
+    out.push_str("msgid \"\"\nmsgstr \"\"\n");
+    out.push_str("\"MIME-Version: 1.0\\n\"\n");
+    out.push_str("\"Content-Type: text/plain; charset=UTF-8\\n\"\n");
+    out.push_str("\"Content-Transfer-Encoding: 8bit\\n\"\n");
+    if let Some(lang) = &doc.target_language {
+        out.push_str(&format!("\"Language: {}\\n\"\n", po_escape(lang)));
+    }
+    if let Some(lang) = &doc.source_language {
+        out.push_str(&format!("\"X-Source-Language: {}\\n\"\n", po_escape(lang)));
+    }
+
+    for unit in &doc.units {
+        out.push('\n');
+        if let Some(note) = &unit.note {
+            out.push_str(&format!("#. {}\n", note.replace('\n', " ")));
+        }
+        if let Some(reference) = &unit.reference {
+            out.push_str(&format!("#: {reference}\n"));
+        }
+        if !unit.translated && unit.target.is_some() {
+            out.push_str("#, fuzzy\n");
+        }
+        out.push_str(&format!("msgctxt {}\n", po_string(&unit.key())));
+        out.push_str(&format!("msgid {}\n", po_string(&unit.source)));
+        out.push_str(&format!(
+            "msgstr {}\n",
+            po_string(unit.target.as_deref().unwrap_or_default())
+        ));
+    }
+    out
+}
+
+/// Chuỗi PO, nhiều dòng thì tách theo `\n` như xgettext
+fn po_string(value: &str) -> String {
+    if !value.contains('\n') {
+        return format!("\"{}\"", po_escape(value));
+    }
+    let mut out = String::from("\"\"");
+    for line in value.split_inclusive('\n') {
+        out.push_str(&format!("\n\"{}\"", po_escape(line)));
+    }
+    out
+}
+
+fn po_escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn po_unescape(value: &str, line: usize) -> Result<String, AppError> {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('t') => out.push('\t'),
+            Some('\\') => out.push('\\'),
+            Some('"') => out.push('"'),
+            other => {
+                return Err(AppError::invalid(
+                    "file",
+                    format!(
+                        "line {line}: unsupported escape \\{}",
+                        other.map(String::from).unwrap_or_default()
+                    ),
+                ));
+            }
+        }
+    }
+    Ok(out)
+}
+
+#[derive(Default)]
+struct PoEntry {
+    reference: Option<String>,
+    fuzzy: bool,
+    msgctxt: Option<String>,
+    msgid: Option<String>,
+    msgstr: Option<String>,
+    plural: bool,
+}
+
+#[derive(Clone, Copy)]
+enum PoKeyword {
+    Msgctxt,
+    Msgid,
+    Msgstr,
+    Ignored,
+}
+
+fn parse_po(body: &str) -> Result<ExchangeDocument, AppError> {
+    let mut doc = ExchangeDocument::default();
+    let mut entries: Vec<PoEntry> = Vec::new();
+    let mut entry = PoEntry::default();
+    let mut last: Option<PoKeyword> = None;
+
+    for (index, raw) in body.trim_start_matches('\u{feff}').lines().enumerate() {
+        let line_no = index + 1;
+        let line = raw.trim();
+        if line.is_empty() {
+            continue;
+        }
+        // Entry mới bắt đầu bằng comment/msgctxt/msgid sau khi đã có msgstr
+        let starts_entry = line.starts_with('#')
+            || line.starts_with("msgctxt")
+            || (line.starts_with("msgid") && !line.starts_with("msgid_plural"));
+        if starts_entry && entry.msgstr.is_some() {
+            entries.push(std::mem::take(&mut entry));
+            last = None;
+        }
+
+        if let Some(comment) = line.strip_prefix('#') {
+            if let Some(flags) = comment.strip_prefix(',') {
+                entry.fuzzy |= flags.split(',').any(|f| f.trim() == "fuzzy");
+            } else if let Some(reference) = comment.strip_prefix(':') {
+                entry.reference = Some(reference.trim().to_string());
+            }
+            // `#~` (obsolete), `#.`, `# ` => bỏ qua
+            continue;
+        }
+
+        let (keyword, rest) = if line.starts_with('"') {
+            (None, line)
+        } else {
+            let (word, rest) = line.split_once(char::is_whitespace).ok_or_else(|| {
+                AppError::invalid("file", format!("line {line_no}: unexpected {line}"))
+            })?;
+            let keyword = match word {
+                "msgctxt" => PoKeyword::Msgctxt,
+                "msgid" => PoKeyword::Msgid,
+                "msgstr" => PoKeyword::Msgstr,
+                "msgid_plural" => {
+                    entry.plural = true;
+                    PoKeyword::Ignored
+                }
+                w if w.starts_with("msgstr[") => {
+                    entry.plural = true;
+                    PoKeyword::Ignored
+                }
+                _ => {
+                    return Err(AppError::invalid(
+                        "file",
+                        format!("line {line_no}: unknown keyword {word}"),
+                    ));
+                }
+            };
+            (Some(keyword), rest.trim())
+        };
+
+        let text = rest
+            .strip_prefix('"')
+            .and_then(|r| r.strip_suffix('"'))
+            .ok_or_else(|| {
+                AppError::invalid("file", format!("line {line_no}: expected a quoted string"))
+            })?;
+        let text = po_unescape(text, line_no)?;
+
+        let current = match keyword {
+            Some(keyword) => {
+                last = Some(keyword);
+                keyword
+            }
+            None => last.ok_or_else(|| {
+                AppError::invalid(
+                    "file",
+                    format!("line {line_no}: string without msgid/msgstr"),
+                )
+            })?,
+        };
+        let slot = match current {
+            PoKeyword::Msgctxt => &mut entry.msgctxt,
+            PoKeyword::Msgid => &mut entry.msgid,
+            PoKeyword::Msgstr => &mut entry.msgstr,
+            PoKeyword::Ignored => continue,
+        };
+        // Từ khoá mới => bắt đầu chuỗi mới, dòng `"..."` tiếp theo => nối vào
+        if keyword.is_some() {
+            *slot = Some(String::new());
+        }
+        slot.get_or_insert_with(String::new).push_str(&text);
+    }
+    if entry.msgid.is_some() {
+        entries.push(entry);
+    }
+
+    for entry in entries {
+        let msgid = entry.msgid.unwrap_or_default();
+        // Header: msgid "" không có msgctxt
+        if msgid.is_empty() && entry.msgctxt.is_none() {
+            for header in entry.msgstr.unwrap_or_default().lines() {
+                if let Some((name, value)) = header.split_once(':') {
+                    match name.trim() {
+                        "Language" => doc.target_language = Some(value.trim().to_string()),
+                        "X-Source-Language" => doc.source_language = Some(value.trim().to_string()),
+                        _ => {}
+                    }
+                }
+            }
+            continue;
+        }
+
+        let key = entry.msgctxt.unwrap_or_default();
+        let (entity, field) = key.rsplit_once(':').unwrap_or((key.as_str(), ""));
+        doc.units.push(ExchangeUnit {
+            entity: entity.to_string(),
+            field: field.to_string(),
+            reference: entry.reference,
+            source: msgid,
+            target: if entry.plural {
+                None
+            } else {
+                entry.msgstr.filter(|s| !s.is_empty())
+            },
+            translated: !entry.fuzzy,
+            note: None,
+        });
+    }
+
+    Ok(doc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit(entity: &str, field: &str, target: Option<&str>, translated: bool) -> ExchangeUnit {
+        ExchangeUnit {
+            entity: entity.to_string(),
+            field: field.to_string(),
+            reference: Some(format!("{entity}/slug")),
+            source: format!("Chào <b>\"{field}\"</b> & \"bạn\"\nDòng 2\t\\"),
+            target: target.map(str::to_string),
+            translated,
+            note: None,
+        }
+    }
+
+    fn sample() -> ExchangeDocument {
+        ExchangeDocument {
+            source_language: Some("vi".to_string()),
+            target_language: Some("en".to_string()),
+            units: vec![
+                unit(
+                    "post:1",
+                    "title",
+                    Some("Hello <b>\"title\"</b>\nLine 2"),
+                    true,
+                ),
+                unit("post:1", "content", Some("Draft"), false),
+                unit("category:2", "name", Some("News & events"), true),
+            ],
+        }
+    }
+
+    #[test]
+    fn xliff_round_trips() {
+        let doc = sample();
+        let parsed = parse(ExchangeFormat::Xliff, &render(ExchangeFormat::Xliff, &doc)).unwrap();
+        assert_eq!(parsed.source_language, doc.source_language);
+        assert_eq!(parsed.target_language, doc.target_language);
+        assert_eq!(parsed.units, doc.units);
+    }
+
+    #[test]
+    fn po_round_trips() {
+        let doc = sample();
+        let parsed = parse(ExchangeFormat::Po, &render(ExchangeFormat::Po, &doc)).unwrap();
+        assert_eq!(parsed.source_language, doc.source_language);
+        assert_eq!(parsed.target_language, doc.target_language);
+        assert_eq!(parsed.units, doc.units);
+    }
+
+    #[test]
+    fn xliff_segment_without_state_is_initial() {
+        let body = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xliff xmlns="urn:oasis:names:tc:xliff:document:2.0" version="2.0" srcLang="vi" trgLang="en">
+  <file id="post:1">
+    <unit id="title"><segment><source>Xin chào</source><target>Hello</target></segment></unit>
+    <unit id="excerpt"><segment state="final"><source>Tóm tắt</source><target>Summary</target></segment></unit>
+  </file>
+</xliff>"#;
+        let doc = parse(ExchangeFormat::Xliff, body).unwrap();
+        let states: Vec<(&str, bool)> = doc
+            .units
+            .iter()
+            .map(|u| (u.field.as_str(), u.translated))
+            .collect();
+        assert_eq!(states, vec![("title", false), ("excerpt", true)]);
+    }
+
+    #[test]
+    fn rejects_xliff_1_and_non_xliff_xml() {
+        let err = parse(
+            ExchangeFormat::Xliff,
+            r#"<xliff version="1.2"><file id="a"></file></xliff>"#,
+        )
+        .unwrap_err();
+        assert!(err.message().contains("version 1.2"), "{}", err.message());
+        assert!(parse(ExchangeFormat::Xliff, "<html></html>").is_err());
+    }
+
+    #[test]
+    fn detects_format_from_content() {
+        assert_eq!(
+            ExchangeFormat::detect("\u{feff}  <?xml version=\"1.0\"?>"),
+            ExchangeFormat::Xliff
+        );
+        assert_eq!(ExchangeFormat::detect("msgid \"\""), ExchangeFormat::Po);
+    }
+}
@@ -0,0 +1,153 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::application::translation::exchange_format::{
+    ExchangeDocument, ExchangeFormat, ExchangeUnit, render,
+};
+use crate::application::translation::translation_language::resolve_translation_language;
+use crate::application::translation::translation_queue::parse_entity_type;
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::domain::repositories::translation_status_repository::{
+    TranslationStatusRepository, TranslationUnitFilter,
+};
+use crate::shared::error::AppError;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ExportTranslationsQuery {
+    pub lang: Option<String>,
+    /// `xliff` (mặc định) | `po`
+    pub format: Option<String>,
+    pub entity_type: Option<String>,
+    /// Id bản ghi gốc, phân tách bằng dấu phẩy
+    pub ids: Option<String>,
+    /// Chỉ bản ghi đổi nội dung từ thời điểm này (`2026-10-01` hoặc RFC 3339)
+    pub since: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExportedTranslations {
+    pub format: ExchangeFormat,
+    pub filename: String,
+    pub body: String,
+}
+
+/// Xuất nội dung gốc + bản dịch hiện có của 1 ngôn ngữ ra XLIFF 2.0 / PO để gửi bên dịch.
+/// Bản dịch còn mới => `translated`; chưa có hoặc đã cũ => `initial` / `fuzzy`
+pub struct ExportTranslationsUseCase {
+    language_repo: Arc<dyn LanguageRepository>,
+    status_repo: Arc<dyn TranslationStatusRepository>,
+}
+
+impl ExportTranslationsUseCase {
+    pub fn new(
+        language_repo: Arc<dyn LanguageRepository>,
+        status_repo: Arc<dyn TranslationStatusRepository>,
+    ) -> Self {
+        Self {
+            language_repo,
+            status_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        query: ExportTranslationsQuery,
+    ) -> Result<ExportedTranslations, AppError> {
+        let lang = query
+            .lang
+            .as_deref()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .ok_or_else(|| AppError::invalid("lang", "required"))?;
+        let language_code = resolve_translation_language(self.language_repo.as_ref(), lang).await?;
+        let format = match query.format.as_deref().filter(|f| !f.trim().is_empty()) {
+            Some(format) => ExchangeFormat::parse(format).ok_or_else(|| {
+                AppError::invalid("format", format!("{format} (expected xliff or po)"))
+            })?,
+            None => ExchangeFormat::Xliff,
+        };
+        let entity_type = parse_entity_type(query.entity_type)?;
+        let entity_ids = query.ids.as_deref().map(parse_ids).transpose()?;
+        let changed_since = query.since.as_deref().map(parse_since).transpose()?;
+
+        let source_language = self
+            .language_repo
+            .get_default()
+            .await?
+            .map(|l| l.code)
+            .ok_or_else(|| AppError::not_found("DEFAULT_LANGUAGE_NOT_FOUND", "Default language"))?;
+
+        let units = self
+            .status_repo
+            .list_units(&TranslationUnitFilter {
+                language_code: language_code.clone(),
+                entity_type,
+                entity_ids,
+                changed_since,
+            })
+            .await?
+            .into_iter()
+            // Trường gốc trống thì không có gì để dịch
+            .filter_map(|unit| {
+                let source = unit.source.filter(|s| !s.trim().is_empty())?;
+                let outdated = unit.status == "outdated";
+                Some(ExchangeUnit {
+                    entity: format!("{}:{}", unit.entity_type, unit.entity_id),
+                    field: unit.field,
+                    reference: unit
+                        .slug
+                        .map(|slug| format!("{}/{}", unit.entity_type, slug)),
+                    source,
+                    translated: unit.status == "up_to_date" && unit.target.is_some(),
+                    target: unit.target,
+                    note: outdated.then(|| "Source changed since the last translation".to_string()),
+                })
+            })
+            .collect();
+
+        let body = render(
+            format,
+            &ExchangeDocument {
+                source_language: Some(source_language),
+                target_language: Some(language_code.clone()),
+                units,
+            },
+        );
+
+        Ok(ExportedTranslations {
+            format,
+            filename: format!("translations-{language_code}.{}", format.extension()),
+            body,
+        })
+    }
+}
+
+fn parse_ids(value: &str) -> Result<Vec<Uuid>, AppError> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| {
+            Uuid::parse_str(id).map_err(|_| AppError::invalid("ids", format!("{id} is not a UUID")))
+        })
+        .collect()
+}
+
+fn parse_since(value: &str) -> Result<NaiveDateTime, AppError> {
+    let value = value.trim();
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date.and_time(Default::default()));
+    }
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.naive_utc())
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S"))
+        .map_err(|_| {
+            AppError::invalid(
+                "since",
+                format!("{value} (expected YYYY-MM-DD or RFC 3339)"),
+            )
+        })
+}
@@ -0,0 +1,467 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::application::translation::banner_translations::{
+    BannerTranslationInput, BannerTranslationsUseCase,
+};
+use crate::application::translation::category_translations::{
+    CategoryTranslationsUseCase, NameTranslationInput,
+};
+use crate::application::translation::exchange_format::{ExchangeFormat, parse};
+use crate::application::translation::post_translations::{
+    PostTranslationInput, PostTranslationsUseCase,
+};
+use crate::application::translation::tag_translations::TagTranslationsUseCase;
+use crate::application::translation::translation_language::resolve_translation_language;
+use crate::domain::entities::translation_status::{TRANSLATABLE_FIELDS, TranslationUnit};
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::domain::repositories::translation_status_repository::{
+    TranslationStatusRepository, TranslationUnitFilter,
+};
+use crate::shared::error::AppError;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ImportTranslationsQuery {
+    /// Bỏ trống => lấy theo file (`trgLang` / header `Language`)
+    pub lang: Option<String>,
+    /// `xliff` | `po`, bỏ trống => đoán theo nội dung
+    pub format: Option<String>,
+    /// `true` => chỉ trả về diff, không ghi
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportSummary {
+    /// Tổng số unit trong file
+    pub units: usize,
+    /// Unit chưa dịch xong (`initial`/`fuzzy`) hoặc target trống
+    pub skipped: usize,
+    /// Unit lỗi (xem `errors`)
+    pub failed: usize,
+    pub created: usize,
+    pub updated: usize,
+    /// Bản dịch không đổi nhưng bản gốc đã đổi => xác nhận lại bản dịch
+    pub refreshed: usize,
+    pub unchanged: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+    /// Nội dung gốc trong file khác bản gốc hiện tại (bản gốc đổi sau khi export)
+    pub source_changed: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EntityImport {
+    pub entity_type: String,
+    pub entity_id: Uuid,
+    pub slug: Option<String>,
+    /// `create` | `update` | `refresh` | `unchanged`
+    pub action: &'static str,
+    pub changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UnitError {
+    /// Key của unit (`post:<uuid>:title`) hoặc của bản ghi (`post:<uuid>`)
+    pub unit: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportReport {
+    pub language_code: String,
+    pub format: &'static str,
+    pub dry_run: bool,
+    pub summary: ImportSummary,
+    pub entities: Vec<EntityImport>,
+    pub errors: Vec<UnitError>,
+}
+
+/// Unit hợp lệ trong file, gom theo bản ghi gốc
+struct ImportedField {
+    key: String,
+    field: String,
+    source: String,
+    target: String,
+}
+
+/// Nhập bản dịch từ XLIFF 2.0 / PO vào các bảng `*_translations`.
+/// Mỗi bản ghi gốc được kiểm tra và ghi độc lập: lỗi ở 1 bản ghi không chặn các bản ghi khác
+pub struct ImportTranslationsUseCase {
+    language_repo: Arc<dyn LanguageRepository>,
+    status_repo: Arc<dyn TranslationStatusRepository>,
+    posts: PostTranslationsUseCase,
+    categories: CategoryTranslationsUseCase,
+    tags: TagTranslationsUseCase,
+    banners: BannerTranslationsUseCase,
+}
+
+impl ImportTranslationsUseCase {
+    pub fn new(
+        language_repo: Arc<dyn LanguageRepository>,
+        status_repo: Arc<dyn TranslationStatusRepository>,
+        posts: PostTranslationsUseCase,
+        categories: CategoryTranslationsUseCase,
+        tags: TagTranslationsUseCase,
+        banners: BannerTranslationsUseCase,
+    ) -> Self {
+        Self {
+            language_repo,
+            status_repo,
+            posts,
+            categories,
+            tags,
+            banners,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        query: ImportTranslationsQuery,
+        body: &str,
+    ) -> Result<ImportReport, AppError> {
+        let format = match query.format.as_deref().filter(|f| !f.trim().is_empty()) {
+            Some(format) => ExchangeFormat::parse(format).ok_or_else(|| {
+                AppError::invalid("format", format!("{format} (expected xliff or po)"))
+            })?,
+            None => ExchangeFormat::detect(body),
+        };
+        let doc = parse(format, body)?;
+
+        let query_lang = query
+            .lang
+            .as_deref()
+            .map(str::trim)
+            .filter(|l| !l.is_empty());
+        let file_lang = doc.target_language.as_deref().filter(|l| !l.is_empty());
+        let lang = match (query_lang, file_lang) {
+            (Some(q), Some(f)) if !q.eq_ignore_ascii_case(f) => {
+                return Err(AppError::invalid(
+                    "lang",
+                    format!("file targets {f} but lang={q} was requested"),
+                ));
+            }
+            (Some(lang), _) | (None, Some(lang)) => lang,
+            (None, None) => {
+                return Err(AppError::invalid("lang", "required (not declared in file)"));
+            }
+        };
+        let language_code = resolve_translation_language(self.language_repo.as_ref(), lang).await?;
+        let dry_run = query.dry_run.unwrap_or(false);
+
+        let mut summary = ImportSummary {
+            units: doc.units.len(),
+            ..Default::default()
+        };
+        let mut errors: Vec<UnitError> = Vec::new();
+
+        // 1. Kiểm tra từng unit, gom theo bản ghi gốc (giữ thứ tự trong file)
+        let mut order: Vec<(String, Uuid)> = Vec::new();
+        let mut grouped: HashMap<(String, Uuid), Vec<ImportedField>> = HashMap::new();
+        let mut seen: HashSet<String> = HashSet::new();
+        for unit in doc.units {
+            let key = unit.key();
+            let target = unit.target.as_deref().map(str::trim).unwrap_or_default();
+            if !unit.translated || target.is_empty() {
+                summary.skipped += 1;
+                continue;
+            }
+            match parse_unit_key(&unit.entity, &unit.field) {
+                Ok(entity) => {
+                    if !seen.insert(key.clone()) {
+                        errors.push(unit_error(&key, "Duplicate unit"));
+                        continue;
+                    }
+                    if !grouped.contains_key(&entity) {
+                        order.push(entity.clone());
+                    }
+                    grouped.entry(entity).or_default().push(ImportedField {
+                        key,
+                        field: unit.field,
+                        source: unit.source,
+                        target: target.to_string(),
+                    });
+                }
+                Err(e) => errors.push(unit_error(&key, &e)),
+            }
+        }
+
+        // 2. Trạng thái hiện tại của các bản ghi gốc được nhắc tới
+        let current = if order.is_empty() {
+            Vec::new()
+        } else {
+            self.status_repo
+                .list_units(&TranslationUnitFilter {
+                    language_code: language_code.clone(),
+                    entity_ids: Some(order.iter().map(|(_, id)| *id).collect()),
+                    ..Default::default()
+                })
+                .await?
+        };
+        let mut current_by_entity: HashMap<(String, Uuid), Vec<TranslationUnit>> = HashMap::new();
+        for unit in current {
+            current_by_entity
+                .entry((unit.entity_type.clone(), unit.entity_id))
+                .or_default()
+                .push(unit);
+        }
+
+        // 3. Diff + ghi từng bản ghi
+        let mut entities: Vec<EntityImport> = Vec::new();
+        for entity in order {
+            let fields = grouped.remove(&entity).unwrap_or_default();
+            let (entity_type, entity_id) = entity;
+            let Some(current) = current_by_entity.remove(&(entity_type.clone(), entity_id)) else {
+                for field in &fields {
+                    errors.push(unit_error(
+                        &field.key,
+                        format!("{} {entity_id} not found", capitalize(&entity_type)),
+                    ));
+                }
+                continue;
+            };
+
+            let diff = diff_entity(&entity_type, entity_id, &current, &fields);
+            let diff = match diff {
+                Ok(diff) => diff,
+                Err(e) => {
+                    errors.extend(fields.iter().map(|f| unit_error(&f.key, &e)));
+                    continue;
+                }
+            };
+
+            if !dry_run
+                && diff.entity.action != "unchanged"
+                && let Err(e) = self
+                    .write(
+                        &diff.entity,
+                        current[0].r#type.as_deref(),
+                        &language_code,
+                        &diff.values,
+                    )
+                    .await
+            {
+                errors.extend(fields.iter().map(|f| unit_error(&f.key, &e)));
+                continue;
+            }
+
+            match diff.entity.action {
+                "create" => summary.created += 1,
+                "update" => summary.updated += 1,
+                "refresh" => summary.refreshed += 1,
+                _ => summary.unchanged += 1,
+            }
+            entities.push(diff.entity);
+        }
+        summary.failed = errors.len();
+
+        Ok(ImportReport {
+            language_code,
+            format: format.as_str(),
+            dry_run,
+            summary,
+            entities,
+            errors,
+        })
+    }
+
+    /// Ghi bản dịch đã gộp qua use case CRUD tương ứng (cùng validate với API thường)
+    async fn write(
+        &self,
+        entity: &EntityImport,
+        r#type: Option<&str>,
+        lang: &str,
+        values: &HashMap<String, Option<String>>,
+    ) -> Result<(), AppError> {
+        let value = |field: &str| values.get(field).cloned().flatten();
+        let source_slug = entity.slug.as_deref().unwrap_or_default();
+        match entity.entity_type.as_str() {
+            // Theo id: bài viết có thể chưa có slug
+            "post" => {
+                // Giữ slug bản dịch hiện có (đổi tiêu đề không đổi URL)
+                let slug = self
+                    .posts
+                    .find_by_post_id(entity.entity_id, lang)
+                    .await?
+                    .and_then(|existing| existing.slug);
+                self.posts
+                    .upsert_by_post_id(
+                        entity.entity_id,
+                        lang,
+                        PostTranslationInput {
+                            title: value("title").unwrap_or_default(),
+                            slug,
+                            excerpt: value("excerpt"),
+                            content: value("content"),
+                        },
+                    )
+                    .await
+                    .map(|_| ())
+            }
+            "category" => self
+                .categories
+                .upsert(
+                    source_slug,
+                    r#type.unwrap_or("post"),
+                    lang,
+                    NameTranslationInput {
+                        name: value("name").unwrap_or_default(),
+                        description: value("description"),
+                    },
+                )
+                .await
+                .map(|_| ()),
+            "tag" => self
+                .tags
+                .upsert(
+                    source_slug,
+                    r#type.unwrap_or("post"),
+                    lang,
+                    NameTranslationInput {
+                        name: value("name").unwrap_or_default(),
+                        description: value("description"),
+                    },
+                )
+                .await
+                .map(|_| ()),
+            _ => self
+                .banners
+                .upsert(
+                    entity.entity_id,
+                    lang,
+                    BannerTranslationInput {
+                        title: value("title").unwrap_or_default(),
+                        description: value("description"),
+                    },
+                )
+                .await
+                .map(|_| ()),
+        }
+    }
+}
+
+struct EntityDiff {
+    entity: EntityImport,
+    /// Giá trị sau khi gộp của mọi trường (trường không có trong file giữ bản dịch cũ)
+    values: HashMap<String, Option<String>>,
+}
+
+fn diff_entity(
+    entity_type: &str,
+    entity_id: Uuid,
+    current: &[TranslationUnit],
+    fields: &[ImportedField],
+) -> Result<EntityDiff, AppError> {
+    let status = current[0].status.as_str();
+    let mut values: HashMap<String, Option<String>> = current
+        .iter()
+        .map(|u| (u.field.clone(), u.target.clone()))
+        .collect();
+
+    let mut changes = Vec::new();
+    for field in fields {
+        let existing = current.iter().find(|u| u.field == field.field);
+        let before = existing.and_then(|u| u.target.clone());
+        let after = Some(field.target.clone());
+        let source_changed = !field.source.trim().is_empty()
+            && existing
+                .and_then(|u| u.source.as_deref())
+                .unwrap_or_default()
+                .trim()
+                != field.source.trim();
+        if before != after || source_changed {
+            changes.push(FieldChange {
+                field: field.field.clone(),
+                before: before.clone(),
+                after: after.clone(),
+                source_changed,
+            });
+        }
+        values.insert(field.field.clone(), after);
+    }
+
+    let required = required_field(entity_type);
+    if values
+        .get(required)
+        .cloned()
+        .flatten()
+        .is_none_or(|v| v.trim().is_empty())
+    {
+        return Err(AppError::invalid(
+            required,
+            format!("required when {entity_type} has no {required} translation yet"),
+        ));
+    }
+
+    let changed = changes.iter().any(|c| c.before != c.after);
+    let action = if status == "missing" {
+        "create"
+    } else if changed {
+        "update"
+    } else if status == "outdated" {
+        "refresh"
+    } else {
+        "unchanged"
+    };
+
+    Ok(EntityDiff {
+        entity: EntityImport {
+            entity_type: entity_type.to_string(),
+            entity_id,
+            slug: current[0].slug.clone(),
+            action,
+            changes,
+        },
+        values,
+    })
+}
+
+/// `post:<uuid>` + `title` => ("post", uuid), kiểm tra trường có dịch được không
+fn parse_unit_key(entity: &str, field: &str) -> Result<(String, Uuid), AppError> {
+    let (entity_type, id) = entity.split_once(':').ok_or_else(|| {
+        AppError::invalid("unit id", format!("expected <type>:<uuid>, got {entity}"))
+    })?;
+    let fields = TRANSLATABLE_FIELDS
+        .iter()
+        .find(|(t, _)| *t == entity_type)
+        .map(|(_, fields)| *fields)
+        .ok_or_else(|| AppError::invalid("entity type", entity_type.to_string()))?;
+    let id = Uuid::parse_str(id).map_err(|_| AppError::invalid("entity id", id.to_string()))?;
+    if !fields.contains(&field) {
+        return Err(AppError::invalid(
+            "field",
+            format!("{entity_type} has no translatable field {field}"),
+        ));
+    }
+    Ok((entity_type.to_string(), id))
+}
+
+fn required_field(entity_type: &str) -> &'static str {
+    TRANSLATABLE_FIELDS
+        .iter()
+        .find(|(t, _)| *t == entity_type)
+        .map(|(_, fields)| fields[0])
+        .unwrap_or("title")
+}
+
+fn unit_error(unit: &str, error: impl std::fmt::Display) -> UnitError {
+    UnitError {
+        unit: unit.to_string(),
+        error: error.to_string(),
+    }
+}
+
+fn capitalize(value: &str) -> String {
+    let mut chars = value.chars();
+    chars
+        .next()
+        .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
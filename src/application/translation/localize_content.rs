@@ -0,0 +1,347 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::application::post::embed_post_relations::PostView;
+use crate::application::post::post_content::render_post_content;
+use crate::application::translation::negotiate_language::ContentLanguage;
+use crate::domain::entities::banner::Banner;
+use crate::domain::entities::category::Category;
+use crate::domain::entities::post::Post;
+use crate::domain::entities::tag::Tag;
+use crate::domain::repositories::banner_translation_repository::BannerTranslationRepository;
+use crate::domain::repositories::category_translation_repository::CategoryTranslationRepository;
+use crate::domain::repositories::post_translation_repository::PostTranslationRepository;
+use crate::domain::repositories::tag_translation_repository::TagTranslationRepository;
+use crate::shared::error::AppError;
+
+/// Field được lấy từ ngôn ngữ dự phòng: id entity => {field => mã ngôn ngữ}
+#[derive(Debug, Default)]
+pub struct FallbackFields(HashMap<Uuid, BTreeMap<&'static str, String>>);
+
+impl FallbackFields {
+    /// Thêm `fallback_fields` vào mọi object JSON (kể cả quan hệ nhúng) có `id` bị dự phòng
+    pub fn annotate(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                let fields = map
+                    .get("id")
+                    .and_then(Value::as_str)
+                    .and_then(|id| Uuid::parse_str(id).ok())
+                    .and_then(|id| self.0.get(&id));
+                if let Some(fields) = fields {
+                    map.insert("fallback_fields".to_string(), serde_json::json!(fields));
+                }
+                map.values_mut().for_each(|v| self.annotate(v));
+            }
+            Value::Array(items) => items.iter_mut().for_each(|v| self.annotate(v)),
+            _ => {}
+        }
+    }
+
+    /// Giá trị đầu tiên có nội dung theo `language.chain`; tới ngôn ngữ mặc định thì giữ giá trị gốc (`None`).
+    /// Ghi nhận field khi giá trị không thuộc ngôn ngữ được yêu cầu
+    fn resolve(
+        &mut self,
+        language: &ContentLanguage,
+        id: Uuid,
+        field: &'static str,
+        source_present: bool,
+        translated: impl Fn(&str) -> Option<String>,
+    ) -> Option<String> {
+        for code in &language.chain {
+            if *code == language.default_code {
+                break;
+            }
+            if let Some(value) = translated(code).filter(|v| !v.trim().is_empty()) {
+                if *code != language.code {
+                    self.record(id, field, code);
+                }
+                return Some(value);
+            }
+        }
+        if source_present && !language.is_default() {
+            self.record(id, field, &language.default_code);
+        }
+        None
+    }
+
+    fn record(&mut self, id: Uuid, field: &'static str, code: &str) {
+        self.0
+            .entry(id)
+            .or_default()
+            .insert(field, code.to_string());
+    }
+}
+
+/// Bản dịch theo từng ngôn ngữ dự phòng (khác mặc định): mã => id entity => bản dịch
+type ChainTranslations<T> = HashMap<String, HashMap<Uuid, T>>;
+
+/// Ghép bản dịch vào nội dung trả về theo chuỗi dự phòng của `ContentLanguage`
+pub struct LocalizeContentUseCase {
+    post_translation_repo: Arc<dyn PostTranslationRepository>,
+    category_translation_repo: Arc<dyn CategoryTranslationRepository>,
+    tag_translation_repo: Arc<dyn TagTranslationRepository>,
+    banner_translation_repo: Arc<dyn BannerTranslationRepository>,
+}
+
+impl LocalizeContentUseCase {
+    pub fn new(
+        post_translation_repo: Arc<dyn PostTranslationRepository>,
+        category_translation_repo: Arc<dyn CategoryTranslationRepository>,
+        tag_translation_repo: Arc<dyn TagTranslationRepository>,
+        banner_translation_repo: Arc<dyn BannerTranslationRepository>,
+    ) -> Self {
+        Self {
+            post_translation_repo,
+            category_translation_repo,
+            tag_translation_repo,
+            banner_translation_repo,
+        }
+    }
+
+    /// Dịch bài viết cùng category/tags đã nhúng
+    pub async fn post_views(
+        &self,
+        views: &mut [PostView],
+        language: &ContentLanguage,
+    ) -> Result<FallbackFields, AppError> {
+        let mut fallbacks = FallbackFields::default();
+        if language.is_default() {
+            return Ok(fallbacks);
+        }
+        let mut posts: Vec<&mut Post> = views.iter_mut().map(|v| &mut v.post).collect();
+        self.apply_posts(&mut posts, language, &mut fallbacks)
+            .await?;
+
+        let mut categories: Vec<&mut Category> = views
+            .iter_mut()
+            .filter_map(|v| v.category.as_mut().and_then(|c| c.as_mut()))
+            .collect();
+        self.apply_categories(&mut categories, language, &mut fallbacks)
+            .await?;
+
+        let mut tags: Vec<&mut Tag> = views
+            .iter_mut()
+            .filter_map(|v| v.tags.as_mut())
+            .flatten()
+            .collect();
+        self.apply_tags(&mut tags, language, &mut fallbacks).await?;
+        Ok(fallbacks)
+    }
+
+    pub async fn categories(
+        &self,
+        categories: &mut [Category],
+        language: &ContentLanguage,
+    ) -> Result<FallbackFields, AppError> {
+        let mut fallbacks = FallbackFields::default();
+        if !language.is_default() {
+            let mut categories: Vec<&mut Category> = categories.iter_mut().collect();
+            self.apply_categories(&mut categories, language, &mut fallbacks)
+                .await?;
+        }
+        Ok(fallbacks)
+    }
+
+    pub async fn tags(
+        &self,
+        tags: &mut [Tag],
+        language: &ContentLanguage,
+    ) -> Result<FallbackFields, AppError> {
+        let mut fallbacks = FallbackFields::default();
+        if !language.is_default() {
+            let mut tags: Vec<&mut Tag> = tags.iter_mut().collect();
+            self.apply_tags(&mut tags, language, &mut fallbacks).await?;
+        }
+        Ok(fallbacks)
+    }
+
+    pub async fn banners(
+        &self,
+        banners: &mut [&mut Banner],
+        language: &ContentLanguage,
+    ) -> Result<FallbackFields, AppError> {
+        let mut fallbacks = FallbackFields::default();
+        if language.is_default() || banners.is_empty() {
+            return Ok(fallbacks);
+        }
+        let ids: Vec<Uuid> = banners.iter().map(|b| b.id).collect();
+        let mut translations: ChainTranslations<_> = HashMap::new();
+        for code in translated_codes(language) {
+            let rows = self.banner_translation_repo.find_many(&ids, code).await?;
+            translations.insert(
+                code.clone(),
+                rows.into_iter().map(|t| (t.banner_id, t)).collect(),
+            );
+        }
+        let lookup = |code: &str, id: &Uuid| translations.get(code).and_then(|m| m.get(id));
+
+        for banner in banners.iter_mut() {
+            let id = banner.id;
+            if let Some(title) = fallbacks.resolve(language, id, "title", true, |code| {
+                lookup(code, &id).map(|t| t.title.clone())
+            }) {
+                banner.title = title;
+            }
+            if let Some(description) = fallbacks.resolve(
+                language,
+                id,
+                "description",
+                banner.description.is_some(),
+                |code| lookup(code, &id).and_then(|t| t.description.clone()),
+            ) {
+                banner.description = Some(description);
+            }
+        }
+        Ok(fallbacks)
+    }
+
+    async fn apply_posts(
+        &self,
+        posts: &mut [&mut Post],
+        language: &ContentLanguage,
+        fallbacks: &mut FallbackFields,
+    ) -> Result<(), AppError> {
+        if posts.is_empty() {
+            return Ok(());
+        }
+        let ids: Vec<Uuid> = posts.iter().map(|p| p.id).collect();
+        let mut translations: ChainTranslations<_> = HashMap::new();
+        for code in translated_codes(language) {
+            let rows = self.post_translation_repo.find_many(&ids, code).await?;
+            translations.insert(
+                code.clone(),
+                rows.into_iter().map(|t| (t.post_id, t)).collect(),
+            );
+        }
+        let lookup = |code: &str, id: &Uuid| translations.get(code).and_then(|m| m.get(id));
+
+        for post in posts.iter_mut() {
+            let id = post.id;
+            if let Some(title) = fallbacks.resolve(language, id, "title", true, |code| {
+                lookup(code, &id).map(|t| t.title.clone())
+            }) {
+                post.title = title;
+            }
+            if let Some(slug) =
+                fallbacks.resolve(language, id, "slug", post.slug.is_some(), |code| {
+                    lookup(code, &id).and_then(|t| t.slug.clone())
+                })
+            {
+                post.slug = Some(slug);
+            }
+            if let Some(excerpt) =
+                fallbacks.resolve(language, id, "excerpt", post.excerpt.is_some(), |code| {
+                    lookup(code, &id).and_then(|t| t.excerpt.clone())
+                })
+            {
+                post.excerpt = Some(excerpt);
+            }
+            let has_content = post.content.is_some() || post.content_blocks.is_some();
+            if let Some(content) = fallbacks.resolve(language, id, "content", has_content, |code| {
+                lookup(code, &id).and_then(|t| t.content.clone())
+            }) {
+                // Nội dung dịch render lại theo `content_format` của bài gốc; block chỉ có ở bản gốc
+                post.content = Some(content);
+                post.content_blocks = None;
+                render_post_content(post, false);
+            }
+        }
+        Ok(())
+    }
+
+    async fn apply_categories(
+        &self,
+        categories: &mut [&mut Category],
+        language: &ContentLanguage,
+        fallbacks: &mut FallbackFields,
+    ) -> Result<(), AppError> {
+        if categories.is_empty() {
+            return Ok(());
+        }
+        let mut ids: Vec<Uuid> = categories.iter().map(|c| c.id).collect();
+        ids.sort();
+        ids.dedup();
+        let mut translations: ChainTranslations<_> = HashMap::new();
+        for code in translated_codes(language) {
+            let rows = self.category_translation_repo.find_many(&ids, code).await?;
+            translations.insert(
+                code.clone(),
+                rows.into_iter().map(|t| (t.category_id, t)).collect(),
+            );
+        }
+        let lookup = |code: &str, id: &Uuid| translations.get(code).and_then(|m| m.get(id));
+
+        for category in categories.iter_mut() {
+            let id = category.id;
+            if let Some(name) = fallbacks.resolve(language, id, "name", true, |code| {
+                lookup(code, &id).map(|t| t.name.clone())
+            }) {
+                category.name = name;
+            }
+            if let Some(description) = fallbacks.resolve(
+                language,
+                id,
+                "description",
+                category.description.is_some(),
+                |code| lookup(code, &id).and_then(|t| t.description.clone()),
+            ) {
+                category.description = Some(description);
+            }
+        }
+        Ok(())
+    }
+
+    async fn apply_tags(
+        &self,
+        tags: &mut [&mut Tag],
+        language: &ContentLanguage,
+        fallbacks: &mut FallbackFields,
+    ) -> Result<(), AppError> {
+        if tags.is_empty() {
+            return Ok(());
+        }
+        let mut ids: Vec<Uuid> = tags.iter().map(|t| t.id).collect();
+        ids.sort();
+        ids.dedup();
+        let mut translations: ChainTranslations<_> = HashMap::new();
+        for code in translated_codes(language) {
+            let rows = self.tag_translation_repo.find_many(&ids, code).await?;
+            translations.insert(
+                code.clone(),
+                rows.into_iter().map(|t| (t.tag_id, t)).collect(),
+            );
+        }
+        let lookup = |code: &str, id: &Uuid| translations.get(code).and_then(|m| m.get(id));
+
+        for tag in tags.iter_mut() {
+            let id = tag.id;
+            if let Some(name) = fallbacks.resolve(language, id, "name", true, |code| {
+                lookup(code, &id).map(|t| t.name.clone())
+            }) {
+                tag.name = name;
+            }
+            if let Some(description) = fallbacks.resolve(
+                language,
+                id,
+                "description",
+                tag.description.is_some(),
+                |code| lookup(code, &id).and_then(|t| t.description.clone()),
+            ) {
+                tag.description = Some(description);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Các ngôn ngữ trong chuỗi cần tra bảng bản dịch (ngôn ngữ mặc định nằm ở bản ghi gốc)
+fn translated_codes(language: &ContentLanguage) -> impl Iterator<Item = &String> {
+    language
+        .chain
+        .iter()
+        .take_while(move |code| **code != language.default_code)
+}
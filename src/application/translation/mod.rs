@@ -0,0 +1,12 @@
+pub mod banner_translations;
+pub mod category_translations;
+pub mod exchange_format;
+pub mod export_translations;
+pub mod import_translations;
+pub mod localize_content;
+pub mod negotiate_language;
+pub mod post_translations;
+pub mod tag_translations;
+pub mod translation_coverage;
+pub mod translation_language;
+pub mod translation_queue;
@@ -0,0 +1,257 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::entities::language::Language;
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::domain::repositories::setting_repository::SettingRepository;
+use crate::domain::repositories::user_profile_repository::UserProfileRepository;
+use crate::shared::error::{AppError, ErrorKind};
+
+/// Setting khai báo ngôn ngữ dự phòng: `{"en-GB": ["en"], "ja": ["en"]}`
+pub const LANGUAGE_FALLBACKS_KEY: &str = "language_fallbacks";
+
+/// Nguồn xác định ngôn ngữ của request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LanguageSource {
+    /// `?lang=`
+    Query,
+    /// `user_profiles.preferred_language` của user đang đăng nhập
+    UserPreference,
+    /// Header `Accept-Language`
+    AcceptLanguage,
+    /// Slug trong URL là slug của bản dịch
+    TranslatedSlug,
+    /// Mã ngôn ngữ nằm trong path (`/i18n/:lang/:namespace`)
+    Path,
+    /// Ngôn ngữ mặc định
+    Default,
+}
+
+/// Ngôn ngữ phục vụ request cùng chuỗi dự phòng
+#[derive(Debug, Clone)]
+pub struct ContentLanguage {
+    pub code: String,
+    pub source: LanguageSource,
+    /// Thứ tự tra bản dịch: `code`, các ngôn ngữ dự phòng, cuối cùng là ngôn ngữ mặc định
+    pub chain: Vec<String>,
+    pub default_code: String,
+}
+
+impl ContentLanguage {
+    pub fn is_default(&self) -> bool {
+        self.code == self.default_code
+    }
+}
+
+/// Chọn ngôn ngữ cho request: `?lang=` > ngôn ngữ ưu tiên của user > `Accept-Language` > mặc định
+pub struct NegotiateLanguageUseCase {
+    language_repo: Arc<dyn LanguageRepository>,
+    setting_repo: Arc<dyn SettingRepository>,
+    user_profile_repo: Arc<dyn UserProfileRepository>,
+}
+
+impl NegotiateLanguageUseCase {
+    pub fn new(
+        language_repo: Arc<dyn LanguageRepository>,
+        setting_repo: Arc<dyn SettingRepository>,
+        user_profile_repo: Arc<dyn UserProfileRepository>,
+    ) -> Self {
+        Self {
+            language_repo,
+            setting_repo,
+            user_profile_repo,
+        }
+    }
+
+    /// `lang` không hợp lệ => lỗi; preference/header không khớp ngôn ngữ nào thì bỏ qua
+    pub async fn execute(
+        &self,
+        lang: Option<&str>,
+        user_id: Option<Uuid>,
+        accept_language: Option<&str>,
+    ) -> Result<ContentLanguage, AppError> {
+        let languages = self.language_repo.list_all().await?;
+
+        if let Some(code) = lang.map(str::trim).filter(|l| !l.is_empty()) {
+            let code = find_language(&languages, code).ok_or_else(|| {
+                AppError::new(
+                    ErrorKind::Invalid,
+                    "LANGUAGE_INVALID",
+                    format!("Invalid language: {code}"),
+                )
+                .with("code", code)
+            })?;
+            return self.build(&languages, code, LanguageSource::Query).await;
+        }
+
+        if let Some(user_id) = user_id
+            && let Some(code) = self
+                .user_profile_repo
+                .find_by_user_id(user_id)
+                .await?
+                .and_then(|p| p.preferred_language)
+                .and_then(|code| find_language(&languages, &code))
+        {
+            return self
+                .build(&languages, code, LanguageSource::UserPreference)
+                .await;
+        }
+
+        if let Some(code) = accept_language
+            .map(parse_accept_language)
+            .unwrap_or_default()
+            .iter()
+            .find_map(|tag| lookup_language(&languages, tag))
+        {
+            return self
+                .build(&languages, code, LanguageSource::AcceptLanguage)
+                .await;
+        }
+
+        let default_code = default_language(&languages)?;
+        self.build(&languages, default_code, LanguageSource::Default)
+            .await
+    }
+
+    /// Ngôn ngữ của bản dịch khớp slug trong URL (thay cho kết quả negotiate khi không có `?lang=`)
+    pub async fn for_translated_slug(&self, code: &str) -> Result<ContentLanguage, AppError> {
+        let languages = self.language_repo.list_all().await?;
+        let code = find_language(&languages, code).ok_or_else(|| {
+            AppError::new(
+                ErrorKind::Invalid,
+                "LANGUAGE_INVALID",
+                format!("Invalid language: {code}"),
+            )
+            .with("code", code)
+        })?;
+        self.build(&languages, code, LanguageSource::TranslatedSlug)
+            .await
+    }
+
+    /// Ngôn ngữ ghi trong path: không khớp ngôn ngữ nào => không tìm thấy
+    pub async fn for_path(&self, code: &str) -> Result<ContentLanguage, AppError> {
+        let languages = self.language_repo.list_all().await?;
+        let code = find_language(&languages, code).ok_or_else(|| {
+            AppError::new(
+                ErrorKind::NotFound,
+                "LANGUAGE_NOT_FOUND",
+                format!("Language {code} not found"),
+            )
+            .with("code", code)
+        })?;
+        self.build(&languages, code, LanguageSource::Path).await
+    }
+
+    /// Dựng chuỗi dự phòng cho 1 mã ngôn ngữ đã hợp lệ
+    async fn build(
+        &self,
+        languages: &[Language],
+        code: String,
+        source: LanguageSource,
+    ) -> Result<ContentLanguage, AppError> {
+        let default_code = default_language(languages)?;
+        let mut chain = vec![code.clone()];
+        if code != default_code {
+            let configured = self.fallbacks().await?;
+            for candidate in configured
+                .get(&code)
+                .into_iter()
+                .flatten()
+                .cloned()
+                .chain(primary_subtag(&code).map(str::to_string))
+            {
+                if let Some(candidate) = find_language(languages, &candidate)
+                    && !chain.contains(&candidate)
+                    && candidate != default_code
+                {
+                    chain.push(candidate);
+                }
+            }
+            chain.push(default_code.clone());
+        }
+        Ok(ContentLanguage {
+            code,
+            source,
+            chain,
+            default_code,
+        })
+    }
+
+    async fn fallbacks(&self) -> Result<HashMap<String, Vec<String>>, AppError> {
+        Ok(self
+            .setting_repo
+            .get_by_key(LANGUAGE_FALLBACKS_KEY)
+            .await?
+            .and_then(|s| s.value)
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_default())
+    }
+}
+
+/// Tag trong `Accept-Language` theo thứ tự ưu tiên (q giảm dần, bỏ `*`, `q=0` và q không hợp lệ)
+pub fn parse_accept_language(header: &str) -> Vec<String> {
+    let mut tags: Vec<(String, f32)> = header
+        .split(',')
+        .filter_map(|part| {
+            let mut pieces = part.split(';');
+            let tag = pieces.next()?.trim();
+            if tag.is_empty() || tag == "*" {
+                return None;
+            }
+            let q = match pieces.find_map(|p| p.trim().strip_prefix("q=")) {
+                Some(q) => parse_quality(q.trim())?,
+                None => 1.0,
+            };
+            (q > 0.0).then(|| (tag.to_string(), q))
+        })
+        .collect();
+    // sort ổn định: cùng q thì giữ thứ tự trong header
+    tags.sort_by(|a, b| b.1.total_cmp(&a.1));
+    tags.into_iter().map(|(tag, _)| tag).collect()
+}
+
+/// `qvalue` theo RFC 9110: `0`..`1`, tối đa 3 chữ số thập phân
+fn parse_quality(q: &str) -> Option<f32> {
+    let (int, frac) = q.split_once('.').unwrap_or((q, ""));
+    let valid = matches!(int, "0" | "1")
+        && frac.len() <= 3
+        && frac.bytes().all(|b| b.is_ascii_digit())
+        && (int == "0" || frac.bytes().all(|b| b == b'0'));
+    valid.then(|| q.parse().ok()).flatten()
+}
+
+/// So khớp kiểu "lookup" (RFC 4647): `en-US` => `en-US`, rồi `en`
+fn lookup_language(languages: &[Language], tag: &str) -> Option<String> {
+    let mut tag = tag;
+    loop {
+        if let Some(code) = find_language(languages, tag) {
+            return Some(code);
+        }
+        tag = primary_subtag(tag)?;
+    }
+}
+
+/// Bỏ subtag cuối: `zh-Hant-TW` => `zh-Hant`
+fn primary_subtag(tag: &str) -> Option<&str> {
+    tag.rsplit_once('-').map(|(head, _)| head)
+}
+
+/// Mã ngôn ngữ đúng như trong DB (so khớp không phân biệt hoa thường)
+fn find_language(languages: &[Language], code: &str) -> Option<String> {
+    languages
+        .iter()
+        .find(|l| l.code.eq_ignore_ascii_case(code))
+        .map(|l| l.code.clone())
+}
+
+fn default_language(languages: &[Language]) -> Result<String, AppError> {
+    languages
+        .iter()
+        .find(|l| l.is_default == Some(true))
+        .map(|l| l.code.clone())
+        .ok_or_else(|| AppError::not_found("DEFAULT_LANGUAGE_NOT_FOUND", "Default language"))
+}
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::application::common::resolve_slug::save_with_slug;
+use crate::application::translation::translation_language::{
+    optional_text, required_text, resolve_translation_language,
+};
+use crate::domain::entities::post::Post;
+use crate::domain::entities::post_translation::{NewPostTranslation, PostTranslation};
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::domain::repositories::post_repository::PostRepository;
+use crate::domain::repositories::post_translation_repository::PostTranslationRepository;
+use crate::domain::repositories::slug_repository::{SlugRepository, SlugScope};
+use crate::shared::error::AppError;
+
+/// Ghi đè toàn bộ bản dịch của 1 ngôn ngữ
+#[derive(Debug, Clone, Deserialize)]
+pub struct PostTranslationInput {
+    pub title: String,
+    /// Bỏ trống => sinh từ `title`, trùng thì thêm hậu tố số
+    pub slug: Option<String>,
+    pub excerpt: Option<String>,
+    /// Cùng định dạng `content_format` với bài gốc
+    pub content: Option<String>,
+}
+
+/// CRUD bản dịch bài viết: `/posts/:slug/translations[/:lang]`
+pub struct PostTranslationsUseCase {
+    post_repo: Arc<dyn PostRepository>,
+    translation_repo: Arc<dyn PostTranslationRepository>,
+    language_repo: Arc<dyn LanguageRepository>,
+    slug_repo: Arc<dyn SlugRepository>,
+}
+
+impl PostTranslationsUseCase {
+    pub fn new(
+        post_repo: Arc<dyn PostRepository>,
+        translation_repo: Arc<dyn PostTranslationRepository>,
+        language_repo: Arc<dyn LanguageRepository>,
+        slug_repo: Arc<dyn SlugRepository>,
+    ) -> Self {
+        Self {
+            post_repo,
+            translation_repo,
+            language_repo,
+            slug_repo,
+        }
+    }
+
+    pub async fn list(&self, slug: &str) -> Result<Vec<PostTranslation>, AppError> {
+        let post = self.find_post(slug).await?;
+        Ok(self.translation_repo.list_by_post(post.id).await?)
+    }
+
+    pub async fn get(&self, slug: &str, lang: &str) -> Result<PostTranslation, AppError> {
+        let post = self.find_post(slug).await?;
+        self.translation_repo
+            .find(post.id, lang.trim())
+            .await?
+            .ok_or_else(|| AppError::not_found("POST_TRANSLATION_NOT_FOUND", "Post translation"))
+    }
+
+    pub async fn upsert(
+        &self,
+        slug: &str,
+        lang: &str,
+        input: PostTranslationInput,
+    ) -> Result<PostTranslation, AppError> {
+        let post = self.find_post(slug).await?;
+        self.upsert_by_post_id(post.id, lang, input).await
+    }
+
+    /// Bản dịch theo id bài gốc (import: bài chưa có slug)
+    pub async fn find_by_post_id(
+        &self,
+        post_id: Uuid,
+        lang: &str,
+    ) -> Result<Option<PostTranslation>, AppError> {
+        Ok(self.translation_repo.find(post_id, lang.trim()).await?)
+    }
+
+    /// Như `upsert` nhưng xác định bài gốc theo id
+    pub async fn upsert_by_post_id(
+        &self,
+        post_id: Uuid,
+        lang: &str,
+        input: PostTranslationInput,
+    ) -> Result<PostTranslation, AppError> {
+        let language_code = resolve_translation_language(self.language_repo.as_ref(), lang).await?;
+        let title = required_text(&input.title, "title")?;
+        let translation = NewPostTranslation {
+            post_id,
+            language_code,
+            title,
+            slug: None,
+            excerpt: optional_text(input.excerpt),
+            content: optional_text(input.content),
+        };
+
+        let (repo, translation) = (&self.translation_repo, &translation);
+        save_with_slug(
+            self.slug_repo.as_ref(),
+            SlugScope::PostTranslation {
+                language_code: &translation.language_code,
+            },
+            input.slug.as_deref(),
+            &translation.title,
+            Some(post_id),
+            move |slug| {
+                repo.upsert(NewPostTranslation {
+                    slug: Some(slug),
+                    ..translation.clone()
+                })
+            },
+        )
+        .await
+    }
+
+    pub async fn delete(&self, slug: &str, lang: &str) -> Result<(), AppError> {
+        let post = self.find_post(slug).await?;
+        self.translation_repo.delete(post.id, lang.trim()).await
+    }
+
+    async fn find_post(&self, slug: &str) -> Result<Post, AppError> {
+        self.post_repo
+            .find_by_slug(slug)
+            .await?
+            .ok_or_else(|| AppError::not_found("POST_NOT_FOUND", "Post"))
+    }
+}
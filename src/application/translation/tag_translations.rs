@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use crate::application::translation::category_translations::NameTranslationInput;
+use crate::application::translation::translation_language::{
+    optional_text, required_text, resolve_translation_language,
+};
+use crate::domain::entities::tag::Tag;
+use crate::domain::entities::tag_translation::{NewTagTranslation, TagTranslation};
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::domain::repositories::tag_repository::TagRepository;
+use crate::domain::repositories::tag_translation_repository::TagTranslationRepository;
+use crate::shared::error::AppError;
+
+/// CRUD bản dịch tag: `/tags/:slug/translations[/:lang]?type=`
+pub struct TagTranslationsUseCase {
+    tag_repo: Arc<dyn TagRepository>,
+    translation_repo: Arc<dyn TagTranslationRepository>,
+    language_repo: Arc<dyn LanguageRepository>,
+}
+
+impl TagTranslationsUseCase {
+    pub fn new(
+        tag_repo: Arc<dyn TagRepository>,
+        translation_repo: Arc<dyn TagTranslationRepository>,
+        language_repo: Arc<dyn LanguageRepository>,
+    ) -> Self {
+        Self {
+            tag_repo,
+            translation_repo,
+            language_repo,
+        }
+    }
+
+    pub async fn list(&self, slug: &str, r#type: &str) -> Result<Vec<TagTranslation>, AppError> {
+        let tag = self.find_tag(slug, r#type).await?;
+        Ok(self.translation_repo.list_by_tag(tag.id).await?)
+    }
+
+    pub async fn get(
+        &self,
+        slug: &str,
+        r#type: &str,
+        lang: &str,
+    ) -> Result<TagTranslation, AppError> {
+        let tag = self.find_tag(slug, r#type).await?;
+        self.translation_repo
+            .find(tag.id, lang.trim())
+            .await?
+            .ok_or_else(|| AppError::not_found("TAG_TRANSLATION_NOT_FOUND", "Tag translation"))
+    }
+
+    pub async fn upsert(
+        &self,
+        slug: &str,
+        r#type: &str,
+        lang: &str,
+        input: NameTranslationInput,
+    ) -> Result<TagTranslation, AppError> {
+        let tag = self.find_tag(slug, r#type).await?;
+        let language_code = resolve_translation_language(self.language_repo.as_ref(), lang).await?;
+
+        Ok(self
+            .translation_repo
+            .upsert(NewTagTranslation {
+                tag_id: tag.id,
+                language_code,
+                name: required_text(&input.name, "name")?,
+                description: optional_text(input.description),
+            })
+            .await?)
+    }
+
+    pub async fn delete(&self, slug: &str, r#type: &str, lang: &str) -> Result<(), AppError> {
+        let tag = self.find_tag(slug, r#type).await?;
+        self.translation_repo.delete(tag.id, lang.trim()).await
+    }
+
+    async fn find_tag(&self, slug: &str, r#type: &str) -> Result<Tag, AppError> {
+        self.tag_repo
+            .find_by_slug_and_type(slug, r#type)
+            .await?
+            .ok_or_else(|| AppError::not_found("TAG_NOT_FOUND", "Tag"))
+    }
+}
@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::application::translation::translation_language::resolve_translation_language;
+use crate::domain::entities::translation_status::{
+    TRANSLATABLE_ENTITY_TYPES, TranslationCoverageRow,
+};
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::domain::repositories::translation_status_repository::TranslationStatusRepository;
+use crate::shared::error::AppError;
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CoverageSummary {
+    pub total: i64,
+    pub up_to_date: i64,
+    pub outdated: i64,
+    pub missing: i64,
+    /// % bản ghi có bản dịch còn mới (không có bản ghi nào => 100)
+    pub coverage_percent: f64,
+}
+
+impl CoverageSummary {
+    fn from_rows<'a>(rows: impl Iterator<Item = &'a TranslationCoverageRow>) -> Self {
+        let mut summary = Self::default();
+        for row in rows {
+            summary.total += row.total;
+            summary.up_to_date += row.up_to_date;
+            summary.outdated += row.outdated;
+            summary.missing += row.missing;
+        }
+        summary.coverage_percent = if summary.total == 0 {
+            100.0
+        } else {
+            (summary.up_to_date as f64 * 1000.0 / summary.total as f64).round() / 10.0
+        };
+        summary
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EntityCoverage {
+    pub entity_type: &'static str,
+    #[serde(flatten)]
+    pub summary: CoverageSummary,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LanguageCoverage {
+    pub language_code: String,
+    pub name: String,
+    #[serde(flatten)]
+    pub summary: CoverageSummary,
+    pub entities: Vec<EntityCoverage>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TranslationCoverageReport {
+    pub default_language: String,
+    pub languages: Vec<LanguageCoverage>,
+}
+
+/// Độ phủ bản dịch theo ngôn ngữ và loại nội dung (post/category/tag/banner)
+pub struct TranslationCoverageUseCase {
+    language_repo: Arc<dyn LanguageRepository>,
+    status_repo: Arc<dyn TranslationStatusRepository>,
+}
+
+impl TranslationCoverageUseCase {
+    pub fn new(
+        language_repo: Arc<dyn LanguageRepository>,
+        status_repo: Arc<dyn TranslationStatusRepository>,
+    ) -> Self {
+        Self {
+            language_repo,
+            status_repo,
+        }
+    }
+
+    /// `lang` bỏ trống => mọi ngôn ngữ khác mặc định
+    pub async fn execute(&self, lang: Option<&str>) -> Result<TranslationCoverageReport, AppError> {
+        let lang = match lang.map(str::trim).filter(|l| !l.is_empty()) {
+            Some(lang) => {
+                Some(resolve_translation_language(self.language_repo.as_ref(), lang).await?)
+            }
+            None => None,
+        };
+        let rows = self.status_repo.coverage(lang.as_deref()).await?;
+
+        let languages = self.language_repo.list_all().await?;
+        let default_language = languages
+            .iter()
+            .find(|l| l.is_default == Some(true))
+            .map(|l| l.code.clone())
+            .ok_or_else(|| AppError::not_found("DEFAULT_LANGUAGE_NOT_FOUND", "Default language"))?;
+
+        // Mọi loại nội dung đều có mặt, kể cả khi chưa có bản ghi nào
+        let languages = languages
+            .into_iter()
+            .filter(|l| l.code != default_language)
+            .filter(|l| lang.as_ref().is_none_or(|code| &l.code == code))
+            .map(|l| {
+                let rows: Vec<&TranslationCoverageRow> =
+                    rows.iter().filter(|r| r.language_code == l.code).collect();
+                let entities = TRANSLATABLE_ENTITY_TYPES
+                    .iter()
+                    .map(|entity_type| EntityCoverage {
+                        entity_type,
+                        summary: CoverageSummary::from_rows(
+                            rows.iter()
+                                .copied()
+                                .filter(|r| r.entity_type == *entity_type),
+                        ),
+                    })
+                    .collect();
+                LanguageCoverage {
+                    language_code: l.code,
+                    name: l.name,
+                    summary: CoverageSummary::from_rows(rows.into_iter()),
+                    entities,
+                }
+            })
+            .collect();
+
+        Ok(TranslationCoverageReport {
+            default_language,
+            languages,
+        })
+    }
+}
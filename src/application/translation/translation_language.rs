@@ -0,0 +1,49 @@
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::shared::error::{AppError, ErrorKind};
+
+/// Ngôn ngữ đích của bản dịch: phải tồn tại và khác ngôn ngữ mặc định
+/// (nội dung ngôn ngữ mặc định nằm ở chính bản ghi gốc)
+pub async fn resolve_translation_language(
+    language_repo: &dyn LanguageRepository,
+    lang: &str,
+) -> Result<String, AppError> {
+    let code = lang.trim();
+    let language = language_repo.find_by_code(code).await?.ok_or_else(|| {
+        AppError::new(
+            ErrorKind::Invalid,
+            "LANGUAGE_INVALID",
+            format!("Invalid language: {code}"),
+        )
+        .with("code", code)
+    })?;
+    if language.is_default == Some(true) {
+        return Err(AppError::new(
+            ErrorKind::Invalid,
+            "LANGUAGE_IS_DEFAULT",
+            format!("Invalid language: {code} is the default language, update the source instead"),
+        )
+        .with("code", code));
+    }
+    Ok(language.code)
+}
+
+/// Bắt buộc có nội dung (sau khi trim)
+pub fn required_text(value: &str, field: &str) -> Result<String, AppError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(AppError::new(
+            ErrorKind::Invalid,
+            "FIELD_REQUIRED",
+            format!("Invalid {field}: must not be empty"),
+        )
+        .with("field", field));
+    }
+    Ok(value.to_string())
+}
+
+/// Chuỗi rỗng => `None`
+pub fn optional_text(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use crate::application::common::list_params::{ListParams, PaginatedResult};
+use crate::application::translation::translation_language::resolve_translation_language;
+use crate::domain::entities::translation_status::{
+    TRANSLATABLE_ENTITY_TYPES, TranslationQueueItem,
+};
+use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::domain::repositories::translation_status_repository::{
+    TranslationQueueFilter, TranslationStatusRepository,
+};
+use crate::shared::error::AppError;
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct TranslationQueueQuery {
+    pub lang: Option<String>,
+    /// `post` | `category` | `tag` | `banner`
+    pub entity_type: Option<String>,
+    /// `missing` | `outdated`
+    pub status: Option<String>,
+}
+
+/// Hàng đợi cần dịch của 1 ngôn ngữ: bản ghi chưa có bản dịch hoặc bản gốc đã đổi
+/// sau lần cập nhật bản dịch cuối (tự chuyển sang `outdated`, lưu bản dịch => rời hàng đợi)
+pub struct TranslationQueueUseCase {
+    language_repo: Arc<dyn LanguageRepository>,
+    status_repo: Arc<dyn TranslationStatusRepository>,
+}
+
+impl TranslationQueueUseCase {
+    pub fn new(
+        language_repo: Arc<dyn LanguageRepository>,
+        status_repo: Arc<dyn TranslationStatusRepository>,
+    ) -> Self {
+        Self {
+            language_repo,
+            status_repo,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        params: &ListParams,
+        query: TranslationQueueQuery,
+    ) -> Result<PaginatedResult<TranslationQueueItem>, AppError> {
+        let lang = query
+            .lang
+            .as_deref()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .ok_or_else(|| AppError::invalid("lang", "required"))?;
+        let language_code = resolve_translation_language(self.language_repo.as_ref(), lang).await?;
+
+        let entity_type = parse_entity_type(query.entity_type)?;
+        let status = non_empty(query.status);
+        if let Some(status) = &status
+            && status != "missing"
+            && status != "outdated"
+        {
+            return Err(AppError::invalid(
+                "status",
+                format!("{status} (expected missing or outdated)"),
+            ));
+        }
+
+        let filter = TranslationQueueFilter {
+            language_code,
+            entity_type,
+            status,
+            search: params.search.clone(),
+        };
+        let limit = params.limit.unwrap_or(20).clamp(1, 100);
+        self.status_repo
+            .search_queue(
+                &filter,
+                params.sort_by.clone(),
+                params.cursor.clone(),
+                limit,
+            )
+            .await
+    }
+}
+
+/// `entity_type` lọc theo loại nội dung (không phân biệt hoa thường), bỏ trống => tất cả
+pub fn parse_entity_type(value: Option<String>) -> Result<Option<String>, AppError> {
+    let Some(entity_type) = non_empty(value) else {
+        return Ok(None);
+    };
+    if !TRANSLATABLE_ENTITY_TYPES.contains(&entity_type.as_str()) {
+        return Err(AppError::invalid(
+            "entity_type",
+            format!("{entity_type} (expected post, category, tag or banner)"),
+        ));
+    }
+    Ok(Some(entity_type))
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_ascii_lowercase())
+        .filter(|v| !v.is_empty())
+}
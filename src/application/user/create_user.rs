@@ -2,6 +2,7 @@ use crate::domain::{
     entities::user::{NewUser, User},
     repositories::user_repository::UserRepository,
 };
+use crate::shared::error::AppError;
 
 pub struct CreateUserUseCase<R: UserRepository> {
     repo: R,
@@ -12,8 +13,8 @@ impl<R: UserRepository> CreateUserUseCase<R> {
         Self { repo }
     }
 
-    pub async fn execute(&self, mut user: NewUser) -> Result<User, String> {
+    pub async fn execute(&self, mut user: NewUser) -> Result<User, AppError> {
         user.password = crate::shared::utils::hash::hash_password(&user.password)?;
-        self.repo.create(user).await
+        Ok(self.repo.create(user).await?)
     }
 }
@@ -1,4 +1,5 @@
 use crate::domain::repositories::user_repository::UserRepository;
+use crate::shared::error::AppError;
 
 pub struct DeleteUserUseCase<R: UserRepository> {
     repo: R,
@@ -11,7 +12,7 @@ impl<R: UserRepository> DeleteUserUseCase<R> {
         Self { repo }
     }
 
-    pub async fn execute(&self, id: Uuid) -> Result<(), String> {
+    pub async fn execute(&self, id: Uuid) -> Result<(), AppError> {
         self.repo.delete(id).await
     }
 }
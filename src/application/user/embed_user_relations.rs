@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::entities::role::Role;
+use crate::domain::entities::user::User;
+use crate::domain::entities::user_profile::UserProfile;
+use crate::domain::repositories::role_repository::RoleRepository;
+use crate::domain::repositories::user_profile_repository::UserProfileRepository;
+use crate::shared::error::AppError;
+
+/// Các quan hệ có thể include cho user
+pub const USER_INCLUDES: &[&str] = &["roles", "profile"];
+
+/// User kèm các quan hệ đã include
+#[derive(Debug, Clone, Serialize)]
+pub struct UserView {
+    #[serde(flatten)]
+    pub user: User,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Vec<Role>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<Option<UserProfile>>,
+}
+
+pub struct EmbedUserRelationsUseCase {
+    role_repo: Arc<dyn RoleRepository>,
+    profile_repo: Arc<dyn UserProfileRepository>,
+}
+
+impl EmbedUserRelationsUseCase {
+    pub fn new(
+        role_repo: Arc<dyn RoleRepository>,
+        profile_repo: Arc<dyn UserProfileRepository>,
+    ) -> Self {
+        Self {
+            role_repo,
+            profile_repo,
+        }
+    }
+
+    /// Nhúng role/profile cho danh sách user, mỗi quan hệ chỉ tốn một query
+    pub async fn execute(
+        &self,
+        users: Vec<User>,
+        includes: &[String],
+    ) -> Result<Vec<UserView>, AppError> {
+        let has = |name: &str| includes.iter().any(|i| i == name);
+        let ids: Vec<Uuid> = users.iter().map(|u| u.id).collect();
+
+        let roles = if has("roles") {
+            let mut grouped: HashMap<Uuid, Vec<Role>> = HashMap::new();
+            for (user_id, role) in self.role_repo.find_by_user_ids(&ids).await? {
+                grouped.entry(user_id).or_default().push(role);
+            }
+            Some(grouped)
+        } else {
+            None
+        };
+
+        let profiles = if has("profile") {
+            let profiles = self.profile_repo.find_by_user_ids(&ids).await?;
+            Some(
+                profiles
+                    .into_iter()
+                    .map(|p| (p.user_id, p))
+                    .collect::<HashMap<_, _>>(),
+            )
+        } else {
+            None
+        };
+
+        Ok(users
+            .into_iter()
+            .map(|user| UserView {
+                roles: roles
+                    .as_ref()
+                    .map(|m| m.get(&user.id).cloned().unwrap_or_default()),
+                profile: profiles.as_ref().map(|m| m.get(&user.id).cloned()),
+                user,
+            })
+            .collect())
+    }
+}
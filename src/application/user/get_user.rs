@@ -1,4 +1,5 @@
 use crate::domain::{entities::user::User, repositories::user_repository::UserRepository};
+use crate::shared::error::AppError;
 
 pub struct GetUserUseCase<R: UserRepository> {
     repo: R,
@@ -11,7 +12,7 @@ impl<R: UserRepository> GetUserUseCase<R> {
         Self { repo }
     }
 
-    pub async fn execute(&self, id: Uuid) -> Result<Option<User>, String> {
-        self.repo.find_by_id(id).await
+    pub async fn execute(&self, id: Uuid) -> Result<Option<User>, AppError> {
+        Ok(self.repo.find_by_id(id).await?)
     }
 }
@@ -1,6 +1,7 @@
 use crate::application::common::list_params::{ListParams, PaginatedResult};
 use crate::domain::entities::user::User;
 use crate::domain::repositories::user_repository::{UserRepository, UserSearchFilter};
+use crate::shared::error::AppError;
 
 pub struct GetUsersUseCase<R: UserRepository> {
     repo: R,
@@ -11,7 +12,7 @@ impl<R: UserRepository> GetUsersUseCase<R> {
         Self { repo }
     }
 
-    pub async fn execute(&self, params: &ListParams) -> Result<PaginatedResult<User>, String> {
+    pub async fn execute(&self, params: &ListParams) -> Result<PaginatedResult<User>, AppError> {
         let filter = UserSearchFilter {
             search: params.search.clone(),
         };
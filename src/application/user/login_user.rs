@@ -2,6 +2,7 @@ use serde::Deserialize;
 
 use crate::{
     domain::repositories::user_repository::UserRepository,
+    shared::error::{AppError, ErrorKind},
     shared::utils::{hash::verify_password, jwt::create_jwt},
 };
 
@@ -31,17 +32,21 @@ impl<R: UserRepository> LoginUseCase<R> {
         Self { repo }
     }
 
-    pub async fn execute(&self, req: LoginRequest) -> Result<LoginOutput, String> {
+    pub async fn execute(&self, req: LoginRequest) -> Result<LoginOutput, AppError> {
         let user = self
             .repo
             .find_by_email(&req.email)
             .await?
-            .ok_or_else(|| "User not found".to_string())?;
+            .ok_or_else(|| AppError::not_found("USER_NOT_FOUND", "User"))?;
 
         let is_valid = verify_password(&req.password, &user.password)?;
 
         if !is_valid {
-            return Err("Invalid credentials".to_string());
+            return Err(AppError::new(
+                ErrorKind::Unauthorized,
+                "AUTH_INVALID_CREDENTIALS",
+                "Invalid credentials",
+            ));
         }
 
         let secret = std::env::var("JWT_SECRET").expect("JWT_SECRET must be set");
@@ -1,5 +1,6 @@
 pub mod create_user;
 pub mod delete_user;
+pub mod embed_user_relations;
 pub mod get_user;
 pub mod get_users;
 pub mod login_user;
@@ -2,6 +2,7 @@ use crate::domain::{
     entities::user::{UpdateUser, User},
     repositories::user_repository::UserRepository,
 };
+use crate::shared::error::AppError;
 
 pub struct UpdateUserUseCase<R: UserRepository> {
     repo: R,
@@ -14,7 +15,7 @@ impl<R: UserRepository> UpdateUserUseCase<R> {
         Self { repo }
     }
 
-    pub async fn execute(&self, id: Uuid, user: UpdateUser) -> Result<User, String> {
-        self.repo.update(id, user).await
+    pub async fn execute(&self, id: Uuid, user: UpdateUser) -> Result<User, AppError> {
+        Ok(self.repo.update(id, user).await?)
     }
 }
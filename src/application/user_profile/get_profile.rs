@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::domain::entities::user_profile::UserProfile;
 use crate::domain::repositories::user_profile_repository::UserProfileRepository;
+use crate::shared::error::AppError;
 use uuid::Uuid;
 
 pub struct GetProfileUseCase {
@@ -13,7 +14,7 @@ impl GetProfileUseCase {
         Self { repo }
     }
 
-    pub async fn execute(&self, user_id: Uuid) -> Result<Option<UserProfile>, String> {
-        self.repo.find_by_user_id(user_id).await
+    pub async fn execute(&self, user_id: Uuid) -> Result<Option<UserProfile>, AppError> {
+        Ok(self.repo.find_by_user_id(user_id).await?)
     }
 }
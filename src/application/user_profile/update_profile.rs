@@ -1,23 +1,50 @@
 use std::sync::Arc;
 
 use crate::domain::entities::user_profile::{UpdateUserProfile, UserProfile};
+use crate::domain::repositories::language_repository::LanguageRepository;
 use crate::domain::repositories::user_profile_repository::UserProfileRepository;
+use crate::shared::error::AppError;
 use uuid::Uuid;
 
 pub struct UpdateProfileUseCase {
     repo: Arc<dyn UserProfileRepository>,
+    language_repo: Arc<dyn LanguageRepository>,
 }
 
 impl UpdateProfileUseCase {
-    pub fn new(repo: Arc<dyn UserProfileRepository>) -> Self {
-        Self { repo }
+    pub fn new(
+        repo: Arc<dyn UserProfileRepository>,
+        language_repo: Arc<dyn LanguageRepository>,
+    ) -> Self {
+        Self {
+            repo,
+            language_repo,
+        }
     }
 
     pub async fn execute(
         &self,
         user_id: Uuid,
-        data: UpdateUserProfile,
-    ) -> Result<UserProfile, String> {
-        self.repo.upsert(user_id, data).await
+        mut data: UpdateUserProfile,
+    ) -> Result<UserProfile, AppError> {
+        // Lưu đúng mã trong `languages` (client có thể gửi `EN`, `en`...); `""` => bỏ ngôn ngữ ưu tiên
+        if let Some(code) = data
+            .preferred_language
+            .as_deref()
+            .map(str::trim)
+            .filter(|c| !c.is_empty())
+        {
+            let language = self
+                .language_repo
+                .list_all()
+                .await?
+                .into_iter()
+                .find(|l| l.code.eq_ignore_ascii_case(code))
+                .ok_or_else(|| AppError::invalid("preferred_language", code.to_string()))?;
+            data.preferred_language = Some(language.code);
+        } else if data.preferred_language.is_some() {
+            data.preferred_language = Some(String::new());
+        }
+        Ok(self.repo.upsert(user_id, data).await?)
     }
 }
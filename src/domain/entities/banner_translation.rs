@@ -0,0 +1,25 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Bản dịch banner theo 1 ngôn ngữ (`banner_translations`)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct BannerTranslation {
+    pub id: Uuid,
+    pub banner_id: Uuid,
+    pub language_code: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewBannerTranslation {
+    pub banner_id: Uuid,
+    pub language_code: String,
+    pub title: String,
+    pub description: Option<String>,
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Phiên bản schema hiện tại của `posts.content_blocks`
+pub const CONTENT_BLOCKS_VERSION: u32 = 1;
+
+/// Tài liệu nội dung dạng block, lưu nguyên trong cột JSONB `content_blocks`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ContentBlocks {
+    pub version: u32,
+    pub blocks: Vec<ContentBlock>,
+}
+
+/// 1 block nội dung, phân biệt theo field `type`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentBlock {
+    /// `text` hỗ trợ markdown inline (đậm, nghiêng, link, code)
+    Paragraph {
+        text: String,
+    },
+    Heading {
+        level: u8,
+        text: String,
+    },
+    Image {
+        media_id: Uuid,
+        /// Server điền từ `media.file_path` khi lưu, client gửi lên sẽ bị ghi đè
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        src: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        alt: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        caption: Option<String>,
+    },
+    Quote {
+        text: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cite: Option<String>,
+    },
+    Code {
+        code: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        language: Option<String>,
+    },
+    /// Nhúng nội dung ngoài (video, tweet, ...) theo URL http(s)
+    Embed {
+        url: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        caption: Option<String>,
+    },
+    /// Tham chiếu banner theo `key`, frontend tự tải banner item
+    Banner {
+        key: String,
+    },
+}
@@ -1,13 +1,26 @@
 pub mod audit_log;
 pub mod banner;
 pub mod banner_item;
+pub mod banner_translation;
 pub mod category;
+pub mod category_translation;
+pub mod content_block;
 pub mod language;
 pub mod media;
 pub mod permission;
 pub mod post;
+pub mod post_media;
+pub mod post_review;
+pub mod post_revision;
+pub mod post_translation;
+pub mod redirect;
 pub mod role;
+pub mod seo_metadata;
 pub mod setting;
+pub mod sitemap;
 pub mod tag;
+pub mod tag_translation;
+pub mod translation_key;
+pub mod translation_status;
 pub mod user;
 pub mod user_profile;
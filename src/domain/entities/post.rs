@@ -3,26 +3,160 @@ use serde::{Deserialize, Serialize};
 use sqlx::{FromRow, Type};
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize, Type)]
-#[sqlx(type_name = "post_status", rename_all = "lowercase")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "post_status", rename_all = "snake_case")]
 pub enum PostStatus {
     Draft,
+    /// Đã gửi, chờ reviewer duyệt
+    InReview,
+    /// Reviewer yêu cầu chỉnh sửa
+    ChangesRequested,
+    /// Chờ tới `published_at` để worker tự publish
+    Scheduled,
     Published,
     Archived,
 }
 
+impl PostStatus {
+    /// Nhãn enum trong DB (`post_status`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PostStatus::Draft => "draft",
+            PostStatus::InReview => "in_review",
+            PostStatus::ChangesRequested => "changes_requested",
+            PostStatus::Scheduled => "scheduled",
+            PostStatus::Published => "published",
+            PostStatus::Archived => "archived",
+        }
+    }
+
+    /// Parse từ query string, không phân biệt hoa thường (`published`, `Published`)
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "draft" => Some(PostStatus::Draft),
+            "in_review" => Some(PostStatus::InReview),
+            "changes_requested" => Some(PostStatus::ChangesRequested),
+            "scheduled" => Some(PostStatus::Scheduled),
+            "published" => Some(PostStatus::Published),
+            "archived" => Some(PostStatus::Archived),
+            _ => None,
+        }
+    }
+
+    /// Trạng thái công khai (hoặc chờ công khai): chỉ người có `post:publish` được chuyển tới
+    pub fn is_publication(&self) -> bool {
+        matches!(self, PostStatus::Published | PostStatus::Scheduled)
+    }
+
+    /// Quy tắc chuyển trạng thái của quy trình biên tập
+    /// (`from = None`: bài mới tạo)
+    pub fn can_transition(from: Option<&PostStatus>, to: &PostStatus) -> bool {
+        use PostStatus::*;
+
+        let Some(from) = from else {
+            return matches!(to, Draft | InReview | Scheduled | Published);
+        };
+        if from == to {
+            return true;
+        }
+        match from {
+            Draft => matches!(to, InReview | Scheduled | Published | Archived),
+            InReview => matches!(to, Draft | ChangesRequested | Scheduled | Published),
+            ChangesRequested => matches!(to, Draft | InReview),
+            Scheduled => matches!(to, Draft | Published | Archived),
+            Published => matches!(to, Draft | Archived),
+            Archived => matches!(to, Draft | Published),
+        }
+    }
+}
+
+/// Định dạng của `Post.content`, quyết định cách render sang HTML
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "post_content_format", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ContentFormat {
+    Markdown,
+    #[default]
+    Html,
+    Plain,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Post {
     pub id: Uuid,
     pub author_id: Uuid,
     pub category_id: Option<Uuid>,
+    /// Ảnh đại diện của bài viết (media)
+    pub featured_media_id: Option<Uuid>,
     pub title: String,
     pub slug: Option<String>,
     pub excerpt: Option<String>,
     pub content: Option<String>,
+    pub content_format: ContentFormat,
+    /// Nội dung dạng block (`{version, blocks}`), ưu tiên hơn `content` khi render
+    pub content_blocks: Option<serde_json::Value>,
+    /// HTML đã sanitize (có anchor cho heading), sinh lại mỗi lần lưu
+    pub content_html: Option<String>,
+    /// Text thuần render từ nội dung, chỉ dùng để index tìm kiếm
+    #[serde(default, skip_serializing)]
+    pub content_text: Option<String>,
+    /// Mục lục `[{level, text, anchor}]`
+    pub toc: Option<serde_json::Value>,
+    pub word_count: i32,
+    pub reading_time_minutes: i32,
     pub status: Option<PostStatus>,
     pub published_at: Option<NaiveDateTime>,
+    /// Thời điểm tự động gỡ bài (chuyển sang `archived`)
+    pub unpublish_at: Option<NaiveDateTime>,
     pub created_at: Option<NaiveDateTime>,
     pub updated_at: Option<NaiveDateTime>,
     pub deleted_at: Option<NaiveDateTime>,
 }
+
+/// 1 lần chuyển trạng thái do worker lên lịch thực hiện
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct PostScheduleTransition {
+    pub post_id: Uuid,
+    pub slug: Option<String>,
+    pub from_status: Option<PostStatus>,
+    pub to_status: Option<PostStatus>,
+    /// Mốc thời gian đã tới hạn (`published_at` hoặc `unpublish_at`)
+    pub due_at: Option<NaiveDateTime>,
+}
+
+/// Kết quả full-text search bài viết (kèm điểm rank và đoạn trích highlight)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PostSearchHit {
+    pub id: Uuid,
+    pub author_id: Uuid,
+    pub category_id: Option<Uuid>,
+    pub title: String,
+    pub slug: Option<String>,
+    pub excerpt: Option<String>,
+    pub status: Option<PostStatus>,
+    pub published_at: Option<NaiveDateTime>,
+    /// Ngôn ngữ của bản khớp tốt nhất (bài gốc dùng ngôn ngữ mặc định)
+    pub language_code: Option<String>,
+    pub rank: f32,
+    /// Đoạn trích có đánh dấu `<mark>...</mark>`
+    pub headline: Option<String>,
+    /// `fulltext` hoặc `trigram` (fallback khi gõ sai chính tả)
+    pub match_type: String,
+}
+
+/// 1 bài trong feed RSS/Atom/JSON Feed (đã áp bản dịch nếu lọc theo ngôn ngữ)
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct PostFeedItem {
+    pub id: Uuid,
+    pub title: String,
+    pub slug: Option<String>,
+    pub excerpt: Option<String>,
+    /// Nội dung thô, chỉ dùng để render khi chưa có `content_html` (bài cũ, bản dịch)
+    pub content: Option<String>,
+    pub content_format: ContentFormat,
+    pub content_html: Option<String>,
+    pub author_name: Option<String>,
+    pub category_name: Option<String>,
+    pub published_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+}
@@ -0,0 +1,25 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// 1 media trong gallery của bài viết
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PostMedia {
+    pub id: Uuid,
+    pub post_id: Uuid,
+    pub media_id: Uuid,
+    /// Thứ tự hiển thị (tăng dần)
+    pub position: i32,
+    pub caption: Option<String>,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+/// Media gán vào gallery, `position` theo thứ tự trong danh sách gửi lên
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewPostMedia {
+    pub media_id: Uuid,
+    pub caption: Option<String>,
+}
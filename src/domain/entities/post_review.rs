@@ -0,0 +1,29 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Reviewer được gán cho bài viết
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PostReviewer {
+    pub id: Uuid,
+    pub post_id: Uuid,
+    pub reviewer_id: Uuid,
+    pub reviewer_name: Option<String>,
+    /// User thực hiện gán
+    pub assigned_by: Option<Uuid>,
+    pub created_at: Option<NaiveDateTime>,
+}
+
+/// Nhận xét review, `decision`: `approved` | `changes_requested` | `None` (chỉ bình luận)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PostReviewComment {
+    pub id: Uuid,
+    pub post_id: Uuid,
+    pub author_id: Uuid,
+    pub decision: Option<String>,
+    pub body: String,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+    pub deleted_at: Option<NaiveDateTime>,
+}
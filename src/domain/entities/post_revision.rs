@@ -0,0 +1,26 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::entities::post::ContentFormat;
+
+/// Snapshot nội dung bài viết tại một lần lưu
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PostRevision {
+    pub id: Uuid,
+    pub post_id: Uuid,
+    pub revision_number: i32,
+    /// User thực hiện lần lưu
+    pub saved_by: Option<Uuid>,
+    pub title: String,
+    pub slug: Option<String>,
+    pub excerpt: Option<String>,
+    pub content: Option<String>,
+    /// NULL với revision tạo trước khi có `content_format`
+    pub content_format: Option<ContentFormat>,
+    pub content_blocks: Option<serde_json::Value>,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+    pub deleted_at: Option<NaiveDateTime>,
+}
@@ -0,0 +1,30 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Bản dịch bài viết theo 1 ngôn ngữ (`post_translations`)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PostTranslation {
+    pub id: Uuid,
+    pub post_id: Uuid,
+    pub language_code: String,
+    pub title: String,
+    /// Slug riêng của bản dịch, unique theo ngôn ngữ
+    pub slug: Option<String>,
+    pub excerpt: Option<String>,
+    pub content: Option<String>,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewPostTranslation {
+    pub post_id: Uuid,
+    pub language_code: String,
+    pub title: String,
+    pub slug: Option<String>,
+    pub excerpt: Option<String>,
+    pub content: Option<String>,
+}
@@ -0,0 +1,20 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Redirect do admin quản lý: `source_path` -> `target` (301/302)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Redirect {
+    pub id: Uuid,
+    pub source_path: String,
+    /// Path nội bộ (`/blog/new`) hoặc URL đầy đủ
+    pub target: String,
+    pub status_code: i32,
+    pub is_active: bool,
+    pub hit_count: i64,
+    pub last_hit_at: Option<NaiveDateTime>,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+    pub deleted_at: Option<NaiveDateTime>,
+}
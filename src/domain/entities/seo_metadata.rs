@@ -0,0 +1,36 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// SEO của 1 post/category theo 1 ngôn ngữ
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct SeoMetadata {
+    pub id: Uuid,
+    /// `post` | `category`
+    pub entity_type: String,
+    pub entity_id: Uuid,
+    pub language_code: String,
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    pub canonical_url: Option<String>,
+    pub robots_index: bool,
+    pub robots_follow: bool,
+    pub og_image_media_id: Option<Uuid>,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewSeoMetadata {
+    pub entity_type: String,
+    pub entity_id: Uuid,
+    pub language_code: String,
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    pub canonical_url: Option<String>,
+    pub robots_index: bool,
+    pub robots_follow: bool,
+    pub og_image_media_id: Option<Uuid>,
+}
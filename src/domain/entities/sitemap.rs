@@ -0,0 +1,23 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use sqlx::FromRow;
+
+/// 1 URL trong sitemap (post/category/tag) kèm các bản dịch để sinh `hreflang`
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct SitemapEntry {
+    pub slug: String,
+    /// Type của category/tag, `None` với post
+    pub r#type: Option<String>,
+    pub updated_at: Option<NaiveDateTime>,
+    /// Mã ngôn ngữ có bản dịch
+    pub translation_languages: Vec<String>,
+    /// Slug theo thứ tự `translation_languages` (bản dịch không có slug riêng => slug gốc)
+    pub translation_slugs: Vec<String>,
+}
+
+/// Tổng số URL và thời điểm cập nhật mới nhất của 1 nhóm sitemap
+#[derive(Debug, Clone, Default, Serialize, FromRow)]
+pub struct SitemapStats {
+    pub total: i64,
+    pub last_modified: Option<NaiveDateTime>,
+}
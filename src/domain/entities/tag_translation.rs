@@ -0,0 +1,25 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Bản dịch tag theo 1 ngôn ngữ (`tag_translations`)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TagTranslation {
+    pub id: Uuid,
+    pub tag_id: Uuid,
+    pub language_code: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewTagTranslation {
+    pub tag_id: Uuid,
+    pub language_code: String,
+    pub name: String,
+    pub description: Option<String>,
+}
@@ -0,0 +1,39 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Chuỗi giao diện (message key) của frontend, unique trong `namespace`
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TranslationKey {
+    pub id: Uuid,
+    /// Nhóm chuỗi theo màn hình/app: `common`, `checkout`...
+    pub namespace: String,
+    /// `cart.items_count`
+    pub key: String,
+    /// Ngữ cảnh cho người dịch
+    pub description: Option<String>,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+/// Nội dung message key theo 1 ngôn ngữ (cú pháp ICU MessageFormat)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TranslationValue {
+    pub id: Uuid,
+    pub key_id: Uuid,
+    pub language_code: String,
+    pub value: String,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+/// 1 dòng của bundle `/i18n/:lang/:namespace`
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct BundleMessage {
+    pub key: String,
+    pub language_code: String,
+    pub value: String,
+}
@@ -0,0 +1,59 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Loại nội dung có bản dịch
+pub const TRANSLATABLE_ENTITY_TYPES: &[&str] = &["post", "category", "tag", "banner"];
+
+/// Trường cần dịch theo loại nội dung, trường đầu tiên là bắt buộc
+pub const TRANSLATABLE_FIELDS: &[(&str, &[&str])] = &[
+    ("post", &["title", "excerpt", "content"]),
+    ("category", &["name", "description"]),
+    ("tag", &["name", "description"]),
+    ("banner", &["title", "description"]),
+];
+
+/// 1 dòng của view `translation_status` (bản ghi gốc × ngôn ngữ)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TranslationQueueItem {
+    pub entity_type: String,
+    /// Id của bản ghi gốc
+    pub id: Uuid,
+    /// Type của category/tag
+    pub r#type: Option<String>,
+    pub slug: Option<String>,
+    /// Tiêu đề/tên ở ngôn ngữ mặc định
+    pub label: String,
+    pub language_code: String,
+    /// `missing` | `outdated` | `up_to_date`
+    pub status: String,
+    pub source_changed_at: Option<NaiveDateTime>,
+    pub translation_updated_at: Option<NaiveDateTime>,
+}
+
+/// Số bản ghi theo trạng thái dịch của 1 loại nội dung ở 1 ngôn ngữ
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranslationCoverageRow {
+    pub entity_type: String,
+    pub language_code: String,
+    pub total: i64,
+    pub up_to_date: i64,
+    pub outdated: i64,
+    pub missing: i64,
+}
+
+/// 1 trường cần dịch của bản ghi gốc (nội dung gốc + bản dịch hiện có), dùng cho export/import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranslationUnit {
+    pub entity_type: String,
+    pub entity_id: Uuid,
+    /// Type của category/tag
+    pub r#type: Option<String>,
+    pub slug: Option<String>,
+    pub field: String,
+    pub source: Option<String>,
+    pub target: Option<String>,
+    /// Trạng thái dịch của cả bản ghi: `missing` | `outdated` | `up_to_date`
+    pub status: String,
+}
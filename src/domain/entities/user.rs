@@ -10,6 +10,17 @@ pub enum UserStatus {
     Deleted,
 }
 
+impl UserStatus {
+    /// Nhãn enum trong DB (`user_status`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserStatus::Active => "active",
+            UserStatus::Inactive => "inactive",
+            UserStatus::Deleted => "deleted",
+        }
+    }
+}
+
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
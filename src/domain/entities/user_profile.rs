@@ -11,6 +11,8 @@ pub struct UserProfile {
     pub phone: Option<String>,
     pub address: Option<String>,
     pub bio: Option<String>,
+    /// Mã ngôn ngữ ưu tiên khi đọc nội dung
+    pub preferred_language: Option<String>,
     pub created_at: Option<NaiveDateTime>,
     pub updated_at: Option<NaiveDateTime>,
     pub deleted_at: Option<NaiveDateTime>,
@@ -22,4 +24,6 @@ pub struct UpdateUserProfile {
     pub phone: Option<String>,
     pub address: Option<String>,
     pub bio: Option<String>,
+    /// `None` => giữ nguyên, `""` => bỏ ngôn ngữ ưu tiên
+    pub preferred_language: Option<String>,
 }
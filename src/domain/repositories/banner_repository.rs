@@ -15,6 +15,11 @@ pub trait BannerRepository: Send + Sync {
     async fn soft_delete_many(&self, ids: &[Uuid]) -> Result<(), String>;
 
     async fn list_items(&self, banner_id: Uuid) -> Result<Vec<BannerItem>, String>;
+    /// Lấy item của nhiều banner trong một query
+    async fn list_items_by_banner_ids(
+        &self,
+        banner_ids: &[Uuid],
+    ) -> Result<Vec<BannerItem>, String>;
     async fn find_item_by_id(&self, id: Uuid) -> Result<Option<BannerItem>, String>;
     async fn create_item(&self, item: BannerItem) -> Result<BannerItem, String>;
     async fn update_item(&self, item: BannerItem) -> Result<BannerItem, String>;
@@ -39,6 +44,13 @@ impl<T: BannerRepository + ?Sized + Send + Sync> BannerRepository for std::sync:
         (**self).list_items(banner_id).await
     }
 
+    async fn list_items_by_banner_ids(
+        &self,
+        banner_ids: &[Uuid],
+    ) -> Result<Vec<BannerItem>, String> {
+        (**self).list_items_by_banner_ids(banner_ids).await
+    }
+
     async fn find_item_by_id(&self, id: Uuid) -> Result<Option<BannerItem>, String> {
         (**self).find_item_by_id(id).await
     }
@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::banner_translation::{BannerTranslation, NewBannerTranslation};
+use crate::shared::error::AppError;
+
+#[async_trait]
+pub trait BannerTranslationRepository: Send + Sync {
+    async fn list_by_banner(&self, banner_id: Uuid) -> Result<Vec<BannerTranslation>, String>;
+    async fn find(
+        &self,
+        banner_id: Uuid,
+        language_code: &str,
+    ) -> Result<Option<BannerTranslation>, String>;
+    /// Bản dịch của nhiều banner cho 1 ngôn ngữ (banner chưa dịch không có trong kết quả)
+    async fn find_many(
+        &self,
+        banner_ids: &[Uuid],
+        language_code: &str,
+    ) -> Result<Vec<BannerTranslation>, String>;
+    /// Tạo mới hoặc ghi đè bản dịch của (banner, ngôn ngữ), kể cả bản đã xoá mềm
+    async fn upsert(&self, translation: NewBannerTranslation) -> Result<BannerTranslation, String>;
+    async fn delete(&self, banner_id: Uuid, language_code: &str) -> Result<(), AppError>;
+}
+
+#[async_trait]
+impl<T: BannerTranslationRepository + ?Sized + Send + Sync> BannerTranslationRepository
+    for std::sync::Arc<T>
+{
+    async fn list_by_banner(&self, banner_id: Uuid) -> Result<Vec<BannerTranslation>, String> {
+        (**self).list_by_banner(banner_id).await
+    }
+
+    async fn find(
+        &self,
+        banner_id: Uuid,
+        language_code: &str,
+    ) -> Result<Option<BannerTranslation>, String> {
+        (**self).find(banner_id, language_code).await
+    }
+
+    async fn find_many(
+        &self,
+        banner_ids: &[Uuid],
+        language_code: &str,
+    ) -> Result<Vec<BannerTranslation>, String> {
+        (**self).find_many(banner_ids, language_code).await
+    }
+
+    async fn upsert(&self, translation: NewBannerTranslation) -> Result<BannerTranslation, String> {
+        (**self).upsert(translation).await
+    }
+
+    async fn delete(&self, banner_id: Uuid, language_code: &str) -> Result<(), AppError> {
+        (**self).delete(banner_id, language_code).await
+    }
+}
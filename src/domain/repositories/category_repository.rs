@@ -6,13 +6,22 @@ use uuid::Uuid;
 pub trait CategoryRepository: Send + Sync {
     #[allow(dead_code)]
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Category>, String>;
+    /// Lấy nhiều category theo danh sách id (dùng cho batch-load quan hệ)
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Category>, String>;
+    /// Chuỗi category từ gốc tới `id` (bao gồm chính nó), dùng cho breadcrumb
+    async fn find_ancestors(&self, id: Uuid) -> Result<Vec<Category>, String>;
     async fn find_by_slug_and_type(
         &self,
         slug: &str,
         r#type: &str,
     ) -> Result<Option<Category>, String>;
     async fn list_by_type(&self, r#type: &str) -> Result<Vec<Category>, String>;
+    /// Cây category theo `type` từ các gốc (`parent_id` NULL), tối đa `max_depth` tầng
+    async fn list_tree(&self, r#type: &str, max_depth: i32) -> Result<Vec<Category>, String>;
+    /// Các category con cháu của `id` (không gồm chính nó), tối đa `max_depth` tầng
+    async fn find_descendants(&self, id: Uuid, max_depth: i32) -> Result<Vec<Category>, String>;
     async fn create(&self, category: Category) -> Result<Category, String>;
+    /// Đổi slug hoặc type => ghi lịch sử slug (redirect 301) trong cùng transaction
     async fn update(&self, category: Category) -> Result<Category, String>;
     async fn soft_delete(&self, id: Uuid) -> Result<(), String>;
     async fn soft_delete_many(&self, ids: &[Uuid]) -> Result<(), String>;
@@ -24,6 +33,14 @@ impl<T: CategoryRepository + ?Sized + Send + Sync> CategoryRepository for std::s
         (**self).find_by_id(id).await
     }
 
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Category>, String> {
+        (**self).find_by_ids(ids).await
+    }
+
+    async fn find_ancestors(&self, id: Uuid) -> Result<Vec<Category>, String> {
+        (**self).find_ancestors(id).await
+    }
+
     async fn find_by_slug_and_type(
         &self,
         slug: &str,
@@ -36,6 +53,14 @@ impl<T: CategoryRepository + ?Sized + Send + Sync> CategoryRepository for std::s
         (**self).list_by_type(r#type).await
     }
 
+    async fn list_tree(&self, r#type: &str, max_depth: i32) -> Result<Vec<Category>, String> {
+        (**self).list_tree(r#type, max_depth).await
+    }
+
+    async fn find_descendants(&self, id: Uuid, max_depth: i32) -> Result<Vec<Category>, String> {
+        (**self).find_descendants(id, max_depth).await
+    }
+
     async fn create(&self, category: Category) -> Result<Category, String> {
         (**self).create(category).await
     }
@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::category_translation::{CategoryTranslation, NewCategoryTranslation};
+use crate::shared::error::AppError;
+
+#[async_trait]
+pub trait CategoryTranslationRepository: Send + Sync {
+    async fn list_by_category(&self, category_id: Uuid)
+    -> Result<Vec<CategoryTranslation>, String>;
+    async fn find(
+        &self,
+        category_id: Uuid,
+        language_code: &str,
+    ) -> Result<Option<CategoryTranslation>, String>;
+    /// Bản dịch của nhiều category cho 1 ngôn ngữ (category chưa dịch không có trong kết quả)
+    async fn find_many(
+        &self,
+        category_ids: &[Uuid],
+        language_code: &str,
+    ) -> Result<Vec<CategoryTranslation>, String>;
+    /// Tạo mới hoặc ghi đè bản dịch của (category, ngôn ngữ), kể cả bản đã xoá mềm
+    async fn upsert(
+        &self,
+        translation: NewCategoryTranslation,
+    ) -> Result<CategoryTranslation, String>;
+    async fn delete(&self, category_id: Uuid, language_code: &str) -> Result<(), AppError>;
+}
+
+#[async_trait]
+impl<T: CategoryTranslationRepository + ?Sized + Send + Sync> CategoryTranslationRepository
+    for std::sync::Arc<T>
+{
+    async fn list_by_category(
+        &self,
+        category_id: Uuid,
+    ) -> Result<Vec<CategoryTranslation>, String> {
+        (**self).list_by_category(category_id).await
+    }
+
+    async fn find(
+        &self,
+        category_id: Uuid,
+        language_code: &str,
+    ) -> Result<Option<CategoryTranslation>, String> {
+        (**self).find(category_id, language_code).await
+    }
+
+    async fn find_many(
+        &self,
+        category_ids: &[Uuid],
+        language_code: &str,
+    ) -> Result<Vec<CategoryTranslation>, String> {
+        (**self).find_many(category_ids, language_code).await
+    }
+
+    async fn upsert(
+        &self,
+        translation: NewCategoryTranslation,
+    ) -> Result<CategoryTranslation, String> {
+        (**self).upsert(translation).await
+    }
+
+    async fn delete(&self, category_id: Uuid, language_code: &str) -> Result<(), AppError> {
+        (**self).delete(category_id, language_code).await
+    }
+}
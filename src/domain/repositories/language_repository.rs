@@ -1,12 +1,53 @@
 use crate::domain::entities::language::Language;
+use crate::shared::error::AppError;
 use async_trait::async_trait;
 
+/// Xử lý bản dịch còn lại khi xoá ngôn ngữ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TranslationDisposal {
+    /// Còn bản dịch => không xoá
+    Refuse,
+    /// Chuyển bản dịch sang ngôn ngữ khác (bản dịch trùng với bản đã có ở ngôn ngữ đích bị xoá mềm,
+    /// slug bản dịch post trùng với bài khác ở ngôn ngữ đích => không xoá)
+    Migrate { to: String },
+    /// Xoá mềm toàn bộ bản dịch
+    Cascade,
+}
+
+/// Kết quả xoá ngôn ngữ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LanguageDeletion {
+    Deleted {
+        migrated: i64,
+        removed: i64,
+    },
+    NotFound,
+    IsDefault,
+    HasTranslations {
+        count: i64,
+    },
+    /// Slug bản dịch post đã có ở ngôn ngữ đích (của bài khác)
+    SlugConflicts {
+        slugs: Vec<String>,
+    },
+}
+
 #[async_trait]
 #[allow(dead_code)]
 pub trait LanguageRepository: Send + Sync {
     async fn list_all(&self) -> Result<Vec<Language>, String>;
     async fn get_default(&self) -> Result<Option<Language>, String>;
     async fn find_by_code(&self, code: &str) -> Result<Option<Language>, String>;
+    /// Tạo ngôn ngữ (khôi phục nếu mã đã bị xoá mềm). `None` nếu mã đang được dùng
+    async fn create(&self, code: &str, name: &str) -> Result<Option<Language>, String>;
+    async fn update_name(&self, code: &str, name: &str) -> Result<Option<Language>, String>;
+    /// Đặt ngôn ngữ mặc định trong 1 transaction (bỏ cờ ở ngôn ngữ mặc định cũ)
+    async fn set_default(&self, code: &str) -> Result<Option<Language>, String>;
+    async fn soft_delete(
+        &self,
+        code: &str,
+        disposal: TranslationDisposal,
+    ) -> Result<LanguageDeletion, AppError>;
 }
 
 #[async_trait]
@@ -22,4 +63,24 @@ impl<T: LanguageRepository + ?Sized + Send + Sync> LanguageRepository for std::s
     async fn find_by_code(&self, code: &str) -> Result<Option<Language>, String> {
         (**self).find_by_code(code).await
     }
+
+    async fn create(&self, code: &str, name: &str) -> Result<Option<Language>, String> {
+        (**self).create(code, name).await
+    }
+
+    async fn update_name(&self, code: &str, name: &str) -> Result<Option<Language>, String> {
+        (**self).update_name(code, name).await
+    }
+
+    async fn set_default(&self, code: &str) -> Result<Option<Language>, String> {
+        (**self).set_default(code).await
+    }
+
+    async fn soft_delete(
+        &self,
+        code: &str,
+        disposal: TranslationDisposal,
+    ) -> Result<LanguageDeletion, AppError> {
+        (**self).soft_delete(code, disposal).await
+    }
 }
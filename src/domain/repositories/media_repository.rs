@@ -1,4 +1,5 @@
 use crate::domain::entities::media::{Media, NewMedia};
+use crate::shared::error::AppError;
 use crate::shared::utils::query::PaginatedResult;
 use async_trait::async_trait;
 
@@ -14,6 +15,8 @@ pub struct MediaSearchFilter {
 pub trait MediaRepository: Send + Sync {
     async fn create(&self, media: NewMedia) -> Result<Media, String>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Media>, String>;
+    /// Media chưa xoá theo danh sách id (bỏ qua id không tồn tại)
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Media>, String>;
     /// Cập nhật metadata cho 1 media (theo id trong struct)
     async fn update(&self, media: Media) -> Result<Media, String>;
     async fn search(
@@ -22,7 +25,7 @@ pub trait MediaRepository: Send + Sync {
         sort_by: Option<String>,
         cursor: Option<String>,
         limit: i64,
-    ) -> Result<PaginatedResult<Media>, String>;
+    ) -> Result<PaginatedResult<Media>, AppError>;
     #[allow(dead_code)]
     async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<Media>, String>;
 
@@ -42,6 +45,10 @@ impl<T: MediaRepository + ?Sized + Send + Sync> MediaRepository for std::sync::A
         (**self).find_by_id(id).await
     }
 
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Media>, String> {
+        (**self).find_by_ids(ids).await
+    }
+
     async fn update(&self, media: Media) -> Result<Media, String> {
         (**self).update(media).await
     }
@@ -52,7 +59,7 @@ impl<T: MediaRepository + ?Sized + Send + Sync> MediaRepository for std::sync::A
         sort_by: Option<String>,
         cursor: Option<String>,
         limit: i64,
-    ) -> Result<PaginatedResult<Media>, String> {
+    ) -> Result<PaginatedResult<Media>, AppError> {
         (**self).search(filter, sort_by, cursor, limit).await
     }
 
@@ -1,12 +1,26 @@
 pub mod audit_log_repository;
 pub mod banner_repository;
+pub mod banner_translation_repository;
 pub mod category_repository;
+pub mod category_translation_repository;
 pub mod language_repository;
 pub mod media_repository;
 pub mod permission_repository;
+pub mod post_media_repository;
 pub mod post_repository;
+pub mod post_review_repository;
+pub mod post_revision_repository;
+pub mod post_translation_repository;
+pub mod redirect_repository;
 pub mod role_repository;
+pub mod seo_metadata_repository;
 pub mod setting_repository;
+pub mod sitemap_repository;
+pub mod slug_redirect_repository;
+pub mod slug_repository;
 pub mod tag_repository;
+pub mod tag_translation_repository;
+pub mod translation_key_repository;
+pub mod translation_status_repository;
 pub mod user_profile_repository;
 pub mod user_repository;
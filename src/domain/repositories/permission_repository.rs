@@ -1,4 +1,5 @@
 use crate::domain::entities::permission::{NewPermission, Permission, UpdatePermission};
+use crate::shared::error::AppError;
 use crate::shared::utils::query::PaginatedResult;
 use async_trait::async_trait;
 
@@ -19,14 +20,16 @@ pub trait PermissionRepository: Send + Sync {
         sort_by: Option<String>,
         cursor: Option<String>,
         limit: i64,
-    ) -> Result<PaginatedResult<Permission>, String>;
+    ) -> Result<PaginatedResult<Permission>, AppError>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Permission>, String>;
     async fn create(&self, permission: NewPermission) -> Result<Permission, String>;
     async fn update(&self, id: Uuid, permission: UpdatePermission) -> Result<Permission, String>;
-    async fn delete(&self, id: Uuid) -> Result<(), String>;
+    async fn delete(&self, id: Uuid) -> Result<(), AppError>;
     async fn find_by_role_id(&self, role_id: Uuid) -> Result<Vec<Permission>, String>;
     async fn assign_to_role(&self, role_id: Uuid, permission_id: Uuid) -> Result<(), String>;
     async fn revoke_from_role(&self, role_id: Uuid, permission_id: Uuid) -> Result<(), String>;
+    /// User có permission `name` thông qua ít nhất một role
+    async fn user_has_permission(&self, user_id: Uuid, name: &str) -> Result<bool, String>;
 }
 
 #[async_trait]
@@ -41,7 +44,7 @@ impl<T: PermissionRepository + ?Sized + Send + Sync> PermissionRepository for st
         sort_by: Option<String>,
         cursor: Option<String>,
         limit: i64,
-    ) -> Result<PaginatedResult<Permission>, String> {
+    ) -> Result<PaginatedResult<Permission>, AppError> {
         (**self).search(filter, sort_by, cursor, limit).await
     }
 
@@ -57,7 +60,7 @@ impl<T: PermissionRepository + ?Sized + Send + Sync> PermissionRepository for st
         (**self).update(id, permission).await
     }
 
-    async fn delete(&self, id: Uuid) -> Result<(), String> {
+    async fn delete(&self, id: Uuid) -> Result<(), AppError> {
         (**self).delete(id).await
     }
 
@@ -72,4 +75,8 @@ impl<T: PermissionRepository + ?Sized + Send + Sync> PermissionRepository for st
     async fn revoke_from_role(&self, role_id: Uuid, permission_id: Uuid) -> Result<(), String> {
         (**self).revoke_from_role(role_id, permission_id).await
     }
+
+    async fn user_has_permission(&self, user_id: Uuid, name: &str) -> Result<bool, String> {
+        (**self).user_has_permission(user_id, name).await
+    }
 }
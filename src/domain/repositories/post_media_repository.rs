@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::post_media::{NewPostMedia, PostMedia};
+use crate::shared::error::AppError;
+
+#[async_trait]
+pub trait PostMediaRepository: Send + Sync {
+    /// Gallery của các bài viết, sắp theo `position`
+    async fn list_by_post_ids(&self, post_ids: &[Uuid]) -> Result<Vec<PostMedia>, String>;
+    /// Thay thế toàn bộ gallery (thứ tự theo `items`)
+    async fn replace(&self, post_id: Uuid, items: &[NewPostMedia]) -> Result<(), String>;
+    /// Thêm vào cuối gallery, media đã có thì cập nhật caption
+    async fn append(&self, post_id: Uuid, items: &[NewPostMedia]) -> Result<(), String>;
+    async fn remove(&self, post_id: Uuid, media_id: Uuid) -> Result<(), AppError>;
+}
+
+#[async_trait]
+impl<T: PostMediaRepository + ?Sized + Send + Sync> PostMediaRepository for std::sync::Arc<T> {
+    async fn list_by_post_ids(&self, post_ids: &[Uuid]) -> Result<Vec<PostMedia>, String> {
+        (**self).list_by_post_ids(post_ids).await
+    }
+
+    async fn replace(&self, post_id: Uuid, items: &[NewPostMedia]) -> Result<(), String> {
+        (**self).replace(post_id, items).await
+    }
+
+    async fn append(&self, post_id: Uuid, items: &[NewPostMedia]) -> Result<(), String> {
+        (**self).append(post_id, items).await
+    }
+
+    async fn remove(&self, post_id: Uuid, media_id: Uuid) -> Result<(), AppError> {
+        (**self).remove(post_id, media_id).await
+    }
+}
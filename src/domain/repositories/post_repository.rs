@@ -1,13 +1,63 @@
-use crate::domain::entities::post::{Post, PostStatus};
+use crate::domain::entities::post::{
+    Post, PostFeedItem, PostScheduleTransition, PostSearchHit, PostStatus,
+};
+use crate::domain::entities::post_review::PostReviewComment;
+use crate::shared::error::AppError;
+use crate::shared::utils::query::PaginatedResult;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use uuid::Uuid;
 
-#[derive(Debug, Clone)]
-#[allow(dead_code)]
+#[derive(Debug, Clone, Default)]
 pub struct PostSearchFilter {
     pub search: Option<String>,
+    /// Lọc theo category, bao gồm cả các category con
     pub category_id: Option<Uuid>,
+    /// Slug của tag (type = 'post')
+    pub tag_slug: Option<String>,
+    pub author_id: Option<Uuid>,
+    /// Chỉ bài được gán cho reviewer này
+    pub reviewer_id: Option<Uuid>,
     pub status: Option<PostStatus>,
+    pub published_from: Option<NaiveDateTime>,
+    pub published_to: Option<NaiveDateTime>,
+    /// Chế độ public: chỉ bài đã publish và `published_at` không nằm ở tương lai,
+    /// bỏ qua `status`
+    pub public_only: bool,
+}
+
+/// Tham số full-text search bài viết
+#[derive(Debug, Clone)]
+pub struct PostFullTextQuery {
+    pub query: String,
+    /// Chỉ tìm trong bản dịch của ngôn ngữ này (bài gốc thuộc ngôn ngữ mặc định)
+    pub language_code: Option<String>,
+    pub category_id: Option<Uuid>,
+    pub tag_slug: Option<String>,
+    /// Mặc định chỉ tìm bài đã publish
+    pub status: Option<PostStatus>,
+    pub limit: i64,
+}
+
+/// Tham số lấy bài cho feed (chỉ bài đã publish, mới nhất trước)
+#[derive(Debug, Clone, Default)]
+pub struct PostFeedQuery {
+    /// Lọc theo category, bao gồm cả các category con
+    pub category_id: Option<Uuid>,
+    /// Slug của tag (type = 'post')
+    pub tag_slug: Option<String>,
+    /// Chỉ bài có bản dịch ở ngôn ngữ này (`None`: bài gốc)
+    pub language_code: Option<String>,
+    pub limit: i64,
+}
+
+/// Revision ghi kèm khi tạo/cập nhật bài viết, trong cùng transaction với bài viết
+#[derive(Debug, Clone, Copy)]
+pub struct PostRevisionOptions {
+    /// User thực hiện lần lưu
+    pub saved_by: Option<Uuid>,
+    /// Số revision tối đa giữ lại (<= 0: không giới hạn)
+    pub retention: i64,
 }
 
 #[async_trait]
@@ -15,15 +65,63 @@ pub trait PostRepository: Send + Sync {
     #[allow(dead_code)]
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Post>, String>;
     async fn find_by_slug(&self, slug: &str) -> Result<Option<Post>, String>;
-    async fn search(&self, filter: &PostSearchFilter) -> Result<Vec<Post>, String>;
-    /// Tạo bài viết mới
-    async fn create(&self, post: Post) -> Result<Post, String>;
-    /// Cập nhật bài viết (theo id)
-    async fn update(&self, post: Post) -> Result<Post, String>;
+    async fn search(
+        &self,
+        filter: &PostSearchFilter,
+        sort_by: Option<String>,
+        cursor: Option<String>,
+        limit: i64,
+    ) -> Result<PaginatedResult<Post>, AppError>;
+    /// Full-text search (ts_rank + ts_headline), fallback trigram khi không có kết quả
+    async fn search_fulltext(
+        &self,
+        query: &PostFullTextQuery,
+    ) -> Result<Vec<PostSearchHit>, String>;
+    /// Bài đã publish cho feed, áp bản dịch khi có `language_code`
+    async fn list_feed(&self, query: &PostFeedQuery) -> Result<Vec<PostFeedItem>, String>;
+    /// Tạo bài viết mới, `tag_ids` (nếu có) được gán và `revision` (nếu có) được ghi
+    /// trong cùng transaction
+    async fn create(
+        &self,
+        post: Post,
+        tag_ids: Option<&[Uuid]>,
+        revision: Option<&PostRevisionOptions>,
+    ) -> Result<Post, String>;
+    /// Cập nhật bài viết (theo id), `tag_ids = Some(..)` thay thế toàn bộ tag,
+    /// `revision` (nếu có) snapshot bài sau khi lưu, slug đổi thì ghi lịch sử slug (redirect 301),
+    /// tất cả trong cùng transaction
+    async fn update(
+        &self,
+        post: Post,
+        tag_ids: Option<&[Uuid]>,
+        revision: Option<&PostRevisionOptions>,
+    ) -> Result<Post, String>;
+    /// Thay thế toàn bộ tag của bài viết
+    /// Lưu quyết định review: cập nhật trạng thái/lịch của bài và thêm nhận xét
+    /// `decision` trong cùng transaction
+    async fn save_review_decision(
+        &self,
+        post: Post,
+        reviewer_id: Uuid,
+        decision: &str,
+        body: &str,
+    ) -> Result<(Post, PostReviewComment), String>;
+    async fn set_tags(&self, post_id: Uuid, tag_ids: &[Uuid]) -> Result<(), String>;
+    /// Gán thêm tag (bỏ qua tag đã gán)
+    async fn attach_tags(&self, post_id: Uuid, tag_ids: &[Uuid]) -> Result<(), String>;
+    /// Gỡ tag khỏi bài viết
+    async fn detach_tags(&self, post_id: Uuid, tag_ids: &[Uuid]) -> Result<(), String>;
     /// Xoá mềm 1 bài viết
     async fn soft_delete(&self, id: Uuid) -> Result<(), String>;
     /// Xoá mềm nhiều bài viết theo danh sách id
     async fn soft_delete_many(&self, ids: &[Uuid]) -> Result<(), String>;
+    /// Chuyển tối đa `limit` bài `scheduled` đã tới `published_at` sang `published`.
+    /// Bỏ qua bài đang bị instance khác khoá (`FOR UPDATE SKIP LOCKED`).
+    /// Audit log `post.scheduled_publish` được ghi trong cùng transaction
+    async fn publish_due(&self, limit: i64) -> Result<Vec<PostScheduleTransition>, String>;
+    /// Chuyển tối đa `limit` bài `published` đã tới `unpublish_at` sang `archived`,
+    /// audit log `post.scheduled_unpublish` ghi trong cùng transaction
+    async fn unpublish_due(&self, limit: i64) -> Result<Vec<PostScheduleTransition>, String>;
 }
 
 #[async_trait]
@@ -36,16 +134,67 @@ impl<T: PostRepository + ?Sized + Send + Sync> PostRepository for std::sync::Arc
         (**self).find_by_slug(slug).await
     }
 
-    async fn search(&self, filter: &PostSearchFilter) -> Result<Vec<Post>, String> {
-        (**self).search(filter).await
+    async fn search(
+        &self,
+        filter: &PostSearchFilter,
+        sort_by: Option<String>,
+        cursor: Option<String>,
+        limit: i64,
+    ) -> Result<PaginatedResult<Post>, AppError> {
+        (**self).search(filter, sort_by, cursor, limit).await
     }
 
-    async fn create(&self, post: Post) -> Result<Post, String> {
-        (**self).create(post).await
+    async fn search_fulltext(
+        &self,
+        query: &PostFullTextQuery,
+    ) -> Result<Vec<PostSearchHit>, String> {
+        (**self).search_fulltext(query).await
     }
 
-    async fn update(&self, post: Post) -> Result<Post, String> {
-        (**self).update(post).await
+    async fn list_feed(&self, query: &PostFeedQuery) -> Result<Vec<PostFeedItem>, String> {
+        (**self).list_feed(query).await
+    }
+
+    async fn create(
+        &self,
+        post: Post,
+        tag_ids: Option<&[Uuid]>,
+        revision: Option<&PostRevisionOptions>,
+    ) -> Result<Post, String> {
+        (**self).create(post, tag_ids, revision).await
+    }
+
+    async fn update(
+        &self,
+        post: Post,
+        tag_ids: Option<&[Uuid]>,
+        revision: Option<&PostRevisionOptions>,
+    ) -> Result<Post, String> {
+        (**self).update(post, tag_ids, revision).await
+    }
+
+    async fn save_review_decision(
+        &self,
+        post: Post,
+        reviewer_id: Uuid,
+        decision: &str,
+        body: &str,
+    ) -> Result<(Post, PostReviewComment), String> {
+        (**self)
+            .save_review_decision(post, reviewer_id, decision, body)
+            .await
+    }
+
+    async fn set_tags(&self, post_id: Uuid, tag_ids: &[Uuid]) -> Result<(), String> {
+        (**self).set_tags(post_id, tag_ids).await
+    }
+
+    async fn attach_tags(&self, post_id: Uuid, tag_ids: &[Uuid]) -> Result<(), String> {
+        (**self).attach_tags(post_id, tag_ids).await
+    }
+
+    async fn detach_tags(&self, post_id: Uuid, tag_ids: &[Uuid]) -> Result<(), String> {
+        (**self).detach_tags(post_id, tag_ids).await
     }
 
     async fn soft_delete(&self, id: Uuid) -> Result<(), String> {
@@ -55,4 +204,12 @@ impl<T: PostRepository + ?Sized + Send + Sync> PostRepository for std::sync::Arc
     async fn soft_delete_many(&self, ids: &[Uuid]) -> Result<(), String> {
         (**self).soft_delete_many(ids).await
     }
+
+    async fn publish_due(&self, limit: i64) -> Result<Vec<PostScheduleTransition>, String> {
+        (**self).publish_due(limit).await
+    }
+
+    async fn unpublish_due(&self, limit: i64) -> Result<Vec<PostScheduleTransition>, String> {
+        (**self).unpublish_due(limit).await
+    }
 }
@@ -0,0 +1,63 @@
+use crate::domain::entities::post_review::{PostReviewComment, PostReviewer};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait PostReviewRepository: Send + Sync {
+    /// Thay thế toàn bộ reviewer của bài viết
+    async fn set_reviewers(
+        &self,
+        post_id: Uuid,
+        reviewer_ids: &[Uuid],
+        assigned_by: Option<Uuid>,
+    ) -> Result<(), String>;
+    async fn list_reviewers(&self, post_id: Uuid) -> Result<Vec<PostReviewer>, String>;
+    async fn is_reviewer(&self, post_id: Uuid, user_id: Uuid) -> Result<bool, String>;
+    async fn add_comment(
+        &self,
+        post_id: Uuid,
+        author_id: Uuid,
+        decision: Option<&str>,
+        body: &str,
+    ) -> Result<PostReviewComment, String>;
+    /// Nhận xét theo thứ tự thời gian (cũ trước)
+    async fn list_comments(&self, post_id: Uuid) -> Result<Vec<PostReviewComment>, String>;
+}
+
+#[async_trait]
+impl<T: PostReviewRepository + ?Sized + Send + Sync> PostReviewRepository for std::sync::Arc<T> {
+    async fn set_reviewers(
+        &self,
+        post_id: Uuid,
+        reviewer_ids: &[Uuid],
+        assigned_by: Option<Uuid>,
+    ) -> Result<(), String> {
+        (**self)
+            .set_reviewers(post_id, reviewer_ids, assigned_by)
+            .await
+    }
+
+    async fn list_reviewers(&self, post_id: Uuid) -> Result<Vec<PostReviewer>, String> {
+        (**self).list_reviewers(post_id).await
+    }
+
+    async fn is_reviewer(&self, post_id: Uuid, user_id: Uuid) -> Result<bool, String> {
+        (**self).is_reviewer(post_id, user_id).await
+    }
+
+    async fn add_comment(
+        &self,
+        post_id: Uuid,
+        author_id: Uuid,
+        decision: Option<&str>,
+        body: &str,
+    ) -> Result<PostReviewComment, String> {
+        (**self)
+            .add_comment(post_id, author_id, decision, body)
+            .await
+    }
+
+    async fn list_comments(&self, post_id: Uuid) -> Result<Vec<PostReviewComment>, String> {
+        (**self).list_comments(post_id).await
+    }
+}
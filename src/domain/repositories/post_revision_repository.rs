@@ -0,0 +1,31 @@
+use crate::domain::entities::post_revision::PostRevision;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait PostRevisionRepository: Send + Sync {
+    /// Danh sách revision của bài viết, mới nhất trước
+    async fn list_by_post(&self, post_id: Uuid) -> Result<Vec<PostRevision>, String>;
+    async fn find_by_number(
+        &self,
+        post_id: Uuid,
+        revision_number: i32,
+    ) -> Result<Option<PostRevision>, String>;
+}
+
+#[async_trait]
+impl<T: PostRevisionRepository + ?Sized + Send + Sync> PostRevisionRepository
+    for std::sync::Arc<T>
+{
+    async fn list_by_post(&self, post_id: Uuid) -> Result<Vec<PostRevision>, String> {
+        (**self).list_by_post(post_id).await
+    }
+
+    async fn find_by_number(
+        &self,
+        post_id: Uuid,
+        revision_number: i32,
+    ) -> Result<Option<PostRevision>, String> {
+        (**self).find_by_number(post_id, revision_number).await
+    }
+}
@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::post_translation::{NewPostTranslation, PostTranslation};
+use crate::shared::error::AppError;
+
+#[async_trait]
+pub trait PostTranslationRepository: Send + Sync {
+    async fn list_by_post(&self, post_id: Uuid) -> Result<Vec<PostTranslation>, String>;
+    async fn find(
+        &self,
+        post_id: Uuid,
+        language_code: &str,
+    ) -> Result<Option<PostTranslation>, String>;
+    /// Bản dịch của nhiều bài cho 1 ngôn ngữ (bài chưa dịch không có trong kết quả)
+    async fn find_many(
+        &self,
+        post_ids: &[Uuid],
+        language_code: &str,
+    ) -> Result<Vec<PostTranslation>, String>;
+    /// Bản dịch theo slug đã dịch; `language_code = None` => tìm ở mọi ngôn ngữ
+    async fn find_by_slug(
+        &self,
+        slug: &str,
+        language_code: Option<&str>,
+    ) -> Result<Option<PostTranslation>, String>;
+    /// Tạo mới hoặc ghi đè bản dịch của (bài, ngôn ngữ), kể cả bản đã xoá mềm
+    async fn upsert(&self, translation: NewPostTranslation) -> Result<PostTranslation, String>;
+    async fn delete(&self, post_id: Uuid, language_code: &str) -> Result<(), AppError>;
+}
+
+#[async_trait]
+impl<T: PostTranslationRepository + ?Sized + Send + Sync> PostTranslationRepository
+    for std::sync::Arc<T>
+{
+    async fn list_by_post(&self, post_id: Uuid) -> Result<Vec<PostTranslation>, String> {
+        (**self).list_by_post(post_id).await
+    }
+
+    async fn find(
+        &self,
+        post_id: Uuid,
+        language_code: &str,
+    ) -> Result<Option<PostTranslation>, String> {
+        (**self).find(post_id, language_code).await
+    }
+
+    async fn find_many(
+        &self,
+        post_ids: &[Uuid],
+        language_code: &str,
+    ) -> Result<Vec<PostTranslation>, String> {
+        (**self).find_many(post_ids, language_code).await
+    }
+
+    async fn find_by_slug(
+        &self,
+        slug: &str,
+        language_code: Option<&str>,
+    ) -> Result<Option<PostTranslation>, String> {
+        (**self).find_by_slug(slug, language_code).await
+    }
+
+    async fn upsert(&self, translation: NewPostTranslation) -> Result<PostTranslation, String> {
+        (**self).upsert(translation).await
+    }
+
+    async fn delete(&self, post_id: Uuid, language_code: &str) -> Result<(), AppError> {
+        (**self).delete(post_id, language_code).await
+    }
+}
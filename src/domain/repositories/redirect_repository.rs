@@ -0,0 +1,67 @@
+use crate::domain::entities::redirect::Redirect;
+use crate::shared::error::AppError;
+use crate::shared::utils::query::PaginatedResult;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Default)]
+pub struct RedirectSearchFilter {
+    /// Tìm theo `source_path` / `target`
+    pub search: Option<String>,
+    pub is_active: Option<bool>,
+}
+
+#[async_trait]
+pub trait RedirectRepository: Send + Sync {
+    async fn search(
+        &self,
+        filter: &RedirectSearchFilter,
+        sort_by: Option<String>,
+        cursor: Option<String>,
+        limit: i64,
+    ) -> Result<PaginatedResult<Redirect>, AppError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Redirect>, String>;
+    async fn find_by_source_path(&self, source_path: &str) -> Result<Option<Redirect>, String>;
+    async fn create(&self, redirect: Redirect) -> Result<Redirect, String>;
+    async fn update(&self, redirect: Redirect) -> Result<Redirect, String>;
+    async fn soft_delete(&self, id: Uuid) -> Result<(), AppError>;
+    /// Redirect đang bật cho `source_path`, tăng `hit_count` trong cùng câu lệnh
+    async fn hit(&self, source_path: &str) -> Result<Option<Redirect>, String>;
+}
+
+#[async_trait]
+impl<T: RedirectRepository + ?Sized + Send + Sync> RedirectRepository for std::sync::Arc<T> {
+    async fn search(
+        &self,
+        filter: &RedirectSearchFilter,
+        sort_by: Option<String>,
+        cursor: Option<String>,
+        limit: i64,
+    ) -> Result<PaginatedResult<Redirect>, AppError> {
+        (**self).search(filter, sort_by, cursor, limit).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Redirect>, String> {
+        (**self).find_by_id(id).await
+    }
+
+    async fn find_by_source_path(&self, source_path: &str) -> Result<Option<Redirect>, String> {
+        (**self).find_by_source_path(source_path).await
+    }
+
+    async fn create(&self, redirect: Redirect) -> Result<Redirect, String> {
+        (**self).create(redirect).await
+    }
+
+    async fn update(&self, redirect: Redirect) -> Result<Redirect, String> {
+        (**self).update(redirect).await
+    }
+
+    async fn soft_delete(&self, id: Uuid) -> Result<(), AppError> {
+        (**self).soft_delete(id).await
+    }
+
+    async fn hit(&self, source_path: &str) -> Result<Option<Redirect>, String> {
+        (**self).hit(source_path).await
+    }
+}
@@ -1,4 +1,5 @@
 use crate::domain::entities::role::{NewRole, Role, UpdateRole};
+use crate::shared::error::AppError;
 use crate::shared::utils::query::PaginatedResult;
 use async_trait::async_trait;
 
@@ -19,12 +20,14 @@ pub trait RoleRepository: Send + Sync {
         sort_by: Option<String>,
         cursor: Option<String>,
         limit: i64,
-    ) -> Result<PaginatedResult<Role>, String>;
+    ) -> Result<PaginatedResult<Role>, AppError>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Role>, String>;
     async fn create(&self, role: NewRole) -> Result<Role, String>;
     async fn update(&self, id: Uuid, role: UpdateRole) -> Result<Role, String>;
-    async fn delete(&self, id: Uuid) -> Result<(), String>;
+    async fn delete(&self, id: Uuid) -> Result<(), AppError>;
     async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<Role>, String>;
+    /// Lấy role của nhiều user trong một query, trả về cặp (user_id, role)
+    async fn find_by_user_ids(&self, user_ids: &[Uuid]) -> Result<Vec<(Uuid, Role)>, String>;
     async fn assign_to_user(&self, user_id: Uuid, role_id: Uuid) -> Result<(), String>;
     async fn revoke_from_user(&self, user_id: Uuid, role_id: Uuid) -> Result<(), String>;
 }
@@ -41,7 +44,7 @@ impl<T: RoleRepository + ?Sized + Send + Sync> RoleRepository for std::sync::Arc
         sort_by: Option<String>,
         cursor: Option<String>,
         limit: i64,
-    ) -> Result<PaginatedResult<Role>, String> {
+    ) -> Result<PaginatedResult<Role>, AppError> {
         (**self).search(filter, sort_by, cursor, limit).await
     }
 
@@ -57,7 +60,7 @@ impl<T: RoleRepository + ?Sized + Send + Sync> RoleRepository for std::sync::Arc
         (**self).update(id, role).await
     }
 
-    async fn delete(&self, id: Uuid) -> Result<(), String> {
+    async fn delete(&self, id: Uuid) -> Result<(), AppError> {
         (**self).delete(id).await
     }
 
@@ -65,6 +68,10 @@ impl<T: RoleRepository + ?Sized + Send + Sync> RoleRepository for std::sync::Arc
         (**self).find_by_user_id(user_id).await
     }
 
+    async fn find_by_user_ids(&self, user_ids: &[Uuid]) -> Result<Vec<(Uuid, Role)>, String> {
+        (**self).find_by_user_ids(user_ids).await
+    }
+
     async fn assign_to_user(&self, user_id: Uuid, role_id: Uuid) -> Result<(), String> {
         (**self).assign_to_user(user_id, role_id).await
     }
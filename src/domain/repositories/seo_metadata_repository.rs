@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::seo_metadata::{NewSeoMetadata, SeoMetadata};
+
+/// Loại entity có SEO (cột `seo_metadata.entity_type`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeoEntity {
+    Post,
+    Category,
+}
+
+impl SeoEntity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SeoEntity::Post => "post",
+            SeoEntity::Category => "category",
+        }
+    }
+}
+
+#[async_trait]
+pub trait SeoMetadataRepository: Send + Sync {
+    async fn find(
+        &self,
+        entity: SeoEntity,
+        entity_id: Uuid,
+        language_code: &str,
+    ) -> Result<Option<SeoMetadata>, String>;
+    /// Tạo mới hoặc ghi đè SEO của (entity, ngôn ngữ)
+    async fn upsert(&self, seo: NewSeoMetadata) -> Result<SeoMetadata, String>;
+}
+
+#[async_trait]
+impl<T: SeoMetadataRepository + ?Sized + Send + Sync> SeoMetadataRepository for std::sync::Arc<T> {
+    async fn find(
+        &self,
+        entity: SeoEntity,
+        entity_id: Uuid,
+        language_code: &str,
+    ) -> Result<Option<SeoMetadata>, String> {
+        (**self).find(entity, entity_id, language_code).await
+    }
+
+    async fn upsert(&self, seo: NewSeoMetadata) -> Result<SeoMetadata, String> {
+        (**self).upsert(seo).await
+    }
+}
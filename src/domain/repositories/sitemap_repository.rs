@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::entities::sitemap::{SitemapEntry, SitemapStats};
+
+/// Nhóm URL của sitemap, mỗi nhóm là 1 chuỗi sitemap con `/sitemaps/{nhóm}-{trang}.xml`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SitemapSection {
+    Posts,
+    Categories,
+    Tags,
+}
+
+impl SitemapSection {
+    pub const ALL: [SitemapSection; 3] = [
+        SitemapSection::Posts,
+        SitemapSection::Categories,
+        SitemapSection::Tags,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SitemapSection::Posts => "posts",
+            SitemapSection::Categories => "categories",
+            SitemapSection::Tags => "tags",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.as_str() == value)
+    }
+}
+
+#[async_trait]
+pub trait SitemapRepository: Send + Sync {
+    /// Chỉ tính URL công khai (post đã publish, category/tag chưa xoá)
+    async fn stats(&self, section: SitemapSection) -> Result<SitemapStats, String>;
+    /// URL công khai theo thứ tự ổn định (id) để chia trang
+    async fn list(
+        &self,
+        section: SitemapSection,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<SitemapEntry>, String>;
+    /// Mốc gần nhất trong tương lai bài viết tự vào/ra sitemap (`published_at`/`unpublish_at`)
+    async fn next_schedule_change(&self) -> Result<Option<NaiveDateTime>, String>;
+}
+
+#[async_trait]
+impl<T: SitemapRepository + ?Sized + Send + Sync> SitemapRepository for std::sync::Arc<T> {
+    async fn stats(&self, section: SitemapSection) -> Result<SitemapStats, String> {
+        (**self).stats(section).await
+    }
+
+    async fn list(
+        &self,
+        section: SitemapSection,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<SitemapEntry>, String> {
+        (**self).list(section, offset, limit).await
+    }
+
+    async fn next_schedule_change(&self) -> Result<Option<NaiveDateTime>, String> {
+        (**self).next_schedule_change().await
+    }
+}
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+
+use crate::domain::repositories::slug_repository::SlugScope;
+
+/// Vị trí hiện tại của entity có slug cũ
+#[derive(Debug, Clone)]
+pub struct SlugRedirectTarget {
+    pub slug: String,
+    /// Phạm vi hiện tại (type của category, rỗng với post)
+    pub scope: String,
+}
+
+/// Lịch sử slug: slug cũ trỏ về entity để redirect tới slug hiện tại.
+/// Lịch sử được ghi trong transaction cập nhật entity (repo của post/category)
+#[async_trait]
+pub trait SlugRedirectRepository: Send + Sync {
+    /// Vị trí hiện tại của entity từng dùng `old_slug` (None nếu không có lịch sử hoặc entity đã xoá)
+    async fn find_current_slug(
+        &self,
+        scope: SlugScope<'_>,
+        old_slug: &str,
+    ) -> Result<Option<SlugRedirectTarget>, String>;
+}
+
+#[async_trait]
+impl<T: SlugRedirectRepository + ?Sized + Send + Sync> SlugRedirectRepository
+    for std::sync::Arc<T>
+{
+    async fn find_current_slug(
+        &self,
+        scope: SlugScope<'_>,
+        old_slug: &str,
+    ) -> Result<Option<SlugRedirectTarget>, String> {
+        (**self).find_current_slug(scope, old_slug).await
+    }
+}
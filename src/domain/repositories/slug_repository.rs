@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Phạm vi unique của slug (theo constraint trong DB)
+#[derive(Debug, Clone, Copy)]
+pub enum SlugScope<'a> {
+    /// `posts_slug_unique (slug)`, không trùng cả slug bản dịch của bài khác
+    Post,
+    /// `banners_slug_unique (slug)`
+    Banner,
+    /// `categories_slug_type_unique (slug, type)`
+    Category { r#type: &'a str },
+    /// `tags_slug_type_unique (slug, type)`
+    Tag { r#type: &'a str },
+    /// `post_translations_lang_slug_unique (language_code, slug)`, không trùng cả slug gốc của bài khác
+    PostTranslation { language_code: &'a str },
+}
+
+impl SlugScope<'_> {
+    /// Tên entity (cột `slug_redirects.entity_type`)
+    pub fn entity_type(&self) -> &'static str {
+        match self {
+            SlugScope::Post => "post",
+            SlugScope::Banner => "banner",
+            SlugScope::Category { .. } => "category",
+            SlugScope::Tag { .. } => "tag",
+            SlugScope::PostTranslation { .. } => "post_translation",
+        }
+    }
+
+    /// Phạm vi con trong entity (type của category/tag, rỗng với post/banner)
+    pub fn scope_key(&self) -> &str {
+        match self {
+            SlugScope::Category { r#type } | SlugScope::Tag { r#type } => r#type,
+            SlugScope::PostTranslation { language_code } => language_code,
+            SlugScope::Post | SlugScope::Banner => "",
+        }
+    }
+
+    /// Slug dự phòng khi nội dung nguồn không tạo được slug (vd: chỉ có ký hiệu)
+    pub fn fallback(&self) -> &'static str {
+        match self {
+            SlugScope::PostTranslation { .. } => "post",
+            _ => self.entity_type(),
+        }
+    }
+
+    /// Slug trùng với route tĩnh cùng cấp (`/categories/tree`...), không được dùng
+    pub fn reserved(&self) -> &'static [&'static str] {
+        match self {
+            SlugScope::Category { .. } => &["tree", "bulk-delete"],
+            _ => &[],
+        }
+    }
+}
+
+#[async_trait]
+pub trait SlugRepository: Send + Sync {
+    /// Các slug đã dùng trong `scope` bằng `base` hoặc dạng `base-...`
+    /// (gồm cả bản ghi đã xoá mềm vì constraint vẫn áp dụng), bỏ qua bản ghi `exclude_id`
+    async fn find_taken(
+        &self,
+        scope: SlugScope<'_>,
+        base: &str,
+        exclude_id: Option<Uuid>,
+    ) -> Result<Vec<String>, String>;
+}
+
+#[async_trait]
+impl<T: SlugRepository + ?Sized + Send + Sync> SlugRepository for std::sync::Arc<T> {
+    async fn find_taken(
+        &self,
+        scope: SlugScope<'_>,
+        base: &str,
+        exclude_id: Option<Uuid>,
+    ) -> Result<Vec<String>, String> {
+        (**self).find_taken(scope, base, exclude_id).await
+    }
+}
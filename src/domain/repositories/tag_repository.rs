@@ -8,6 +8,16 @@ pub trait TagRepository: Send + Sync {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Tag>, String>;
     async fn find_by_slug_and_type(&self, slug: &str, r#type: &str) -> Result<Option<Tag>, String>;
     async fn list_by_type(&self, r#type: &str) -> Result<Vec<Tag>, String>;
+    /// Lấy nhiều tag theo id (mọi type)
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Tag>, String>;
+    /// Lấy nhiều tag theo slug trong cùng một type
+    async fn find_by_slugs_and_type(
+        &self,
+        slugs: &[String],
+        r#type: &str,
+    ) -> Result<Vec<Tag>, String>;
+    /// Lấy tag của nhiều bài viết trong một query, trả về cặp (post_id, tag)
+    async fn find_by_post_ids(&self, post_ids: &[Uuid]) -> Result<Vec<(Uuid, Tag)>, String>;
     async fn create(&self, tag: Tag) -> Result<Tag, String>;
     async fn update(&self, tag: Tag) -> Result<Tag, String>;
     async fn soft_delete(&self, id: Uuid) -> Result<(), String>;
@@ -28,6 +38,22 @@ impl<T: TagRepository + ?Sized + Send + Sync> TagRepository for std::sync::Arc<T
         (**self).list_by_type(r#type).await
     }
 
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Tag>, String> {
+        (**self).find_by_ids(ids).await
+    }
+
+    async fn find_by_slugs_and_type(
+        &self,
+        slugs: &[String],
+        r#type: &str,
+    ) -> Result<Vec<Tag>, String> {
+        (**self).find_by_slugs_and_type(slugs, r#type).await
+    }
+
+    async fn find_by_post_ids(&self, post_ids: &[Uuid]) -> Result<Vec<(Uuid, Tag)>, String> {
+        (**self).find_by_post_ids(post_ids).await
+    }
+
     async fn create(&self, tag: Tag) -> Result<Tag, String> {
         (**self).create(tag).await
     }
@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::tag_translation::{NewTagTranslation, TagTranslation};
+use crate::shared::error::AppError;
+
+#[async_trait]
+pub trait TagTranslationRepository: Send + Sync {
+    async fn list_by_tag(&self, tag_id: Uuid) -> Result<Vec<TagTranslation>, String>;
+    async fn find(
+        &self,
+        tag_id: Uuid,
+        language_code: &str,
+    ) -> Result<Option<TagTranslation>, String>;
+    /// Bản dịch của nhiều tag cho 1 ngôn ngữ (tag chưa dịch không có trong kết quả)
+    async fn find_many(
+        &self,
+        tag_ids: &[Uuid],
+        language_code: &str,
+    ) -> Result<Vec<TagTranslation>, String>;
+    /// Tạo mới hoặc ghi đè bản dịch của (tag, ngôn ngữ), kể cả bản đã xoá mềm
+    async fn upsert(&self, translation: NewTagTranslation) -> Result<TagTranslation, String>;
+    async fn delete(&self, tag_id: Uuid, language_code: &str) -> Result<(), AppError>;
+}
+
+#[async_trait]
+impl<T: TagTranslationRepository + ?Sized + Send + Sync> TagTranslationRepository
+    for std::sync::Arc<T>
+{
+    async fn list_by_tag(&self, tag_id: Uuid) -> Result<Vec<TagTranslation>, String> {
+        (**self).list_by_tag(tag_id).await
+    }
+
+    async fn find(
+        &self,
+        tag_id: Uuid,
+        language_code: &str,
+    ) -> Result<Option<TagTranslation>, String> {
+        (**self).find(tag_id, language_code).await
+    }
+
+    async fn find_many(
+        &self,
+        tag_ids: &[Uuid],
+        language_code: &str,
+    ) -> Result<Vec<TagTranslation>, String> {
+        (**self).find_many(tag_ids, language_code).await
+    }
+
+    async fn upsert(&self, translation: NewTagTranslation) -> Result<TagTranslation, String> {
+        (**self).upsert(translation).await
+    }
+
+    async fn delete(&self, tag_id: Uuid, language_code: &str) -> Result<(), AppError> {
+        (**self).delete(tag_id, language_code).await
+    }
+}
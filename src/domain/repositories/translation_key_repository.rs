@@ -0,0 +1,125 @@
+use crate::domain::entities::translation_key::{BundleMessage, TranslationKey, TranslationValue};
+use crate::shared::error::AppError;
+use crate::shared::utils::query::PaginatedResult;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Default)]
+pub struct TranslationKeySearchFilter {
+    pub namespace: Option<String>,
+    /// Tìm theo `key` / `description`
+    pub search: Option<String>,
+}
+
+#[async_trait]
+pub trait TranslationKeyRepository: Send + Sync {
+    async fn search(
+        &self,
+        filter: &TranslationKeySearchFilter,
+        sort_by: Option<String>,
+        cursor: Option<String>,
+        limit: i64,
+    ) -> Result<PaginatedResult<TranslationKey>, AppError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<TranslationKey>, String>;
+    async fn find_by_name(
+        &self,
+        namespace: &str,
+        key: &str,
+    ) -> Result<Option<TranslationKey>, String>;
+    /// Tạo key cùng các giá trị ban đầu `(language_code, value)` trong 1 transaction
+    async fn create(
+        &self,
+        key: TranslationKey,
+        values: Vec<(String, String)>,
+    ) -> Result<TranslationKey, String>;
+    async fn update(&self, key: TranslationKey) -> Result<TranslationKey, String>;
+    /// Xoá mềm key cùng mọi giá trị của nó
+    async fn soft_delete(&self, id: Uuid) -> Result<(), AppError>;
+    async fn list_values(&self, key_ids: &[Uuid]) -> Result<Vec<TranslationValue>, String>;
+    async fn upsert_value(
+        &self,
+        key_id: Uuid,
+        language_code: &str,
+        value: &str,
+    ) -> Result<TranslationValue, String>;
+    async fn delete_value(&self, key_id: Uuid, language_code: &str) -> Result<(), AppError>;
+    /// Giá trị của mọi key trong `namespace` ở các ngôn ngữ `language_codes`
+    async fn bundle(
+        &self,
+        namespace: &str,
+        language_codes: &[String],
+    ) -> Result<Vec<BundleMessage>, String>;
+    async fn namespace_exists(&self, namespace: &str) -> Result<bool, String>;
+}
+
+#[async_trait]
+impl<T: TranslationKeyRepository + ?Sized + Send + Sync> TranslationKeyRepository
+    for std::sync::Arc<T>
+{
+    async fn search(
+        &self,
+        filter: &TranslationKeySearchFilter,
+        sort_by: Option<String>,
+        cursor: Option<String>,
+        limit: i64,
+    ) -> Result<PaginatedResult<TranslationKey>, AppError> {
+        (**self).search(filter, sort_by, cursor, limit).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<TranslationKey>, String> {
+        (**self).find_by_id(id).await
+    }
+
+    async fn find_by_name(
+        &self,
+        namespace: &str,
+        key: &str,
+    ) -> Result<Option<TranslationKey>, String> {
+        (**self).find_by_name(namespace, key).await
+    }
+
+    async fn create(
+        &self,
+        key: TranslationKey,
+        values: Vec<(String, String)>,
+    ) -> Result<TranslationKey, String> {
+        (**self).create(key, values).await
+    }
+
+    async fn update(&self, key: TranslationKey) -> Result<TranslationKey, String> {
+        (**self).update(key).await
+    }
+
+    async fn soft_delete(&self, id: Uuid) -> Result<(), AppError> {
+        (**self).soft_delete(id).await
+    }
+
+    async fn list_values(&self, key_ids: &[Uuid]) -> Result<Vec<TranslationValue>, String> {
+        (**self).list_values(key_ids).await
+    }
+
+    async fn upsert_value(
+        &self,
+        key_id: Uuid,
+        language_code: &str,
+        value: &str,
+    ) -> Result<TranslationValue, String> {
+        (**self).upsert_value(key_id, language_code, value).await
+    }
+
+    async fn delete_value(&self, key_id: Uuid, language_code: &str) -> Result<(), AppError> {
+        (**self).delete_value(key_id, language_code).await
+    }
+
+    async fn bundle(
+        &self,
+        namespace: &str,
+        language_codes: &[String],
+    ) -> Result<Vec<BundleMessage>, String> {
+        (**self).bundle(namespace, language_codes).await
+    }
+
+    async fn namespace_exists(&self, namespace: &str) -> Result<bool, String> {
+        (**self).namespace_exists(namespace).await
+    }
+}
@@ -0,0 +1,80 @@
+use crate::domain::entities::translation_status::{
+    TranslationCoverageRow, TranslationQueueItem, TranslationUnit,
+};
+use crate::shared::error::AppError;
+use crate::shared::utils::query::PaginatedResult;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Default)]
+pub struct TranslationQueueFilter {
+    pub language_code: String,
+    /// `post` | `category` | `tag` | `banner`
+    pub entity_type: Option<String>,
+    /// `missing` | `outdated`, bỏ trống => cả hai
+    pub status: Option<String>,
+    /// Tìm theo tiêu đề/tên
+    pub search: Option<String>,
+}
+
+/// Chọn bản ghi gốc để export/import (các điều kiện kết hợp AND)
+#[derive(Debug, Clone, Default)]
+pub struct TranslationUnitFilter {
+    pub language_code: String,
+    pub entity_type: Option<String>,
+    pub entity_ids: Option<Vec<Uuid>>,
+    /// Bản gốc đổi nội dung từ thời điểm này
+    pub changed_since: Option<NaiveDateTime>,
+}
+
+#[async_trait]
+pub trait TranslationStatusRepository: Send + Sync {
+    /// Đếm theo (loại nội dung, ngôn ngữ); `None` => mọi ngôn ngữ khác mặc định
+    async fn coverage(
+        &self,
+        language_code: Option<&str>,
+    ) -> Result<Vec<TranslationCoverageRow>, String>;
+    /// Bản ghi chưa dịch hoặc bản dịch đã cũ
+    async fn search_queue(
+        &self,
+        filter: &TranslationQueueFilter,
+        sort_by: Option<String>,
+        cursor: Option<String>,
+        limit: i64,
+    ) -> Result<PaginatedResult<TranslationQueueItem>, AppError>;
+    /// Từng trường cần dịch, sắp theo loại nội dung, thời điểm đổi nội dung gốc
+    async fn list_units(
+        &self,
+        filter: &TranslationUnitFilter,
+    ) -> Result<Vec<TranslationUnit>, String>;
+}
+
+#[async_trait]
+impl<T: TranslationStatusRepository + ?Sized + Send + Sync> TranslationStatusRepository
+    for std::sync::Arc<T>
+{
+    async fn coverage(
+        &self,
+        language_code: Option<&str>,
+    ) -> Result<Vec<TranslationCoverageRow>, String> {
+        (**self).coverage(language_code).await
+    }
+
+    async fn search_queue(
+        &self,
+        filter: &TranslationQueueFilter,
+        sort_by: Option<String>,
+        cursor: Option<String>,
+        limit: i64,
+    ) -> Result<PaginatedResult<TranslationQueueItem>, AppError> {
+        (**self).search_queue(filter, sort_by, cursor, limit).await
+    }
+
+    async fn list_units(
+        &self,
+        filter: &TranslationUnitFilter,
+    ) -> Result<Vec<TranslationUnit>, String> {
+        (**self).list_units(filter).await
+    }
+}
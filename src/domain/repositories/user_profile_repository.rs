@@ -5,6 +5,8 @@ use uuid::Uuid;
 #[async_trait]
 pub trait UserProfileRepository: Send + Sync {
     async fn find_by_user_id(&self, user_id: Uuid) -> Result<Option<UserProfile>, String>;
+    /// Lấy profile của nhiều user trong một query
+    async fn find_by_user_ids(&self, user_ids: &[Uuid]) -> Result<Vec<UserProfile>, String>;
     async fn upsert(&self, user_id: Uuid, data: UpdateUserProfile) -> Result<UserProfile, String>;
 }
 
@@ -14,6 +16,10 @@ impl<T: UserProfileRepository + ?Sized + Send + Sync> UserProfileRepository for
         (**self).find_by_user_id(user_id).await
     }
 
+    async fn find_by_user_ids(&self, user_ids: &[Uuid]) -> Result<Vec<UserProfile>, String> {
+        (**self).find_by_user_ids(user_ids).await
+    }
+
     async fn upsert(&self, user_id: Uuid, data: UpdateUserProfile) -> Result<UserProfile, String> {
         (**self).upsert(user_id, data).await
     }
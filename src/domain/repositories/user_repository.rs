@@ -1,4 +1,5 @@
 use crate::domain::entities::user::{NewUser, UpdateUser, User};
+use crate::shared::error::AppError;
 use crate::shared::utils::query::PaginatedResult;
 use async_trait::async_trait;
 
@@ -19,13 +20,15 @@ pub trait UserRepository: Send + Sync {
         sort_by: Option<String>,
         cursor: Option<String>,
         limit: i64,
-    ) -> Result<PaginatedResult<User>, String>;
+    ) -> Result<PaginatedResult<User>, AppError>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, String>;
+    /// Lấy nhiều user theo danh sách id (dùng cho batch-load quan hệ)
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<User>, String>;
     async fn find_by_email(&self, email: &str) -> Result<Option<User>, String>;
 
     async fn create(&self, user: NewUser) -> Result<User, String>;
     async fn update(&self, id: Uuid, user: UpdateUser) -> Result<User, String>;
-    async fn delete(&self, id: Uuid) -> Result<(), String>;
+    async fn delete(&self, id: Uuid) -> Result<(), AppError>;
 }
 
 #[async_trait]
@@ -40,7 +43,7 @@ impl<T: UserRepository + ?Sized + Send + Sync> UserRepository for std::sync::Arc
         sort_by: Option<String>,
         cursor: Option<String>,
         limit: i64,
-    ) -> Result<PaginatedResult<User>, String> {
+    ) -> Result<PaginatedResult<User>, AppError> {
         (**self).search(filter, sort_by, cursor, limit).await
     }
 
@@ -48,6 +51,10 @@ impl<T: UserRepository + ?Sized + Send + Sync> UserRepository for std::sync::Arc
         (**self).find_by_id(id).await
     }
 
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<User>, String> {
+        (**self).find_by_ids(ids).await
+    }
+
     async fn find_by_email(&self, email: &str) -> Result<Option<User>, String> {
         (**self).find_by_email(email).await
     }
@@ -60,7 +67,7 @@ impl<T: UserRepository + ?Sized + Send + Sync> UserRepository for std::sync::Arc
         (**self).update(id, user).await
     }
 
-    async fn delete(&self, id: Uuid) -> Result<(), String> {
+    async fn delete(&self, id: Uuid) -> Result<(), AppError> {
         (**self).delete(id).await
     }
 }
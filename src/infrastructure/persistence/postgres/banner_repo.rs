@@ -86,6 +86,26 @@ impl BannerRepository for PgBannerRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn list_items_by_banner_ids(
+        &self,
+        banner_ids: &[Uuid],
+    ) -> Result<Vec<BannerItem>, String> {
+        sqlx::query_as!(
+            BannerItem,
+            r#"
+            SELECT id, banner_id, title, subtitle, image_url, link_url, position,
+                   created_at, updated_at, deleted_at
+            FROM banner_items
+            WHERE banner_id = ANY($1) AND deleted_at IS NULL
+            ORDER BY banner_id, position NULLS LAST, created_at ASC
+            "#,
+            banner_ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
     async fn find_item_by_id(&self, id: Uuid) -> Result<Option<BannerItem>, String> {
         sqlx::query_as!(
             BannerItem,
@@ -0,0 +1,132 @@
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::domain::entities::banner_translation::{BannerTranslation, NewBannerTranslation};
+use crate::domain::repositories::banner_translation_repository::BannerTranslationRepository;
+use crate::shared::error::AppError;
+
+pub struct PgBannerTranslationRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgBannerTranslationRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BannerTranslationRepository for PgBannerTranslationRepository {
+    async fn list_by_banner(&self, banner_id: Uuid) -> Result<Vec<BannerTranslation>, String> {
+        sqlx::query_as!(
+            BannerTranslation,
+            r#"
+            SELECT id, banner_id, language_code, title, description,
+                   created_at, updated_at, deleted_at
+            FROM banner_translations
+            WHERE banner_id = $1 AND deleted_at IS NULL
+            ORDER BY language_code
+            "#,
+            banner_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find(
+        &self,
+        banner_id: Uuid,
+        language_code: &str,
+    ) -> Result<Option<BannerTranslation>, String> {
+        sqlx::query_as!(
+            BannerTranslation,
+            r#"
+            SELECT id, banner_id, language_code, title, description,
+                   created_at, updated_at, deleted_at
+            FROM banner_translations
+            WHERE banner_id = $1 AND language_code = $2 AND deleted_at IS NULL
+            "#,
+            banner_id,
+            language_code
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_many(
+        &self,
+        banner_ids: &[Uuid],
+        language_code: &str,
+    ) -> Result<Vec<BannerTranslation>, String> {
+        if banner_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        sqlx::query_as!(
+            BannerTranslation,
+            r#"
+            SELECT id, banner_id, language_code, title, description,
+                   created_at, updated_at, deleted_at
+            FROM banner_translations
+            WHERE banner_id = ANY($1) AND language_code = $2 AND deleted_at IS NULL
+            "#,
+            banner_ids,
+            language_code
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn upsert(&self, translation: NewBannerTranslation) -> Result<BannerTranslation, String> {
+        let saved = sqlx::query_as!(
+            BannerTranslation,
+            r#"
+            INSERT INTO banner_translations (banner_id, language_code, title, description)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (banner_id, language_code) DO UPDATE
+            SET title = EXCLUDED.title,
+                description = EXCLUDED.description,
+                updated_at = NOW(),
+                deleted_at = NULL
+            RETURNING id, banner_id, language_code, title, description,
+                      created_at, updated_at, deleted_at
+            "#,
+            translation.banner_id,
+            translation.language_code,
+            translation.title,
+            translation.description,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(saved)
+    }
+
+    async fn delete(&self, banner_id: Uuid, language_code: &str) -> Result<(), AppError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE banner_translations
+            SET deleted_at = NOW(), updated_at = NOW()
+            WHERE banner_id = $1 AND language_code = $2 AND deleted_at IS NULL
+            "#,
+            banner_id,
+            language_code
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found(
+                "BANNER_TRANSLATION_NOT_FOUND",
+                "Banner translation",
+            ));
+        }
+        Ok(())
+    }
+}
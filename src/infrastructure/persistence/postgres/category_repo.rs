@@ -1,17 +1,26 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use sqlx::{Pool, Postgres};
 use uuid::Uuid;
 
 use crate::domain::entities::category::Category;
 use crate::domain::repositories::category_repository::CategoryRepository;
+use crate::domain::repositories::slug_repository::SlugScope;
+use crate::infrastructure::persistence::postgres::slug_redirect_repo::record_slug_change_tx;
+use crate::shared::sitemap_cache::SitemapCache;
 
 pub struct PgCategoryRepository {
     pool: Pool<Postgres>,
+    sitemap_cache: Arc<SitemapCache>,
 }
 
 impl PgCategoryRepository {
-    pub fn new(pool: Pool<Postgres>) -> Self {
-        Self { pool }
+    pub fn new(pool: Pool<Postgres>, sitemap_cache: Arc<SitemapCache>) -> Self {
+        Self {
+            pool,
+            sitemap_cache,
+        }
     }
 }
 
@@ -33,6 +42,49 @@ impl CategoryRepository for PgCategoryRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Category>, String> {
+        sqlx::query_as!(
+            Category,
+            r#"
+            SELECT id, parent_id, name, slug, type, description,
+                   created_at, updated_at, deleted_at
+            FROM categories
+            WHERE id = ANY($1) AND deleted_at IS NULL
+            "#,
+            ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_ancestors(&self, id: Uuid) -> Result<Vec<Category>, String> {
+        // `depth` chặn vòng lặp nếu dữ liệu parent_id bị trỏ vòng
+        sqlx::query_as!(
+            Category,
+            r#"
+            WITH RECURSIVE chain AS (
+                SELECT c.*, 0 AS depth
+                FROM categories c
+                WHERE c.id = $1 AND c.deleted_at IS NULL
+                UNION ALL
+                SELECT p.*, chain.depth + 1
+                FROM categories p
+                INNER JOIN chain ON p.id = chain.parent_id
+                WHERE p.deleted_at IS NULL AND chain.depth < 32
+            )
+            SELECT id as "id!", parent_id, name as "name!", slug, type as "type!", description,
+                   created_at, updated_at, deleted_at
+            FROM chain
+            ORDER BY depth DESC
+            "#,
+            id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
     async fn find_by_slug_and_type(
         &self,
         slug: &str,
@@ -71,6 +123,64 @@ impl CategoryRepository for PgCategoryRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn list_tree(&self, r#type: &str, max_depth: i32) -> Result<Vec<Category>, String> {
+        // Con của category đã xoá mềm bị ẩn theo cha
+        sqlx::query_as!(
+            Category,
+            r#"
+            WITH RECURSIVE tree AS (
+                SELECT c.*, 1 AS depth
+                FROM categories c
+                WHERE c.type = $1 AND c.parent_id IS NULL AND c.deleted_at IS NULL
+                UNION ALL
+                SELECT c.*, tree.depth + 1
+                FROM categories c
+                INNER JOIN tree ON c.parent_id = tree.id
+                WHERE c.type = tree.type AND c.deleted_at IS NULL AND tree.depth < $2
+            )
+            SELECT id as "id!", parent_id, name as "name!", slug, type as "type!", description,
+                   created_at, updated_at, deleted_at
+            FROM tree
+            ORDER BY depth, name
+            "#,
+            r#type,
+            max_depth
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_descendants(&self, id: Uuid, max_depth: i32) -> Result<Vec<Category>, String> {
+        // `c.id <> $1` chặn vòng lặp nếu dữ liệu parent_id bị trỏ vòng về gốc
+        sqlx::query_as!(
+            Category,
+            r#"
+            WITH RECURSIVE tree AS (
+                SELECT c.*, 1 AS depth
+                FROM categories c
+                INNER JOIN categories root ON root.id = c.parent_id
+                WHERE root.id = $1 AND c.type = root.type AND c.deleted_at IS NULL
+                UNION ALL
+                SELECT c.*, tree.depth + 1
+                FROM categories c
+                INNER JOIN tree ON c.parent_id = tree.id
+                WHERE c.type = tree.type AND c.deleted_at IS NULL
+                  AND c.id <> $1 AND tree.depth < $2
+            )
+            SELECT id as "id!", parent_id, name as "name!", slug, type as "type!", description,
+                   created_at, updated_at, deleted_at
+            FROM tree
+            ORDER BY depth, name
+            "#,
+            id,
+            max_depth
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
     async fn create(&self, category: Category) -> Result<Category, String> {
         let created = sqlx::query_as!(
             Category,
@@ -94,10 +204,21 @@ impl CategoryRepository for PgCategoryRepository {
         .await
         .map_err(|e| e.to_string())?;
 
+        self.sitemap_cache.invalidate();
         Ok(created)
     }
 
     async fn update(&self, category: Category) -> Result<Category, String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        let previous = sqlx::query!(
+            "SELECT slug, type FROM categories WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+            category.id
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
         let updated = sqlx::query_as!(
             Category,
             r#"
@@ -119,10 +240,33 @@ impl CategoryRepository for PgCategoryRepository {
             category.r#type,
             category.description,
         )
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| e.to_string())?;
 
+        // URL cũ (`slug` + `type`) => 301 tới slug + type mới
+        if let Some(previous) = previous
+            && let (Some(old), Some(new)) = (previous.slug.as_deref(), updated.slug.as_deref())
+            && !old.is_empty()
+            && (old != new || previous.r#type != updated.r#type)
+        {
+            record_slug_change_tx(
+                &mut tx,
+                SlugScope::Category {
+                    r#type: &previous.r#type,
+                },
+                SlugScope::Category {
+                    r#type: &updated.r#type,
+                },
+                updated.id,
+                old,
+                new,
+            )
+            .await?;
+        }
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        self.sitemap_cache.invalidate();
         Ok(updated)
     }
 
@@ -139,6 +283,7 @@ impl CategoryRepository for PgCategoryRepository {
         .await
         .map_err(|e| e.to_string())?;
 
+        self.sitemap_cache.invalidate();
         Ok(())
     }
 
@@ -159,6 +304,7 @@ impl CategoryRepository for PgCategoryRepository {
         .await
         .map_err(|e| e.to_string())?;
 
+        self.sitemap_cache.invalidate();
         Ok(())
     }
 }
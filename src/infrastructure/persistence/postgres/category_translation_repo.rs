@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::domain::entities::category_translation::{CategoryTranslation, NewCategoryTranslation};
+use crate::domain::repositories::category_translation_repository::CategoryTranslationRepository;
+use crate::shared::error::AppError;
+use crate::shared::sitemap_cache::SitemapCache;
+
+pub struct PgCategoryTranslationRepository {
+    pool: Pool<Postgres>,
+    sitemap_cache: Arc<SitemapCache>,
+}
+
+impl PgCategoryTranslationRepository {
+    pub fn new(pool: Pool<Postgres>, sitemap_cache: Arc<SitemapCache>) -> Self {
+        Self {
+            pool,
+            sitemap_cache,
+        }
+    }
+}
+
+#[async_trait]
+impl CategoryTranslationRepository for PgCategoryTranslationRepository {
+    async fn list_by_category(
+        &self,
+        category_id: Uuid,
+    ) -> Result<Vec<CategoryTranslation>, String> {
+        sqlx::query_as!(
+            CategoryTranslation,
+            r#"
+            SELECT id, category_id, language_code, name, description,
+                   created_at, updated_at, deleted_at
+            FROM category_translations
+            WHERE category_id = $1 AND deleted_at IS NULL
+            ORDER BY language_code
+            "#,
+            category_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find(
+        &self,
+        category_id: Uuid,
+        language_code: &str,
+    ) -> Result<Option<CategoryTranslation>, String> {
+        sqlx::query_as!(
+            CategoryTranslation,
+            r#"
+            SELECT id, category_id, language_code, name, description,
+                   created_at, updated_at, deleted_at
+            FROM category_translations
+            WHERE category_id = $1 AND language_code = $2 AND deleted_at IS NULL
+            "#,
+            category_id,
+            language_code
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_many(
+        &self,
+        category_ids: &[Uuid],
+        language_code: &str,
+    ) -> Result<Vec<CategoryTranslation>, String> {
+        if category_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        sqlx::query_as!(
+            CategoryTranslation,
+            r#"
+            SELECT id, category_id, language_code, name, description,
+                   created_at, updated_at, deleted_at
+            FROM category_translations
+            WHERE category_id = ANY($1) AND language_code = $2 AND deleted_at IS NULL
+            "#,
+            category_ids,
+            language_code
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn upsert(
+        &self,
+        translation: NewCategoryTranslation,
+    ) -> Result<CategoryTranslation, String> {
+        let saved = sqlx::query_as!(
+            CategoryTranslation,
+            r#"
+            INSERT INTO category_translations (category_id, language_code, name, description)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (category_id, language_code) DO UPDATE
+            SET name = EXCLUDED.name,
+                description = EXCLUDED.description,
+                updated_at = NOW(),
+                deleted_at = NULL
+            RETURNING id, category_id, language_code, name, description,
+                      created_at, updated_at, deleted_at
+            "#,
+            translation.category_id,
+            translation.language_code,
+            translation.name,
+            translation.description,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        self.sitemap_cache.invalidate();
+        Ok(saved)
+    }
+
+    async fn delete(&self, category_id: Uuid, language_code: &str) -> Result<(), AppError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE category_translations
+            SET deleted_at = NOW(), updated_at = NOW()
+            WHERE category_id = $1 AND language_code = $2 AND deleted_at IS NULL
+            "#,
+            category_id,
+            language_code
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found(
+                "CATEGORY_TRANSLATION_NOT_FOUND",
+                "Category translation",
+            ));
+        }
+        self.sitemap_cache.invalidate();
+        Ok(())
+    }
+}
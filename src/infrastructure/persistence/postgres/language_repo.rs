@@ -1,36 +1,107 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
-use sqlx::{Pool, Postgres};
+use sqlx::{PgExecutor, Pool, Postgres};
 
 use crate::domain::entities::language::Language;
-use crate::domain::repositories::language_repository::LanguageRepository;
+use crate::domain::repositories::language_repository::{
+    LanguageDeletion, LanguageRepository, TranslationDisposal,
+};
+use crate::infrastructure::persistence::postgres::translation_key_repo::MessageBundleCache;
+use crate::shared::error::{AppError, ErrorKind};
+use crate::shared::read_cache::ReadCache;
+use crate::shared::sitemap_cache::SitemapCache;
+
+/// Bảng chứa dữ liệu theo `language_code`: (bảng, cột xác định entity)
+const TRANSLATION_TABLES: &[(&str, &[&str])] = &[
+    ("post_translations", &["post_id"]),
+    ("category_translations", &["category_id"]),
+    ("tag_translations", &["tag_id"]),
+    ("banner_translations", &["banner_id"]),
+    ("seo_metadata", &["entity_type", "entity_id"]),
+    ("translation_values", &["key_id"]),
+];
 
 #[allow(dead_code)]
 pub struct PgLanguageRepository {
     pool: Pool<Postgres>,
+    sitemap_cache: Arc<SitemapCache>,
+    /// Xoá ngôn ngữ xoá/chuyển `translation_values` sang ngôn ngữ khác
+    bundles: Arc<MessageBundleCache>,
+    /// `list_all` chạy ở mọi request đọc nội dung (chọn ngôn ngữ)
+    languages: ReadCache<(), Vec<Language>>,
 }
 
 #[allow(dead_code)]
 impl PgLanguageRepository {
-    pub fn new(pool: Pool<Postgres>) -> Self {
-        Self { pool }
+    pub fn new(
+        pool: Pool<Postgres>,
+        sitemap_cache: Arc<SitemapCache>,
+        bundles: Arc<MessageBundleCache>,
+    ) -> Self {
+        Self {
+            pool,
+            sitemap_cache,
+            bundles,
+            languages: ReadCache::new(),
+        }
     }
 }
 
+/// Số bản dịch (post/category/tag/banner/SEO) chưa xoá của ngôn ngữ
+async fn count_active_translations(
+    executor: impl PgExecutor<'_>,
+    code: &str,
+) -> Result<i64, String> {
+    sqlx::query_scalar!(
+        r#"
+        SELECT (
+            (SELECT COUNT(*) FROM post_translations WHERE language_code = $1 AND deleted_at IS NULL)
+          + (SELECT COUNT(*) FROM category_translations WHERE language_code = $1 AND deleted_at IS NULL)
+          + (SELECT COUNT(*) FROM tag_translations WHERE language_code = $1 AND deleted_at IS NULL)
+          + (SELECT COUNT(*) FROM banner_translations WHERE language_code = $1 AND deleted_at IS NULL)
+          + (SELECT COUNT(*) FROM seo_metadata WHERE language_code = $1 AND deleted_at IS NULL)
+          + (SELECT COUNT(*) FROM translation_values WHERE language_code = $1 AND deleted_at IS NULL)
+        ) AS "count!"
+        "#,
+        code
+    )
+    .fetch_one(executor)
+    .await
+    .map_err(|e| e.to_string())
+}
+
+/// `(a.col1, a.col2) = (b.col1, b.col2)`
+fn same_owner(owner: &[&str], a: &str, b: &str) -> String {
+    let cols = |alias: &str| {
+        owner
+            .iter()
+            .map(|c| format!("{alias}.{c}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    format!("({}) = ({})", cols(a), cols(b))
+}
+
 #[async_trait]
 impl LanguageRepository for PgLanguageRepository {
     async fn list_all(&self) -> Result<Vec<Language>, String> {
-        sqlx::query_as!(
-            Language,
-            r#"
-            SELECT id, code, name, is_default, created_at, updated_at, deleted_at
-            FROM languages
-            WHERE deleted_at IS NULL
-            ORDER BY code
-            "#
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| e.to_string())
+        self.languages
+            .get_or_load((), || async {
+                sqlx::query_as!(
+                    Language,
+                    r#"
+                    SELECT id, code, name, is_default, created_at, updated_at, deleted_at
+                    FROM languages
+                    WHERE deleted_at IS NULL
+                    ORDER BY code
+                    "#
+                )
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| e.to_string())
+            })
+            .await
     }
 
     async fn get_default(&self) -> Result<Option<Language>, String> {
@@ -63,4 +134,255 @@ impl LanguageRepository for PgLanguageRepository {
         .await
         .map_err(|e| e.to_string())
     }
+
+    async fn create(&self, code: &str, name: &str) -> Result<Option<Language>, String> {
+        // Mã đã xoá mềm => khôi phục (giữ nguyên bản dịch cũ đã xoá mềm)
+        let created = sqlx::query_as!(
+            Language,
+            r#"
+            INSERT INTO languages (code, name, is_default, created_at, updated_at)
+            VALUES ($1, $2, FALSE, NOW(), NOW())
+            ON CONFLICT (code) DO UPDATE
+            SET name = EXCLUDED.name, is_default = FALSE, deleted_at = NULL, updated_at = NOW()
+            WHERE languages.deleted_at IS NOT NULL
+            RETURNING id, code, name, is_default, created_at, updated_at, deleted_at
+            "#,
+            code,
+            name
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        if created.is_some() {
+            self.languages.invalidate();
+            self.sitemap_cache.invalidate();
+        }
+        Ok(created)
+    }
+
+    async fn update_name(&self, code: &str, name: &str) -> Result<Option<Language>, String> {
+        let result = sqlx::query_as!(
+            Language,
+            r#"
+            UPDATE languages
+            SET name = $2, updated_at = NOW()
+            WHERE code = $1 AND deleted_at IS NULL
+            RETURNING id, code, name, is_default, created_at, updated_at, deleted_at
+            "#,
+            code,
+            name
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.to_string());
+        self.languages.invalidate_on_ok(result)
+    }
+
+    async fn set_default(&self, code: &str) -> Result<Option<Language>, String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        // Khoá mọi ngôn ngữ để 2 request đổi mặc định đồng thời chạy tuần tự
+        sqlx::query!("SELECT id FROM languages WHERE deleted_at IS NULL FOR UPDATE")
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        // Bỏ cờ trước rồi mới bật: index unique kiểm tra theo từng dòng
+        sqlx::query!(
+            r#"
+            UPDATE languages
+            SET is_default = FALSE, updated_at = NOW()
+            WHERE is_default AND code <> $1
+            "#,
+            code
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        let language = sqlx::query_as!(
+            Language,
+            r#"
+            UPDATE languages
+            SET is_default = TRUE, updated_at = NOW()
+            WHERE code = $1 AND deleted_at IS NULL
+            RETURNING id, code, name, is_default, created_at, updated_at, deleted_at
+            "#,
+            code
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        // Không tìm thấy => rollback, giữ ngôn ngữ mặc định cũ
+        if language.is_some() {
+            tx.commit().await.map_err(|e| e.to_string())?;
+            self.languages.invalidate();
+            self.sitemap_cache.invalidate();
+        }
+        Ok(language)
+    }
+
+    async fn soft_delete(
+        &self,
+        code: &str,
+        disposal: TranslationDisposal,
+    ) -> Result<LanguageDeletion, AppError> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        let is_default = sqlx::query_scalar!(
+            r#"
+            SELECT COALESCE(is_default, FALSE) AS "is_default!"
+            FROM languages
+            WHERE code = $1 AND deleted_at IS NULL
+            FOR UPDATE
+            "#,
+            code
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        match is_default {
+            None => return Ok(LanguageDeletion::NotFound),
+            Some(true) => return Ok(LanguageDeletion::IsDefault),
+            Some(false) => {}
+        }
+
+        let count = count_active_translations(&mut *tx, code).await?;
+        if count > 0 && disposal == TranslationDisposal::Refuse {
+            return Ok(LanguageDeletion::HasTranslations { count });
+        }
+
+        let mut migrated = 0;
+        if let TranslationDisposal::Migrate { to } = &disposal {
+            let target = sqlx::query_scalar!(
+                "SELECT id FROM languages WHERE code = $1 AND deleted_at IS NULL FOR SHARE",
+                to
+            )
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+            if target.is_none() {
+                return Err(AppError::new(
+                    ErrorKind::NotFound,
+                    "LANGUAGE_NOT_FOUND",
+                    format!("Language {to} not found"),
+                )
+                .with("code", to.as_str()));
+            }
+
+            // Slug bản dịch unique theo ngôn ngữ: trùng với bài khác ở ngôn ngữ đích => dừng lại để xử lý tay
+            let conflicts = sqlx::query_scalar!(
+                r#"
+                SELECT s.slug AS "slug!"
+                FROM post_translations s
+                JOIN post_translations d
+                  ON d.language_code = $2 AND d.slug = s.slug
+                 AND d.post_id <> s.post_id AND d.deleted_at IS NULL
+                WHERE s.language_code = $1 AND s.deleted_at IS NULL
+                  AND NOT EXISTS (
+                      SELECT 1 FROM post_translations o
+                      WHERE o.post_id = s.post_id AND o.language_code = $2 AND o.deleted_at IS NULL
+                  )
+                ORDER BY s.slug
+                "#,
+                code,
+                to
+            )
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+            if !conflicts.is_empty() {
+                return Ok(LanguageDeletion::SlugConflicts { slugs: conflicts });
+            }
+
+            for (table, owner) in TRANSLATION_TABLES {
+                // Bản dịch đã xoá mềm ở ngôn ngữ đích chặn unique constraint => xoá hẳn
+                sqlx::query(&format!(
+                    "DELETE FROM {table} d
+                     WHERE d.language_code = $2 AND d.deleted_at IS NOT NULL
+                       AND EXISTS (
+                           SELECT 1 FROM {table} s
+                           WHERE s.language_code = $1 AND s.deleted_at IS NULL AND {same}
+                       )",
+                    same = same_owner(owner, "s", "d"),
+                ))
+                .bind(code)
+                .bind(to)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| e.to_string())?;
+
+                // Giữ `updated_at`: nội dung bản dịch không đổi (trạng thái bản dịch so theo mốc này)
+                migrated += sqlx::query(&format!(
+                    "UPDATE {table} s
+                     SET language_code = $2
+                     WHERE s.language_code = $1 AND s.deleted_at IS NULL
+                       AND NOT EXISTS (
+                           SELECT 1 FROM {table} d
+                           WHERE d.language_code = $2 AND {same}
+                       )",
+                    same = same_owner(owner, "s", "d"),
+                ))
+                .bind(code)
+                .bind(to)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| e.to_string())?
+                .rows_affected() as i64;
+            }
+        }
+
+        // Phần còn lại (cascade, hoặc trùng với bản dịch sẵn có ở ngôn ngữ đích)
+        let mut removed = 0;
+        for (table, _) in TRANSLATION_TABLES {
+            removed += sqlx::query(&format!(
+                "UPDATE {table}
+                 SET deleted_at = NOW(), updated_at = NOW()
+                 WHERE language_code = $1 AND deleted_at IS NULL"
+            ))
+            .bind(code)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?
+            .rows_affected() as i64;
+        }
+
+        let replacement = match &disposal {
+            TranslationDisposal::Migrate { to } => Some(to.as_str()),
+            _ => None,
+        };
+        sqlx::query!(
+            r#"
+            UPDATE user_profiles
+            SET preferred_language = $2, updated_at = NOW()
+            WHERE preferred_language = $1
+            "#,
+            code,
+            replacement
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        sqlx::query!(
+            r#"
+            UPDATE languages
+            SET deleted_at = NOW(), updated_at = NOW()
+            WHERE code = $1
+            "#,
+            code
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        self.languages.invalidate();
+        self.sitemap_cache.invalidate();
+        self.bundles.invalidate();
+        Ok(LanguageDeletion::Deleted { migrated, removed })
+    }
 }
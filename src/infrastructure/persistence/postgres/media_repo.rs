@@ -3,9 +3,10 @@ use sqlx::{Pool, Postgres};
 
 use crate::domain::entities::media::{Media, NewMedia};
 use crate::domain::repositories::media_repository::{MediaRepository, MediaSearchFilter};
+use crate::shared::error::AppError;
 use crate::shared::utils::query::{
-    BindValue, FieldInfo, FieldType, ListParams, PaginatedResult, SortDirection, build_query,
-    build_query_with_seed, encode_cursor_text, encode_cursor_ts,
+    BindValue, CursorRow, CursorValue, FieldInfo, FieldType, ListParams, PaginatedResult,
+    SortDirection, build_query, build_query_with_seed,
 };
 
 pub struct PgMediaRepository {
@@ -20,6 +21,21 @@ impl PgMediaRepository {
 
 use uuid::Uuid;
 
+impl CursorRow for Media {
+    fn cursor_id(&self) -> Uuid {
+        self.id
+    }
+
+    fn cursor_value(&self, field: &str) -> CursorValue {
+        match field {
+            "media_type" => CursorValue::Text(Some(self.media_type.clone())),
+            "file_path" => CursorValue::Text(Some(self.file_path.clone())),
+            "created_at" => CursorValue::Timestamp(self.created_at),
+            _ => CursorValue::Unsupported,
+        }
+    }
+}
+
 #[async_trait]
 impl MediaRepository for PgMediaRepository {
     async fn create(&self, media: NewMedia) -> Result<Media, String> {
@@ -70,6 +86,20 @@ impl MediaRepository for PgMediaRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Media>, String> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        sqlx::query_as!(
+            Media,
+            r#"SELECT id, user_id, media_type, file_path, created_at, updated_at, deleted_at FROM media WHERE id = ANY($1) AND deleted_at IS NULL"#,
+            ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
     async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<Media>, String> {
         sqlx::query_as!(
             Media,
@@ -87,7 +117,7 @@ impl MediaRepository for PgMediaRepository {
         sort_by: Option<String>,
         cursor: Option<String>,
         limit: i64,
-    ) -> Result<PaginatedResult<Media>, String> {
+    ) -> Result<PaginatedResult<Media>, AppError> {
         let allowed_fields = [
             FieldInfo {
                 name: "media_type",
@@ -111,6 +141,7 @@ impl MediaRepository for PgMediaRepository {
             sort_by,
             cursor,
             limit: Some(limit),
+            ..Default::default()
         };
 
         let built = if let Some(uid) = filter.user_id {
@@ -136,7 +167,7 @@ impl MediaRepository for PgMediaRepository {
         };
 
         let mut query = sqlx::query_as::<_, Media>(&built.sql);
-        for b in built.binds {
+        for b in built.binds.clone() {
             query = match b {
                 BindValue::Text(v) => query.bind(v),
                 BindValue::Timestamp(v) => query.bind(v),
@@ -149,20 +180,7 @@ impl MediaRepository for PgMediaRepository {
             .fetch_all(&self.pool)
             .await
             .map_err(|e| e.to_string())?;
-        let next_cursor = if items.len() as i64 == built.limit {
-            if let Some(last) = items.last() {
-                match built.sort_field {
-                    "media_type" => Some(encode_cursor_text(&last.media_type, last.id)),
-                    "file_path" => Some(encode_cursor_text(&last.file_path, last.id)),
-                    "created_at" => last.created_at.map(|dt| encode_cursor_ts(dt, last.id)),
-                    _ => None,
-                }
-            } else {
-                None
-            }
-        } else {
-            None
-        };
+        let next_cursor = built.next_cursor(&items)?;
 
         Ok(PaginatedResult {
             items,
@@ -1,12 +1,26 @@
 pub mod audit_log_repo;
 pub mod banner_repo;
+pub mod banner_translation_repo;
 pub mod category_repo;
+pub mod category_translation_repo;
 pub mod language_repo;
 pub mod media_repo;
 pub mod permission_repo;
+pub mod post_media_repo;
 pub mod post_repo;
+pub mod post_review_repo;
+pub mod post_revision_repo;
+pub mod post_translation_repo;
+pub mod redirect_repo;
 pub mod role_repo;
+pub mod seo_metadata_repo;
 pub mod setting_repo;
+pub mod sitemap_repo;
+pub mod slug_redirect_repo;
+pub mod slug_repo;
 pub mod tag_repo;
+pub mod tag_translation_repo;
+pub mod translation_key_repo;
+pub mod translation_status_repo;
 pub mod user_profile_repo;
 pub mod user_repo;
@@ -6,9 +6,10 @@ use crate::domain::entities::permission::{NewPermission, Permission, UpdatePermi
 use crate::domain::repositories::permission_repository::{
     PermissionRepository, PermissionSearchFilter,
 };
+use crate::shared::error::AppError;
 use crate::shared::utils::query::{
-    BindValue, FieldInfo, FieldType, ListParams, PaginatedResult, SortDirection, build_query,
-    encode_cursor_text, encode_cursor_ts,
+    BindValue, CursorRow, CursorValue, FieldInfo, FieldType, ListParams, PaginatedResult,
+    SortDirection, build_query,
 };
 
 pub struct PgPermissionRepository {
@@ -21,6 +22,20 @@ impl PgPermissionRepository {
     }
 }
 
+impl CursorRow for Permission {
+    fn cursor_id(&self) -> Uuid {
+        self.id
+    }
+
+    fn cursor_value(&self, field: &str) -> CursorValue {
+        match field {
+            "name" => CursorValue::Text(Some(self.name.clone())),
+            "created_at" => CursorValue::Timestamp(self.created_at),
+            _ => CursorValue::Unsupported,
+        }
+    }
+}
+
 #[async_trait]
 impl PermissionRepository for PgPermissionRepository {
     async fn find_all(&self) -> Result<Vec<Permission>, String> {
@@ -39,7 +54,7 @@ impl PermissionRepository for PgPermissionRepository {
         sort_by: Option<String>,
         cursor: Option<String>,
         limit: i64,
-    ) -> Result<PaginatedResult<Permission>, String> {
+    ) -> Result<PaginatedResult<Permission>, AppError> {
         let allowed_fields = [
             FieldInfo {
                 name: "name",
@@ -59,6 +74,7 @@ impl PermissionRepository for PgPermissionRepository {
             sort_by,
             cursor,
             limit: Some(limit),
+            ..Default::default()
         };
 
         let built = build_query(
@@ -71,7 +87,7 @@ impl PermissionRepository for PgPermissionRepository {
         )?;
 
         let mut query = sqlx::query_as::<_, Permission>(&built.sql);
-        for b in built.binds {
+        for b in built.binds.clone() {
             query = match b {
                 BindValue::Text(v) => query.bind(v),
                 BindValue::Timestamp(v) => query.bind(v),
@@ -84,19 +100,7 @@ impl PermissionRepository for PgPermissionRepository {
             .fetch_all(&self.pool)
             .await
             .map_err(|e| e.to_string())?;
-        let next_cursor = if items.len() as i64 == built.limit {
-            if let Some(last) = items.last() {
-                match built.sort_field {
-                    "name" => Some(encode_cursor_text(&last.name, last.id)),
-                    "created_at" => last.created_at.map(|dt| encode_cursor_ts(dt, last.id)),
-                    _ => None,
-                }
-            } else {
-                None
-            }
-        } else {
-            None
-        };
+        let next_cursor = built.next_cursor(&items)?;
 
         Ok(PaginatedResult {
             items,
@@ -154,7 +158,7 @@ impl PermissionRepository for PgPermissionRepository {
         .map_err(|e| e.to_string())
     }
 
-    async fn delete(&self, id: Uuid) -> Result<(), String> {
+    async fn delete(&self, id: Uuid) -> Result<(), AppError> {
         let result = sqlx::query!(
             "UPDATE permissions SET deleted_at = NOW() WHERE id = $1",
             id
@@ -164,7 +168,7 @@ impl PermissionRepository for PgPermissionRepository {
         .map_err(|e| e.to_string())?;
 
         if result.rows_affected() == 0 {
-            return Err("Permission not found".to_string());
+            return Err(AppError::not_found("PERMISSION_NOT_FOUND", "Permission"));
         }
 
         Ok(())
@@ -232,4 +236,24 @@ impl PermissionRepository for PgPermissionRepository {
 
         Ok(())
     }
+
+    async fn user_has_permission(&self, user_id: Uuid, name: &str) -> Result<bool, String> {
+        sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM user_roles ur
+                INNER JOIN roles r ON r.id = ur.role_id AND r.deleted_at IS NULL
+                INNER JOIN role_permissions rp ON rp.role_id = ur.role_id AND rp.deleted_at IS NULL
+                INNER JOIN permissions p ON p.id = rp.permission_id AND p.deleted_at IS NULL
+                WHERE ur.user_id = $1 AND ur.deleted_at IS NULL AND p.name = $2
+            ) as "exists!"
+            "#,
+            user_id,
+            name
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
 }
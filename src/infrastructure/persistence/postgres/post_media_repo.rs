@@ -0,0 +1,136 @@
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::domain::entities::post_media::{NewPostMedia, PostMedia};
+use crate::domain::repositories::post_media_repository::PostMediaRepository;
+use crate::shared::error::AppError;
+
+pub struct PgPostMediaRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgPostMediaRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PostMediaRepository for PgPostMediaRepository {
+    async fn list_by_post_ids(&self, post_ids: &[Uuid]) -> Result<Vec<PostMedia>, String> {
+        if post_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        sqlx::query_as!(
+            PostMedia,
+            r#"
+            SELECT pm.id, pm.post_id, pm.media_id, pm.position, pm.caption,
+                   pm.created_at, pm.updated_at, pm.deleted_at
+            FROM post_media pm
+            INNER JOIN media m ON m.id = pm.media_id
+            WHERE pm.post_id = ANY($1) AND pm.deleted_at IS NULL AND m.deleted_at IS NULL
+            ORDER BY pm.post_id, pm.position, pm.created_at
+            "#,
+            post_ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn replace(&self, post_id: Uuid, items: &[NewPostMedia]) -> Result<(), String> {
+        let media_ids: Vec<Uuid> = items.iter().map(|i| i.media_id).collect();
+        let positions: Vec<i32> = (0..items.len() as i32).collect();
+        let captions: Vec<Option<String>> = items.iter().map(|i| i.caption.clone()).collect();
+
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        sqlx::query!(
+            r#"
+            UPDATE post_media
+            SET deleted_at = NOW(), updated_at = NOW()
+            WHERE post_id = $1 AND deleted_at IS NULL AND NOT (media_id = ANY($2))
+            "#,
+            post_id,
+            &media_ids
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO post_media (post_id, media_id, position, caption)
+            SELECT $1, x.media_id, x.position, x.caption
+            FROM UNNEST($2::uuid[], $3::int[], $4::text[]) AS x(media_id, position, caption)
+            ON CONFLICT (post_id, media_id)
+            DO UPDATE SET position = EXCLUDED.position, caption = EXCLUDED.caption,
+                          deleted_at = NULL, updated_at = NOW()
+            "#,
+            post_id,
+            &media_ids,
+            &positions,
+            &captions as &[Option<String>]
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        tx.commit().await.map_err(|e| e.to_string())
+    }
+
+    async fn append(&self, post_id: Uuid, items: &[NewPostMedia]) -> Result<(), String> {
+        let media_ids: Vec<Uuid> = items.iter().map(|i| i.media_id).collect();
+        let positions: Vec<i32> = (0..items.len() as i32).collect();
+        let captions: Vec<Option<String>> = items.iter().map(|i| i.caption.clone()).collect();
+
+        // Media đang có trong gallery giữ nguyên vị trí, chỉ cập nhật caption
+        sqlx::query!(
+            r#"
+            WITH last AS (
+                SELECT COALESCE(MAX(position) + 1, 0) AS next
+                FROM post_media
+                WHERE post_id = $1 AND deleted_at IS NULL
+            )
+            INSERT INTO post_media (post_id, media_id, position, caption)
+            SELECT $1, x.media_id, last.next + x.position, x.caption
+            FROM UNNEST($2::uuid[], $3::int[], $4::text[]) AS x(media_id, position, caption), last
+            ON CONFLICT (post_id, media_id)
+            DO UPDATE SET position = CASE WHEN post_media.deleted_at IS NULL
+                                          THEN post_media.position ELSE EXCLUDED.position END,
+                          caption = EXCLUDED.caption,
+                          deleted_at = NULL, updated_at = NOW()
+            "#,
+            post_id,
+            &media_ids,
+            &positions,
+            &captions as &[Option<String>]
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    async fn remove(&self, post_id: Uuid, media_id: Uuid) -> Result<(), AppError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE post_media
+            SET deleted_at = NOW(), updated_at = NOW()
+            WHERE post_id = $1 AND media_id = $2 AND deleted_at IS NULL
+            "#,
+            post_id,
+            media_id
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found("POST_MEDIA_NOT_FOUND", "Post media"));
+        }
+        Ok(())
+    }
+}
@@ -1,17 +1,100 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
-use sqlx::{Pool, Postgres};
+use sqlx::{Pool, Postgres, Transaction};
 use uuid::Uuid;
 
-use crate::domain::entities::post::{Post, PostStatus};
-use crate::domain::repositories::post_repository::{PostRepository, PostSearchFilter};
+use crate::domain::entities::post::{
+    ContentFormat, Post, PostFeedItem, PostScheduleTransition, PostSearchHit, PostStatus,
+};
+use crate::domain::entities::post_review::PostReviewComment;
+use crate::domain::repositories::post_repository::{
+    PostFeedQuery, PostFullTextQuery, PostRepository, PostRevisionOptions, PostSearchFilter,
+};
+use crate::domain::repositories::slug_repository::SlugScope;
+use crate::infrastructure::persistence::postgres::slug_redirect_repo::record_slug_change_tx;
+use crate::shared::error::AppError;
+use crate::shared::sitemap_cache::SitemapCache;
+use crate::shared::utils::query::{
+    BindValue, CursorRow, CursorValue, FieldInfo, FieldType, ListParams, PaginatedResult,
+    SortDirection, build_query_with_seed,
+};
+
+/// Tài liệu tìm kiếm: bài gốc (ngôn ngữ mặc định, config 'simple') + từng bản dịch.
+const SEARCH_DOCS_CTE: &str = r#"
+    docs AS (
+        SELECT p.id AS post_id,
+               (SELECT code FROM languages WHERE is_default AND deleted_at IS NULL LIMIT 1) AS language_code,
+               p.title, p.excerpt, coalesce(p.content_text, p.content) AS content,
+               p.search_vector,
+               'simple'::regconfig AS cfg
+        FROM posts p
+        WHERE p.deleted_at IS NULL
+        UNION ALL
+        SELECT pt.post_id, pt.language_code, pt.title, pt.excerpt, pt.content, pt.search_vector,
+               post_search_config(pt.language_code) AS cfg
+        FROM post_translations pt
+        WHERE pt.deleted_at IS NULL
+    )
+"#;
+
+/// Filter chung trên bảng posts (alias `p`): $2 lang, $3 category, $4 tag slug, $5 status.
+/// Không lọc status (tìm kiếm công khai) => chỉ bài đang trong khung xuất bản như list/feed
+const SEARCH_POST_FILTERS: &str = r#"
+    p.deleted_at IS NULL
+    AND ($3::uuid IS NULL OR p.category_id = $3)
+    AND ($4::text IS NULL OR EXISTS (
+        SELECT 1
+        FROM post_tags ptg
+        INNER JOIN tags t ON t.id = ptg.tag_id
+        WHERE ptg.post_id = p.id AND ptg.deleted_at IS NULL
+          AND t.deleted_at IS NULL AND t.type = 'post' AND t.slug = $4
+    ))
+    AND p.status = COALESCE($5, 'published'::post_status)
+    AND ($5::post_status IS NOT NULL OR (
+        (p.published_at IS NULL OR p.published_at <= NOW())
+        AND (p.unpublish_at IS NULL OR p.unpublish_at > NOW())
+    ))
+"#;
+
+const HEADLINE_OPTIONS: &str =
+    "StartSel=<mark>, StopSel=</mark>, MaxFragments=2, MaxWords=30, MinWords=10";
 
 pub struct PgPostRepository {
     pool: Pool<Postgres>,
+    sitemap_cache: Arc<SitemapCache>,
 }
 
 impl PgPostRepository {
-    pub fn new(pool: Pool<Postgres>) -> Self {
-        Self { pool }
+    pub fn new(pool: Pool<Postgres>, sitemap_cache: Arc<SitemapCache>) -> Self {
+        Self {
+            pool,
+            sitemap_cache,
+        }
+    }
+}
+
+impl CursorRow for Post {
+    fn cursor_id(&self) -> Uuid {
+        self.id
+    }
+
+    fn cursor_value(&self, field: &str) -> CursorValue {
+        match field {
+            "title" => CursorValue::Text(Some(self.title.clone())),
+            "slug" => CursorValue::Text(self.slug.clone()),
+            "excerpt" => CursorValue::Text(self.excerpt.clone()),
+            "status" => CursorValue::Text(self.status.as_ref().map(|s| s.as_str().to_string())),
+            "published_at" => CursorValue::Timestamp(self.published_at),
+            "unpublish_at" => CursorValue::Timestamp(self.unpublish_at),
+            "word_count" => CursorValue::Text(Some(self.word_count.to_string())),
+            "reading_time_minutes" => {
+                CursorValue::Text(Some(self.reading_time_minutes.to_string()))
+            }
+            "created_at" => CursorValue::Timestamp(self.created_at),
+            "updated_at" => CursorValue::Timestamp(self.updated_at),
+            _ => CursorValue::Unsupported,
+        }
     }
 }
 
@@ -21,8 +104,10 @@ impl PostRepository for PgPostRepository {
         sqlx::query_as!(
             Post,
             r#"
-            SELECT id, author_id, category_id, title, slug, excerpt, content,
-                   status as "status: PostStatus", published_at,
+            SELECT id, author_id, category_id, featured_media_id, title, slug, excerpt, content,
+                   content_format as "content_format: ContentFormat", content_blocks,
+                   content_html, content_text, toc, word_count, reading_time_minutes,
+                   status as "status: PostStatus", published_at, unpublish_at,
                    created_at, updated_at, deleted_at
             FROM posts
             WHERE id = $1 AND deleted_at IS NULL
@@ -38,8 +123,10 @@ impl PostRepository for PgPostRepository {
         sqlx::query_as!(
             Post,
             r#"
-            SELECT id, author_id, category_id, title, slug, excerpt, content,
-                   status as "status: PostStatus", published_at,
+            SELECT id, author_id, category_id, featured_media_id, title, slug, excerpt, content,
+                   content_format as "content_format: ContentFormat", content_blocks,
+                   content_html, content_text, toc, word_count, reading_time_minutes,
+                   status as "status: PostStatus", published_at, unpublish_at,
                    created_at, updated_at, deleted_at
             FROM posts
             WHERE slug = $1 AND deleted_at IS NULL
@@ -51,39 +138,365 @@ impl PostRepository for PgPostRepository {
         .map_err(|e| e.to_string())
     }
 
-    async fn search(&self, _filter: &PostSearchFilter) -> Result<Vec<Post>, String> {
-        // Để đảm bảo không lỗi runtime, tạm thời chỉ trả về các bài viết đã publish,
-        // có thể mở rộng sau để filter theo category/status/search bằng QueryBuilder.
-        let posts = sqlx::query_as!(
-            Post,
+    async fn search(
+        &self,
+        filter: &PostSearchFilter,
+        sort_by: Option<String>,
+        cursor: Option<String>,
+        limit: i64,
+    ) -> Result<PaginatedResult<Post>, AppError> {
+        let allowed_fields = [
+            FieldInfo {
+                name: "title",
+                field_type: FieldType::Text,
+            },
+            FieldInfo {
+                name: "slug",
+                field_type: FieldType::Text,
+            },
+            FieldInfo {
+                name: "excerpt",
+                field_type: FieldType::Text,
+            },
+            FieldInfo {
+                name: "status",
+                field_type: FieldType::Enum("post_status"),
+            },
+            FieldInfo {
+                name: "published_at",
+                field_type: FieldType::Timestamp,
+            },
+            FieldInfo {
+                name: "unpublish_at",
+                field_type: FieldType::Timestamp,
+            },
+            FieldInfo {
+                name: "word_count",
+                field_type: FieldType::Integer,
+            },
+            FieldInfo {
+                name: "reading_time_minutes",
+                field_type: FieldType::Integer,
+            },
+            FieldInfo {
+                name: "created_at",
+                field_type: FieldType::Timestamp,
+            },
+            FieldInfo {
+                name: "updated_at",
+                field_type: FieldType::Timestamp,
+            },
+        ];
+
+        let base_sql = "SELECT id, author_id, category_id, featured_media_id, title, slug, excerpt, content, content_format, content_blocks, content_html, content_text, toc, word_count, reading_time_minutes, status, published_at, unpublish_at, created_at, updated_at, deleted_at FROM posts WHERE deleted_at IS NULL";
+
+        // Filter cố định, placeholder đánh số từ $1 theo thứ tự push
+        let mut filters: Vec<(String, BindValue)> = Vec::new();
+
+        if filter.public_only {
+            let idx = filters.len() + 1;
+            filters.push((
+                format!(
+                    "status = ${idx}::post_status \
+                     AND (published_at IS NULL OR published_at <= NOW()) \
+                     AND (unpublish_at IS NULL OR unpublish_at > NOW())"
+                ),
+                BindValue::Text(PostStatus::Published.as_str().to_string()),
+            ));
+        } else if let Some(status) = &filter.status {
+            let idx = filters.len() + 1;
+            filters.push((
+                format!("status = ${idx}::post_status"),
+                BindValue::Text(status.as_str().to_string()),
+            ));
+        }
+        if let Some(category_id) = filter.category_id {
+            let idx = filters.len() + 1;
+            filters.push((
+                format!(
+                    "category_id IN (
+                        WITH RECURSIVE tree AS (
+                            SELECT id FROM categories WHERE id = ${idx} AND deleted_at IS NULL
+                            UNION ALL
+                            SELECT c.id FROM categories c
+                            INNER JOIN tree ON c.parent_id = tree.id
+                            WHERE c.deleted_at IS NULL
+                        )
+                        SELECT id FROM tree
+                    )"
+                ),
+                BindValue::Uuid(category_id),
+            ));
+        }
+        if let Some(tag_slug) = &filter.tag_slug {
+            let idx = filters.len() + 1;
+            filters.push((
+                format!(
+                    "EXISTS (
+                        SELECT 1 FROM post_tags pt
+                        INNER JOIN tags t ON t.id = pt.tag_id
+                        WHERE pt.post_id = posts.id AND pt.deleted_at IS NULL
+                          AND t.deleted_at IS NULL AND t.type = 'post' AND t.slug = ${idx}
+                    )"
+                ),
+                BindValue::Text(tag_slug.clone()),
+            ));
+        }
+        if let Some(author_id) = filter.author_id {
+            let idx = filters.len() + 1;
+            filters.push((format!("author_id = ${idx}"), BindValue::Uuid(author_id)));
+        }
+        if let Some(reviewer_id) = filter.reviewer_id {
+            let idx = filters.len() + 1;
+            filters.push((
+                format!(
+                    "EXISTS (
+                        SELECT 1 FROM post_reviewers pr
+                        WHERE pr.post_id = posts.id AND pr.deleted_at IS NULL
+                          AND pr.reviewer_id = ${idx}
+                    )"
+                ),
+                BindValue::Uuid(reviewer_id),
+            ));
+        }
+        if let Some(from) = filter.published_from {
+            let idx = filters.len() + 1;
+            filters.push((
+                format!("published_at >= ${idx}"),
+                BindValue::Timestamp(from),
+            ));
+        }
+        if let Some(to) = filter.published_to {
+            let idx = filters.len() + 1;
+            filters.push((format!("published_at <= ${idx}"), BindValue::Timestamp(to)));
+        }
+
+        let base_filters = filters
+            .iter()
+            .map(|(clause, bind)| (clause.as_str(), bind.clone()))
+            .collect::<Vec<_>>();
+
+        let params = ListParams {
+            search: filter.search.clone(),
+            fields: None,
+            sort_by,
+            cursor,
+            limit: Some(limit),
+            ..Default::default()
+        };
+
+        // Public mặc định theo ngày publish, chế độ quản trị theo ngày tạo
+        let default_sort = if filter.public_only {
+            "published_at"
+        } else {
+            "created_at"
+        };
+
+        let built = build_query_with_seed(
+            base_sql,
+            &params,
+            &allowed_fields,
+            default_sort,
+            SortDirection::Desc,
+            &["title", "excerpt"],
+            &base_filters,
+            base_filters.len() as i32,
+        )?;
+
+        let mut query = sqlx::query_as::<_, Post>(&built.sql);
+        for b in built.binds.clone() {
+            query = match b {
+                BindValue::Text(v) => query.bind(v),
+                BindValue::Timestamp(v) => query.bind(v),
+                BindValue::Uuid(v) => query.bind(v),
+                BindValue::I64(v) => query.bind(v),
+            };
+        }
+
+        let items = query
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        let next_cursor = built.next_cursor(&items)?;
+
+        Ok(PaginatedResult {
+            items,
+            next_cursor,
+            limit: built.limit,
+        })
+    }
+
+    async fn search_fulltext(
+        &self,
+        query: &PostFullTextQuery,
+    ) -> Result<Vec<PostSearchHit>, String> {
+        // Rank + limit trước, ts_headline (tốn kém) chỉ chạy trên các dòng được trả về
+        let fulltext_sql = format!(
             r#"
-            SELECT id, author_id, category_id, title, slug, excerpt, content,
-                   status as "status: PostStatus", published_at,
-                   created_at, updated_at, deleted_at
-            FROM posts
-            WHERE deleted_at IS NULL
-              AND status = 'published'
-            ORDER BY published_at DESC NULLS LAST, created_at DESC
+            WITH {SEARCH_DOCS_CTE},
+            matches AS (
+                SELECT DISTINCT ON (d.post_id)
+                       d.post_id, d.language_code, d.content, d.excerpt, d.cfg,
+                       websearch_to_tsquery(d.cfg, $1) AS query,
+                       ts_rank(d.search_vector, websearch_to_tsquery(d.cfg, $1)) AS rank
+                FROM docs d
+                WHERE d.search_vector @@ websearch_to_tsquery(d.cfg, $1)
+                  AND ($2::text IS NULL OR d.language_code = $2)
+                ORDER BY d.post_id, rank DESC
+            ),
+            ranked AS (
+                SELECT p.id, p.author_id, p.category_id, p.title, p.slug, p.excerpt,
+                       p.status, p.published_at,
+                       m.language_code, m.rank, m.content AS match_content,
+                       m.excerpt AS match_excerpt, m.cfg, m.query
+                FROM matches m
+                INNER JOIN posts p ON p.id = m.post_id
+                WHERE {SEARCH_POST_FILTERS}
+                ORDER BY m.rank DESC, p.published_at DESC NULLS LAST, p.id
+                LIMIT $6
+            )
+            SELECT id, author_id, category_id, title, slug, excerpt, status, published_at,
+                   language_code, rank,
+                   ts_headline(cfg, coalesce(match_content, match_excerpt, title), query,
+                               '{HEADLINE_OPTIONS}') AS headline,
+                   'fulltext' AS match_type
+            FROM ranked
+            ORDER BY rank DESC, published_at DESC NULLS LAST, id
+            "#
+        );
+
+        let hits = sqlx::query_as::<_, PostSearchHit>(&fulltext_sql)
+            .bind(&query.query)
+            .bind(&query.language_code)
+            .bind(query.category_id)
+            .bind(&query.tag_slug)
+            .bind(&query.status)
+            .bind(query.limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if !hits.is_empty() {
+            return Ok(hits);
+        }
+
+        // Fallback trigram: chịu được lỗi chính tả trên title
+        let trigram_sql = format!(
+            r#"
+            WITH {SEARCH_DOCS_CTE},
+            matches AS (
+                SELECT DISTINCT ON (d.post_id)
+                       d.post_id, d.language_code, d.excerpt,
+                       GREATEST(similarity(d.title, $1), word_similarity($1, d.title)) AS rank
+                FROM docs d
+                WHERE (d.title % $1 OR $1 <% d.title)
+                  AND ($2::text IS NULL OR d.language_code = $2)
+                ORDER BY d.post_id, rank DESC
+            )
+            SELECT p.id, p.author_id, p.category_id, p.title, p.slug, p.excerpt,
+                   p.status, p.published_at,
+                   m.language_code, m.rank, m.excerpt AS headline,
+                   'trigram' AS match_type
+            FROM matches m
+            INNER JOIN posts p ON p.id = m.post_id
+            WHERE {SEARCH_POST_FILTERS}
+            ORDER BY m.rank DESC, p.published_at DESC NULLS LAST, p.id
+            LIMIT $6
             "#
+        );
+
+        sqlx::query_as::<_, PostSearchHit>(&trigram_sql)
+            .bind(&query.query)
+            .bind(&query.language_code)
+            .bind(query.category_id)
+            .bind(&query.tag_slug)
+            .bind(&query.status)
+            .bind(query.limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn list_feed(&self, query: &PostFeedQuery) -> Result<Vec<PostFeedItem>, String> {
+        sqlx::query_as!(
+            PostFeedItem,
+            r#"
+            SELECT p.id,
+                   COALESCE(pt.title, p.title) AS "title!",
+                   COALESCE(pt.slug, p.slug) AS slug,
+                   CASE WHEN pt.id IS NULL THEN p.excerpt ELSE pt.excerpt END AS excerpt,
+                   CASE WHEN pt.id IS NULL THEN p.content ELSE pt.content END AS content,
+                   p.content_format as "content_format: ContentFormat",
+                   CASE WHEN pt.id IS NULL THEN p.content_html END AS content_html,
+                   u.name AS "author_name?",
+                   c.name AS "category_name?",
+                   p.published_at,
+                   GREATEST(p.updated_at, pt.updated_at) AS updated_at
+            FROM posts p
+            LEFT JOIN post_translations pt
+                   ON $3::text IS NOT NULL AND pt.post_id = p.id
+                  AND pt.language_code = $3 AND pt.deleted_at IS NULL
+            LEFT JOIN users u ON u.id = p.author_id
+            LEFT JOIN categories c ON c.id = p.category_id AND c.deleted_at IS NULL
+            WHERE p.deleted_at IS NULL
+              AND p.status = 'published'
+              AND (p.published_at IS NULL OR p.published_at <= NOW())
+              AND (p.unpublish_at IS NULL OR p.unpublish_at > NOW())
+              AND ($3::text IS NULL OR pt.id IS NOT NULL)
+              AND ($1::uuid IS NULL OR p.category_id IN (
+                    WITH RECURSIVE tree AS (
+                        SELECT id FROM categories WHERE id = $1 AND deleted_at IS NULL
+                        UNION ALL
+                        SELECT ch.id FROM categories ch
+                        INNER JOIN tree ON ch.parent_id = tree.id
+                        WHERE ch.deleted_at IS NULL
+                    )
+                    SELECT id FROM tree
+                  ))
+              AND ($2::text IS NULL OR EXISTS (
+                    SELECT 1 FROM post_tags ptg
+                    INNER JOIN tags t ON t.id = ptg.tag_id
+                    WHERE ptg.post_id = p.id AND ptg.deleted_at IS NULL
+                      AND t.deleted_at IS NULL AND t.type = 'post' AND t.slug = $2
+                  ))
+            ORDER BY p.published_at DESC NULLS LAST, p.id DESC
+            LIMIT $4
+            "#,
+            query.category_id,
+            query.tag_slug,
+            query.language_code,
+            query.limit
         )
         .fetch_all(&self.pool)
         .await
-        .map_err(|e| e.to_string())?;
-
-        Ok(posts)
+        .map_err(|e| e.to_string())
     }
 
-    async fn create(&self, post: Post) -> Result<Post, String> {
+    async fn create(
+        &self,
+        post: Post,
+        tag_ids: Option<&[Uuid]>,
+        revision: Option<&PostRevisionOptions>,
+    ) -> Result<Post, String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
         let created = sqlx::query_as!(
             Post,
             r#"
             INSERT INTO posts (
-                id, author_id, category_id, title, slug, excerpt, content,
-                published_at, created_at, updated_at, deleted_at
+                id, author_id, category_id, featured_media_id, title, slug, excerpt, content,
+                content_format, content_blocks, content_html, content_text, toc,
+                word_count, reading_time_minutes,
+                status, published_at, unpublish_at, created_at, updated_at, deleted_at
+            )
+            VALUES (
+                $1, $2, $3, $18, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17,
+                NOW(), NOW(), NULL
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, NOW(), NOW(), NULL)
-            RETURNING id, author_id, category_id, title, slug, excerpt, content,
-                      status as "status: PostStatus", published_at,
+            RETURNING id, author_id, category_id, featured_media_id, title, slug, excerpt, content,
+                      content_format as "content_format: ContentFormat", content_blocks,
+                      content_html, content_text, toc, word_count, reading_time_minutes,
+                      status as "status: PostStatus", published_at, unpublish_at,
                       created_at, updated_at, deleted_at
             "#,
             post.id,
@@ -93,16 +506,51 @@ impl PostRepository for PgPostRepository {
             post.slug,
             post.excerpt,
             post.content,
+            post.content_format as ContentFormat,
+            post.content_blocks,
+            post.content_html,
+            post.content_text,
+            post.toc,
+            post.word_count,
+            post.reading_time_minutes,
+            post.status as Option<PostStatus>,
             post.published_at,
+            post.unpublish_at,
+            post.featured_media_id,
         )
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| e.to_string())?;
 
+        if let Some(tag_ids) = tag_ids {
+            replace_tags_tx(&mut tx, created.id, tag_ids).await?;
+        }
+        if let Some(revision) = revision {
+            record_revision_tx(&mut tx, &created, revision).await?;
+        }
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        self.sitemap_cache.invalidate();
         Ok(created)
     }
 
-    async fn update(&self, post: Post) -> Result<Post, String> {
+    async fn update(
+        &self,
+        post: Post,
+        tag_ids: Option<&[Uuid]>,
+        revision: Option<&PostRevisionOptions>,
+    ) -> Result<Post, String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        let old_slug = sqlx::query_scalar!(
+            "SELECT slug FROM posts WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
+            post.id
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?
+        .flatten();
+
         let updated = sqlx::query_as!(
             Post,
             r#"
@@ -113,11 +561,23 @@ impl PostRepository for PgPostRepository {
                 slug = $5,
                 excerpt = $6,
                 content = $7,
-                published_at = $8,
+                content_format = $8,
+                content_blocks = $9,
+                content_html = $10,
+                content_text = $11,
+                toc = $12,
+                word_count = $13,
+                reading_time_minutes = $14,
+                status = $15,
+                published_at = $16,
+                unpublish_at = $17,
+                featured_media_id = $18,
                 updated_at = NOW()
             WHERE id = $1 AND deleted_at IS NULL
-            RETURNING id, author_id, category_id, title, slug, excerpt, content,
-                      status as "status: PostStatus", published_at,
+            RETURNING id, author_id, category_id, featured_media_id, title, slug, excerpt, content,
+                      content_format as "content_format: ContentFormat", content_blocks,
+                      content_html, content_text, toc, word_count, reading_time_minutes,
+                      status as "status: PostStatus", published_at, unpublish_at,
                       created_at, updated_at, deleted_at
             "#,
             post.id,
@@ -127,15 +587,135 @@ impl PostRepository for PgPostRepository {
             post.slug,
             post.excerpt,
             post.content,
+            post.content_format as ContentFormat,
+            post.content_blocks,
+            post.content_html,
+            post.content_text,
+            post.toc,
+            post.word_count,
+            post.reading_time_minutes,
+            post.status as Option<PostStatus>,
             post.published_at,
+            post.unpublish_at,
+            post.featured_media_id,
         )
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| e.to_string())?;
 
+        // Slug cũ => 301 tới slug mới
+        if let (Some(old), Some(new)) = (old_slug.as_deref(), updated.slug.as_deref())
+            && !old.is_empty()
+            && old != new
+        {
+            record_slug_change_tx(
+                &mut tx,
+                SlugScope::Post,
+                SlugScope::Post,
+                updated.id,
+                old,
+                new,
+            )
+            .await?;
+        }
+        if let Some(tag_ids) = tag_ids {
+            replace_tags_tx(&mut tx, updated.id, tag_ids).await?;
+        }
+        if let Some(revision) = revision {
+            record_revision_tx(&mut tx, &updated, revision).await?;
+        }
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        self.sitemap_cache.invalidate();
         Ok(updated)
     }
 
+    async fn save_review_decision(
+        &self,
+        post: Post,
+        reviewer_id: Uuid,
+        decision: &str,
+        body: &str,
+    ) -> Result<(Post, PostReviewComment), String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        let reviewed = sqlx::query_as!(
+            Post,
+            r#"
+            UPDATE posts
+            SET status = $2,
+                published_at = $3,
+                unpublish_at = $4,
+                updated_at = NOW()
+            WHERE id = $1 AND deleted_at IS NULL
+            RETURNING id, author_id, category_id, featured_media_id, title, slug, excerpt, content,
+                      content_format as "content_format: ContentFormat", content_blocks,
+                      content_html, content_text, toc, word_count, reading_time_minutes,
+                      status as "status: PostStatus", published_at, unpublish_at,
+                      created_at, updated_at, deleted_at
+            "#,
+            post.id,
+            post.status as Option<PostStatus>,
+            post.published_at,
+            post.unpublish_at,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        let comment = sqlx::query_as!(
+            PostReviewComment,
+            r#"
+            INSERT INTO post_review_comments (post_id, author_id, decision, body)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, post_id, author_id, decision, body, created_at, updated_at, deleted_at
+            "#,
+            post.id,
+            reviewer_id,
+            decision,
+            body
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        self.sitemap_cache.invalidate();
+        Ok((reviewed, comment))
+    }
+
+    async fn set_tags(&self, post_id: Uuid, tag_ids: &[Uuid]) -> Result<(), String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+        replace_tags_tx(&mut tx, post_id, tag_ids).await?;
+        let result = tx.commit().await.map_err(|e| e.to_string());
+        self.sitemap_cache.invalidate_on_ok(result)
+    }
+
+    async fn attach_tags(&self, post_id: Uuid, tag_ids: &[Uuid]) -> Result<(), String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+        attach_tags_tx(&mut tx, post_id, tag_ids).await?;
+        let result = tx.commit().await.map_err(|e| e.to_string());
+        self.sitemap_cache.invalidate_on_ok(result)
+    }
+
+    async fn detach_tags(&self, post_id: Uuid, tag_ids: &[Uuid]) -> Result<(), String> {
+        sqlx::query!(
+            r#"
+            UPDATE post_tags
+            SET deleted_at = NOW(), updated_at = NOW()
+            WHERE post_id = $1 AND tag_id = ANY($2) AND deleted_at IS NULL
+            "#,
+            post_id,
+            tag_ids
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        self.sitemap_cache.invalidate();
+        Ok(())
+    }
+
     async fn soft_delete(&self, id: Uuid) -> Result<(), String> {
         sqlx::query!(
             r#"
@@ -149,6 +729,7 @@ impl PostRepository for PgPostRepository {
         .await
         .map_err(|e| e.to_string())?;
 
+        self.sitemap_cache.invalidate();
         Ok(())
     }
 
@@ -169,6 +750,233 @@ impl PostRepository for PgPostRepository {
         .await
         .map_err(|e| e.to_string())?;
 
+        self.sitemap_cache.invalidate();
         Ok(())
     }
+
+    async fn publish_due(&self, limit: i64) -> Result<Vec<PostScheduleTransition>, String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        let transitions = sqlx::query_as!(
+            PostScheduleTransition,
+            r#"
+            WITH due AS (
+                SELECT id, status
+                FROM posts
+                WHERE deleted_at IS NULL
+                  AND status = 'scheduled'
+                  AND published_at <= NOW()
+                ORDER BY published_at
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            )
+            UPDATE posts p
+            SET status = 'published', updated_at = NOW()
+            FROM due
+            WHERE p.id = due.id
+            RETURNING p.id as post_id, p.slug,
+                      due.status as "from_status: PostStatus",
+                      p.status as "to_status: PostStatus",
+                      p.published_at as due_at
+            "#,
+            limit
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        audit_schedule_transitions_tx(&mut tx, &transitions, "post.scheduled_publish").await?;
+        tx.commit().await.map_err(|e| e.to_string())?;
+
+        if !transitions.is_empty() {
+            self.sitemap_cache.invalidate();
+        }
+        Ok(transitions)
+    }
+
+    async fn unpublish_due(&self, limit: i64) -> Result<Vec<PostScheduleTransition>, String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        let transitions = sqlx::query_as!(
+            PostScheduleTransition,
+            r#"
+            WITH due AS (
+                SELECT id, status
+                FROM posts
+                WHERE deleted_at IS NULL
+                  AND status = 'published'
+                  AND unpublish_at <= NOW()
+                ORDER BY unpublish_at
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            )
+            UPDATE posts p
+            SET status = 'archived', updated_at = NOW()
+            FROM due
+            WHERE p.id = due.id
+            RETURNING p.id as post_id, p.slug,
+                      due.status as "from_status: PostStatus",
+                      p.status as "to_status: PostStatus",
+                      p.unpublish_at as due_at
+            "#,
+            limit
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        audit_schedule_transitions_tx(&mut tx, &transitions, "post.scheduled_unpublish").await?;
+        tx.commit().await.map_err(|e| e.to_string())?;
+
+        if !transitions.is_empty() {
+            self.sitemap_cache.invalidate();
+        }
+        Ok(transitions)
+    }
+}
+
+/// Ghi audit log cho từng lần chuyển trạng thái của scheduler
+async fn audit_schedule_transitions_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    transitions: &[PostScheduleTransition],
+    action: &str,
+) -> Result<(), String> {
+    for transition in transitions {
+        sqlx::query!(
+            r#"
+            INSERT INTO audit_logs (id, user_id, action, entity_type, entity_id, metadata, old_data, new_data)
+            VALUES ($1, NULL, $2, 'post', $3, $4, $5, $6)
+            "#,
+            Uuid::new_v4(),
+            action,
+            transition.post_id,
+            serde_json::json!({
+                "source": "post_scheduler",
+                "slug": transition.slug,
+                "due_at": transition.due_at,
+            }),
+            serde_json::json!({
+                "status": transition.from_status.as_ref().map(PostStatus::as_str),
+            }),
+            serde_json::json!({
+                "status": transition.to_status.as_ref().map(PostStatus::as_str),
+            })
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Gán tag cho bài viết trong transaction.
+/// Chỉ nhận tag `type = 'post'`; liên kết đã xoá mềm trước đó được khôi phục.
+async fn attach_tags_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    post_id: Uuid,
+    tag_ids: &[Uuid],
+) -> Result<(), String> {
+    if tag_ids.is_empty() {
+        return Ok(());
+    }
+
+    sqlx::query!(
+        r#"
+        INSERT INTO post_tags (post_id, tag_id)
+        SELECT $1, t.id
+        FROM tags t
+        WHERE t.id = ANY($2) AND t.type = 'post' AND t.deleted_at IS NULL
+        ON CONFLICT (post_id, tag_id)
+        DO UPDATE SET deleted_at = NULL, updated_at = NOW()
+        WHERE post_tags.deleted_at IS NOT NULL
+        "#,
+        post_id,
+        tag_ids
+    )
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Thay thế toàn bộ tag của bài viết bằng `tag_ids` (xoá mềm các tag không còn trong danh sách)
+async fn replace_tags_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    post_id: Uuid,
+    tag_ids: &[Uuid],
+) -> Result<(), String> {
+    sqlx::query!(
+        r#"
+        UPDATE post_tags
+        SET deleted_at = NOW(), updated_at = NOW()
+        WHERE post_id = $1 AND deleted_at IS NULL AND NOT (tag_id = ANY($2))
+        "#,
+        post_id,
+        tag_ids
+    )
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    attach_tags_tx(tx, post_id, tag_ids).await
+}
+
+/// Snapshot bài viết thành revision mới rồi prune theo retention, trong transaction ghi bài.
+/// Khoá dòng bài viết tới khi commit để các lần lưu đồng thời được tuần tự hoá,
+/// `MAX(revision_number) + 1` không bị trùng
+async fn record_revision_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    post: &Post,
+    revision: &PostRevisionOptions,
+) -> Result<(), String> {
+    sqlx::query!("SELECT id FROM posts WHERE id = $1 FOR UPDATE", post.id)
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO post_revisions (
+            post_id, revision_number, saved_by, title, slug, excerpt, content, content_format,
+            content_blocks
+        )
+        SELECT $1, COALESCE(MAX(revision_number), 0) + 1, $2, $3, $4, $5, $6, $7, $8
+        FROM post_revisions
+        WHERE post_id = $1
+        "#,
+        post.id,
+        revision.saved_by,
+        post.title,
+        post.slug,
+        post.excerpt,
+        post.content,
+        post.content_format as ContentFormat,
+        post.content_blocks
+    )
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    if revision.retention > 0 {
+        sqlx::query!(
+            r#"
+            DELETE FROM post_revisions
+            WHERE post_id = $1
+              AND id NOT IN (
+                  SELECT id FROM post_revisions
+                  WHERE post_id = $1
+                  ORDER BY revision_number DESC
+                  LIMIT $2
+              )
+            "#,
+            post.id,
+            revision.retention
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
 }
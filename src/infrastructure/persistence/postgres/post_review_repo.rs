@@ -0,0 +1,135 @@
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::domain::entities::post_review::{PostReviewComment, PostReviewer};
+use crate::domain::repositories::post_review_repository::PostReviewRepository;
+
+pub struct PgPostReviewRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgPostReviewRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PostReviewRepository for PgPostReviewRepository {
+    async fn set_reviewers(
+        &self,
+        post_id: Uuid,
+        reviewer_ids: &[Uuid],
+        assigned_by: Option<Uuid>,
+    ) -> Result<(), String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        sqlx::query!(
+            r#"
+            UPDATE post_reviewers
+            SET deleted_at = NOW(), updated_at = NOW()
+            WHERE post_id = $1 AND deleted_at IS NULL AND NOT (reviewer_id = ANY($2))
+            "#,
+            post_id,
+            reviewer_ids
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO post_reviewers (post_id, reviewer_id, assigned_by)
+            SELECT $1, u.id, $3
+            FROM users u
+            WHERE u.id = ANY($2) AND u.deleted_at IS NULL
+            ON CONFLICT (post_id, reviewer_id)
+            DO UPDATE SET deleted_at = NULL, assigned_by = EXCLUDED.assigned_by, updated_at = NOW()
+            WHERE post_reviewers.deleted_at IS NOT NULL
+            "#,
+            post_id,
+            reviewer_ids,
+            assigned_by
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        tx.commit().await.map_err(|e| e.to_string())
+    }
+
+    async fn list_reviewers(&self, post_id: Uuid) -> Result<Vec<PostReviewer>, String> {
+        sqlx::query_as!(
+            PostReviewer,
+            r#"
+            SELECT pr.id, pr.post_id, pr.reviewer_id, u.name as "reviewer_name?",
+                   pr.assigned_by, pr.created_at
+            FROM post_reviewers pr
+            LEFT JOIN users u ON u.id = pr.reviewer_id
+            WHERE pr.post_id = $1 AND pr.deleted_at IS NULL
+            ORDER BY pr.created_at
+            "#,
+            post_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn is_reviewer(&self, post_id: Uuid, user_id: Uuid) -> Result<bool, String> {
+        sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM post_reviewers
+                WHERE post_id = $1 AND reviewer_id = $2 AND deleted_at IS NULL
+            ) as "exists!"
+            "#,
+            post_id,
+            user_id
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn add_comment(
+        &self,
+        post_id: Uuid,
+        author_id: Uuid,
+        decision: Option<&str>,
+        body: &str,
+    ) -> Result<PostReviewComment, String> {
+        sqlx::query_as!(
+            PostReviewComment,
+            r#"
+            INSERT INTO post_review_comments (post_id, author_id, decision, body)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, post_id, author_id, decision, body, created_at, updated_at, deleted_at
+            "#,
+            post_id,
+            author_id,
+            decision,
+            body
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn list_comments(&self, post_id: Uuid) -> Result<Vec<PostReviewComment>, String> {
+        sqlx::query_as!(
+            PostReviewComment,
+            r#"
+            SELECT id, post_id, author_id, decision, body, created_at, updated_at, deleted_at
+            FROM post_review_comments
+            WHERE post_id = $1 AND deleted_at IS NULL
+            ORDER BY created_at, id
+            "#,
+            post_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::domain::entities::post::ContentFormat;
+use crate::domain::entities::post_revision::PostRevision;
+use crate::domain::repositories::post_revision_repository::PostRevisionRepository;
+
+pub struct PgPostRevisionRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgPostRevisionRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PostRevisionRepository for PgPostRevisionRepository {
+    async fn list_by_post(&self, post_id: Uuid) -> Result<Vec<PostRevision>, String> {
+        sqlx::query_as!(
+            PostRevision,
+            r#"
+            SELECT id, post_id, revision_number, saved_by, title, slug, excerpt, content,
+                   content_format as "content_format: ContentFormat", content_blocks,
+                   created_at, updated_at, deleted_at
+            FROM post_revisions
+            WHERE post_id = $1 AND deleted_at IS NULL
+            ORDER BY revision_number DESC
+            "#,
+            post_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_by_number(
+        &self,
+        post_id: Uuid,
+        revision_number: i32,
+    ) -> Result<Option<PostRevision>, String> {
+        sqlx::query_as!(
+            PostRevision,
+            r#"
+            SELECT id, post_id, revision_number, saved_by, title, slug, excerpt, content,
+                   content_format as "content_format: ContentFormat", content_blocks,
+                   created_at, updated_at, deleted_at
+            FROM post_revisions
+            WHERE post_id = $1 AND revision_number = $2 AND deleted_at IS NULL
+            "#,
+            post_id,
+            revision_number
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+}
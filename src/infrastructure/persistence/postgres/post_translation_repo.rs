@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::domain::entities::post_translation::{NewPostTranslation, PostTranslation};
+use crate::domain::repositories::post_translation_repository::PostTranslationRepository;
+use crate::shared::error::AppError;
+use crate::shared::sitemap_cache::SitemapCache;
+
+pub struct PgPostTranslationRepository {
+    pool: Pool<Postgres>,
+    sitemap_cache: Arc<SitemapCache>,
+}
+
+impl PgPostTranslationRepository {
+    pub fn new(pool: Pool<Postgres>, sitemap_cache: Arc<SitemapCache>) -> Self {
+        Self {
+            pool,
+            sitemap_cache,
+        }
+    }
+}
+
+#[async_trait]
+impl PostTranslationRepository for PgPostTranslationRepository {
+    async fn list_by_post(&self, post_id: Uuid) -> Result<Vec<PostTranslation>, String> {
+        sqlx::query_as!(
+            PostTranslation,
+            r#"
+            SELECT id, post_id, language_code, title, slug, excerpt, content,
+                   created_at, updated_at, deleted_at
+            FROM post_translations
+            WHERE post_id = $1 AND deleted_at IS NULL
+            ORDER BY language_code
+            "#,
+            post_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find(
+        &self,
+        post_id: Uuid,
+        language_code: &str,
+    ) -> Result<Option<PostTranslation>, String> {
+        sqlx::query_as!(
+            PostTranslation,
+            r#"
+            SELECT id, post_id, language_code, title, slug, excerpt, content,
+                   created_at, updated_at, deleted_at
+            FROM post_translations
+            WHERE post_id = $1 AND language_code = $2 AND deleted_at IS NULL
+            "#,
+            post_id,
+            language_code
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_many(
+        &self,
+        post_ids: &[Uuid],
+        language_code: &str,
+    ) -> Result<Vec<PostTranslation>, String> {
+        if post_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        sqlx::query_as!(
+            PostTranslation,
+            r#"
+            SELECT id, post_id, language_code, title, slug, excerpt, content,
+                   created_at, updated_at, deleted_at
+            FROM post_translations
+            WHERE post_id = ANY($1) AND language_code = $2 AND deleted_at IS NULL
+            "#,
+            post_ids,
+            language_code
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_by_slug(
+        &self,
+        slug: &str,
+        language_code: Option<&str>,
+    ) -> Result<Option<PostTranslation>, String> {
+        sqlx::query_as!(
+            PostTranslation,
+            r#"
+            SELECT pt.id, pt.post_id, pt.language_code, pt.title, pt.slug, pt.excerpt, pt.content,
+                   pt.created_at, pt.updated_at, pt.deleted_at
+            FROM post_translations pt
+            INNER JOIN posts p ON p.id = pt.post_id AND p.deleted_at IS NULL
+            WHERE pt.slug = $1 AND pt.deleted_at IS NULL
+              AND ($2::text IS NULL OR pt.language_code = $2)
+            ORDER BY pt.language_code
+            LIMIT 1
+            "#,
+            slug,
+            language_code
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn upsert(&self, translation: NewPostTranslation) -> Result<PostTranslation, String> {
+        let saved = sqlx::query_as!(
+            PostTranslation,
+            r#"
+            INSERT INTO post_translations (post_id, language_code, title, slug, excerpt, content)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (post_id, language_code) DO UPDATE
+            SET title = EXCLUDED.title,
+                slug = EXCLUDED.slug,
+                excerpt = EXCLUDED.excerpt,
+                content = EXCLUDED.content,
+                updated_at = NOW(),
+                deleted_at = NULL
+            RETURNING id, post_id, language_code, title, slug, excerpt, content,
+                      created_at, updated_at, deleted_at
+            "#,
+            translation.post_id,
+            translation.language_code,
+            translation.title,
+            translation.slug,
+            translation.excerpt,
+            translation.content,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        self.sitemap_cache.invalidate();
+        Ok(saved)
+    }
+
+    async fn delete(&self, post_id: Uuid, language_code: &str) -> Result<(), AppError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE post_translations
+            SET deleted_at = NOW(), updated_at = NOW()
+            WHERE post_id = $1 AND language_code = $2 AND deleted_at IS NULL
+            "#,
+            post_id,
+            language_code
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found(
+                "POST_TRANSLATION_NOT_FOUND",
+                "Post translation",
+            ));
+        }
+        self.sitemap_cache.invalidate();
+        Ok(())
+    }
+}
@@ -0,0 +1,247 @@
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::domain::entities::redirect::Redirect;
+use crate::domain::repositories::redirect_repository::{RedirectRepository, RedirectSearchFilter};
+use crate::shared::error::AppError;
+use crate::shared::utils::query::{
+    BindValue, CursorRow, CursorValue, FieldInfo, FieldType, ListParams, PaginatedResult,
+    SortDirection, build_query_with_seed,
+};
+
+pub struct PgRedirectRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgRedirectRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+impl CursorRow for Redirect {
+    fn cursor_id(&self) -> Uuid {
+        self.id
+    }
+
+    fn cursor_value(&self, field: &str) -> CursorValue {
+        match field {
+            "source_path" => CursorValue::Text(Some(self.source_path.clone())),
+            "target" => CursorValue::Text(Some(self.target.clone())),
+            "hit_count" => CursorValue::Text(Some(self.hit_count.to_string())),
+            "last_hit_at" => CursorValue::Timestamp(self.last_hit_at),
+            "created_at" => CursorValue::Timestamp(self.created_at),
+            "updated_at" => CursorValue::Timestamp(self.updated_at),
+            _ => CursorValue::Unsupported,
+        }
+    }
+}
+
+#[async_trait]
+impl RedirectRepository for PgRedirectRepository {
+    async fn search(
+        &self,
+        filter: &RedirectSearchFilter,
+        sort_by: Option<String>,
+        cursor: Option<String>,
+        limit: i64,
+    ) -> Result<PaginatedResult<Redirect>, AppError> {
+        let allowed_fields = [
+            FieldInfo {
+                name: "source_path",
+                field_type: FieldType::Text,
+            },
+            FieldInfo {
+                name: "target",
+                field_type: FieldType::Text,
+            },
+            FieldInfo {
+                name: "hit_count",
+                field_type: FieldType::Integer,
+            },
+            FieldInfo {
+                name: "last_hit_at",
+                field_type: FieldType::Timestamp,
+            },
+            FieldInfo {
+                name: "created_at",
+                field_type: FieldType::Timestamp,
+            },
+            FieldInfo {
+                name: "updated_at",
+                field_type: FieldType::Timestamp,
+            },
+        ];
+
+        let base_sql = "SELECT id, source_path, target, status_code, is_active, hit_count, last_hit_at, created_at, updated_at, deleted_at FROM redirects WHERE deleted_at IS NULL";
+
+        let mut filters: Vec<(String, BindValue)> = Vec::new();
+        if let Some(is_active) = filter.is_active {
+            let idx = filters.len() + 1;
+            filters.push((
+                format!("is_active = ${idx}::boolean"),
+                BindValue::Text(is_active.to_string()),
+            ));
+        }
+        let base_filters = filters
+            .iter()
+            .map(|(clause, bind)| (clause.as_str(), bind.clone()))
+            .collect::<Vec<_>>();
+
+        let params = ListParams {
+            search: filter.search.clone(),
+            fields: None,
+            sort_by,
+            cursor,
+            limit: Some(limit),
+            ..Default::default()
+        };
+
+        let built = build_query_with_seed(
+            base_sql,
+            &params,
+            &allowed_fields,
+            "created_at",
+            SortDirection::Desc,
+            &["source_path", "target"],
+            &base_filters,
+            base_filters.len() as i32,
+        )?;
+
+        let mut query = sqlx::query_as::<_, Redirect>(&built.sql);
+        for b in built.binds.clone() {
+            query = match b {
+                BindValue::Text(v) => query.bind(v),
+                BindValue::Timestamp(v) => query.bind(v),
+                BindValue::Uuid(v) => query.bind(v),
+                BindValue::I64(v) => query.bind(v),
+            };
+        }
+
+        let items = query
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        let next_cursor = built.next_cursor(&items)?;
+
+        Ok(PaginatedResult {
+            items,
+            next_cursor,
+            limit: built.limit,
+        })
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Redirect>, String> {
+        sqlx::query_as!(
+            Redirect,
+            r#"
+            SELECT id, source_path, target, status_code, is_active, hit_count, last_hit_at,
+                   created_at, updated_at, deleted_at
+            FROM redirects
+            WHERE id = $1 AND deleted_at IS NULL
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_by_source_path(&self, source_path: &str) -> Result<Option<Redirect>, String> {
+        sqlx::query_as!(
+            Redirect,
+            r#"
+            SELECT id, source_path, target, status_code, is_active, hit_count, last_hit_at,
+                   created_at, updated_at, deleted_at
+            FROM redirects
+            WHERE source_path = $1 AND deleted_at IS NULL
+            "#,
+            source_path
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn create(&self, redirect: Redirect) -> Result<Redirect, String> {
+        sqlx::query_as!(
+            Redirect,
+            r#"
+            INSERT INTO redirects (id, source_path, target, status_code, is_active)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, source_path, target, status_code, is_active, hit_count, last_hit_at,
+                      created_at, updated_at, deleted_at
+            "#,
+            redirect.id,
+            redirect.source_path,
+            redirect.target,
+            redirect.status_code,
+            redirect.is_active
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn update(&self, redirect: Redirect) -> Result<Redirect, String> {
+        sqlx::query_as!(
+            Redirect,
+            r#"
+            UPDATE redirects
+            SET source_path = $2,
+                target = $3,
+                status_code = $4,
+                is_active = $5,
+                updated_at = NOW()
+            WHERE id = $1 AND deleted_at IS NULL
+            RETURNING id, source_path, target, status_code, is_active, hit_count, last_hit_at,
+                      created_at, updated_at, deleted_at
+            "#,
+            redirect.id,
+            redirect.source_path,
+            redirect.target,
+            redirect.status_code,
+            redirect.is_active
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn soft_delete(&self, id: Uuid) -> Result<(), AppError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE redirects
+            SET deleted_at = NOW(), updated_at = NOW()
+            WHERE id = $1 AND deleted_at IS NULL
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found("REDIRECT_NOT_FOUND", "Redirect"));
+        }
+        Ok(())
+    }
+
+    async fn hit(&self, source_path: &str) -> Result<Option<Redirect>, String> {
+        sqlx::query_as!(
+            Redirect,
+            r#"
+            UPDATE redirects
+            SET hit_count = hit_count + 1, last_hit_at = NOW()
+            WHERE source_path = $1 AND is_active AND deleted_at IS NULL
+            RETURNING id, source_path, target, status_code, is_active, hit_count, last_hit_at,
+                      created_at, updated_at, deleted_at
+            "#,
+            source_path
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+}
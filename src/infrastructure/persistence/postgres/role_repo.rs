@@ -3,9 +3,10 @@ use sqlx::{Pool, Postgres};
 
 use crate::domain::entities::role::{NewRole, Role, UpdateRole};
 use crate::domain::repositories::role_repository::{RoleRepository, RoleSearchFilter};
+use crate::shared::error::AppError;
 use crate::shared::utils::query::{
-    BindValue, FieldInfo, FieldType, ListParams, PaginatedResult, SortDirection, build_query,
-    encode_cursor_text, encode_cursor_ts,
+    BindValue, CursorRow, CursorValue, FieldInfo, FieldType, ListParams, PaginatedResult,
+    SortDirection, build_query,
 };
 
 pub struct PgRoleRepository {
@@ -20,6 +21,20 @@ impl PgRoleRepository {
 
 use uuid::Uuid;
 
+impl CursorRow for Role {
+    fn cursor_id(&self) -> Uuid {
+        self.id
+    }
+
+    fn cursor_value(&self, field: &str) -> CursorValue {
+        match field {
+            "name" => CursorValue::Text(Some(self.name.clone())),
+            "created_at" => CursorValue::Timestamp(self.created_at),
+            _ => CursorValue::Unsupported,
+        }
+    }
+}
+
 #[async_trait]
 impl RoleRepository for PgRoleRepository {
     async fn find_all(&self) -> Result<Vec<Role>, String> {
@@ -38,7 +53,7 @@ impl RoleRepository for PgRoleRepository {
         sort_by: Option<String>,
         cursor: Option<String>,
         limit: i64,
-    ) -> Result<PaginatedResult<Role>, String> {
+    ) -> Result<PaginatedResult<Role>, AppError> {
         let allowed_fields = [
             FieldInfo {
                 name: "name",
@@ -58,6 +73,7 @@ impl RoleRepository for PgRoleRepository {
             sort_by,
             cursor,
             limit: Some(limit),
+            ..Default::default()
         };
 
         let built = build_query(
@@ -70,7 +86,7 @@ impl RoleRepository for PgRoleRepository {
         )?;
 
         let mut query = sqlx::query_as::<_, Role>(&built.sql);
-        for b in built.binds {
+        for b in built.binds.clone() {
             query = match b {
                 BindValue::Text(v) => query.bind(v),
                 BindValue::Timestamp(v) => query.bind(v),
@@ -83,19 +99,7 @@ impl RoleRepository for PgRoleRepository {
             .fetch_all(&self.pool)
             .await
             .map_err(|e| e.to_string())?;
-        let next_cursor = if items.len() as i64 == built.limit {
-            if let Some(last) = items.last() {
-                match built.sort_field {
-                    "name" => Some(encode_cursor_text(&last.name, last.id)),
-                    "created_at" => last.created_at.map(|dt| encode_cursor_ts(dt, last.id)),
-                    _ => None,
-                }
-            } else {
-                None
-            }
-        } else {
-            None
-        };
+        let next_cursor = built.next_cursor(&items)?;
 
         Ok(PaginatedResult {
             items,
@@ -148,14 +152,14 @@ impl RoleRepository for PgRoleRepository {
         .map_err(|e| e.to_string())
     }
 
-    async fn delete(&self, id: Uuid) -> Result<(), String> {
+    async fn delete(&self, id: Uuid) -> Result<(), AppError> {
         let result = sqlx::query!("UPDATE roles SET deleted_at = NOW() WHERE id = $1", id)
             .execute(&self.pool)
             .await
             .map_err(|e| e.to_string())?;
 
         if result.rows_affected() == 0 {
-            return Err("Role not found".to_string());
+            return Err(AppError::not_found("ROLE_NOT_FOUND", "Role"));
         }
 
         Ok(())
@@ -177,6 +181,38 @@ impl RoleRepository for PgRoleRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_by_user_ids(&self, user_ids: &[Uuid]) -> Result<Vec<(Uuid, Role)>, String> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT ur.user_id, r.id, r.name, r.created_at, r.updated_at, r.deleted_at
+            FROM roles r
+            INNER JOIN user_roles ur ON r.id = ur.role_id
+            WHERE ur.user_id = ANY($1) AND r.deleted_at IS NULL AND ur.deleted_at IS NULL
+            ORDER BY r.name
+            "#,
+            user_ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| {
+                (
+                    r.user_id,
+                    Role {
+                        id: r.id,
+                        name: r.name,
+                        created_at: r.created_at,
+                        updated_at: r.updated_at,
+                        deleted_at: r.deleted_at,
+                    },
+                )
+            })
+            .collect())
+    }
+
     async fn assign_to_user(&self, user_id: Uuid, role_id: Uuid) -> Result<(), String> {
         let existing = sqlx::query!(
             r#"SELECT id, deleted_at FROM user_roles WHERE user_id = $1 AND role_id = $2 ORDER BY created_at DESC LIMIT 1"#,
@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::domain::entities::seo_metadata::{NewSeoMetadata, SeoMetadata};
+use crate::domain::repositories::seo_metadata_repository::{SeoEntity, SeoMetadataRepository};
+
+pub struct PgSeoMetadataRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgSeoMetadataRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SeoMetadataRepository for PgSeoMetadataRepository {
+    async fn find(
+        &self,
+        entity: SeoEntity,
+        entity_id: Uuid,
+        language_code: &str,
+    ) -> Result<Option<SeoMetadata>, String> {
+        sqlx::query_as!(
+            SeoMetadata,
+            r#"
+            SELECT id, entity_type, entity_id, language_code, meta_title, meta_description,
+                   canonical_url, robots_index, robots_follow, og_image_media_id,
+                   created_at, updated_at, deleted_at
+            FROM seo_metadata
+            WHERE entity_type = $1 AND entity_id = $2 AND language_code = $3
+              AND deleted_at IS NULL
+            "#,
+            entity.as_str(),
+            entity_id,
+            language_code
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn upsert(&self, seo: NewSeoMetadata) -> Result<SeoMetadata, String> {
+        sqlx::query_as!(
+            SeoMetadata,
+            r#"
+            INSERT INTO seo_metadata (
+                entity_type, entity_id, language_code, meta_title, meta_description,
+                canonical_url, robots_index, robots_follow, og_image_media_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (entity_type, entity_id, language_code)
+            DO UPDATE SET meta_title = EXCLUDED.meta_title,
+                          meta_description = EXCLUDED.meta_description,
+                          canonical_url = EXCLUDED.canonical_url,
+                          robots_index = EXCLUDED.robots_index,
+                          robots_follow = EXCLUDED.robots_follow,
+                          og_image_media_id = EXCLUDED.og_image_media_id,
+                          deleted_at = NULL,
+                          updated_at = NOW()
+            RETURNING id, entity_type, entity_id, language_code, meta_title, meta_description,
+                      canonical_url, robots_index, robots_follow, og_image_media_id,
+                      created_at, updated_at, deleted_at
+            "#,
+            seo.entity_type,
+            seo.entity_id,
+            seo.language_code,
+            seo.meta_title,
+            seo.meta_description,
+            seo.canonical_url,
+            seo.robots_index,
+            seo.robots_follow,
+            seo.og_image_media_id
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+}
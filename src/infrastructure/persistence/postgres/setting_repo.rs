@@ -1,38 +1,54 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use sqlx::{Pool, Postgres};
 
 use crate::domain::entities::setting::Setting;
 use crate::domain::repositories::setting_repository::SettingRepository;
+use crate::shared::read_cache::ReadCache;
+use crate::shared::sitemap_cache::SitemapCache;
 
 pub struct PgSettingRepository {
     pool: Pool<Postgres>,
+    /// URL trong sitemap dựng theo các setting `seo_*`
+    sitemap_cache: Arc<SitemapCache>,
+    /// Setting đọc theo key ở mọi request (vd `language_fallbacks`)
+    cache: ReadCache<String, Option<Setting>>,
 }
 
 impl PgSettingRepository {
-    pub fn new(pool: Pool<Postgres>) -> Self {
-        Self { pool }
+    pub fn new(pool: Pool<Postgres>, sitemap_cache: Arc<SitemapCache>) -> Self {
+        Self {
+            pool,
+            sitemap_cache,
+            cache: ReadCache::new(),
+        }
     }
 }
 
 #[async_trait]
 impl SettingRepository for PgSettingRepository {
     async fn get_by_key(&self, key: &str) -> Result<Option<Setting>, String> {
-        sqlx::query_as!(
-            Setting,
-            r#"
-            SELECT id, key, value as "value: _", description, created_at, updated_at, deleted_at
-            FROM settings
-            WHERE key = $1 AND deleted_at IS NULL
-            "#,
-            key
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(|e| e.to_string())
+        self.cache
+            .get_or_load(key.to_string(), || async {
+                sqlx::query_as!(
+                    Setting,
+                    r#"
+                    SELECT id, key, value as "value: _", description, created_at, updated_at, deleted_at
+                    FROM settings
+                    WHERE key = $1 AND deleted_at IS NULL
+                    "#,
+                    key
+                )
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| e.to_string())
+            })
+            .await
     }
 
     async fn set(&self, key: &str, value: Option<serde_json::Value>) -> Result<Setting, String> {
-        sqlx::query_as!(
+        let result = sqlx::query_as!(
             Setting,
             r#"
             INSERT INTO settings (key, value)
@@ -46,7 +62,9 @@ impl SettingRepository for PgSettingRepository {
         )
         .fetch_one(&self.pool)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string());
+        let result = self.cache.invalidate_on_ok(result);
+        self.sitemap_cache.invalidate_on_ok(result)
     }
 
     async fn list(&self) -> Result<Vec<Setting>, String> {
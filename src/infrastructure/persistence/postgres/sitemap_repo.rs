@@ -0,0 +1,173 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use sqlx::{Pool, Postgres};
+
+use crate::domain::entities::sitemap::{SitemapEntry, SitemapStats};
+use crate::domain::repositories::sitemap_repository::{SitemapRepository, SitemapSection};
+
+pub struct PgSitemapRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgSitemapRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SitemapRepository for PgSitemapRepository {
+    async fn stats(&self, section: SitemapSection) -> Result<SitemapStats, String> {
+        let query = match section {
+            SitemapSection::Posts => sqlx::query_as!(
+                SitemapStats,
+                r#"
+                SELECT COUNT(*) as "total!", MAX(COALESCE(updated_at, published_at)) as last_modified
+                FROM posts
+                WHERE deleted_at IS NULL AND slug IS NOT NULL
+                  AND status = 'published'
+                  AND (published_at IS NULL OR published_at <= NOW())
+                  AND (unpublish_at IS NULL OR unpublish_at > NOW())
+                "#
+            )
+            .fetch_one(&self.pool)
+            .await,
+            SitemapSection::Categories => sqlx::query_as!(
+                SitemapStats,
+                r#"
+                SELECT COUNT(*) as "total!", MAX(updated_at) as last_modified
+                FROM categories
+                WHERE deleted_at IS NULL AND slug IS NOT NULL
+                "#
+            )
+            .fetch_one(&self.pool)
+            .await,
+            SitemapSection::Tags => sqlx::query_as!(
+                SitemapStats,
+                r#"
+                SELECT COUNT(*) as "total!", MAX(updated_at) as last_modified
+                FROM tags
+                WHERE deleted_at IS NULL AND slug IS NOT NULL
+                "#
+            )
+            .fetch_one(&self.pool)
+            .await,
+        };
+        query.map_err(|e| e.to_string())
+    }
+
+    async fn list(
+        &self,
+        section: SitemapSection,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<SitemapEntry>, String> {
+        let query = match section {
+            SitemapSection::Posts => sqlx::query_as!(
+                SitemapEntry,
+                r#"
+                SELECT p.slug as "slug!", NULL::text as "type",
+                       COALESCE(p.updated_at, p.published_at) as updated_at,
+                       COALESCE(
+                           ARRAY_AGG(pt.language_code ORDER BY pt.language_code)
+                               FILTER (WHERE pt.id IS NOT NULL),
+                           '{}'
+                       ) as "translation_languages!",
+                       COALESCE(
+                           ARRAY_AGG(COALESCE(NULLIF(pt.slug, ''), p.slug) ORDER BY pt.language_code)
+                               FILTER (WHERE pt.id IS NOT NULL),
+                           '{}'
+                       ) as "translation_slugs!"
+                FROM posts p
+                LEFT JOIN post_translations pt
+                       ON pt.post_id = p.id AND pt.deleted_at IS NULL
+                WHERE p.deleted_at IS NULL AND p.slug IS NOT NULL
+                  AND p.status = 'published'
+                  AND (p.published_at IS NULL OR p.published_at <= NOW())
+                  AND (p.unpublish_at IS NULL OR p.unpublish_at > NOW())
+                GROUP BY p.id
+                ORDER BY p.id
+                OFFSET $1 LIMIT $2
+                "#,
+                offset,
+                limit
+            )
+            .fetch_all(&self.pool)
+            .await,
+            SitemapSection::Categories => sqlx::query_as!(
+                SitemapEntry,
+                r#"
+                SELECT c.slug as "slug!", c.type as "type?", c.updated_at,
+                       COALESCE(
+                           ARRAY_AGG(ct.language_code ORDER BY ct.language_code)
+                               FILTER (WHERE ct.id IS NOT NULL),
+                           '{}'
+                       ) as "translation_languages!",
+                       COALESCE(
+                           ARRAY_AGG(c.slug ORDER BY ct.language_code)
+                               FILTER (WHERE ct.id IS NOT NULL),
+                           '{}'
+                       ) as "translation_slugs!"
+                FROM categories c
+                LEFT JOIN category_translations ct
+                       ON ct.category_id = c.id AND ct.deleted_at IS NULL
+                WHERE c.deleted_at IS NULL AND c.slug IS NOT NULL
+                GROUP BY c.id
+                ORDER BY c.id
+                OFFSET $1 LIMIT $2
+                "#,
+                offset,
+                limit
+            )
+            .fetch_all(&self.pool)
+            .await,
+            SitemapSection::Tags => sqlx::query_as!(
+                SitemapEntry,
+                r#"
+                SELECT t.slug as "slug!", t.type as "type?", t.updated_at,
+                       COALESCE(
+                           ARRAY_AGG(tt.language_code ORDER BY tt.language_code)
+                               FILTER (WHERE tt.id IS NOT NULL),
+                           '{}'
+                       ) as "translation_languages!",
+                       COALESCE(
+                           ARRAY_AGG(t.slug ORDER BY tt.language_code)
+                               FILTER (WHERE tt.id IS NOT NULL),
+                           '{}'
+                       ) as "translation_slugs!"
+                FROM tags t
+                LEFT JOIN tag_translations tt
+                       ON tt.tag_id = t.id AND tt.deleted_at IS NULL
+                WHERE t.deleted_at IS NULL AND t.slug IS NOT NULL
+                GROUP BY t.id
+                ORDER BY t.id
+                OFFSET $1 LIMIT $2
+                "#,
+                offset,
+                limit
+            )
+            .fetch_all(&self.pool)
+            .await,
+        };
+        query.map_err(|e| e.to_string())
+    }
+
+    async fn next_schedule_change(&self) -> Result<Option<NaiveDateTime>, String> {
+        sqlx::query_scalar!(
+            r#"
+            SELECT MIN(at) as at
+            FROM (
+                SELECT published_at AS at FROM posts
+                WHERE deleted_at IS NULL AND status IN ('published', 'scheduled')
+                  AND published_at > NOW()
+                UNION ALL
+                SELECT unpublish_at FROM posts
+                WHERE deleted_at IS NULL AND status = 'published' AND unpublish_at > NOW()
+            ) due
+            "#
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+}
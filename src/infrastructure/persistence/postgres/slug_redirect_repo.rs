@@ -0,0 +1,119 @@
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::domain::repositories::slug_redirect_repository::{
+    SlugRedirectRepository, SlugRedirectTarget,
+};
+use crate::domain::repositories::slug_repository::SlugScope;
+
+pub struct PgSlugRedirectRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgSlugRedirectRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SlugRedirectRepository for PgSlugRedirectRepository {
+    async fn find_current_slug(
+        &self,
+        scope: SlugScope<'_>,
+        old_slug: &str,
+    ) -> Result<Option<SlugRedirectTarget>, String> {
+        let current = match scope {
+            SlugScope::Post => {
+                sqlx::query_as!(
+                    SlugRedirectTarget,
+                    r#"
+                    SELECT p.slug as "slug!", '' as "scope!"
+                    FROM slug_redirects sr
+                    INNER JOIN posts p
+                        ON p.id = sr.entity_id AND p.deleted_at IS NULL AND p.slug IS NOT NULL
+                    WHERE sr.entity_type = $1 AND sr.scope = $2 AND sr.old_slug = $3
+                      AND sr.deleted_at IS NULL
+                    "#,
+                    scope.entity_type(),
+                    scope.scope_key(),
+                    old_slug
+                )
+                .fetch_optional(&self.pool)
+                .await
+            }
+            SlugScope::Category { .. } => {
+                sqlx::query_as!(
+                    SlugRedirectTarget,
+                    r#"
+                    SELECT c.slug as "slug!", c.type as "scope!"
+                    FROM slug_redirects sr
+                    INNER JOIN categories c
+                        ON c.id = sr.entity_id AND c.deleted_at IS NULL AND c.slug IS NOT NULL
+                    WHERE sr.entity_type = $1 AND sr.scope = $2 AND sr.old_slug = $3
+                      AND sr.deleted_at IS NULL
+                    "#,
+                    scope.entity_type(),
+                    scope.scope_key(),
+                    old_slug
+                )
+                .fetch_optional(&self.pool)
+                .await
+            }
+            // Chưa lưu lịch sử slug cho tag/banner/bản dịch
+            SlugScope::Tag { .. } | SlugScope::Banner | SlugScope::PostTranslation { .. } => {
+                return Ok(None);
+            }
+        };
+
+        current.map_err(|e| e.to_string())
+    }
+}
+
+/// Ghi nhận đổi slug `old_slug` (trong `old_scope`) `-> new_slug` (trong `new_scope`) của entity,
+/// trong transaction cập nhật entity để slug mới và redirect từ slug cũ được commit cùng lúc.
+/// Vị trí mới được gỡ khỏi lịch sử của chính entity (trường hợp đổi về slug cũ)
+pub async fn record_slug_change_tx(
+    tx: &mut Transaction<'_, Postgres>,
+    old_scope: SlugScope<'_>,
+    new_scope: SlugScope<'_>,
+    entity_id: Uuid,
+    old_slug: &str,
+    new_slug: &str,
+) -> Result<(), String> {
+    // Slug cũ có thể từng thuộc entity khác (đã đổi tên) => chuyển về entity hiện tại
+    sqlx::query!(
+        r#"
+        INSERT INTO slug_redirects (entity_type, scope, entity_id, old_slug)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (entity_type, scope, old_slug)
+        DO UPDATE SET entity_id = EXCLUDED.entity_id, deleted_at = NULL, updated_at = NOW()
+        "#,
+        old_scope.entity_type(),
+        old_scope.scope_key(),
+        entity_id,
+        old_slug
+    )
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    sqlx::query!(
+        r#"
+        UPDATE slug_redirects
+        SET deleted_at = NOW(), updated_at = NOW()
+        WHERE entity_type = $1 AND scope = $2 AND entity_id = $3 AND old_slug = $4
+          AND deleted_at IS NULL
+        "#,
+        new_scope.entity_type(),
+        new_scope.scope_key(),
+        entity_id,
+        new_slug
+    )
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::domain::repositories::slug_repository::{SlugRepository, SlugScope};
+
+pub struct PgSlugRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgSlugRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SlugRepository for PgSlugRepository {
+    async fn find_taken(
+        &self,
+        scope: SlugScope<'_>,
+        base: &str,
+        exclude_id: Option<Uuid>,
+    ) -> Result<Vec<String>, String> {
+        // `base` chỉ gồm [a-z0-9-] (đã qua slugify) nên an toàn với LIKE
+        let pattern = format!("{base}-%");
+        let rows = match scope {
+            // Slug bản dịch (mọi ngôn ngữ) cũng chiếm URL `/posts/:slug`;
+            // bản dịch của chính bài đó không tính là trùng
+            SlugScope::Post => {
+                sqlx::query_scalar!(
+                    r#"
+                    SELECT slug as "slug!" FROM posts
+                    WHERE (slug = $1 OR slug LIKE $2) AND ($3::uuid IS NULL OR id <> $3)
+                    UNION
+                    SELECT slug as "slug!" FROM post_translations
+                    WHERE deleted_at IS NULL
+                      AND (slug = $1 OR slug LIKE $2)
+                      AND ($3::uuid IS NULL OR post_id <> $3)
+                    "#,
+                    base,
+                    pattern,
+                    exclude_id
+                )
+                .fetch_all(&self.pool)
+                .await
+            }
+            SlugScope::Banner => {
+                sqlx::query_scalar!(
+                    r#"
+                    SELECT slug as "slug!" FROM banners
+                    WHERE (slug = $1 OR slug LIKE $2) AND ($3::uuid IS NULL OR id <> $3)
+                    "#,
+                    base,
+                    pattern,
+                    exclude_id
+                )
+                .fetch_all(&self.pool)
+                .await
+            }
+            SlugScope::Category { r#type } => {
+                sqlx::query_scalar!(
+                    r#"
+                    SELECT slug as "slug!" FROM categories
+                    WHERE type = $4 AND (slug = $1 OR slug LIKE $2)
+                      AND ($3::uuid IS NULL OR id <> $3)
+                    "#,
+                    base,
+                    pattern,
+                    exclude_id,
+                    r#type
+                )
+                .fetch_all(&self.pool)
+                .await
+            }
+            SlugScope::Tag { r#type } => {
+                sqlx::query_scalar!(
+                    r#"
+                    SELECT slug as "slug!" FROM tags
+                    WHERE type = $4 AND (slug = $1 OR slug LIKE $2)
+                      AND ($3::uuid IS NULL OR id <> $3)
+                    "#,
+                    base,
+                    pattern,
+                    exclude_id,
+                    r#type
+                )
+                .fetch_all(&self.pool)
+                .await
+            }
+            // `exclude_id` là id bài viết: bản dịch khác của chính bài đó không tính là trùng
+            SlugScope::PostTranslation { language_code } => {
+                sqlx::query_scalar!(
+                    r#"
+                    SELECT slug as "slug!" FROM post_translations
+                    WHERE language_code = $4 AND deleted_at IS NULL
+                      AND (slug = $1 OR slug LIKE $2)
+                      AND ($3::uuid IS NULL OR post_id <> $3)
+                    UNION
+                    SELECT slug as "slug!" FROM posts
+                    WHERE (slug = $1 OR slug LIKE $2) AND ($3::uuid IS NULL OR id <> $3)
+                    "#,
+                    base,
+                    pattern,
+                    exclude_id,
+                    language_code
+                )
+                .fetch_all(&self.pool)
+                .await
+            }
+        };
+
+        rows.map_err(|e| e.to_string())
+    }
+}
@@ -1,17 +1,24 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use sqlx::{Pool, Postgres};
 use uuid::Uuid;
 
 use crate::domain::entities::tag::Tag;
 use crate::domain::repositories::tag_repository::TagRepository;
+use crate::shared::sitemap_cache::SitemapCache;
 
 pub struct PgTagRepository {
     pool: Pool<Postgres>,
+    sitemap_cache: Arc<SitemapCache>,
 }
 
 impl PgTagRepository {
-    pub fn new(pool: Pool<Postgres>) -> Self {
-        Self { pool }
+    pub fn new(pool: Pool<Postgres>, sitemap_cache: Arc<SitemapCache>) -> Self {
+        Self {
+            pool,
+            sitemap_cache,
+        }
     }
 }
 
@@ -67,6 +74,79 @@ impl TagRepository for PgTagRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Tag>, String> {
+        sqlx::query_as!(
+            Tag,
+            r#"
+            SELECT id, name, slug, type, description,
+                   created_at, updated_at, deleted_at
+            FROM tags
+            WHERE id = ANY($1) AND deleted_at IS NULL
+            "#,
+            ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_by_slugs_and_type(
+        &self,
+        slugs: &[String],
+        r#type: &str,
+    ) -> Result<Vec<Tag>, String> {
+        sqlx::query_as!(
+            Tag,
+            r#"
+            SELECT id, name, slug, type, description,
+                   created_at, updated_at, deleted_at
+            FROM tags
+            WHERE slug = ANY($1) AND type = $2 AND deleted_at IS NULL
+            "#,
+            slugs,
+            r#type
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_by_post_ids(&self, post_ids: &[Uuid]) -> Result<Vec<(Uuid, Tag)>, String> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT pt.post_id, t.id, t.name, t.slug, t.type, t.description,
+                   t.created_at, t.updated_at, t.deleted_at
+            FROM post_tags pt
+            INNER JOIN tags t ON t.id = pt.tag_id
+            WHERE pt.post_id = ANY($1) AND pt.deleted_at IS NULL AND t.deleted_at IS NULL
+            ORDER BY t.name
+            "#,
+            post_ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| {
+                (
+                    r.post_id,
+                    Tag {
+                        id: r.id,
+                        name: r.name,
+                        slug: r.slug,
+                        r#type: r.r#type,
+                        description: r.description,
+                        created_at: r.created_at,
+                        updated_at: r.updated_at,
+                        deleted_at: r.deleted_at,
+                    },
+                )
+            })
+            .collect())
+    }
+
     async fn create(&self, tag: Tag) -> Result<Tag, String> {
         let created = sqlx::query_as!(
             Tag,
@@ -89,6 +169,7 @@ impl TagRepository for PgTagRepository {
         .await
         .map_err(|e| e.to_string())?;
 
+        self.sitemap_cache.invalidate();
         Ok(created)
     }
 
@@ -116,6 +197,7 @@ impl TagRepository for PgTagRepository {
         .await
         .map_err(|e| e.to_string())?;
 
+        self.sitemap_cache.invalidate();
         Ok(updated)
     }
 
@@ -132,6 +214,7 @@ impl TagRepository for PgTagRepository {
         .await
         .map_err(|e| e.to_string())?;
 
+        self.sitemap_cache.invalidate();
         Ok(())
     }
 
@@ -152,6 +235,7 @@ impl TagRepository for PgTagRepository {
         .await
         .map_err(|e| e.to_string())?;
 
+        self.sitemap_cache.invalidate();
         Ok(())
     }
 }
@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::domain::entities::tag_translation::{NewTagTranslation, TagTranslation};
+use crate::domain::repositories::tag_translation_repository::TagTranslationRepository;
+use crate::shared::error::AppError;
+use crate::shared::sitemap_cache::SitemapCache;
+
+pub struct PgTagTranslationRepository {
+    pool: Pool<Postgres>,
+    sitemap_cache: Arc<SitemapCache>,
+}
+
+impl PgTagTranslationRepository {
+    pub fn new(pool: Pool<Postgres>, sitemap_cache: Arc<SitemapCache>) -> Self {
+        Self {
+            pool,
+            sitemap_cache,
+        }
+    }
+}
+
+#[async_trait]
+impl TagTranslationRepository for PgTagTranslationRepository {
+    async fn list_by_tag(&self, tag_id: Uuid) -> Result<Vec<TagTranslation>, String> {
+        sqlx::query_as!(
+            TagTranslation,
+            r#"
+            SELECT id, tag_id, language_code, name, description,
+                   created_at, updated_at, deleted_at
+            FROM tag_translations
+            WHERE tag_id = $1 AND deleted_at IS NULL
+            ORDER BY language_code
+            "#,
+            tag_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find(
+        &self,
+        tag_id: Uuid,
+        language_code: &str,
+    ) -> Result<Option<TagTranslation>, String> {
+        sqlx::query_as!(
+            TagTranslation,
+            r#"
+            SELECT id, tag_id, language_code, name, description,
+                   created_at, updated_at, deleted_at
+            FROM tag_translations
+            WHERE tag_id = $1 AND language_code = $2 AND deleted_at IS NULL
+            "#,
+            tag_id,
+            language_code
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_many(
+        &self,
+        tag_ids: &[Uuid],
+        language_code: &str,
+    ) -> Result<Vec<TagTranslation>, String> {
+        if tag_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        sqlx::query_as!(
+            TagTranslation,
+            r#"
+            SELECT id, tag_id, language_code, name, description,
+                   created_at, updated_at, deleted_at
+            FROM tag_translations
+            WHERE tag_id = ANY($1) AND language_code = $2 AND deleted_at IS NULL
+            "#,
+            tag_ids,
+            language_code
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn upsert(&self, translation: NewTagTranslation) -> Result<TagTranslation, String> {
+        let saved = sqlx::query_as!(
+            TagTranslation,
+            r#"
+            INSERT INTO tag_translations (tag_id, language_code, name, description)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (tag_id, language_code) DO UPDATE
+            SET name = EXCLUDED.name,
+                description = EXCLUDED.description,
+                updated_at = NOW(),
+                deleted_at = NULL
+            RETURNING id, tag_id, language_code, name, description,
+                      created_at, updated_at, deleted_at
+            "#,
+            translation.tag_id,
+            translation.language_code,
+            translation.name,
+            translation.description,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        self.sitemap_cache.invalidate();
+        Ok(saved)
+    }
+
+    async fn delete(&self, tag_id: Uuid, language_code: &str) -> Result<(), AppError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE tag_translations
+            SET deleted_at = NOW(), updated_at = NOW()
+            WHERE tag_id = $1 AND language_code = $2 AND deleted_at IS NULL
+            "#,
+            tag_id,
+            language_code
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found(
+                "TAG_TRANSLATION_NOT_FOUND",
+                "Tag translation",
+            ));
+        }
+        self.sitemap_cache.invalidate();
+        Ok(())
+    }
+}
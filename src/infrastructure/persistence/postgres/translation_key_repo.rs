@@ -0,0 +1,380 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::domain::entities::translation_key::{BundleMessage, TranslationKey, TranslationValue};
+use crate::domain::repositories::translation_key_repository::{
+    TranslationKeyRepository, TranslationKeySearchFilter,
+};
+use crate::shared::error::AppError;
+use crate::shared::read_cache::ReadCache;
+use crate::shared::utils::query::{
+    BindValue, CursorRow, CursorValue, FieldInfo, FieldType, ListParams, PaginatedResult,
+    SortDirection, build_query_with_seed,
+};
+
+/// Bundle theo (namespace, chuỗi ngôn ngữ): message lỗi được dịch ở mọi response lỗi.
+/// Dùng chung với repo ngôn ngữ (xoá ngôn ngữ xoá/chuyển bản dịch)
+pub type MessageBundleCache = ReadCache<(String, Vec<String>), Vec<BundleMessage>>;
+
+pub struct PgTranslationKeyRepository {
+    pool: Pool<Postgres>,
+    bundles: Arc<MessageBundleCache>,
+}
+
+impl PgTranslationKeyRepository {
+    pub fn new(pool: Pool<Postgres>, bundles: Arc<MessageBundleCache>) -> Self {
+        Self { pool, bundles }
+    }
+}
+
+impl CursorRow for TranslationKey {
+    fn cursor_id(&self) -> Uuid {
+        self.id
+    }
+
+    fn cursor_value(&self, field: &str) -> CursorValue {
+        match field {
+            "namespace" => CursorValue::Text(Some(self.namespace.clone())),
+            "key" => CursorValue::Text(Some(self.key.clone())),
+            "created_at" => CursorValue::Timestamp(self.created_at),
+            "updated_at" => CursorValue::Timestamp(self.updated_at),
+            _ => CursorValue::Unsupported,
+        }
+    }
+}
+
+#[async_trait]
+impl TranslationKeyRepository for PgTranslationKeyRepository {
+    async fn search(
+        &self,
+        filter: &TranslationKeySearchFilter,
+        sort_by: Option<String>,
+        cursor: Option<String>,
+        limit: i64,
+    ) -> Result<PaginatedResult<TranslationKey>, AppError> {
+        let allowed_fields = [
+            FieldInfo {
+                name: "namespace",
+                field_type: FieldType::Text,
+            },
+            FieldInfo {
+                name: "key",
+                field_type: FieldType::Text,
+            },
+            FieldInfo {
+                name: "created_at",
+                field_type: FieldType::Timestamp,
+            },
+            FieldInfo {
+                name: "updated_at",
+                field_type: FieldType::Timestamp,
+            },
+        ];
+
+        let base_sql = "SELECT id, namespace, key, description, created_at, updated_at, deleted_at FROM translation_keys WHERE deleted_at IS NULL";
+
+        let mut filters: Vec<(String, BindValue)> = Vec::new();
+        if let Some(namespace) = &filter.namespace {
+            let idx = filters.len() + 1;
+            filters.push((
+                format!("namespace = ${idx}"),
+                BindValue::Text(namespace.clone()),
+            ));
+        }
+        let base_filters = filters
+            .iter()
+            .map(|(clause, bind)| (clause.as_str(), bind.clone()))
+            .collect::<Vec<_>>();
+
+        let params = ListParams {
+            search: filter.search.clone(),
+            fields: None,
+            sort_by,
+            cursor,
+            limit: Some(limit),
+            ..Default::default()
+        };
+
+        let built = build_query_with_seed(
+            base_sql,
+            &params,
+            &allowed_fields,
+            "key",
+            SortDirection::Asc,
+            &["key", "description"],
+            &base_filters,
+            base_filters.len() as i32,
+        )?;
+
+        let mut query = sqlx::query_as::<_, TranslationKey>(&built.sql);
+        for b in built.binds.clone() {
+            query = match b {
+                BindValue::Text(v) => query.bind(v),
+                BindValue::Timestamp(v) => query.bind(v),
+                BindValue::Uuid(v) => query.bind(v),
+                BindValue::I64(v) => query.bind(v),
+            };
+        }
+
+        let items = query
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        let next_cursor = built.next_cursor(&items)?;
+
+        Ok(PaginatedResult {
+            items,
+            next_cursor,
+            limit: built.limit,
+        })
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<TranslationKey>, String> {
+        sqlx::query_as!(
+            TranslationKey,
+            r#"
+            SELECT id, namespace, key, description, created_at, updated_at, deleted_at
+            FROM translation_keys
+            WHERE id = $1 AND deleted_at IS NULL
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn find_by_name(
+        &self,
+        namespace: &str,
+        key: &str,
+    ) -> Result<Option<TranslationKey>, String> {
+        sqlx::query_as!(
+            TranslationKey,
+            r#"
+            SELECT id, namespace, key, description, created_at, updated_at, deleted_at
+            FROM translation_keys
+            WHERE namespace = $1 AND key = $2 AND deleted_at IS NULL
+            "#,
+            namespace,
+            key
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn create(
+        &self,
+        key: TranslationKey,
+        values: Vec<(String, String)>,
+    ) -> Result<TranslationKey, String> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        let created = sqlx::query_as!(
+            TranslationKey,
+            r#"
+            INSERT INTO translation_keys (id, namespace, key, description)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, namespace, key, description, created_at, updated_at, deleted_at
+            "#,
+            key.id,
+            key.namespace,
+            key.key,
+            key.description
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        for (language_code, value) in values {
+            sqlx::query!(
+                r#"
+                INSERT INTO translation_values (key_id, language_code, value)
+                VALUES ($1, $2, $3)
+                "#,
+                created.id,
+                language_code,
+                value
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+        }
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        self.bundles.invalidate();
+        Ok(created)
+    }
+
+    async fn update(&self, key: TranslationKey) -> Result<TranslationKey, String> {
+        let updated = sqlx::query_as!(
+            TranslationKey,
+            r#"
+            UPDATE translation_keys
+            SET namespace = $2,
+                key = $3,
+                description = $4,
+                updated_at = NOW()
+            WHERE id = $1 AND deleted_at IS NULL
+            RETURNING id, namespace, key, description, created_at, updated_at, deleted_at
+            "#,
+            key.id,
+            key.namespace,
+            key.key,
+            key.description
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| e.to_string());
+        self.bundles.invalidate_on_ok(updated)
+    }
+
+    async fn soft_delete(&self, id: Uuid) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        let result = sqlx::query!(
+            r#"
+            UPDATE translation_keys
+            SET deleted_at = NOW(), updated_at = NOW()
+            WHERE id = $1 AND deleted_at IS NULL
+            "#,
+            id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found(
+                "TRANSLATION_KEY_NOT_FOUND",
+                "Translation key",
+            ));
+        }
+
+        sqlx::query!(
+            r#"
+            UPDATE translation_values
+            SET deleted_at = NOW(), updated_at = NOW()
+            WHERE key_id = $1 AND deleted_at IS NULL
+            "#,
+            id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+        self.bundles.invalidate();
+        Ok(())
+    }
+
+    async fn list_values(&self, key_ids: &[Uuid]) -> Result<Vec<TranslationValue>, String> {
+        sqlx::query_as!(
+            TranslationValue,
+            r#"
+            SELECT id, key_id, language_code, value, created_at, updated_at, deleted_at
+            FROM translation_values
+            WHERE key_id = ANY($1) AND deleted_at IS NULL
+            ORDER BY language_code
+            "#,
+            key_ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn upsert_value(
+        &self,
+        key_id: Uuid,
+        language_code: &str,
+        value: &str,
+    ) -> Result<TranslationValue, String> {
+        let upserted = sqlx::query_as!(
+            TranslationValue,
+            r#"
+            INSERT INTO translation_values (key_id, language_code, value)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (key_id, language_code) DO UPDATE
+            SET value = EXCLUDED.value, deleted_at = NULL, updated_at = NOW()
+            RETURNING id, key_id, language_code, value, created_at, updated_at, deleted_at
+            "#,
+            key_id,
+            language_code,
+            value
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| e.to_string());
+        self.bundles.invalidate_on_ok(upserted)
+    }
+
+    async fn delete_value(&self, key_id: Uuid, language_code: &str) -> Result<(), AppError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE translation_values
+            SET deleted_at = NOW(), updated_at = NOW()
+            WHERE key_id = $1 AND language_code = $2 AND deleted_at IS NULL
+            "#,
+            key_id,
+            language_code
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found(
+                "TRANSLATION_VALUE_NOT_FOUND",
+                "Translation value",
+            ));
+        }
+        self.bundles.invalidate();
+        Ok(())
+    }
+
+    async fn bundle(
+        &self,
+        namespace: &str,
+        language_codes: &[String],
+    ) -> Result<Vec<BundleMessage>, String> {
+        let key = (namespace.to_string(), language_codes.to_vec());
+        self.bundles
+            .get_or_load(key, || async {
+                sqlx::query_as!(
+                    BundleMessage,
+                    r#"
+                    SELECT k.key, v.language_code, v.value
+                    FROM translation_keys k
+                    JOIN translation_values v ON v.key_id = k.id AND v.deleted_at IS NULL
+                    WHERE k.namespace = $1 AND k.deleted_at IS NULL
+                      AND v.language_code = ANY($2)
+                    ORDER BY k.key
+                    "#,
+                    namespace,
+                    language_codes
+                )
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| e.to_string())
+            })
+            .await
+    }
+
+    async fn namespace_exists(&self, namespace: &str) -> Result<bool, String> {
+        sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM translation_keys WHERE namespace = $1 AND deleted_at IS NULL
+            ) AS "exists!"
+            "#,
+            namespace
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+}
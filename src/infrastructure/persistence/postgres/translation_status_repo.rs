@@ -0,0 +1,252 @@
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::domain::entities::translation_status::{
+    TranslationCoverageRow, TranslationQueueItem, TranslationUnit,
+};
+use crate::domain::repositories::translation_status_repository::{
+    TranslationQueueFilter, TranslationStatusRepository, TranslationUnitFilter,
+};
+use crate::shared::error::AppError;
+use crate::shared::utils::query::{
+    BindValue, CursorRow, CursorValue, FieldInfo, FieldType, ListParams, PaginatedResult,
+    SortDirection, build_query_with_seed,
+};
+
+pub struct PgTranslationStatusRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PgTranslationStatusRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+impl CursorRow for TranslationQueueItem {
+    fn cursor_id(&self) -> Uuid {
+        self.id
+    }
+
+    fn cursor_value(&self, field: &str) -> CursorValue {
+        match field {
+            "label" => CursorValue::Text(Some(self.label.clone())),
+            "source_changed_at" => CursorValue::Timestamp(self.source_changed_at),
+            "translation_updated_at" => CursorValue::Timestamp(self.translation_updated_at),
+            _ => CursorValue::Unsupported,
+        }
+    }
+}
+
+#[async_trait]
+impl TranslationStatusRepository for PgTranslationStatusRepository {
+    async fn coverage(
+        &self,
+        language_code: Option<&str>,
+    ) -> Result<Vec<TranslationCoverageRow>, String> {
+        sqlx::query_as!(
+            TranslationCoverageRow,
+            r#"
+            SELECT entity_type AS "entity_type!",
+                   language_code AS "language_code!",
+                   COUNT(*) AS "total!",
+                   COUNT(*) FILTER (WHERE status = 'up_to_date') AS "up_to_date!",
+                   COUNT(*) FILTER (WHERE status = 'outdated') AS "outdated!",
+                   COUNT(*) FILTER (WHERE status = 'missing') AS "missing!"
+            FROM translation_status
+            WHERE $1::TEXT IS NULL OR language_code = $1
+            GROUP BY entity_type, language_code
+            ORDER BY language_code, entity_type
+            "#,
+            language_code
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn search_queue(
+        &self,
+        filter: &TranslationQueueFilter,
+        sort_by: Option<String>,
+        cursor: Option<String>,
+        limit: i64,
+    ) -> Result<PaginatedResult<TranslationQueueItem>, AppError> {
+        let allowed_fields = [
+            FieldInfo {
+                name: "label",
+                field_type: FieldType::Text,
+            },
+            FieldInfo {
+                name: "source_changed_at",
+                field_type: FieldType::Timestamp,
+            },
+            FieldInfo {
+                name: "translation_updated_at",
+                field_type: FieldType::Timestamp,
+            },
+        ];
+
+        let base_sql = "SELECT entity_type, id, type, slug, label, language_code, status, source_changed_at, translation_updated_at FROM translation_status WHERE status <> 'up_to_date'";
+
+        let mut filters: Vec<(String, BindValue)> = vec![(
+            "language_code = $1".to_string(),
+            BindValue::Text(filter.language_code.clone()),
+        )];
+        if let Some(entity_type) = &filter.entity_type {
+            let idx = filters.len() + 1;
+            filters.push((
+                format!("entity_type = ${idx}"),
+                BindValue::Text(entity_type.clone()),
+            ));
+        }
+        if let Some(status) = &filter.status {
+            let idx = filters.len() + 1;
+            filters.push((format!("status = ${idx}"), BindValue::Text(status.clone())));
+        }
+        let base_filters = filters
+            .iter()
+            .map(|(clause, bind)| (clause.as_str(), bind.clone()))
+            .collect::<Vec<_>>();
+
+        let params = ListParams {
+            search: filter.search.clone(),
+            fields: None,
+            sort_by,
+            cursor,
+            limit: Some(limit),
+            ..Default::default()
+        };
+
+        // Hàng đợi: bản gốc đổi lâu nhất được dịch trước
+        let built = build_query_with_seed(
+            base_sql,
+            &params,
+            &allowed_fields,
+            "source_changed_at",
+            SortDirection::Asc,
+            &["label"],
+            &base_filters,
+            base_filters.len() as i32,
+        )?;
+
+        let mut query = sqlx::query_as::<_, TranslationQueueItem>(&built.sql);
+        for b in built.binds.clone() {
+            query = match b {
+                BindValue::Text(v) => query.bind(v),
+                BindValue::Timestamp(v) => query.bind(v),
+                BindValue::Uuid(v) => query.bind(v),
+                BindValue::I64(v) => query.bind(v),
+            };
+        }
+
+        let items = query
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        let next_cursor = built.next_cursor(&items)?;
+
+        Ok(PaginatedResult {
+            items,
+            next_cursor,
+            limit: built.limit,
+        })
+    }
+
+    async fn list_units(
+        &self,
+        filter: &TranslationUnitFilter,
+    ) -> Result<Vec<TranslationUnit>, String> {
+        // Mỗi bản ghi gốc tách thành nhiều dòng, 1 dòng / trường cần dịch
+        let rows = sqlx::query!(
+            r#"
+            SELECT s.entity_type AS "entity_type!", s.id AS "entity_id!", s.type AS "type?",
+                   s.slug AS "slug?", f.field AS "field!", f.source AS "source?",
+                   f.target AS "target?", s.status AS "status!",
+                   s.source_changed_at, f.position
+            FROM translation_status s
+            JOIN posts p ON p.id = s.id
+            LEFT JOIN post_translations t
+                ON t.post_id = p.id AND t.language_code = s.language_code AND t.deleted_at IS NULL
+            CROSS JOIN LATERAL (VALUES
+                (1, 'title', p.title, t.title),
+                (2, 'excerpt', p.excerpt, t.excerpt),
+                (3, 'content', p.content, t.content)
+            ) AS f(position, field, source, target)
+            WHERE s.entity_type = 'post' AND s.language_code = $1
+              AND ($2::TEXT IS NULL OR s.entity_type = $2)
+              AND ($3::UUID[] IS NULL OR s.id = ANY($3))
+              AND ($4::TIMESTAMP IS NULL OR s.source_changed_at >= $4)
+            UNION ALL
+            SELECT s.entity_type, s.id, s.type, s.slug, f.field, f.source, f.target, s.status,
+                   s.source_changed_at, f.position
+            FROM translation_status s
+            JOIN categories c ON c.id = s.id
+            LEFT JOIN category_translations t
+                ON t.category_id = c.id AND t.language_code = s.language_code AND t.deleted_at IS NULL
+            CROSS JOIN LATERAL (VALUES
+                (1, 'name', c.name, t.name),
+                (2, 'description', c.description, t.description)
+            ) AS f(position, field, source, target)
+            WHERE s.entity_type = 'category' AND s.language_code = $1
+              AND ($2::TEXT IS NULL OR s.entity_type = $2)
+              AND ($3::UUID[] IS NULL OR s.id = ANY($3))
+              AND ($4::TIMESTAMP IS NULL OR s.source_changed_at >= $4)
+            UNION ALL
+            SELECT s.entity_type, s.id, s.type, s.slug, f.field, f.source, f.target, s.status,
+                   s.source_changed_at, f.position
+            FROM translation_status s
+            JOIN tags g ON g.id = s.id
+            LEFT JOIN tag_translations t
+                ON t.tag_id = g.id AND t.language_code = s.language_code AND t.deleted_at IS NULL
+            CROSS JOIN LATERAL (VALUES
+                (1, 'name', g.name, t.name),
+                (2, 'description', g.description, t.description)
+            ) AS f(position, field, source, target)
+            WHERE s.entity_type = 'tag' AND s.language_code = $1
+              AND ($2::TEXT IS NULL OR s.entity_type = $2)
+              AND ($3::UUID[] IS NULL OR s.id = ANY($3))
+              AND ($4::TIMESTAMP IS NULL OR s.source_changed_at >= $4)
+            UNION ALL
+            SELECT s.entity_type, s.id, s.type, s.slug, f.field, f.source, f.target, s.status,
+                   s.source_changed_at, f.position
+            FROM translation_status s
+            JOIN banners b ON b.id = s.id
+            LEFT JOIN banner_translations t
+                ON t.banner_id = b.id AND t.language_code = s.language_code AND t.deleted_at IS NULL
+            CROSS JOIN LATERAL (VALUES
+                (1, 'title', b.title, t.title),
+                (2, 'description', b.description, t.description)
+            ) AS f(position, field, source, target)
+            WHERE s.entity_type = 'banner' AND s.language_code = $1
+              AND ($2::TEXT IS NULL OR s.entity_type = $2)
+              AND ($3::UUID[] IS NULL OR s.id = ANY($3))
+              AND ($4::TIMESTAMP IS NULL OR s.source_changed_at >= $4)
+            -- entity_type, source_changed_at, entity_id, position
+            ORDER BY 1, 9, 2, 10
+            "#,
+            filter.language_code,
+            filter.entity_type,
+            filter.entity_ids.as_deref(),
+            filter.changed_since
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| TranslationUnit {
+                entity_type: r.entity_type,
+                entity_id: r.entity_id,
+                r#type: r.r#type,
+                slug: r.slug,
+                field: r.field,
+                source: r.source,
+                target: r.target,
+                status: r.status,
+            })
+            .collect())
+    }
+}
@@ -21,7 +21,7 @@ impl UserProfileRepository for PgUserProfileRepository {
         sqlx::query_as!(
             UserProfile,
             r#"
-            SELECT id, user_id, avatar_url, phone, address, bio,
+            SELECT id, user_id, avatar_url, phone, address, bio, preferred_language,
                    created_at, updated_at, deleted_at
             FROM user_profiles
             WHERE user_id = $1 AND deleted_at IS NULL
@@ -33,27 +33,46 @@ impl UserProfileRepository for PgUserProfileRepository {
         .map_err(|e: sqlx::Error| e.to_string())
     }
 
+    async fn find_by_user_ids(&self, user_ids: &[Uuid]) -> Result<Vec<UserProfile>, String> {
+        sqlx::query_as!(
+            UserProfile,
+            r#"
+            SELECT id, user_id, avatar_url, phone, address, bio, preferred_language,
+                   created_at, updated_at, deleted_at
+            FROM user_profiles
+            WHERE user_id = ANY($1) AND deleted_at IS NULL
+            "#,
+            user_ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
     async fn upsert(&self, user_id: Uuid, data: UpdateUserProfile) -> Result<UserProfile, String> {
         sqlx::query_as!(
             UserProfile,
             r#"
-            INSERT INTO user_profiles (user_id, avatar_url, phone, address, bio)
-            VALUES ($1, $2, $3, $4, $5)
+            INSERT INTO user_profiles (user_id, avatar_url, phone, address, bio, preferred_language)
+            VALUES ($1, $2, $3, $4, $5, NULLIF($6, ''))
             ON CONFLICT (user_id) DO UPDATE
             SET avatar_url = COALESCE($2, user_profiles.avatar_url),
                 phone      = COALESCE($3, user_profiles.phone),
                 address    = COALESCE($4, user_profiles.address),
                 bio        = COALESCE($5, user_profiles.bio),
+                -- "" => xoá ngôn ngữ ưu tiên
+                preferred_language = NULLIF(COALESCE($6, user_profiles.preferred_language), ''),
                 updated_at = NOW(),
                 deleted_at = NULL
-            RETURNING id, user_id, avatar_url, phone, address, bio,
+            RETURNING id, user_id, avatar_url, phone, address, bio, preferred_language,
                       created_at, updated_at, deleted_at
             "#,
             user_id,
             data.avatar_url,
             data.phone,
             data.address,
-            data.bio
+            data.bio,
+            data.preferred_language
         )
         .fetch_one(&self.pool)
         .await
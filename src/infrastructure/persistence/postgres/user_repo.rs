@@ -3,9 +3,10 @@ use sqlx::{Pool, Postgres, Row};
 
 use crate::domain::entities::user::{NewUser, UpdateUser, User, UserStatus};
 use crate::domain::repositories::user_repository::{UserRepository, UserSearchFilter};
+use crate::shared::error::AppError;
 use crate::shared::utils::query::{
-    BindValue, FieldInfo, FieldType, ListParams, PaginatedResult, SortDirection, build_query,
-    encode_cursor_text, encode_cursor_ts,
+    BindValue, CursorRow, CursorValue, FieldInfo, FieldType, ListParams, PaginatedResult,
+    SortDirection, build_query,
 };
 
 pub struct PgUserRepository {
@@ -20,6 +21,22 @@ impl PgUserRepository {
 
 use uuid::Uuid;
 
+impl CursorRow for User {
+    fn cursor_id(&self) -> Uuid {
+        self.id
+    }
+
+    fn cursor_value(&self, field: &str) -> CursorValue {
+        match field {
+            "name" => CursorValue::Text(Some(self.name.clone())),
+            "email" => CursorValue::Text(Some(self.email.clone())),
+            "status" => CursorValue::Text(self.status.as_ref().map(|s| s.as_str().to_string())),
+            "created_at" => CursorValue::Timestamp(self.created_at),
+            _ => CursorValue::Unsupported,
+        }
+    }
+}
+
 #[async_trait]
 impl UserRepository for PgUserRepository {
     async fn find_all(&self) -> Result<Vec<User>, String> {
@@ -38,7 +55,7 @@ impl UserRepository for PgUserRepository {
         sort_by: Option<String>,
         cursor: Option<String>,
         limit: i64,
-    ) -> Result<PaginatedResult<User>, String> {
+    ) -> Result<PaginatedResult<User>, AppError> {
         let allowed_fields = [
             FieldInfo {
                 name: "name",
@@ -48,13 +65,17 @@ impl UserRepository for PgUserRepository {
                 name: "email",
                 field_type: FieldType::Text,
             },
+            FieldInfo {
+                name: "status",
+                field_type: FieldType::Enum("user_status"),
+            },
             FieldInfo {
                 name: "created_at",
                 field_type: FieldType::Timestamp,
             },
         ];
 
-        let base_sql = r#"SELECT id, name, email, password, status as "status: UserStatus", created_at, updated_at, deleted_at FROM users"#;
+        let base_sql = "SELECT id, name, email, password, status, created_at, updated_at, deleted_at FROM users";
 
         let params = ListParams {
             search: filter.search.clone(),
@@ -62,6 +83,7 @@ impl UserRepository for PgUserRepository {
             sort_by,
             cursor,
             limit: Some(limit),
+            ..Default::default()
         };
 
         let built = build_query(
@@ -74,7 +96,7 @@ impl UserRepository for PgUserRepository {
         )?;
 
         let mut query = sqlx::query_as::<_, User>(&built.sql);
-        for b in built.binds {
+        for b in built.binds.clone() {
             query = match b {
                 BindValue::Text(v) => query.bind(v),
                 BindValue::Timestamp(v) => query.bind(v),
@@ -88,20 +110,7 @@ impl UserRepository for PgUserRepository {
             .await
             .map_err(|e| e.to_string())?;
 
-        let next_cursor = if items.len() as i64 == built.limit {
-            if let Some(last) = items.last() {
-                match built.sort_field {
-                    "name" => Some(encode_cursor_text(&last.name, last.id)),
-                    "email" => Some(encode_cursor_text(&last.email, last.id)),
-                    "created_at" => last.created_at.map(|dt| encode_cursor_ts(dt, last.id)),
-                    _ => None,
-                }
-            } else {
-                None
-            }
-        } else {
-            None
-        };
+        let next_cursor = built.next_cursor(&items)?;
 
         Ok(PaginatedResult {
             items,
@@ -121,6 +130,17 @@ impl UserRepository for PgUserRepository {
         .map_err(|e| e.to_string())
     }
 
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<User>, String> {
+        sqlx::query_as!(
+            User,
+            r#"SELECT id, name, email, password, status as "status: UserStatus", created_at, updated_at, deleted_at FROM users WHERE id = ANY($1) AND deleted_at IS NULL"#,
+            ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())
+    }
+
     async fn find_by_email(&self, email: &str) -> Result<Option<User>, String> {
         sqlx::query_as!(
             User,
@@ -196,7 +216,7 @@ impl UserRepository for PgUserRepository {
         .map_err(|e| e.to_string())
     }
 
-    async fn delete(&self, id: Uuid) -> Result<(), String> {
+    async fn delete(&self, id: Uuid) -> Result<(), AppError> {
         // Soft delete
         let result = sqlx::query!(
             "UPDATE users SET deleted_at = NOW(), status = 'deleted' WHERE id = $1",
@@ -207,7 +227,7 @@ impl UserRepository for PgUserRepository {
         .map_err(|e| e.to_string())?;
 
         if result.rows_affected() == 0 {
-            return Err("User not found".to_string());
+            return Err(AppError::not_found("USER_NOT_FOUND", "User"));
         }
 
         Ok(())
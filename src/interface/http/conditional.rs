@@ -0,0 +1,43 @@
+use axum::http::{HeaderMap, header};
+use chrono::{DateTime, NaiveDateTime};
+use sha2::{Digest, Sha256};
+
+/// ETag mạnh theo nội dung response (128 bit đầu của SHA-256)
+pub fn content_etag(body: &[u8]) -> String {
+    format!(
+        "\"{}\"",
+        Sha256::digest(body)
+            .iter()
+            .take(16)
+            .map(|b| format!("{b:02x}"))
+            .collect::<String>()
+    )
+}
+
+/// `If-None-Match` được ưu tiên; chỉ xét `If-Modified-Since` khi client không gửi ETag
+pub fn is_not_modified(headers: &HeaderMap, etag: &str, updated: Option<NaiveDateTime>) -> bool {
+    if let Some(value) = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|v| v.to_str().ok())
+    {
+        return value
+            .split(',')
+            .map(|t| t.trim().trim_start_matches("W/"))
+            .any(|t| t == "*" || t == etag);
+    }
+    match (
+        updated,
+        headers
+            .get(header::IF_MODIFIED_SINCE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| DateTime::parse_from_rfc2822(v).ok()),
+    ) {
+        // HTTP-date chỉ chính xác tới giây
+        (Some(updated), Some(since)) => updated.and_utc().timestamp() <= since.timestamp(),
+        _ => false,
+    }
+}
+
+pub fn http_date(value: NaiveDateTime) -> String {
+    value.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
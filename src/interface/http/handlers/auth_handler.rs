@@ -8,6 +8,7 @@ use crate::application::user::{
 };
 use crate::domain::entities::user::NewUser;
 use crate::interface::http::response::ApiResponse;
+use crate::shared::error::{AppError, ErrorKind};
 
 pub async fn login(
     State(state): State<Arc<AppState>>,
@@ -60,7 +61,11 @@ pub async fn me(
             return ApiResponse::<()>::error(
                 StatusCode::BAD_REQUEST,
                 "INVALID_TOKEN_SUB".to_string(),
-                "Token subject is not a valid uuid".to_string(),
+                AppError::new(
+                    ErrorKind::Invalid,
+                    "AUTH_TOKEN_INVALID",
+                    "Token subject is not a valid uuid",
+                ),
                 None,
                 None,
             )
@@ -73,7 +78,7 @@ pub async fn me(
         Ok(None) => ApiResponse::<()>::error(
             StatusCode::NOT_FOUND,
             "USER_NOT_FOUND".to_string(),
-            "User not found".to_string(),
+            AppError::not_found("USER_NOT_FOUND", "User"),
             None,
             None,
         )
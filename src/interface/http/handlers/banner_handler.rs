@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
 };
@@ -10,23 +10,68 @@ use axum::{
 use crate::{
     app::state::AppState,
     application::banner::{
-        bulk_delete_banners::BulkDeleteBannersInput, bulk_delete_banners::BulkDeleteBannersUseCase,
-        create_banner::CreateBannerInput, create_banner::CreateBannerUseCase,
-        create_banner_item::CreateBannerItemInput, create_banner_item::CreateBannerItemUseCase,
-        delete_banner::DeleteBannerUseCase, delete_banner_item::DeleteBannerItemUseCase,
-        get_banner_by_key::GetBannerByKeyUseCase, list_active_banners::ListActiveBannersUseCase,
-        update_banner::UpdateBannerInput, update_banner::UpdateBannerUseCase,
-        update_banner_item::UpdateBannerItemInput, update_banner_item::UpdateBannerItemUseCase,
+        bulk_delete_banners::BulkDeleteBannersInput,
+        bulk_delete_banners::BulkDeleteBannersUseCase,
+        create_banner::CreateBannerInput,
+        create_banner::CreateBannerUseCase,
+        create_banner_item::CreateBannerItemInput,
+        create_banner_item::CreateBannerItemUseCase,
+        delete_banner::DeleteBannerUseCase,
+        delete_banner_item::DeleteBannerItemUseCase,
+        embed_banner_items::{BANNER_INCLUDES, EmbedBannerItemsUseCase},
+        get_banner_by_key::GetBannerByKeyUseCase,
+        list_active_banners::ListActiveBannersUseCase,
+        update_banner::UpdateBannerInput,
+        update_banner::UpdateBannerUseCase,
+        update_banner_item::UpdateBannerItemInput,
+        update_banner_item::UpdateBannerItemUseCase,
     },
-    interface::http::response::ApiResponse,
+    application::common::list_params::ReadParams,
+    interface::http::{
+        projection::project,
+        request_language::{RequestLanguage, localize_content},
+        response::{ApiResponse, error_response, error_status},
+    },
+    shared::error::AppError,
 };
 use uuid::Uuid;
 
-pub async fn list_active_banners(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+pub async fn list_active_banners(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<ReadParams>,
+    language: RequestLanguage,
+) -> impl IntoResponse {
     let usecase = ListActiveBannersUseCase::new(state.banner_repo.clone());
+    let localize = localize_content(&state);
+    let embed = EmbedBannerItemsUseCase::new(state.banner_repo.clone());
+    let selected = params.selected();
+    let includes = params.includes(BANNER_INCLUDES);
+
+    let result = match usecase.execute().await {
+        Ok(banners) => match embed.execute(banners, &includes).await {
+            Ok(mut views) => {
+                let mut banners: Vec<_> = views.iter_mut().map(|v| &mut v.banner).collect();
+                localize
+                    .banners(&mut banners, &language.0)
+                    .await
+                    .map(|fallbacks| (views, fallbacks))
+            }
+            Err(e) => Err(e),
+        },
+        Err(e) => Err(e),
+    };
 
-    match usecase.execute().await {
-        Ok(banners) => ApiResponse::success(serde_json::json!(banners), None).into_response(),
+    match result {
+        Ok((banners, fallbacks)) => {
+            let mut data = serde_json::Value::Array(
+                banners
+                    .iter()
+                    .map(|b| project(b, selected.as_deref(), &includes))
+                    .collect(),
+            );
+            fallbacks.annotate(&mut data);
+            language.apply(ApiResponse::success(data, None).into_response())
+        }
         Err(e) => ApiResponse::<()>::error(
             StatusCode::INTERNAL_SERVER_ERROR,
             "INTERNAL_SERVER_ERROR".to_string(),
@@ -41,15 +86,30 @@ pub async fn list_active_banners(State(state): State<Arc<AppState>>) -> impl Int
 pub async fn get_banner_by_key(
     State(state): State<Arc<AppState>>,
     Path(key): Path<String>,
+    language: RequestLanguage,
 ) -> impl IntoResponse {
     let usecase = GetBannerByKeyUseCase::new(state.banner_repo.clone());
+    let localize = localize_content(&state);
+
+    let result = match usecase.execute(&key).await {
+        Ok(Some(mut data)) => localize
+            .banners(&mut [&mut data.banner], &language.0)
+            .await
+            .map(|fallbacks| Some((data, fallbacks))),
+        Ok(None) => Ok(None),
+        Err(e) => Err(e),
+    };
 
-    match usecase.execute(&key).await {
-        Ok(Some(data)) => ApiResponse::success(serde_json::json!(data), None).into_response(),
+    match result {
+        Ok(Some((data, fallbacks))) => {
+            let mut data = serde_json::json!(data);
+            fallbacks.annotate(&mut data);
+            language.apply(ApiResponse::success(data, None).into_response())
+        }
         Ok(None) => ApiResponse::<()>::error(
             StatusCode::NOT_FOUND,
             "NOT_FOUND".to_string(),
-            "Banner not found".to_string(),
+            AppError::not_found("BANNER_NOT_FOUND", "Banner"),
             None,
             None,
         )
@@ -69,21 +129,14 @@ pub async fn create_banner(
     State(state): State<Arc<AppState>>,
     Json(payload): Json<CreateBannerInput>,
 ) -> impl IntoResponse {
-    let usecase = CreateBannerUseCase::new(state.banner_repo.clone());
+    let usecase = CreateBannerUseCase::new(state.banner_repo.clone(), state.slug_repo.clone());
     match usecase.execute(payload).await {
         Ok(banner) => ApiResponse::success(
             serde_json::json!(banner),
             Some("Banner created".to_string()),
         )
         .into_response(),
-        Err(e) => ApiResponse::<()>::error(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "INTERNAL_SERVER_ERROR".to_string(),
-            e,
-            None,
-            None,
-        )
-        .into_response(),
+        Err(e) => error_response(e),
     }
 }
 
@@ -92,7 +145,7 @@ pub async fn update_banner(
     Path(id): Path<Uuid>,
     Json(payload): Json<UpdateBannerInput>,
 ) -> impl IntoResponse {
-    let usecase = UpdateBannerUseCase::new(state.banner_repo.clone());
+    let usecase = UpdateBannerUseCase::new(state.banner_repo.clone(), state.slug_repo.clone());
     match usecase.execute(id, payload).await {
         Ok(banner) => ApiResponse::success(
             serde_json::json!(banner),
@@ -100,11 +153,7 @@ pub async fn update_banner(
         )
         .into_response(),
         Err(e) => {
-            let status = if e.contains("not found") {
-                StatusCode::NOT_FOUND
-            } else {
-                StatusCode::INTERNAL_SERVER_ERROR
-            };
+            let (status, _) = error_status(&e);
             ApiResponse::<()>::error(status, "ERROR".to_string(), e, None, None).into_response()
         }
     }
@@ -183,11 +232,7 @@ pub async fn update_banner_item(
         )
         .into_response(),
         Err(e) => {
-            let status = if e.contains("not found") {
-                StatusCode::NOT_FOUND
-            } else {
-                StatusCode::INTERNAL_SERVER_ERROR
-            };
+            let (status, _) = error_status(&e);
             ApiResponse::<()>::error(status, "ERROR".to_string(), e, None, None).into_response()
         }
     }
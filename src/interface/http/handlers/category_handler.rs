@@ -2,8 +2,8 @@ use std::sync::Arc;
 
 use axum::{
     Json,
-    extract::{Path, Query, State},
-    http::StatusCode,
+    extract::{OriginalUri, Path, Query, State},
+    http::{StatusCode, Uri},
     response::IntoResponse,
 };
 use serde::Deserialize;
@@ -13,13 +13,21 @@ use crate::{
     app::state::AppState,
     application::category::{
         bulk_delete_categories::{BulkDeleteCategoriesInput, BulkDeleteCategoriesUseCase},
+        category_tree::{build_category_tree, flatten_category_tree},
         create_category::{CreateCategoryInput, CreateCategoryUseCase},
         delete_category::DeleteCategoryUseCase,
         get_categories::GetCategoriesUseCase,
         get_category::GetCategoryUseCase,
+        get_category_breadcrumbs::GetCategoryBreadcrumbsUseCase,
+        get_category_descendants::GetCategoryDescendantsUseCase,
+        get_category_tree::GetCategoryTreeUseCase,
         update_category::{UpdateCategoryInput, UpdateCategoryUseCase},
     },
-    interface::http::response::ApiResponse,
+    application::common::find_slug_redirect::FindSlugRedirectUseCase,
+    domain::repositories::slug_repository::SlugScope,
+    interface::http::request_language::{RequestLanguage, localize_content},
+    interface::http::response::{ApiResponse, error_response, moved_location},
+    shared::error::AppError,
 };
 
 #[derive(Deserialize)]
@@ -27,15 +35,35 @@ pub struct CategoryQuery {
     pub r#type: Option<String>,
 }
 
+#[derive(Deserialize)]
+pub struct CategoryTreeQuery {
+    pub r#type: Option<String>,
+    pub depth: Option<i32>,
+}
+
 pub async fn list_categories(
     State(state): State<Arc<AppState>>,
     Query(query): Query<CategoryQuery>,
+    language: RequestLanguage,
 ) -> impl IntoResponse {
     let usecase = GetCategoriesUseCase::new(state.category_repo.clone());
+    let localize = localize_content(&state);
     let r#type = query.r#type.unwrap_or_else(|| "post".to_string());
 
-    match usecase.execute(&r#type).await {
-        Ok(cats) => ApiResponse::success(serde_json::json!(cats), None).into_response(),
+    let result = match usecase.execute(&r#type).await {
+        Ok(mut cats) => localize
+            .categories(&mut cats, &language.0)
+            .await
+            .map(|fallbacks| (cats, fallbacks)),
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok((cats, fallbacks)) => {
+            let mut data = serde_json::json!(cats);
+            fallbacks.annotate(&mut data);
+            language.apply(ApiResponse::success(data, None).into_response())
+        }
         Err(e) => ApiResponse::<()>::error(
             StatusCode::INTERNAL_SERVER_ERROR,
             "INTERNAL_SERVER_ERROR".to_string(),
@@ -49,22 +77,34 @@ pub async fn list_categories(
 
 pub async fn get_category(
     State(state): State<Arc<AppState>>,
+    OriginalUri(uri): OriginalUri,
     Path(slug): Path<String>,
     Query(query): Query<CategoryQuery>,
+    language: RequestLanguage,
 ) -> impl IntoResponse {
     let usecase = GetCategoryUseCase::new(state.category_repo.clone());
+    let localize = localize_content(&state);
     let r#type = query.r#type.unwrap_or_else(|| "post".to_string());
 
-    match usecase.execute(&slug, &r#type).await {
-        Ok(Some(cat)) => ApiResponse::success(serde_json::json!(cat), None).into_response(),
-        Ok(None) => ApiResponse::<()>::error(
-            StatusCode::NOT_FOUND,
-            "NOT_FOUND".to_string(),
-            "Category not found".to_string(),
-            None,
-            None,
-        )
-        .into_response(),
+    let result = match usecase.execute(&slug, &r#type).await {
+        Ok(Some(cat)) => {
+            let mut cats = [cat];
+            localize
+                .categories(&mut cats, &language.0)
+                .await
+                .map(|fallbacks| cats.into_iter().next().map(|cat| (cat, fallbacks)))
+        }
+        Ok(None) => Ok(None),
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(Some((cat, fallbacks))) => {
+            let mut data = serde_json::json!(cat);
+            fallbacks.annotate(&mut data);
+            language.apply(ApiResponse::success(data, None).into_response())
+        }
+        Ok(None) => category_not_found(&state, &uri, &slug, &r#type).await,
         Err(e) => ApiResponse::<()>::error(
             StatusCode::INTERNAL_SERVER_ERROR,
             "INTERNAL_SERVER_ERROR".to_string(),
@@ -76,22 +116,108 @@ pub async fn get_category(
     }
 }
 
+/// Cây category lồng nhau (`children`), `?depth=` giới hạn số tầng
+pub async fn get_category_tree(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<CategoryTreeQuery>,
+    language: RequestLanguage,
+) -> impl IntoResponse {
+    let usecase = GetCategoryTreeUseCase::new(state.category_repo.clone());
+    let localize = localize_content(&state);
+    let r#type = query.r#type.unwrap_or_else(|| "post".to_string());
+
+    let result = match usecase.execute(&r#type, query.depth).await {
+        Ok(mut cats) => localize
+            .categories(&mut cats, &language.0)
+            .await
+            .map(|fallbacks| (cats, fallbacks)),
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok((cats, fallbacks)) => {
+            let mut data = serde_json::json!(build_category_tree(cats, None));
+            fallbacks.annotate(&mut data);
+            language.apply(ApiResponse::success(data, None).into_response())
+        }
+        Err(e) => error_response(e),
+    }
+}
+
+/// Breadcrumb: chuỗi category từ gốc tới category hiện tại
+pub async fn get_category_breadcrumbs(
+    State(state): State<Arc<AppState>>,
+    OriginalUri(uri): OriginalUri,
+    Path(slug): Path<String>,
+    Query(query): Query<CategoryQuery>,
+    language: RequestLanguage,
+) -> impl IntoResponse {
+    let usecase = GetCategoryBreadcrumbsUseCase::new(state.category_repo.clone());
+    let localize = localize_content(&state);
+    let r#type = query.r#type.unwrap_or_else(|| "post".to_string());
+
+    let result = match usecase.execute(&slug, &r#type).await {
+        Ok(Some(mut cats)) => localize
+            .categories(&mut cats, &language.0)
+            .await
+            .map(|fallbacks| Some((cats, fallbacks))),
+        Ok(None) => Ok(None),
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(Some((cats, fallbacks))) => {
+            let mut data = serde_json::json!(cats);
+            fallbacks.annotate(&mut data);
+            language.apply(ApiResponse::success(data, None).into_response())
+        }
+        Ok(None) => category_not_found(&state, &uri, &slug, &r#type).await,
+        Err(e) => error_response(e),
+    }
+}
+
+/// Danh sách phẳng các category con cháu (cha đứng trước con), kèm `depth` tính từ category hiện tại
+pub async fn get_category_descendants(
+    State(state): State<Arc<AppState>>,
+    OriginalUri(uri): OriginalUri,
+    Path(slug): Path<String>,
+    Query(query): Query<CategoryTreeQuery>,
+    language: RequestLanguage,
+) -> impl IntoResponse {
+    let usecase = GetCategoryDescendantsUseCase::new(state.category_repo.clone());
+    let localize = localize_content(&state);
+    let r#type = query.r#type.unwrap_or_else(|| "post".to_string());
+
+    let result = match usecase.execute(&slug, &r#type, query.depth).await {
+        Ok(Some(mut subtree)) => localize
+            .categories(&mut subtree.descendants, &language.0)
+            .await
+            .map(|fallbacks| Some((subtree, fallbacks))),
+        Ok(None) => Ok(None),
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(Some((subtree, fallbacks))) => {
+            let tree = build_category_tree(subtree.descendants, Some(subtree.root.id));
+            let mut data = serde_json::json!(flatten_category_tree(tree));
+            fallbacks.annotate(&mut data);
+            language.apply(ApiResponse::success(data, None).into_response())
+        }
+        Ok(None) => category_not_found(&state, &uri, &slug, &r#type).await,
+        Err(e) => error_response(e),
+    }
+}
+
 pub async fn create_category(
     State(state): State<Arc<AppState>>,
     Json(payload): Json<CreateCategoryInput>,
 ) -> impl IntoResponse {
-    let usecase = CreateCategoryUseCase::new(state.category_repo.clone());
+    let usecase = CreateCategoryUseCase::new(state.category_repo.clone(), state.slug_repo.clone());
 
     match usecase.execute(payload).await {
         Ok(cat) => ApiResponse::created(serde_json::json!(cat), None).into_response(),
-        Err(e) => ApiResponse::<()>::error(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "INTERNAL_SERVER_ERROR".to_string(),
-            e,
-            None,
-            None,
-        )
-        .into_response(),
+        Err(e) => error_response(e),
     }
 }
 
@@ -100,18 +226,11 @@ pub async fn update_category(
     Path(id): Path<Uuid>,
     Json(payload): Json<UpdateCategoryInput>,
 ) -> impl IntoResponse {
-    let usecase = UpdateCategoryUseCase::new(state.category_repo.clone());
+    let usecase = UpdateCategoryUseCase::new(state.category_repo.clone(), state.slug_repo.clone());
 
     match usecase.execute(id, payload).await {
         Ok(cat) => ApiResponse::success(serde_json::json!(cat), None).into_response(),
-        Err(e) => ApiResponse::<()>::error(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "INTERNAL_SERVER_ERROR".to_string(),
-            e,
-            None,
-            None,
-        )
-        .into_response(),
+        Err(e) => error_response(e),
     }
 }
 
@@ -157,3 +276,40 @@ pub async fn bulk_delete_categories(
         .into_response(),
     }
 }
+
+/// Không có category mang `slug`: slug cũ => 301 tới slug hiện tại (giữ phần path phía sau
+/// slug, `?type=` theo type hiện tại của category), không thì 404
+async fn category_not_found(
+    state: &AppState,
+    uri: &Uri,
+    slug: &str,
+    r#type: &str,
+) -> axum::response::Response {
+    match FindSlugRedirectUseCase::new(state.slug_redirect_repo.clone())
+        .execute(SlugScope::Category { r#type }, slug)
+        .await
+    {
+        Ok(Some(current)) => ApiResponse::redirect(
+            StatusCode::MOVED_PERMANENTLY,
+            moved_location(uri, slug, &current.slug, &[("type", &current.scope)]),
+            serde_json::json!({ "slug": current.slug, "type": current.scope }),
+        )
+        .into_response(),
+        Ok(None) => ApiResponse::<()>::error(
+            StatusCode::NOT_FOUND,
+            "NOT_FOUND".to_string(),
+            AppError::not_found("CATEGORY_NOT_FOUND", "Category"),
+            None,
+            None,
+        )
+        .into_response(),
+        Err(e) => ApiResponse::<()>::error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "INTERNAL_SERVER_ERROR".to_string(),
+            e,
+            None,
+            None,
+        )
+        .into_response(),
+    }
+}
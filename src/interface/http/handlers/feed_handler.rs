@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{OriginalUri, Path, Query, State},
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    response::IntoResponse,
+};
+use serde::Deserialize;
+
+use crate::{
+    app::state::AppState,
+    application::feed::{
+        feed_format::FeedFormat,
+        get_feed::{FeedScope, GetFeedUseCase},
+    },
+    interface::http::{
+        conditional::{content_etag, http_date, is_not_modified},
+        request_language::RequestLanguage,
+        response::error_response,
+    },
+    shared::error::AppError,
+};
+
+/// Thời gian client/proxy được cache feed (giây)
+const FEED_MAX_AGE: u32 = 300;
+
+#[derive(Deserialize)]
+pub struct FeedQuery {
+    /// Type của category (mặc định `post`)
+    pub r#type: Option<String>,
+}
+
+/// Feed toàn site: `/feed.xml` (RSS 2.0), `/feed.atom` (Atom), `/feed.json` (JSON Feed).
+/// Ngôn ngữ của feed theo ngôn ngữ của request (`?lang=`, `Accept-Language`...)
+pub async fn get_site_feed(
+    State(state): State<Arc<AppState>>,
+    OriginalUri(uri): OriginalUri,
+    headers: HeaderMap,
+    language: RequestLanguage,
+) -> impl IntoResponse {
+    let file = uri.path().rsplit('/').next().unwrap_or_default();
+    render_feed(state, &headers, &uri, file, FeedScope::Site, language).await
+}
+
+/// Feed theo category (gồm cả category con): `/categories/:slug/feed.{xml,atom,json}`
+pub async fn get_category_feed(
+    State(state): State<Arc<AppState>>,
+    OriginalUri(uri): OriginalUri,
+    headers: HeaderMap,
+    Path((slug, file)): Path<(String, String)>,
+    Query(query): Query<FeedQuery>,
+    language: RequestLanguage,
+) -> impl IntoResponse {
+    let r#type = query.r#type.unwrap_or_else(|| "post".to_string());
+    let scope = FeedScope::Category {
+        slug: &slug,
+        r#type: &r#type,
+    };
+    render_feed(state, &headers, &uri, &file, scope, language).await
+}
+
+/// Feed theo tag: `/tags/:slug/feed.{xml,atom,json}`
+pub async fn get_tag_feed(
+    State(state): State<Arc<AppState>>,
+    OriginalUri(uri): OriginalUri,
+    headers: HeaderMap,
+    Path((slug, file)): Path<(String, String)>,
+    language: RequestLanguage,
+) -> impl IntoResponse {
+    render_feed(
+        state,
+        &headers,
+        &uri,
+        &file,
+        FeedScope::Tag { slug: &slug },
+        language,
+    )
+    .await
+}
+
+async fn render_feed(
+    state: Arc<AppState>,
+    headers: &HeaderMap,
+    uri: &axum::http::Uri,
+    file: &str,
+    scope: FeedScope<'_>,
+    language: RequestLanguage,
+) -> axum::response::Response {
+    let Some(format) = FeedFormat::from_file_name(file) else {
+        return error_response(AppError::not_found("FEED_NOT_FOUND", "Feed"));
+    };
+    let usecase = GetFeedUseCase::new(
+        state.post_repo.clone(),
+        state.category_repo.clone(),
+        state.tag_repo.clone(),
+        state.setting_repo.clone(),
+        state.language_repo.clone(),
+    );
+    let self_path = uri.path_and_query().map(|p| p.as_str()).unwrap_or("/");
+    let feed = match usecase
+        .execute(scope, Some(&language.0.code), self_path)
+        .await
+    {
+        Ok(feed) => feed,
+        Err(e) => return error_response(e),
+    };
+
+    let body = format.render(&feed, &feed.self_url);
+    let etag = content_etag(body.as_bytes());
+    let last_modified = feed.updated.map(http_date);
+
+    let mut response = if is_not_modified(headers, &etag, feed.updated) {
+        StatusCode::NOT_MODIFIED.into_response()
+    } else {
+        (
+            [(header::CONTENT_TYPE, format.content_type().to_string())],
+            body,
+        )
+            .into_response()
+    };
+    let response_headers = response.headers_mut();
+    if let Ok(value) = HeaderValue::from_str(&etag) {
+        response_headers.insert(header::ETAG, value);
+    }
+    if let Some(value) = last_modified.and_then(|v| HeaderValue::from_str(&v).ok()) {
+        response_headers.insert(header::LAST_MODIFIED, value);
+    }
+    if let Ok(value) = HeaderValue::from_str(&format!("public, max-age={FEED_MAX_AGE}")) {
+        response_headers.insert(header::CACHE_CONTROL, value);
+    }
+    language.apply(response)
+}
@@ -0,0 +1,204 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    response::IntoResponse,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    app::state::AppState,
+    application::common::list_params::ListParams,
+    application::i18n::{
+        create_translation_key::{CreateTranslationKeyInput, CreateTranslationKeyUseCase},
+        delete_translation_key::DeleteTranslationKeyUseCase,
+        delete_translation_value::DeleteTranslationValueUseCase,
+        get_message_bundle::GetMessageBundleUseCase,
+        get_translation_key::GetTranslationKeyUseCase,
+        get_translation_keys::GetTranslationKeysUseCase,
+        update_translation_key::{UpdateTranslationKeyInput, UpdateTranslationKeyUseCase},
+        upsert_translation_value::{UpsertTranslationValueInput, UpsertTranslationValueUseCase},
+    },
+    application::translation::negotiate_language::NegotiateLanguageUseCase,
+    interface::http::{
+        conditional::{content_etag, is_not_modified},
+        response::{ApiResponse, error_response},
+    },
+    shared::utils::query::is_cursor_error,
+};
+
+/// Thời gian client/CDN được cache bundle (giây), sau đó revalidate bằng ETag
+const BUNDLE_MAX_AGE: u32 = 300;
+
+#[derive(Deserialize)]
+pub struct TranslationKeyListQuery {
+    pub namespace: Option<String>,
+}
+
+/// Danh sách message key (admin) kèm message theo từng ngôn ngữ, lọc `namespace`
+pub async fn list_translation_keys(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<ListParams>,
+    Query(query): Query<TranslationKeyListQuery>,
+) -> impl IntoResponse {
+    let usecase = GetTranslationKeysUseCase::new(state.translation_key_repo.clone());
+    match usecase.execute(&params, query.namespace).await {
+        Ok(result) => {
+            let data = result
+                .items
+                .into_iter()
+                .map(|k| serde_json::json!(k))
+                .collect();
+            let pagination = serde_json::json!({
+                "next_cursor": result.next_cursor,
+                "limit": result.limit,
+                "sort_by": params.sort_by.clone(),
+                "search": params.search.clone()
+            });
+            ApiResponse::<Vec<serde_json::Value>>::success_with_pagination(data, pagination, None)
+                .into_response()
+        }
+        Err(e) if is_cursor_error(&e) => ApiResponse::<()>::error(
+            StatusCode::BAD_REQUEST,
+            "INVALID_CURSOR".to_string(),
+            e,
+            None,
+            None,
+        )
+        .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+pub async fn get_translation_key(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    let usecase = GetTranslationKeyUseCase::new(state.translation_key_repo.clone());
+    match usecase.execute(id).await {
+        Ok(key) => ApiResponse::success(serde_json::json!(key), None).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+pub async fn create_translation_key(
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<CreateTranslationKeyInput>,
+) -> impl IntoResponse {
+    let usecase = CreateTranslationKeyUseCase::new(
+        state.translation_key_repo.clone(),
+        state.language_repo.clone(),
+    );
+    match usecase.execute(payload).await {
+        Ok(key) => ApiResponse::created(serde_json::json!(key), None).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+pub async fn update_translation_key(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateTranslationKeyInput>,
+) -> impl IntoResponse {
+    let usecase = UpdateTranslationKeyUseCase::new(state.translation_key_repo.clone());
+    match usecase.execute(id, payload).await {
+        Ok(key) => ApiResponse::success(serde_json::json!(key), None).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+pub async fn delete_translation_key(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    let usecase = DeleteTranslationKeyUseCase::new(state.translation_key_repo.clone());
+    match usecase.execute(id).await {
+        Ok(()) => ApiResponse::success(
+            serde_json::json!({ "id": id }),
+            Some("Translation key deleted".to_string()),
+        )
+        .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Ghi message của 1 ngôn ngữ, kiểm tra cú pháp ICU MessageFormat
+pub async fn upsert_translation_value(
+    State(state): State<Arc<AppState>>,
+    Path((id, lang)): Path<(Uuid, String)>,
+    Json(payload): Json<UpsertTranslationValueInput>,
+) -> impl IntoResponse {
+    let usecase = UpsertTranslationValueUseCase::new(
+        state.translation_key_repo.clone(),
+        state.language_repo.clone(),
+    );
+    match usecase.execute(id, &lang, payload).await {
+        Ok(value) => ApiResponse::success(serde_json::json!(value), None).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+pub async fn delete_translation_value(
+    State(state): State<Arc<AppState>>,
+    Path((id, lang)): Path<(Uuid, String)>,
+) -> impl IntoResponse {
+    let usecase = DeleteTranslationValueUseCase::new(
+        state.translation_key_repo.clone(),
+        state.language_repo.clone(),
+    );
+    match usecase.execute(id, &lang).await {
+        Ok(language_code) => ApiResponse::success(
+            serde_json::json!({ "id": id, "language_code": language_code }),
+            Some("Translation value deleted".to_string()),
+        )
+        .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Public: bundle JSON `{ "key": "message" }` cho thư viện i18n của frontend.
+/// Key chưa dịch lấy theo chuỗi ngôn ngữ dự phòng; hỗ trợ `If-None-Match` (304)
+pub async fn get_message_bundle(
+    State(state): State<Arc<AppState>>,
+    Path((lang, namespace)): Path<(String, String)>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let language = match NegotiateLanguageUseCase::new(
+        state.language_repo.clone(),
+        state.setting_repo.clone(),
+        state.user_profile_repo.clone(),
+    )
+    .for_path(&lang)
+    .await
+    {
+        Ok(language) => language,
+        Err(e) => return error_response(e),
+    };
+    let usecase = GetMessageBundleUseCase::new(state.translation_key_repo.clone());
+    let bundle = match usecase.execute(&language, &namespace).await {
+        Ok(bundle) => bundle,
+        Err(e) => return error_response(e),
+    };
+
+    let body = serde_json::to_string(&bundle.messages).unwrap_or_else(|_| "{}".to_string());
+    let etag = content_etag(body.as_bytes());
+    let mut response = if is_not_modified(&headers, &etag, None) {
+        StatusCode::NOT_MODIFIED.into_response()
+    } else {
+        ([(header::CONTENT_TYPE, "application/json")], body).into_response()
+    };
+    let response_headers = response.headers_mut();
+    if let Ok(value) = HeaderValue::from_str(&etag) {
+        response_headers.insert(header::ETAG, value);
+    }
+    if let Ok(value) = HeaderValue::from_str(&bundle.language_code) {
+        response_headers.insert(header::CONTENT_LANGUAGE, value);
+    }
+    if let Ok(value) = HeaderValue::from_str(&format!("public, max-age={BUNDLE_MAX_AGE}")) {
+        response_headers.insert(header::CACHE_CONTROL, value);
+    }
+    response
+}
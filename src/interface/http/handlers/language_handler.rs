@@ -1,13 +1,24 @@
 use std::sync::Arc;
 
-use axum::{extract::State, http::StatusCode, response::IntoResponse};
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
 
 use crate::{
     app::state::AppState,
     application::language::{
-        get_default_language::GetDefaultLanguageUseCase, get_languages::GetLanguagesUseCase,
+        create_language::{CreateLanguageInput, CreateLanguageUseCase},
+        delete_language::{DeleteLanguageInput, DeleteLanguageUseCase},
+        get_default_language::GetDefaultLanguageUseCase,
+        get_languages::GetLanguagesUseCase,
+        set_default_language::{SetDefaultLanguageInput, SetDefaultLanguageUseCase},
+        update_language::{UpdateLanguageInput, UpdateLanguageUseCase},
     },
-    interface::http::response::ApiResponse,
+    interface::http::response::{ApiResponse, error_response},
+    shared::error::AppError,
 };
 
 pub async fn list_languages(State(state): State<Arc<AppState>>) -> impl IntoResponse {
@@ -34,7 +45,7 @@ pub async fn get_default_language(State(state): State<Arc<AppState>>) -> impl In
         Ok(None) => ApiResponse::<()>::error(
             StatusCode::NOT_FOUND,
             "NOT_FOUND".to_string(),
-            "Default language not found".to_string(),
+            AppError::not_found("DEFAULT_LANGUAGE_NOT_FOUND", "Default language"),
             None,
             None,
         )
@@ -49,3 +60,56 @@ pub async fn get_default_language(State(state): State<Arc<AppState>>) -> impl In
         .into_response(),
     }
 }
+
+/// Thêm ngôn ngữ (admin), `code` là language tag BCP 47
+pub async fn create_language(
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<CreateLanguageInput>,
+) -> impl IntoResponse {
+    let usecase = CreateLanguageUseCase::new(state.language_repo.clone());
+    match usecase.execute(payload).await {
+        Ok(language) => ApiResponse::created(serde_json::json!(language), None).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+pub async fn update_language(
+    State(state): State<Arc<AppState>>,
+    Path(code): Path<String>,
+    Json(payload): Json<UpdateLanguageInput>,
+) -> impl IntoResponse {
+    let usecase = UpdateLanguageUseCase::new(state.language_repo.clone());
+    match usecase.execute(&code, payload).await {
+        Ok(language) => ApiResponse::success(serde_json::json!(language), None).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Đổi ngôn ngữ mặc định (admin)
+pub async fn set_default_language(
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<SetDefaultLanguageInput>,
+) -> impl IntoResponse {
+    let usecase = SetDefaultLanguageUseCase::new(state.language_repo.clone());
+    match usecase.execute(payload).await {
+        Ok(language) => ApiResponse::success(serde_json::json!(language), None).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Xoá mềm ngôn ngữ (admin): `?on_translations=migrate&migrate_to=en` hoặc `?on_translations=cascade`
+pub async fn delete_language(
+    State(state): State<Arc<AppState>>,
+    Path(code): Path<String>,
+    Query(query): Query<DeleteLanguageInput>,
+) -> impl IntoResponse {
+    let usecase = DeleteLanguageUseCase::new(state.language_repo.clone());
+    match usecase.execute(&code, query).await {
+        Ok(result) => ApiResponse::success(
+            serde_json::json!(result),
+            Some("Language deleted".to_string()),
+        )
+        .into_response(),
+        Err(e) => error_response(e),
+    }
+}
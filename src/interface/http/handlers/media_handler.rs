@@ -19,8 +19,9 @@ use crate::{
         update_media::{UpdateMediaInput, UpdateMediaUseCase},
     },
     domain::entities::media::{Media, NewMedia},
-    interface::http::response::ApiResponse,
-    shared::utils::jwt::Claims,
+    interface::http::response::{ApiResponse, error_status},
+    shared::error::{AppError, ErrorKind},
+    shared::utils::{jwt::Claims, query::is_cursor_error},
 };
 
 const UPLOAD_ROOT: &str = "uploads";
@@ -29,11 +30,15 @@ pub async fn upload_media(
     State(state): State<Arc<AppState>>,
     Extension(claims): Extension<Claims>,
     mut multipart: Multipart,
-) -> Result<Json<Media>, (StatusCode, String)> {
+) -> Result<Json<Media>, axum::response::Response> {
     let user_id = uuid::Uuid::parse_str(&claims.sub).map_err(|_| {
-        (
+        media_error(
             StatusCode::BAD_REQUEST,
-            "Invalid user ID in token".to_string(),
+            AppError::new(
+                ErrorKind::Invalid,
+                "AUTH_TOKEN_INVALID",
+                "Invalid user ID in token",
+            ),
         )
     })?;
 
@@ -41,7 +46,7 @@ pub async fn upload_media(
     if let Some(field) = multipart
         .next_field()
         .await
-        .map_err(|e| (StatusCode::BAD_REQUEST, format!("Multipart error: {}", e)))?
+        .map_err(|e| media_error(StatusCode::BAD_REQUEST, format!("Multipart error: {}", e)))?
     {
         let content_type = field
             .content_type()
@@ -59,7 +64,7 @@ pub async fn upload_media(
         };
 
         let data = field.bytes().await.map_err(|e| {
-            (
+            media_error(
                 StatusCode::INTERNAL_SERVER_ERROR,
                 format!("Failed to read field bytes: {}", e),
             )
@@ -72,7 +77,7 @@ pub async fn upload_media(
         // Ensure directory exists
         if let Some(parent) = full_path.parent() {
             fs::create_dir_all(parent).await.map_err(|e| {
-                (
+                media_error(
                     StatusCode::INTERNAL_SERVER_ERROR,
                     format!("Failed to create directory: {}", e),
                 )
@@ -81,13 +86,13 @@ pub async fn upload_media(
 
         // Save file
         let mut file = fs::File::create(&full_path).await.map_err(|e| {
-            (
+            media_error(
                 StatusCode::INTERNAL_SERVER_ERROR,
                 format!("Failed to create file: {}", e),
             )
         })?;
         file.write_all(&data).await.map_err(|e| {
-            (
+            media_error(
                 StatusCode::INTERNAL_SERVER_ERROR,
                 format!("Failed to write to file: {}", e),
             )
@@ -102,7 +107,7 @@ pub async fn upload_media(
         };
 
         let result = use_case.execute(new_media).await.map_err(|e| {
-            (
+            media_error(
                 StatusCode::INTERNAL_SERVER_ERROR,
                 format!("Database error: {}", e),
             )
@@ -111,22 +116,35 @@ pub async fn upload_media(
         return Ok(Json(result));
     }
 
-    Err((StatusCode::BAD_REQUEST, "No file uploaded".to_string()))
+    Err(media_error(StatusCode::BAD_REQUEST, "No file uploaded"))
 }
 
 pub async fn get_media(
     State(state): State<Arc<AppState>>,
     AxumPath(id): AxumPath<uuid::Uuid>,
-) -> Result<Json<Media>, (StatusCode, String)> {
+) -> Result<Json<Media>, axum::response::Response> {
     let use_case = GetMediaUseCase::new(state.media_repo.clone());
 
     match use_case.get_by_id(id).await {
         Ok(Some(media)) => Ok(Json(media)),
-        Ok(None) => Err((StatusCode::NOT_FOUND, "Media not found".to_string())),
-        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e)),
+        Ok(None) => Err(media_error(
+            StatusCode::NOT_FOUND,
+            AppError::not_found("MEDIA_NOT_FOUND", "Media"),
+        )),
+        Err(e) => Err(media_error(StatusCode::INTERNAL_SERVER_ERROR, e)),
     }
 }
 
+/// Lỗi của route trả thẳng `Media` (không bọc `ApiResponse` khi thành công), mã lỗi theo status
+fn media_error(status: StatusCode, e: impl Into<AppError>) -> axum::response::Response {
+    let code = match status {
+        StatusCode::BAD_REQUEST => "BAD_REQUEST",
+        StatusCode::NOT_FOUND => "NOT_FOUND",
+        _ => "INTERNAL_SERVER_ERROR",
+    };
+    ApiResponse::<()>::error(status, code.to_string(), e, None, None).into_response()
+}
+
 pub async fn update_media(
     State(state): State<Arc<AppState>>,
     AxumPath(id): AxumPath<uuid::Uuid>,
@@ -140,11 +158,7 @@ pub async fn update_media(
                 .into_response()
         }
         Err(e) => {
-            let status = if e.contains("not found") {
-                StatusCode::NOT_FOUND
-            } else {
-                StatusCode::INTERNAL_SERVER_ERROR
-            };
+            let (status, _) = error_status(&e);
             ApiResponse::<()>::error(status, "ERROR".to_string(), e, None, None).into_response()
         }
     }
@@ -213,6 +227,14 @@ pub async fn get_user_media(
             });
             ApiResponse::success_with_pagination(data, pagination, None).into_response()
         }
+        Err(e) if is_cursor_error(&e) => ApiResponse::<()>::error(
+            StatusCode::BAD_REQUEST,
+            "INVALID_CURSOR".to_string(),
+            e,
+            None,
+            None,
+        )
+        .into_response(),
         Err(e) => ApiResponse::<()>::error(
             StatusCode::INTERNAL_SERVER_ERROR,
             "INTERNAL_SERVER_ERROR".to_string(),
@@ -2,12 +2,18 @@ pub mod audit_log_handler;
 pub mod auth_handler;
 pub mod banner_handler;
 pub mod category_handler;
+pub mod feed_handler;
+pub mod i18n_handler;
 pub mod language_handler;
 pub mod media_handler;
 pub mod permission_handler;
 pub mod post_handler;
+pub mod redirect_handler;
 pub mod role_handler;
+pub mod seo_handler;
 pub mod settings_handler;
+pub mod sitemap_handler;
 pub mod tag_handler;
+pub mod translation_handler;
 pub mod user_handler;
 pub mod user_profile_handler;
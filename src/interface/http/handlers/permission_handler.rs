@@ -15,6 +15,8 @@ use crate::application::permission::{
 };
 use crate::domain::entities::permission::{NewPermission, UpdatePermission};
 use crate::interface::http::response::ApiResponse;
+use crate::shared::error::AppError;
+use crate::shared::utils::query::is_cursor_error;
 
 pub async fn get_permissions(
     State(state): State<Arc<AppState>>,
@@ -38,6 +40,14 @@ pub async fn get_permissions(
             ApiResponse::<Vec<serde_json::Value>>::success_with_pagination(data, pagination, None)
                 .into_response()
         }
+        Err(e) if is_cursor_error(&e) => ApiResponse::<()>::error(
+            StatusCode::BAD_REQUEST,
+            "INVALID_CURSOR".to_string(),
+            e,
+            None,
+            None,
+        )
+        .into_response(),
         Err(e) => ApiResponse::<()>::error(
             StatusCode::INTERNAL_SERVER_ERROR,
             "INTERNAL_SERVER_ERROR".to_string(),
@@ -81,7 +91,7 @@ pub async fn get_permission(
         Ok(None) => ApiResponse::<()>::error(
             StatusCode::NOT_FOUND,
             "NOT_FOUND".to_string(),
-            "Permission not found".to_string(),
+            AppError::not_found("PERMISSION_NOT_FOUND", "Permission"),
             None,
             None,
         )
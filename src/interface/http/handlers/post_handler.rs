@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Extension, OriginalUri, Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
 };
@@ -10,22 +10,105 @@ use uuid::Uuid;
 
 use crate::{
     app::state::AppState,
+    application::common::find_slug_redirect::FindSlugRedirectUseCase,
+    application::common::list_params::{ListParams, ReadParams},
+    application::post::resolve_post_tags::POST_TAG_TYPE,
     application::post::{
+        assign_post_reviewers::{AssignPostReviewersInput, AssignPostReviewersUseCase},
         bulk_delete_posts::{BulkDeletePostsInput, BulkDeletePostsUseCase},
         create_post::{CreatePostInput, CreatePostUseCase},
         delete_post::DeletePostUseCase,
-        get_post_by_slug::GetPostBySlugUseCase,
-        get_posts::GetPostsUseCase,
+        detach_post_media::DetachPostMediaUseCase,
+        detach_post_tag::DetachPostTagUseCase,
+        diff_post_revisions::{DiffPostRevisionsInput, DiffPostRevisionsUseCase},
+        embed_post_relations::{EmbedPostRelationsUseCase, POST_INCLUDES},
+        get_post_by_slug::{GetPostBySlugUseCase, PostBySlug},
+        get_post_media::GetPostMediaUseCase,
+        get_post_review::GetPostReviewUseCase,
+        get_post_revision::GetPostRevisionUseCase,
+        get_post_tags::GetPostTagsUseCase,
+        get_posts::{GetPostsUseCase, PostListFilterInput, PostListMode},
+        list_post_revisions::ListPostRevisionsUseCase,
+        restore_post_revision::RestorePostRevisionUseCase,
+        review_post::{ReviewPostInput, ReviewPostUseCase},
+        search_posts::{SearchPostsInput, SearchPostsUseCase},
+        set_post_media::{PostMediaInput, PostMediaMode, SetPostMediaUseCase},
+        set_post_tags::{PostTagsInput, PostTagsMode, SetPostTagsUseCase},
         update_post::{UpdatePostInput, UpdatePostUseCase},
     },
-    interface::http::response::ApiResponse,
+    application::translation::negotiate_language::{LanguageSource, NegotiateLanguageUseCase},
+    domain::repositories::slug_repository::SlugScope,
+    interface::http::{
+        projection::project,
+        request_language::{RequestLanguage, localize_content},
+        response::{ApiResponse, error_response, moved_location},
+    },
+    shared::error::{AppError, ErrorKind},
+    shared::utils::{jwt::Claims, query::is_cursor_error},
 };
 
-pub async fn list_posts(State(state): State<Arc<AppState>>) -> impl IntoResponse {
-    let usecase = GetPostsUseCase::new(state.post_repo.clone());
+/// Danh sách bài viết public (chỉ bài đã publish)
+pub async fn list_posts(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<ListParams>,
+    Query(filter): Query<PostListFilterInput>,
+    language: RequestLanguage,
+) -> impl IntoResponse {
+    list_posts_with_mode(state, params, filter, language, PostListMode::Public).await
+}
 
-    match usecase.execute().await {
-        Ok(posts) => ApiResponse::success(serde_json::json!(posts), None).into_response(),
+/// Bài `in_review` đang chờ user hiện tại duyệt
+pub async fn list_awaiting_review_posts(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Query(params): Query<ListParams>,
+    Query(filter): Query<PostListFilterInput>,
+    language: RequestLanguage,
+) -> impl IntoResponse {
+    let Ok(reviewer_id) = Uuid::parse_str(&claims.sub) else {
+        return post_write_error(AppError::new(
+            ErrorKind::Invalid,
+            "AUTH_TOKEN_INVALID",
+            "Invalid user ID in token",
+        ));
+    };
+    list_posts_with_mode(
+        state,
+        params,
+        filter,
+        language,
+        PostListMode::AwaitingReview(reviewer_id),
+    )
+    .await
+}
+
+/// Danh sách bài viết public gắn với 1 tag: `/tags/:slug/posts`
+pub async fn list_posts_by_tag(
+    State(state): State<Arc<AppState>>,
+    Path(tag_slug): Path<String>,
+    Query(params): Query<ListParams>,
+    language: RequestLanguage,
+) -> impl IntoResponse {
+    match state
+        .tag_repo
+        .find_by_slug_and_type(&tag_slug, POST_TAG_TYPE)
+        .await
+    {
+        Ok(Some(_)) => {
+            let filter = PostListFilterInput {
+                tag: Some(tag_slug),
+                ..Default::default()
+            };
+            list_posts_with_mode(state, params, filter, language, PostListMode::Public).await
+        }
+        Ok(None) => ApiResponse::<()>::error(
+            StatusCode::NOT_FOUND,
+            "NOT_FOUND".to_string(),
+            AppError::not_found("TAG_NOT_FOUND", "Tag"),
+            None,
+            None,
+        )
+        .into_response(),
         Err(e) => ApiResponse::<()>::error(
             StatusCode::INTERNAL_SERVER_ERROR,
             "INTERNAL_SERVER_ERROR".to_string(),
@@ -37,22 +120,182 @@ pub async fn list_posts(State(state): State<Arc<AppState>>) -> impl IntoResponse
     }
 }
 
-pub async fn get_post_by_slug(
+/// Danh sách bài viết cho admin/editor (mọi trạng thái, lọc theo `status`)
+pub async fn list_manage_posts(
     State(state): State<Arc<AppState>>,
-    Path(slug): Path<String>,
+    Query(params): Query<ListParams>,
+    Query(filter): Query<PostListFilterInput>,
+    language: RequestLanguage,
 ) -> impl IntoResponse {
-    let usecase = GetPostBySlugUseCase::new(state.post_repo.clone());
+    list_posts_with_mode(state, params, filter, language, PostListMode::Editorial).await
+}
 
-    match usecase.execute(&slug).await {
-        Ok(Some(post)) => ApiResponse::success(serde_json::json!(post), None).into_response(),
-        Ok(None) => ApiResponse::<()>::error(
-            StatusCode::NOT_FOUND,
-            "NOT_FOUND".to_string(),
-            "Post not found".to_string(),
+async fn list_posts_with_mode(
+    state: Arc<AppState>,
+    params: ListParams,
+    filter: PostListFilterInput,
+    language: RequestLanguage,
+    mode: PostListMode,
+) -> axum::response::Response {
+    let usecase = GetPostsUseCase::new(state.post_repo.clone());
+    let embed = EmbedPostRelationsUseCase::new(
+        state.user_repo.clone(),
+        state.category_repo.clone(),
+        state.tag_repo.clone(),
+        state.media_repo.clone(),
+        state.post_media_repo.clone(),
+    );
+    let localize = localize_content(&state);
+    let read = params.read_params();
+    let selected = read.selected();
+    let includes = read.includes(POST_INCLUDES);
+
+    let result = match usecase.execute(&params, filter, mode).await {
+        Ok(result) => match embed.execute(result.items, &includes).await {
+            Ok(mut items) => localize
+                .post_views(&mut items, &language.0)
+                .await
+                .map(|fallbacks| (items, fallbacks, result.next_cursor, result.limit)),
+            Err(e) => Err(e),
+        },
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok((items, fallbacks, next_cursor, limit)) => {
+            let mut data = serde_json::Value::Array(
+                items
+                    .iter()
+                    .map(|p| project(p, selected.as_deref(), &includes))
+                    .collect(),
+            );
+            fallbacks.annotate(&mut data);
+            let pagination = serde_json::json!({
+                "next_cursor": next_cursor,
+                "limit": limit,
+                "sort_by": params.sort_by.clone(),
+                "fields": params.fields.clone(),
+                "search": params.search.clone(),
+                "select": params.select.clone(),
+                "include": params.include.clone()
+            });
+            language
+                .apply(ApiResponse::success_with_pagination(data, pagination, None).into_response())
+        }
+        Err(e) if is_cursor_error(&e) => ApiResponse::<()>::error(
+            StatusCode::BAD_REQUEST,
+            "INVALID_CURSOR".to_string(),
+            e,
             None,
             None,
         )
         .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Full-text search bài viết: `/posts/search?q=...&lang=&category_id=&tag=&status=&limit=`
+pub async fn search_posts(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Query(input): Query<SearchPostsInput>,
+) -> impl IntoResponse {
+    let usecase = SearchPostsUseCase::new(state.post_repo.clone(), state.permission_repo.clone());
+    let actor = Uuid::parse_str(&claims.sub).ok();
+
+    match usecase.execute(actor, input).await {
+        Ok(hits) => ApiResponse::success(serde_json::json!(hits), None).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+pub async fn get_post_by_slug(
+    State(state): State<Arc<AppState>>,
+    OriginalUri(uri): OriginalUri,
+    Path(slug): Path<String>,
+    Query(params): Query<ReadParams>,
+    mut language: RequestLanguage,
+) -> impl IntoResponse {
+    let usecase =
+        GetPostBySlugUseCase::new(state.post_repo.clone(), state.post_translation_repo.clone());
+    let localize = localize_content(&state);
+    let embed = EmbedPostRelationsUseCase::new(
+        state.user_repo.clone(),
+        state.category_repo.clone(),
+        state.tag_repo.clone(),
+        state.media_repo.clone(),
+        state.post_media_repo.clone(),
+    );
+    let includes = params.includes(POST_INCLUDES);
+
+    let explicit = language.0.source == LanguageSource::Query;
+    let lookup_lang = explicit.then_some(language.0.code.as_str());
+    let result = match usecase.execute(&slug, lookup_lang).await {
+        Ok(Some(PostBySlug {
+            post,
+            language_code,
+        })) => {
+            // Không có `?lang` mà slug thuộc bản dịch => trả về theo ngôn ngữ của slug đó
+            let switched = match language_code.filter(|_| !explicit) {
+                Some(code) => NegotiateLanguageUseCase::new(
+                    state.language_repo.clone(),
+                    state.setting_repo.clone(),
+                    state.user_profile_repo.clone(),
+                )
+                .for_translated_slug(&code)
+                .await
+                .map(|content_language| language.0 = content_language),
+                None => Ok(()),
+            };
+            match switched {
+                Ok(()) => match embed.execute(vec![post], &includes).await {
+                    Ok(mut views) => localize
+                        .post_views(&mut views, &language.0)
+                        .await
+                        .map(|fallbacks| views.into_iter().next().map(|v| (v, fallbacks))),
+                    Err(e) => Err(e),
+                },
+                Err(e) => Err(e),
+            }
+        }
+        Ok(None) => Ok(None),
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(Some((post, fallbacks))) => {
+            let mut data = project(&post, params.selected().as_deref(), &includes);
+            fallbacks.annotate(&mut data);
+            language.apply(ApiResponse::success(data, None).into_response())
+        }
+        // Slug cũ => 301 tới slug hiện tại
+        Ok(None) => match FindSlugRedirectUseCase::new(state.slug_redirect_repo.clone())
+            .execute(SlugScope::Post, &slug)
+            .await
+        {
+            Ok(Some(current)) => ApiResponse::redirect(
+                StatusCode::MOVED_PERMANENTLY,
+                moved_location(&uri, &slug, &current.slug, &[]),
+                serde_json::json!({ "slug": current.slug }),
+            )
+            .into_response(),
+            Ok(None) => ApiResponse::<()>::error(
+                StatusCode::NOT_FOUND,
+                "NOT_FOUND".to_string(),
+                AppError::not_found("POST_NOT_FOUND", "Post"),
+                None,
+                None,
+            )
+            .into_response(),
+            Err(e) => ApiResponse::<()>::error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "INTERNAL_SERVER_ERROR".to_string(),
+                e,
+                None,
+                None,
+            )
+            .into_response(),
+        },
         Err(e) => ApiResponse::<()>::error(
             StatusCode::INTERNAL_SERVER_ERROR,
             "INTERNAL_SERVER_ERROR".to_string(),
@@ -66,40 +309,46 @@ pub async fn get_post_by_slug(
 
 pub async fn create_post(
     State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
     Json(payload): Json<CreatePostInput>,
 ) -> impl IntoResponse {
-    let usecase = CreatePostUseCase::new(state.post_repo.clone());
+    let usecase = CreatePostUseCase::new(
+        state.post_repo.clone(),
+        state.tag_repo.clone(),
+        state.setting_repo.clone(),
+        state.permission_repo.clone(),
+        state.slug_repo.clone(),
+        state.media_repo.clone(),
+        state.banner_repo.clone(),
+    );
+    let saved_by = Uuid::parse_str(&claims.sub).ok();
 
-    match usecase.execute(payload).await {
+    match usecase.execute(payload, saved_by).await {
         Ok(post) => ApiResponse::created(serde_json::json!(post), None).into_response(),
-        Err(e) => ApiResponse::<()>::error(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "INTERNAL_SERVER_ERROR".to_string(),
-            e,
-            None,
-            None,
-        )
-        .into_response(),
+        Err(e) => post_write_error(e),
     }
 }
 
 pub async fn update_post(
     State(state): State<Arc<AppState>>,
     Path(id): Path<Uuid>,
+    Extension(claims): Extension<Claims>,
     Json(payload): Json<UpdatePostInput>,
 ) -> impl IntoResponse {
-    let usecase = UpdatePostUseCase::new(state.post_repo.clone());
+    let usecase = UpdatePostUseCase::new(
+        state.post_repo.clone(),
+        state.tag_repo.clone(),
+        state.setting_repo.clone(),
+        state.permission_repo.clone(),
+        state.slug_repo.clone(),
+        state.media_repo.clone(),
+        state.banner_repo.clone(),
+    );
+    let saved_by = Uuid::parse_str(&claims.sub).ok();
 
-    match usecase.execute(id, payload).await {
+    match usecase.execute(id, payload, saved_by).await {
         Ok(post) => ApiResponse::success(serde_json::json!(post), None).into_response(),
-        Err(e) => ApiResponse::<()>::error(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "INTERNAL_SERVER_ERROR".to_string(),
-            e,
-            None,
-            None,
-        )
-        .into_response(),
+        Err(e) => post_write_error(e),
     }
 }
 
@@ -142,3 +391,278 @@ pub async fn bulk_delete_posts(
         .into_response(),
     }
 }
+
+pub async fn get_post_tags(
+    State(state): State<Arc<AppState>>,
+    Path(slug): Path<String>,
+) -> impl IntoResponse {
+    let usecase = GetPostTagsUseCase::new(state.post_repo.clone(), state.tag_repo.clone());
+    post_tags_response(usecase.execute(&slug).await)
+}
+
+/// Thay thế toàn bộ tag của bài viết
+pub async fn replace_post_tags(
+    State(state): State<Arc<AppState>>,
+    Path(slug): Path<String>,
+    Json(payload): Json<PostTagsInput>,
+) -> impl IntoResponse {
+    let usecase = SetPostTagsUseCase::new(state.post_repo.clone(), state.tag_repo.clone());
+    post_tags_response(usecase.execute(&slug, payload, PostTagsMode::Replace).await)
+}
+
+/// Gán thêm tag cho bài viết
+pub async fn attach_post_tags(
+    State(state): State<Arc<AppState>>,
+    Path(slug): Path<String>,
+    Json(payload): Json<PostTagsInput>,
+) -> impl IntoResponse {
+    let usecase = SetPostTagsUseCase::new(state.post_repo.clone(), state.tag_repo.clone());
+    post_tags_response(usecase.execute(&slug, payload, PostTagsMode::Attach).await)
+}
+
+pub async fn detach_post_tag(
+    State(state): State<Arc<AppState>>,
+    Path((slug, tag_slug)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let usecase = DetachPostTagUseCase::new(state.post_repo.clone(), state.tag_repo.clone());
+    post_tags_response(usecase.execute(&slug, &tag_slug).await)
+}
+
+/// Gallery của bài viết (kèm URL và biến thể)
+pub async fn get_post_media(
+    State(state): State<Arc<AppState>>,
+    Path(slug): Path<String>,
+) -> impl IntoResponse {
+    let usecase = GetPostMediaUseCase::new(
+        state.post_repo.clone(),
+        state.post_media_repo.clone(),
+        state.media_repo.clone(),
+    );
+
+    match usecase.execute(&slug).await {
+        Ok(items) => ApiResponse::success(serde_json::json!(items), None).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Thay thế toàn bộ gallery, thứ tự theo danh sách gửi lên
+pub async fn replace_post_media(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(slug): Path<String>,
+    Json(payload): Json<PostMediaInput>,
+) -> impl IntoResponse {
+    set_post_media(state, claims, slug, payload, PostMediaMode::Replace).await
+}
+
+/// Thêm media vào cuối gallery
+pub async fn append_post_media(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(slug): Path<String>,
+    Json(payload): Json<PostMediaInput>,
+) -> impl IntoResponse {
+    set_post_media(state, claims, slug, payload, PostMediaMode::Append).await
+}
+
+async fn set_post_media(
+    state: Arc<AppState>,
+    claims: Claims,
+    slug: String,
+    payload: PostMediaInput,
+    mode: PostMediaMode,
+) -> axum::response::Response {
+    let usecase = SetPostMediaUseCase::new(
+        state.post_repo.clone(),
+        state.post_media_repo.clone(),
+        state.media_repo.clone(),
+        state.permission_repo.clone(),
+    );
+    let actor = Uuid::parse_str(&claims.sub).ok();
+
+    match usecase.execute(&slug, payload, mode, actor).await {
+        Ok(items) => ApiResponse::success(serde_json::json!(items), None).into_response(),
+        Err(e) => post_write_error(e),
+    }
+}
+
+pub async fn detach_post_media(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path((slug, media_id)): Path<(String, Uuid)>,
+) -> impl IntoResponse {
+    let usecase = DetachPostMediaUseCase::new(
+        state.post_repo.clone(),
+        state.post_media_repo.clone(),
+        state.media_repo.clone(),
+        state.permission_repo.clone(),
+    );
+    let actor = Uuid::parse_str(&claims.sub).ok();
+
+    match usecase.execute(&slug, media_id, actor).await {
+        Ok(items) => ApiResponse::success(serde_json::json!(items), None).into_response(),
+        Err(e) => post_write_error(e),
+    }
+}
+
+pub async fn list_post_revisions(
+    State(state): State<Arc<AppState>>,
+    Path(slug): Path<String>,
+) -> impl IntoResponse {
+    let usecase =
+        ListPostRevisionsUseCase::new(state.post_repo.clone(), state.post_revision_repo.clone());
+
+    match usecase.execute(&slug).await {
+        Ok(revisions) => ApiResponse::success(serde_json::json!(revisions), None).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+pub async fn get_post_revision(
+    State(state): State<Arc<AppState>>,
+    Path((slug, number)): Path<(String, i32)>,
+) -> impl IntoResponse {
+    let usecase =
+        GetPostRevisionUseCase::new(state.post_repo.clone(), state.post_revision_repo.clone());
+
+    match usecase.execute(&slug, number).await {
+        Ok(revision) => ApiResponse::success(serde_json::json!(revision), None).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Diff theo dòng giữa 2 revision: `/posts/:slug/revisions/diff?from=1&to=3`
+pub async fn diff_post_revisions(
+    State(state): State<Arc<AppState>>,
+    Path(slug): Path<String>,
+    Query(input): Query<DiffPostRevisionsInput>,
+) -> impl IntoResponse {
+    let usecase =
+        DiffPostRevisionsUseCase::new(state.post_repo.clone(), state.post_revision_repo.clone());
+
+    match usecase.execute(&slug, input).await {
+        Ok(diff) => ApiResponse::success(serde_json::json!(diff), None).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Khôi phục 1 revision thành lần lưu mới
+pub async fn restore_post_revision(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path((slug, number)): Path<(String, i32)>,
+) -> impl IntoResponse {
+    let usecase = RestorePostRevisionUseCase::new(
+        state.post_repo.clone(),
+        state.post_revision_repo.clone(),
+        state.setting_repo.clone(),
+        state.permission_repo.clone(),
+        state.media_repo.clone(),
+        state.banner_repo.clone(),
+    );
+    let saved_by = Uuid::parse_str(&claims.sub).ok();
+
+    match usecase.execute(&slug, number, saved_by).await {
+        Ok(post) => ApiResponse::success(
+            serde_json::json!(post),
+            Some(format!("Revision {number} restored")),
+        )
+        .into_response(),
+        Err(e) => post_write_error(e),
+    }
+}
+
+/// Trạng thái review: reviewer và nhận xét
+pub async fn get_post_review(
+    State(state): State<Arc<AppState>>,
+    Path(slug): Path<String>,
+) -> impl IntoResponse {
+    let usecase =
+        GetPostReviewUseCase::new(state.post_repo.clone(), state.post_review_repo.clone());
+
+    match usecase.execute(&slug).await {
+        Ok(summary) => ApiResponse::success(serde_json::json!(summary), None).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Gán reviewer (thay thế toàn bộ)
+pub async fn assign_post_reviewers(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(slug): Path<String>,
+    Json(payload): Json<AssignPostReviewersInput>,
+) -> impl IntoResponse {
+    let usecase = AssignPostReviewersUseCase::new(
+        state.post_repo.clone(),
+        state.post_review_repo.clone(),
+        state.permission_repo.clone(),
+    );
+    let assigned_by = Uuid::parse_str(&claims.sub).ok();
+
+    match usecase.execute(&slug, payload, assigned_by).await {
+        Ok(reviewers) => ApiResponse::success(serde_json::json!(reviewers), None).into_response(),
+        Err(e) => post_write_error(e),
+    }
+}
+
+/// Nhận xét / duyệt / yêu cầu chỉnh sửa bài đang review
+pub async fn review_post(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(slug): Path<String>,
+    Json(payload): Json<ReviewPostInput>,
+) -> impl IntoResponse {
+    let usecase = ReviewPostUseCase::new(
+        state.post_repo.clone(),
+        state.post_review_repo.clone(),
+        state.permission_repo.clone(),
+    );
+    let Ok(reviewer_id) = Uuid::parse_str(&claims.sub) else {
+        return post_write_error(AppError::new(
+            ErrorKind::Invalid,
+            "AUTH_TOKEN_INVALID",
+            "Invalid user ID in token",
+        ));
+    };
+
+    match usecase.execute(&slug, payload, reviewer_id).await {
+        Ok(result) => ApiResponse::created(serde_json::json!(result), None).into_response(),
+        Err(e) => post_write_error(e),
+    }
+}
+
+fn post_tags_response(
+    result: Result<Vec<crate::domain::entities::tag::Tag>, AppError>,
+) -> axum::response::Response {
+    match result {
+        Ok(tags) => ApiResponse::success(serde_json::json!(tags), None).into_response(),
+        Err(e) if is_invalid_tag(&e) => invalid_tag_response(e),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Lỗi khi ghi bài viết: tag không hợp lệ có mã riêng `INVALID_TAG`, còn lại theo loại lỗi
+fn post_write_error(e: AppError) -> axum::response::Response {
+    if is_invalid_tag(&e) {
+        return invalid_tag_response(e);
+    }
+    error_response(e)
+}
+
+/// Lỗi validate của field `tag` (tag không tồn tại)
+fn is_invalid_tag(e: &AppError) -> bool {
+    e.key() == Some("VALIDATION_ERROR")
+        && e.params().get("field").and_then(|v| v.as_str()) == Some("tag")
+}
+
+fn invalid_tag_response(e: AppError) -> axum::response::Response {
+    ApiResponse::<()>::error(
+        StatusCode::BAD_REQUEST,
+        "INVALID_TAG".to_string(),
+        e,
+        None,
+        None,
+    )
+    .into_response()
+}
@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    app::state::AppState,
+    application::common::list_params::ListParams,
+    application::redirect::{
+        create_redirect::{CreateRedirectInput, CreateRedirectUseCase},
+        delete_redirect::DeleteRedirectUseCase,
+        get_redirect::GetRedirectUseCase,
+        get_redirects::GetRedirectsUseCase,
+        resolve_redirect::ResolveRedirectUseCase,
+        update_redirect::{UpdateRedirectInput, UpdateRedirectUseCase},
+    },
+    interface::http::response::{ApiResponse, error_response},
+    shared::error::AppError,
+    shared::utils::query::is_cursor_error,
+};
+
+#[derive(Deserialize)]
+pub struct RedirectListQuery {
+    pub is_active: Option<bool>,
+}
+
+#[derive(Deserialize)]
+pub struct ResolveRedirectQuery {
+    pub path: String,
+}
+
+/// Danh sách redirect (admin), lọc `is_active`, sort được theo `hit_count`
+pub async fn list_redirects(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<ListParams>,
+    Query(query): Query<RedirectListQuery>,
+) -> impl IntoResponse {
+    let usecase = GetRedirectsUseCase::new(state.redirect_repo.clone());
+    match usecase.execute(&params, query.is_active).await {
+        Ok(result) => {
+            let data = result
+                .items
+                .into_iter()
+                .map(|r| serde_json::json!(r))
+                .collect();
+            let pagination = serde_json::json!({
+                "next_cursor": result.next_cursor,
+                "limit": result.limit,
+                "sort_by": params.sort_by.clone(),
+                "search": params.search.clone()
+            });
+            ApiResponse::<Vec<serde_json::Value>>::success_with_pagination(data, pagination, None)
+                .into_response()
+        }
+        Err(e) if is_cursor_error(&e) => ApiResponse::<()>::error(
+            StatusCode::BAD_REQUEST,
+            "INVALID_CURSOR".to_string(),
+            e,
+            None,
+            None,
+        )
+        .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+pub async fn get_redirect(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    let usecase = GetRedirectUseCase::new(state.redirect_repo.clone());
+    match usecase.execute(id).await {
+        Ok(Some(redirect)) => {
+            ApiResponse::success(serde_json::json!(redirect), None).into_response()
+        }
+        Ok(None) => error_response(AppError::not_found("REDIRECT_NOT_FOUND", "Redirect")),
+        Err(e) => error_response(e),
+    }
+}
+
+pub async fn create_redirect(
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<CreateRedirectInput>,
+) -> impl IntoResponse {
+    let usecase = CreateRedirectUseCase::new(state.redirect_repo.clone());
+    match usecase.execute(payload).await {
+        Ok(redirect) => ApiResponse::created(serde_json::json!(redirect), None).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+pub async fn update_redirect(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateRedirectInput>,
+) -> impl IntoResponse {
+    let usecase = UpdateRedirectUseCase::new(state.redirect_repo.clone());
+    match usecase.execute(id, payload).await {
+        Ok(redirect) => ApiResponse::success(serde_json::json!(redirect), None).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+pub async fn delete_redirect(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    let usecase = DeleteRedirectUseCase::new(state.redirect_repo.clone());
+    match usecase.execute(id).await {
+        Ok(()) => ApiResponse::success(
+            serde_json::json!({ "id": id }),
+            Some("Redirect deleted".to_string()),
+        )
+        .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Public: frontend tra cứu path (thường khi gặp 404) để biết có cần chuyển hướng
+pub async fn resolve_redirect(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ResolveRedirectQuery>,
+) -> impl IntoResponse {
+    let usecase = ResolveRedirectUseCase::new(state.redirect_repo.clone());
+    match usecase.execute(&query.path).await {
+        Ok(Some(resolved)) => {
+            ApiResponse::success(serde_json::json!(resolved), None).into_response()
+        }
+        Ok(None) => error_response(AppError::not_found("REDIRECT_NOT_FOUND", "Redirect")),
+        Err(e) => error_response(e),
+    }
+}
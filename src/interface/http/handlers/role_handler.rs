@@ -18,7 +18,8 @@ use crate::application::role::{
 };
 use crate::domain::entities::role::{NewRole, UpdateRole};
 use crate::interface::http::response::ApiResponse;
-use crate::shared::utils::query::ListParams;
+use crate::shared::error::AppError;
+use crate::shared::utils::query::{ListParams, is_cursor_error};
 
 pub async fn get_roles(
     State(state): State<Arc<AppState>>,
@@ -42,6 +43,14 @@ pub async fn get_roles(
             ApiResponse::<Vec<serde_json::Value>>::success_with_pagination(data, pagination, None)
                 .into_response()
         }
+        Err(e) if is_cursor_error(&e) => ApiResponse::<()>::error(
+            StatusCode::BAD_REQUEST,
+            "INVALID_CURSOR".to_string(),
+            e,
+            None,
+            None,
+        )
+        .into_response(),
         Err(e) => ApiResponse::<()>::error(
             StatusCode::INTERNAL_SERVER_ERROR,
             "INTERNAL_SERVER_ERROR".to_string(),
@@ -83,7 +92,7 @@ pub async fn get_role(
         Ok(None) => ApiResponse::<()>::error(
             StatusCode::NOT_FOUND,
             "NOT_FOUND".to_string(),
-            "Role not found".to_string(),
+            AppError::not_found("ROLE_NOT_FOUND", "Role"),
             None,
             None,
         )
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Extension, Path, Query, State},
+    response::IntoResponse,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    app::state::AppState,
+    application::seo::{
+        get_category_seo::GetCategorySeoUseCase,
+        get_post_seo::GetPostSeoUseCase,
+        update_seo::{SeoTarget, UpdateSeoInput, UpdateSeoUseCase},
+    },
+    interface::http::{
+        request_language::RequestLanguage,
+        response::{ApiResponse, error_response},
+    },
+    shared::utils::jwt::Claims,
+};
+
+#[derive(Deserialize)]
+pub struct SeoQuery {
+    /// Type của category (mặc định `post`)
+    pub r#type: Option<String>,
+}
+
+/// SEO dựng sẵn của bài viết: meta, Open Graph, JSON-LD `Article` + `BreadcrumbList`
+pub async fn get_post_seo(
+    State(state): State<Arc<AppState>>,
+    Path(slug): Path<String>,
+    language: RequestLanguage,
+) -> impl IntoResponse {
+    let usecase = GetPostSeoUseCase::new(
+        state.post_repo.clone(),
+        state.category_repo.clone(),
+        state.user_repo.clone(),
+        state.media_repo.clone(),
+        state.seo_metadata_repo.clone(),
+        state.setting_repo.clone(),
+        state.language_repo.clone(),
+    );
+
+    match usecase.execute(&slug, Some(&language.0.code)).await {
+        Ok(payload) => {
+            language.apply(ApiResponse::success(serde_json::json!(payload), None).into_response())
+        }
+        Err(e) => error_response(e),
+    }
+}
+
+/// Ghi SEO của bài viết cho 1 ngôn ngữ
+pub async fn update_post_seo(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(slug): Path<String>,
+    Json(payload): Json<UpdateSeoInput>,
+) -> impl IntoResponse {
+    update_seo(state, claims, SeoTarget::Post { slug: &slug }, payload).await
+}
+
+/// SEO dựng sẵn của category: meta, Open Graph, JSON-LD `CollectionPage` + `BreadcrumbList`
+pub async fn get_category_seo(
+    State(state): State<Arc<AppState>>,
+    Path(slug): Path<String>,
+    Query(query): Query<SeoQuery>,
+    language: RequestLanguage,
+) -> impl IntoResponse {
+    let usecase = GetCategorySeoUseCase::new(
+        state.category_repo.clone(),
+        state.media_repo.clone(),
+        state.seo_metadata_repo.clone(),
+        state.setting_repo.clone(),
+        state.language_repo.clone(),
+    );
+    let r#type = query.r#type.unwrap_or_else(|| "post".to_string());
+
+    match usecase
+        .execute(&slug, &r#type, Some(&language.0.code))
+        .await
+    {
+        Ok(payload) => {
+            language.apply(ApiResponse::success(serde_json::json!(payload), None).into_response())
+        }
+        Err(e) => error_response(e),
+    }
+}
+
+/// Ghi SEO của category cho 1 ngôn ngữ (`?type=`, mặc định `post`)
+pub async fn update_category_seo(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(slug): Path<String>,
+    Query(query): Query<SeoQuery>,
+    Json(payload): Json<UpdateSeoInput>,
+) -> impl IntoResponse {
+    let r#type = query.r#type.unwrap_or_else(|| "post".to_string());
+    let target = SeoTarget::Category {
+        slug: &slug,
+        r#type: &r#type,
+    };
+    update_seo(state, claims, target, payload).await
+}
+
+async fn update_seo(
+    state: Arc<AppState>,
+    claims: Claims,
+    target: SeoTarget<'_>,
+    payload: UpdateSeoInput,
+) -> axum::response::Response {
+    let usecase = UpdateSeoUseCase::new(
+        state.post_repo.clone(),
+        state.category_repo.clone(),
+        state.seo_metadata_repo.clone(),
+        state.language_repo.clone(),
+        state.media_repo.clone(),
+        state.permission_repo.clone(),
+    );
+    let actor = Uuid::parse_str(&claims.sub).ok();
+
+    match usecase.execute(target, payload, actor).await {
+        Ok(seo) => ApiResponse::success(serde_json::json!(seo), None).into_response(),
+        Err(e) => error_response(e),
+    }
+}
@@ -14,6 +14,7 @@ use crate::{
         update_setting::UpdateSettingUseCase,
     },
     interface::http::response::ApiResponse,
+    shared::error::AppError,
 };
 
 pub async fn list_settings(State(state): State<Arc<AppState>>) -> impl IntoResponse {
@@ -43,7 +44,7 @@ pub async fn get_setting(
         Ok(None) => ApiResponse::<()>::error(
             StatusCode::NOT_FOUND,
             "NOT_FOUND".to_string(),
-            "Setting not found".to_string(),
+            AppError::not_found("SETTING_NOT_FOUND", "Setting"),
             None,
             None,
         )
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::header,
+    response::IntoResponse,
+};
+
+use crate::{
+    app::state::AppState,
+    application::sitemap::{
+        get_sitemap_index::GetSitemapIndexUseCase, get_sitemap_page::GetSitemapPageUseCase,
+    },
+    domain::repositories::sitemap_repository::SitemapSection,
+    interface::http::response::error_response,
+    shared::error::AppError,
+};
+
+const SITEMAP_CONTENT_TYPE: &str = "application/xml; charset=utf-8";
+
+/// Sitemap index: `/sitemap.xml`
+pub async fn get_sitemap_index(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let usecase = GetSitemapIndexUseCase::new(
+        state.sitemap_repo.clone(),
+        state.setting_repo.clone(),
+        state.language_repo.clone(),
+        state.sitemap_cache.clone(),
+    );
+    match usecase.execute().await {
+        Ok(body) => xml_response(&body),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Sitemap con: `/sitemaps/{posts|categories|tags}-{trang}.xml`
+pub async fn get_sitemap_page(
+    State(state): State<Arc<AppState>>,
+    Path(file): Path<String>,
+) -> impl IntoResponse {
+    let Some((section, page)) = parse_sitemap_file(&file) else {
+        return error_response(AppError::not_found("SITEMAP_NOT_FOUND", "Sitemap"));
+    };
+    let usecase = GetSitemapPageUseCase::new(
+        state.sitemap_repo.clone(),
+        state.setting_repo.clone(),
+        state.language_repo.clone(),
+        state.sitemap_cache.clone(),
+    );
+    match usecase.execute(section, page).await {
+        Ok(body) => xml_response(&body),
+        Err(e) => error_response(e),
+    }
+}
+
+fn parse_sitemap_file(file: &str) -> Option<(SitemapSection, i64)> {
+    let (section, page) = file.strip_suffix(".xml")?.rsplit_once('-')?;
+    Some((SitemapSection::parse(section)?, page.parse().ok()?))
+}
+
+fn xml_response(body: &str) -> axum::response::Response {
+    (
+        [(header::CONTENT_TYPE, SITEMAP_CONTENT_TYPE)],
+        body.to_string(),
+    )
+        .into_response()
+}
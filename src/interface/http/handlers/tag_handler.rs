@@ -19,7 +19,9 @@ use crate::{
         get_tags::GetTagsUseCase,
         update_tag::{UpdateTagInput, UpdateTagUseCase},
     },
-    interface::http::response::ApiResponse,
+    interface::http::request_language::{RequestLanguage, localize_content},
+    interface::http::response::{ApiResponse, error_response},
+    shared::error::AppError,
 };
 
 #[derive(Deserialize)]
@@ -30,12 +32,26 @@ pub struct TagQuery {
 pub async fn list_tags(
     State(state): State<Arc<AppState>>,
     Query(query): Query<TagQuery>,
+    language: RequestLanguage,
 ) -> impl IntoResponse {
     let usecase = GetTagsUseCase::new(state.tag_repo.clone());
+    let localize = localize_content(&state);
     let r#type = query.r#type.unwrap_or_else(|| "post".to_string());
 
-    match usecase.execute(&r#type).await {
-        Ok(tags) => ApiResponse::success(serde_json::json!(tags), None).into_response(),
+    let result = match usecase.execute(&r#type).await {
+        Ok(mut tags) => localize
+            .tags(&mut tags, &language.0)
+            .await
+            .map(|fallbacks| (tags, fallbacks)),
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok((tags, fallbacks)) => {
+            let mut data = serde_json::json!(tags);
+            fallbacks.annotate(&mut data);
+            language.apply(ApiResponse::success(data, None).into_response())
+        }
         Err(e) => ApiResponse::<()>::error(
             StatusCode::INTERNAL_SERVER_ERROR,
             "INTERNAL_SERVER_ERROR".to_string(),
@@ -51,16 +67,34 @@ pub async fn get_tag(
     State(state): State<Arc<AppState>>,
     Path(slug): Path<String>,
     Query(query): Query<TagQuery>,
+    language: RequestLanguage,
 ) -> impl IntoResponse {
     let usecase = GetTagUseCase::new(state.tag_repo.clone());
+    let localize = localize_content(&state);
     let r#type = query.r#type.unwrap_or_else(|| "post".to_string());
 
-    match usecase.execute(&slug, &r#type).await {
-        Ok(Some(tag)) => ApiResponse::success(serde_json::json!(tag), None).into_response(),
+    let result = match usecase.execute(&slug, &r#type).await {
+        Ok(Some(tag)) => {
+            let mut tags = [tag];
+            localize
+                .tags(&mut tags, &language.0)
+                .await
+                .map(|fallbacks| tags.into_iter().next().map(|tag| (tag, fallbacks)))
+        }
+        Ok(None) => Ok(None),
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(Some((tag, fallbacks))) => {
+            let mut data = serde_json::json!(tag);
+            fallbacks.annotate(&mut data);
+            language.apply(ApiResponse::success(data, None).into_response())
+        }
         Ok(None) => ApiResponse::<()>::error(
             StatusCode::NOT_FOUND,
             "NOT_FOUND".to_string(),
-            "Tag not found".to_string(),
+            AppError::not_found("TAG_NOT_FOUND", "Tag"),
             None,
             None,
         )
@@ -80,18 +114,11 @@ pub async fn create_tag(
     State(state): State<Arc<AppState>>,
     Json(payload): Json<CreateTagInput>,
 ) -> impl IntoResponse {
-    let usecase = CreateTagUseCase::new(state.tag_repo.clone());
+    let usecase = CreateTagUseCase::new(state.tag_repo.clone(), state.slug_repo.clone());
 
     match usecase.execute(payload).await {
         Ok(tag) => ApiResponse::created(serde_json::json!(tag), None).into_response(),
-        Err(e) => ApiResponse::<()>::error(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "INTERNAL_SERVER_ERROR".to_string(),
-            e,
-            None,
-            None,
-        )
-        .into_response(),
+        Err(e) => error_response(e),
     }
 }
 
@@ -100,18 +127,11 @@ pub async fn update_tag(
     Path(id): Path<Uuid>,
     Json(payload): Json<UpdateTagInput>,
 ) -> impl IntoResponse {
-    let usecase = UpdateTagUseCase::new(state.tag_repo.clone());
+    let usecase = UpdateTagUseCase::new(state.tag_repo.clone(), state.slug_repo.clone());
 
     match usecase.execute(id, payload).await {
         Ok(tag) => ApiResponse::success(serde_json::json!(tag), None).into_response(),
-        Err(e) => ApiResponse::<()>::error(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "INTERNAL_SERVER_ERROR".to_string(),
-            e,
-            None,
-            None,
-        )
-        .into_response(),
+        Err(e) => error_response(e),
     }
 }
 
@@ -0,0 +1,354 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    app::state::AppState,
+    application::common::list_params::ListParams,
+    application::translation::{
+        banner_translations::{BannerTranslationInput, BannerTranslationsUseCase},
+        category_translations::{CategoryTranslationsUseCase, NameTranslationInput},
+        export_translations::{ExportTranslationsQuery, ExportTranslationsUseCase},
+        import_translations::{ImportTranslationsQuery, ImportTranslationsUseCase},
+        post_translations::{PostTranslationInput, PostTranslationsUseCase},
+        tag_translations::TagTranslationsUseCase,
+        translation_coverage::TranslationCoverageUseCase,
+        translation_queue::{TranslationQueueQuery, TranslationQueueUseCase},
+    },
+    interface::http::response::{ApiResponse, error_response},
+    shared::error::AppError,
+    shared::utils::query::is_cursor_error,
+};
+
+#[derive(Deserialize)]
+pub struct TranslationQuery {
+    /// Type của category/tag (mặc định `post`)
+    pub r#type: Option<String>,
+}
+
+impl TranslationQuery {
+    fn r#type(&self) -> &str {
+        self.r#type.as_deref().unwrap_or("post")
+    }
+}
+
+fn post_translations(state: &AppState) -> PostTranslationsUseCase {
+    PostTranslationsUseCase::new(
+        state.post_repo.clone(),
+        state.post_translation_repo.clone(),
+        state.language_repo.clone(),
+        state.slug_repo.clone(),
+    )
+}
+
+fn category_translations(state: &AppState) -> CategoryTranslationsUseCase {
+    CategoryTranslationsUseCase::new(
+        state.category_repo.clone(),
+        state.category_translation_repo.clone(),
+        state.language_repo.clone(),
+    )
+}
+
+fn tag_translations(state: &AppState) -> TagTranslationsUseCase {
+    TagTranslationsUseCase::new(
+        state.tag_repo.clone(),
+        state.tag_translation_repo.clone(),
+        state.language_repo.clone(),
+    )
+}
+
+fn banner_translations(state: &AppState) -> BannerTranslationsUseCase {
+    BannerTranslationsUseCase::new(
+        state.banner_repo.clone(),
+        state.banner_translation_repo.clone(),
+        state.language_repo.clone(),
+    )
+}
+
+// Posts: /posts/:slug/translations[/:lang]
+
+pub async fn list_post_translations(
+    State(state): State<Arc<AppState>>,
+    Path(slug): Path<String>,
+) -> impl IntoResponse {
+    respond(post_translations(&state).list(&slug).await)
+}
+
+pub async fn get_post_translation(
+    State(state): State<Arc<AppState>>,
+    Path((slug, lang)): Path<(String, String)>,
+) -> impl IntoResponse {
+    respond(post_translations(&state).get(&slug, &lang).await)
+}
+
+/// Tạo mới hoặc ghi đè bản dịch bài viết
+pub async fn upsert_post_translation(
+    State(state): State<Arc<AppState>>,
+    Path((slug, lang)): Path<(String, String)>,
+    Json(payload): Json<PostTranslationInput>,
+) -> impl IntoResponse {
+    respond(
+        post_translations(&state)
+            .upsert(&slug, &lang, payload)
+            .await,
+    )
+}
+
+pub async fn delete_post_translation(
+    State(state): State<Arc<AppState>>,
+    Path((slug, lang)): Path<(String, String)>,
+) -> impl IntoResponse {
+    respond_deleted(post_translations(&state).delete(&slug, &lang).await)
+}
+
+// Categories: /categories/:slug/translations[/:lang]?type=
+
+pub async fn list_category_translations(
+    State(state): State<Arc<AppState>>,
+    Path(slug): Path<String>,
+    Query(query): Query<TranslationQuery>,
+) -> impl IntoResponse {
+    respond(
+        category_translations(&state)
+            .list(&slug, query.r#type())
+            .await,
+    )
+}
+
+pub async fn get_category_translation(
+    State(state): State<Arc<AppState>>,
+    Path((slug, lang)): Path<(String, String)>,
+    Query(query): Query<TranslationQuery>,
+) -> impl IntoResponse {
+    respond(
+        category_translations(&state)
+            .get(&slug, query.r#type(), &lang)
+            .await,
+    )
+}
+
+pub async fn upsert_category_translation(
+    State(state): State<Arc<AppState>>,
+    Path((slug, lang)): Path<(String, String)>,
+    Query(query): Query<TranslationQuery>,
+    Json(payload): Json<NameTranslationInput>,
+) -> impl IntoResponse {
+    respond(
+        category_translations(&state)
+            .upsert(&slug, query.r#type(), &lang, payload)
+            .await,
+    )
+}
+
+pub async fn delete_category_translation(
+    State(state): State<Arc<AppState>>,
+    Path((slug, lang)): Path<(String, String)>,
+    Query(query): Query<TranslationQuery>,
+) -> impl IntoResponse {
+    respond_deleted(
+        category_translations(&state)
+            .delete(&slug, query.r#type(), &lang)
+            .await,
+    )
+}
+
+// Tags: /tags/:slug/translations[/:lang]?type=
+
+pub async fn list_tag_translations(
+    State(state): State<Arc<AppState>>,
+    Path(slug): Path<String>,
+    Query(query): Query<TranslationQuery>,
+) -> impl IntoResponse {
+    respond(tag_translations(&state).list(&slug, query.r#type()).await)
+}
+
+pub async fn get_tag_translation(
+    State(state): State<Arc<AppState>>,
+    Path((slug, lang)): Path<(String, String)>,
+    Query(query): Query<TranslationQuery>,
+) -> impl IntoResponse {
+    respond(
+        tag_translations(&state)
+            .get(&slug, query.r#type(), &lang)
+            .await,
+    )
+}
+
+pub async fn upsert_tag_translation(
+    State(state): State<Arc<AppState>>,
+    Path((slug, lang)): Path<(String, String)>,
+    Query(query): Query<TranslationQuery>,
+    Json(payload): Json<NameTranslationInput>,
+) -> impl IntoResponse {
+    respond(
+        tag_translations(&state)
+            .upsert(&slug, query.r#type(), &lang, payload)
+            .await,
+    )
+}
+
+pub async fn delete_tag_translation(
+    State(state): State<Arc<AppState>>,
+    Path((slug, lang)): Path<(String, String)>,
+    Query(query): Query<TranslationQuery>,
+) -> impl IntoResponse {
+    respond_deleted(
+        tag_translations(&state)
+            .delete(&slug, query.r#type(), &lang)
+            .await,
+    )
+}
+
+// Banners: /banners/:id/translations[/:lang]
+
+pub async fn list_banner_translations(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> impl IntoResponse {
+    respond(banner_translations(&state).list(id).await)
+}
+
+pub async fn get_banner_translation(
+    State(state): State<Arc<AppState>>,
+    Path((id, lang)): Path<(Uuid, String)>,
+) -> impl IntoResponse {
+    respond(banner_translations(&state).get(id, &lang).await)
+}
+
+pub async fn upsert_banner_translation(
+    State(state): State<Arc<AppState>>,
+    Path((id, lang)): Path<(Uuid, String)>,
+    Json(payload): Json<BannerTranslationInput>,
+) -> impl IntoResponse {
+    respond(banner_translations(&state).upsert(id, &lang, payload).await)
+}
+
+pub async fn delete_banner_translation(
+    State(state): State<Arc<AppState>>,
+    Path((id, lang)): Path<(Uuid, String)>,
+) -> impl IntoResponse {
+    respond_deleted(banner_translations(&state).delete(id, &lang).await)
+}
+
+#[derive(Deserialize)]
+pub struct CoverageQuery {
+    pub lang: Option<String>,
+}
+
+/// Độ phủ bản dịch (%) theo ngôn ngữ và loại nội dung
+pub async fn get_translation_coverage(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<CoverageQuery>,
+) -> impl IntoResponse {
+    let usecase = TranslationCoverageUseCase::new(
+        state.language_repo.clone(),
+        state.translation_status_repo.clone(),
+    );
+    respond(usecase.execute(query.lang.as_deref()).await)
+}
+
+/// Hàng đợi cần dịch: `?lang=en&entity_type=post&status=outdated`, phân trang cursor
+pub async fn list_translation_queue(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<ListParams>,
+    Query(query): Query<TranslationQueueQuery>,
+) -> impl IntoResponse {
+    let usecase = TranslationQueueUseCase::new(
+        state.language_repo.clone(),
+        state.translation_status_repo.clone(),
+    );
+    match usecase.execute(&params, query).await {
+        Ok(result) => {
+            let data = result
+                .items
+                .into_iter()
+                .map(|item| serde_json::json!(item))
+                .collect();
+            let pagination = serde_json::json!({
+                "next_cursor": result.next_cursor,
+                "limit": result.limit,
+                "sort_by": params.sort_by.clone(),
+                "search": params.search.clone()
+            });
+            ApiResponse::<Vec<serde_json::Value>>::success_with_pagination(data, pagination, None)
+                .into_response()
+        }
+        Err(e) if is_cursor_error(&e) => ApiResponse::<()>::error(
+            StatusCode::BAD_REQUEST,
+            "INVALID_CURSOR".to_string(),
+            e,
+            None,
+            None,
+        )
+        .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Xuất file XLIFF 2.0 / PO cho bên dịch: `?lang=en&format=po&entity_type=post&ids=..&since=2026-10-01`
+pub async fn export_translations(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ExportTranslationsQuery>,
+) -> impl IntoResponse {
+    let usecase = ExportTranslationsUseCase::new(
+        state.language_repo.clone(),
+        state.translation_status_repo.clone(),
+    );
+    match usecase.execute(query).await {
+        Ok(exported) => (
+            [
+                (
+                    header::CONTENT_TYPE,
+                    exported.format.content_type().to_string(),
+                ),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{}\"", exported.filename),
+                ),
+            ],
+            exported.body,
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// Nhập file XLIFF 2.0 / PO (body thô): `?lang=en&dry_run=true` chỉ trả về diff.
+/// Luôn trả 200 kèm báo cáo lỗi theo từng unit; 400 khi cả file không đọc được
+pub async fn import_translations(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ImportTranslationsQuery>,
+    body: String,
+) -> impl IntoResponse {
+    let usecase = ImportTranslationsUseCase::new(
+        state.language_repo.clone(),
+        state.translation_status_repo.clone(),
+        post_translations(&state),
+        category_translations(&state),
+        tag_translations(&state),
+        banner_translations(&state),
+    );
+    respond(usecase.execute(query, &body).await)
+}
+
+fn respond<T: serde::Serialize>(result: Result<T, AppError>) -> axum::response::Response {
+    match result {
+        Ok(data) => ApiResponse::success(serde_json::json!(data), None).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+fn respond_deleted(result: Result<(), AppError>) -> axum::response::Response {
+    match result {
+        Ok(()) => ApiResponse::success((), Some("Translation deleted successfully".to_string()))
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
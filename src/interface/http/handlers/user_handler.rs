@@ -7,16 +7,22 @@ use axum::{
 use std::sync::Arc;
 
 use crate::app::state::AppState;
-use crate::application::common::list_params::ListParams;
+use crate::application::common::list_params::{ListParams, ReadParams};
 use crate::application::role::{
     assign_role_to_user::AssignRoleToUserUseCase, revoke_role_from_user::RevokeRoleFromUserUseCase,
 };
 use crate::application::user::{
-    create_user::CreateUserUseCase, delete_user::DeleteUserUseCase, get_user::GetUserUseCase,
-    get_users::GetUsersUseCase, update_user::UpdateUserUseCase,
+    create_user::CreateUserUseCase,
+    delete_user::DeleteUserUseCase,
+    embed_user_relations::{EmbedUserRelationsUseCase, USER_INCLUDES},
+    get_user::GetUserUseCase,
+    get_users::GetUsersUseCase,
+    update_user::UpdateUserUseCase,
 };
 use crate::domain::entities::user::{NewUser, UpdateUser};
-use crate::interface::http::response::ApiResponse;
+use crate::interface::http::{projection::project, response::ApiResponse};
+use crate::shared::error::AppError;
+use crate::shared::utils::query::is_cursor_error;
 use uuid::Uuid;
 
 pub async fn get_users(
@@ -24,24 +30,46 @@ pub async fn get_users(
     Query(params): Query<ListParams>,
 ) -> impl IntoResponse {
     let usecase = GetUsersUseCase::new(state.user_repo.clone());
+    let embed =
+        EmbedUserRelationsUseCase::new(state.role_repo.clone(), state.user_profile_repo.clone());
+    let read = params.read_params();
+    let selected = read.selected();
+    let includes = read.includes(USER_INCLUDES);
 
-    match usecase.execute(&params).await {
-        Ok(result) => {
-            let data = result
-                .items
-                .into_iter()
-                .map(|u| serde_json::json!(u))
+    let result = match usecase.execute(&params).await {
+        Ok(result) => embed
+            .execute(result.items, &includes)
+            .await
+            .map(|items| (items, result.next_cursor, result.limit)),
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok((items, next_cursor, limit)) => {
+            let data = items
+                .iter()
+                .map(|u| project(u, selected.as_deref(), &includes))
                 .collect();
             let pagination = serde_json::json!({
-                "next_cursor": result.next_cursor,
-                "limit": result.limit,
+                "next_cursor": next_cursor,
+                "limit": limit,
                 "sort_by": params.sort_by.clone(),
                 "fields": params.fields.clone(),
-                "search": params.search.clone()
+                "search": params.search.clone(),
+                "select": params.select.clone(),
+                "include": params.include.clone()
             });
             ApiResponse::<Vec<serde_json::Value>>::success_with_pagination(data, pagination, None)
                 .into_response()
         }
+        Err(e) if is_cursor_error(&e) => ApiResponse::<()>::error(
+            StatusCode::BAD_REQUEST,
+            "INVALID_CURSOR".to_string(),
+            e,
+            None,
+            None,
+        )
+        .into_response(),
         Err(e) => ApiResponse::<()>::error(
             StatusCode::INTERNAL_SERVER_ERROR,
             "INTERNAL_SERVER_ERROR".to_string(),
@@ -74,14 +102,32 @@ pub async fn create_user(
 pub async fn get_user(
     State(state): State<Arc<AppState>>,
     Path(id): Path<Uuid>,
+    Query(params): Query<ReadParams>,
 ) -> impl IntoResponse {
     let usecase = GetUserUseCase::new(state.user_repo.clone());
-    match usecase.execute(id).await {
-        Ok(Some(user)) => ApiResponse::success(serde_json::json!(user), None).into_response(),
+    let embed =
+        EmbedUserRelationsUseCase::new(state.role_repo.clone(), state.user_profile_repo.clone());
+    let includes = params.includes(USER_INCLUDES);
+
+    let result = match usecase.execute(id).await {
+        Ok(Some(user)) => embed
+            .execute(vec![user], &includes)
+            .await
+            .map(|views| views.into_iter().next()),
+        Ok(None) => Ok(None),
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(Some(user)) => ApiResponse::success(
+            project(&user, params.selected().as_deref(), &includes),
+            None,
+        )
+        .into_response(),
         Ok(None) => ApiResponse::<()>::error(
             StatusCode::NOT_FOUND,
             "NOT_FOUND".to_string(),
-            "User not found".to_string(),
+            AppError::not_found("USER_NOT_FOUND", "User"),
             None,
             None,
         )
@@ -12,7 +12,8 @@ use crate::{
         get_profile::GetProfileUseCase, update_profile::UpdateProfileUseCase,
     },
     domain::entities::user_profile::UpdateUserProfile,
-    interface::http::response::ApiResponse,
+    interface::http::response::{ApiResponse, error_response},
+    shared::error::{AppError, ErrorKind},
     shared::utils::jwt::Claims,
 };
 
@@ -26,7 +27,7 @@ pub async fn get_me_profile(
             return ApiResponse::<()>::error(
                 StatusCode::BAD_REQUEST,
                 "BAD_REQUEST".to_string(),
-                "Invalid user id".to_string(),
+                AppError::new(ErrorKind::Invalid, "AUTH_TOKEN_INVALID", "Invalid user id"),
                 None,
                 None,
             )
@@ -63,7 +64,7 @@ pub async fn update_me_profile(
             return ApiResponse::<()>::error(
                 StatusCode::BAD_REQUEST,
                 "BAD_REQUEST".to_string(),
-                "Invalid user id".to_string(),
+                AppError::new(ErrorKind::Invalid, "AUTH_TOKEN_INVALID", "Invalid user id"),
                 None,
                 None,
             )
@@ -71,17 +72,11 @@ pub async fn update_me_profile(
         }
     };
 
-    let usecase = UpdateProfileUseCase::new(state.user_profile_repo.clone());
+    let usecase =
+        UpdateProfileUseCase::new(state.user_profile_repo.clone(), state.language_repo.clone());
 
     match usecase.execute(user_id, payload).await {
         Ok(profile) => ApiResponse::success(serde_json::json!(profile), None).into_response(),
-        Err(e) => ApiResponse::<()>::error(
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "INTERNAL_SERVER_ERROR".to_string(),
-            e,
-            None,
-            None,
-        )
-        .into_response(),
+        Err(e) => error_response(e),
     }
 }
@@ -2,13 +2,15 @@ use axum::{
     body::Body,
     http::{Request, StatusCode, header},
     middleware::Next,
-    response::Response,
+    response::{IntoResponse, Response},
 };
 
-pub async fn auth_middleware(
-    mut req: Request<Body>,
-    next: Next,
-) -> Result<Response, (StatusCode, String)> {
+use crate::{
+    interface::http::response::ApiResponse,
+    shared::error::{AppError, ErrorKind},
+};
+
+pub async fn auth_middleware(mut req: Request<Body>, next: Next) -> Response {
     let auth_header = req
         .headers()
         .get(header::AUTHORIZATION)
@@ -21,15 +23,34 @@ pub async fn auth_middleware(
 
             match crate::shared::utils::jwt::verify_jwt(token, secret.as_bytes()) {
                 Ok(claims) => {
-                    req.extensions_mut().insert(claims);
-                    Ok(next.run(req).await)
+                    req.extensions_mut().insert(claims.clone());
+                    let mut response = next.run(req).await;
+                    // `localize_errors` bọc ngoài auth => đọc claims từ response để lấy ngôn ngữ của user
+                    response.extensions_mut().insert(claims);
+                    response
                 }
-                Err(_) => Err((StatusCode::UNAUTHORIZED, "Invalid token".to_string())),
+                Err(_) => unauthorized(AppError::new(
+                    ErrorKind::Unauthorized,
+                    "AUTH_TOKEN_INVALID",
+                    "Invalid token",
+                )),
             }
         }
-        _ => Err((
-            StatusCode::UNAUTHORIZED,
-            "Missing or invalid token".to_string(),
+        _ => unauthorized(AppError::new(
+            ErrorKind::Unauthorized,
+            "AUTH_TOKEN_MISSING",
+            "Missing or invalid token",
         )),
     }
 }
+
+fn unauthorized(error: AppError) -> Response {
+    ApiResponse::<()>::error(
+        StatusCode::UNAUTHORIZED,
+        "UNAUTHORIZED".to_string(),
+        error,
+        None,
+        None,
+    )
+    .into_response()
+}
@@ -0,0 +1,206 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::{Query, State},
+    http::{HeaderValue, Request, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use serde_json::{Map, Value, json};
+use uuid::Uuid;
+
+use crate::{
+    app::state::AppState,
+    application::{
+        i18n::localize_error::LocalizeErrorUseCase,
+        translation::negotiate_language::{ContentLanguage, NegotiateLanguageUseCase},
+    },
+    interface::http::response::ApiResponse,
+    shared::{
+        error::{AppError, ErrorKind},
+        utils::jwt::Claims,
+    },
+};
+
+/// Body lỗi lớn hơn mức này không được đọc lại (body lỗi của API chỉ vài KB)
+const MAX_ERROR_BODY_BYTES: usize = 1024 * 1024;
+
+/// Message trả về thay cho lỗi hệ thống chưa có mã (message gốc chỉ ghi log)
+const INTERNAL_ERROR_MESSAGE: &str = "Internal server error";
+
+#[derive(Deserialize)]
+struct LangQuery {
+    lang: Option<String>,
+}
+
+/// Thông tin chọn ngôn ngữ, lấy trước khi request đi vào handler
+struct LanguageHints {
+    lang: Option<String>,
+    accept_language: Option<String>,
+}
+
+/// Mọi response lỗi (4xx/5xx) => `ApiResponse`. Lỗi có mã message (`AppError` gắn ở nơi phát
+/// sinh lỗi, đi kèm response qua extension) được dịch theo ngôn ngữ của request; mã message và
+/// tham số nằm ở `error.details.messageKey`/`params`, `error.code` giữ nguyên mã của handler.
+/// Lỗi dạng text thô (rejection của extractor, router) cũng được bọc lại và gán mã theo status
+pub async fn localize_errors(
+    State(state): State<Arc<AppState>>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let hints = language_hints(&req);
+    let response = next.run(req).await;
+    let status = response.status();
+    if !(status.is_client_error() || status.is_server_error()) {
+        return response;
+    }
+
+    // Claims do auth_middleware gắn vào response (route công khai không có)
+    let user_id = response
+        .extensions()
+        .get::<Claims>()
+        .and_then(|claims| Uuid::parse_str(&claims.sub).ok());
+    let mut error = response.extensions().get::<AppError>().cloned();
+    let (mut parts, body) = response.into_parts();
+    let Ok(bytes) = axum::body::to_bytes(body, MAX_ERROR_BODY_BYTES).await else {
+        return parts.status.into_response();
+    };
+
+    let mut payload = match serde_json::from_slice::<Value>(&bytes) {
+        Ok(value @ Value::Object(_)) if value.get("success") == Some(&Value::Bool(false)) => value,
+        // Body không phải ApiResponse (text thô hoặc rỗng) => bọc lại theo format chung
+        _ => {
+            let text = String::from_utf8_lossy(&bytes).trim().to_string();
+            let message = if text.is_empty() {
+                status.canonical_reason().unwrap_or("Error").to_string()
+            } else {
+                text
+            };
+            let code = status
+                .canonical_reason()
+                .unwrap_or("Error")
+                .to_ascii_uppercase()
+                .replace([' ', '-'], "_");
+            let rejection = rejection_error(status, message);
+            let (_, _, body) =
+                ApiResponse::<()>::error(status, code, rejection.clone(), None, None);
+            error = Some(rejection);
+            json!(body.0)
+        }
+    };
+    let error = error.unwrap_or_else(|| {
+        payload
+            .get("message")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .into()
+    });
+
+    // Lỗi hệ thống chưa có mã: message gốc (lỗi DB...) chỉ ghi log, không trả cho client
+    let error = match error.key() {
+        None if status.is_server_error() => {
+            tracing::error!(status = status.as_u16(), error = %error, "request failed");
+            AppError::new(
+                ErrorKind::Internal,
+                "INTERNAL_SERVER_ERROR",
+                INTERNAL_ERROR_MESSAGE,
+            )
+        }
+        _ => error,
+    };
+
+    let language = match error.key() {
+        Some(_) => negotiate(&state, &hints, user_id).await,
+        None => None,
+    };
+    let localized = match &language {
+        Some(language) => LocalizeErrorUseCase::new(state.translation_key_repo.clone())
+            .execute(language, &error)
+            .await
+            .ok()
+            .flatten(),
+        None => None,
+    };
+    let message = localized.unwrap_or_else(|| error.message().to_string());
+
+    let mut details = match payload.pointer("/error/details") {
+        Some(Value::Object(details)) => details.clone(),
+        Some(Value::Null) | None => Map::new(),
+        Some(other) => Map::from_iter([("errors".to_string(), other.clone())]),
+    };
+    details.insert("message".to_string(), json!(message));
+    details.insert("statusCode".to_string(), json!(status.as_u16()));
+    if let Some(key) = error.key() {
+        details.insert("messageKey".to_string(), json!(key));
+        details.insert("params".to_string(), Value::Object(error.params().clone()));
+    }
+    payload["message"] = json!(message);
+    payload["error"]["details"] = Value::Object(details);
+
+    let body = serde_json::to_vec(&payload).unwrap_or_default();
+    parts.headers.remove(header::CONTENT_LENGTH);
+    parts.headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/json"),
+    );
+    if let Some(language) = &language {
+        if let Ok(value) = HeaderValue::from_str(&language.code) {
+            parts.headers.insert(header::CONTENT_LANGUAGE, value);
+        }
+        parts
+            .headers
+            .append(header::VARY, HeaderValue::from_static("accept-language"));
+    }
+    Response::from_parts(parts, Body::from(body))
+}
+
+/// Body text thô chỉ đến từ axum (router, rejection của extractor) => mã message theo status
+fn rejection_error(status: StatusCode, message: String) -> AppError {
+    let (kind, key) = match status {
+        StatusCode::NOT_FOUND => (ErrorKind::NotFound, "ROUTE_NOT_FOUND"),
+        StatusCode::METHOD_NOT_ALLOWED => (ErrorKind::Invalid, "METHOD_NOT_ALLOWED"),
+        StatusCode::UNSUPPORTED_MEDIA_TYPE => (ErrorKind::Invalid, "UNSUPPORTED_MEDIA_TYPE"),
+        StatusCode::UNPROCESSABLE_ENTITY => (ErrorKind::Invalid, "INVALID_JSON_BODY"),
+        StatusCode::BAD_REQUEST => (ErrorKind::Invalid, "INVALID_REQUEST"),
+        _ => return message.into(),
+    };
+    AppError::new(kind, key, message.clone()).with("reason", message)
+}
+
+fn language_hints(req: &Request<Body>) -> LanguageHints {
+    let lang = Query::<LangQuery>::try_from_uri(req.uri())
+        .ok()
+        .and_then(|q| q.0.lang);
+    let accept_language = req
+        .headers()
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    LanguageHints {
+        lang,
+        accept_language,
+    }
+}
+
+/// `?lang=` không hợp lệ (chính là lỗi đang trả về) => chọn lại như không có `?lang=`
+async fn negotiate(
+    state: &AppState,
+    hints: &LanguageHints,
+    user_id: Option<Uuid>,
+) -> Option<ContentLanguage> {
+    let usecase = NegotiateLanguageUseCase::new(
+        state.language_repo.clone(),
+        state.setting_repo.clone(),
+        state.user_profile_repo.clone(),
+    );
+    let accept_language = hints.accept_language.as_deref();
+    match usecase
+        .execute(hints.lang.as_deref(), user_id, accept_language)
+        .await
+    {
+        Ok(language) => Some(language),
+        Err(_) => usecase.execute(None, user_id, accept_language).await.ok(),
+    }
+}
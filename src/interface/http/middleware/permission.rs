@@ -1,4 +1,6 @@
 use crate::app::state::AppState;
+use crate::interface::http::response::ApiResponse;
+use crate::shared::error::{AppError, ErrorKind};
 use crate::shared::utils::jwt::Claims;
 use axum::{
     Extension,
@@ -6,7 +8,7 @@ use axum::{
     extract::State,
     http::{Request, StatusCode},
     middleware::Next,
-    response::Response,
+    response::{IntoResponse, Response},
 };
 use std::sync::Arc;
 
@@ -18,33 +20,81 @@ pub async fn require_admin_role(
     Extension(claims): Extension<Claims>,
     req: Request<Body>,
     next: Next,
-) -> Result<Response, (StatusCode, String)> {
+) -> Result<Response, Response> {
+    if has_any_role(&state, &claims, &["admin"]).await? {
+        Ok(next.run(req).await)
+    } else {
+        Err(forbidden(AppError::new(
+            ErrorKind::Forbidden,
+            "FORBIDDEN_ADMIN_ROLE",
+            "Forbidden: Requires Admin role",
+        )))
+    }
+}
+
+/// Chỉ cho phép Admin hoặc Editor (khu vực biên tập nội dung).
+pub async fn require_editor_role(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    req: Request<Body>,
+    next: Next,
+) -> Result<Response, Response> {
+    if has_any_role(&state, &claims, &["admin", "editor"]).await? {
+        Ok(next.run(req).await)
+    } else {
+        Err(forbidden(AppError::new(
+            ErrorKind::Forbidden,
+            "FORBIDDEN_EDITOR_ROLE",
+            "Forbidden: Requires Admin or Editor role",
+        )))
+    }
+}
+
+fn forbidden(error: AppError) -> Response {
+    ApiResponse::<()>::error(
+        StatusCode::FORBIDDEN,
+        "FORBIDDEN".to_string(),
+        error,
+        None,
+        None,
+    )
+    .into_response()
+}
+
+/// Check DB xem user (theo claims) có ít nhất một trong các role
+async fn has_any_role(
+    state: &AppState,
+    claims: &Claims,
+    role_names: &[&str],
+) -> Result<bool, Response> {
     // 1. Lấy userId từ claims
     let user_id = uuid::Uuid::parse_str(&claims.sub).map_err(|_| {
-        (
+        ApiResponse::<()>::error(
             StatusCode::BAD_REQUEST,
-            "Invalid user ID in token".to_string(),
+            "BAD_REQUEST".to_string(),
+            AppError::new(
+                ErrorKind::Invalid,
+                "AUTH_TOKEN_INVALID",
+                "Invalid user ID in token",
+            ),
+            None,
+            None,
         )
+        .into_response()
     })?;
 
-    // 2. Check DB xem user có role admin không
+    // 2. Role seed đang dùng tên thường ("admin", "editor")
     match state.role_repo.find_by_user_id(user_id).await {
-        Ok(roles) => {
-            // Role seed đang dùng tên thường "admin"
-            let is_admin = roles.iter().any(|r| r.name.eq_ignore_ascii_case("admin"));
-
-            if is_admin {
-                Ok(next.run(req).await)
-            } else {
-                Err((
-                    StatusCode::FORBIDDEN,
-                    "Forbidden: Requires Admin role".to_string(),
-                ))
-            }
-        }
-        Err(_) => Err((
+        Ok(roles) => Ok(roles
+            .iter()
+            .any(|r| role_names.iter().any(|n| r.name.eq_ignore_ascii_case(n)))),
+        Err(e) => Err(ApiResponse::<()>::error(
             StatusCode::INTERNAL_SERVER_ERROR,
-            "Failed to verify roles".to_string(),
-        )),
+            "INTERNAL_SERVER_ERROR".to_string(),
+            format!("Failed to verify roles: {e}"),
+            None,
+            None,
+        )
+        .into_response()),
     }
 }
@@ -1,3 +1,6 @@
+pub mod conditional;
 pub mod handlers;
 pub mod middleware;
+pub mod projection;
+pub mod request_language;
 pub mod response;
@@ -0,0 +1,24 @@
+use serde::Serialize;
+use serde_json::Value;
+
+/// Chuyển item sang JSON và chỉ giữ các cột trong `select`.
+/// `id` và các quan hệ đã include luôn được giữ lại; `select = None` trả về toàn bộ.
+pub fn project<T: Serialize>(item: &T, selected: Option<&[String]>, includes: &[String]) -> Value {
+    let value = serde_json::json!(item);
+    let Some(selected) = selected else {
+        return value;
+    };
+
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .filter(|(key, _)| {
+                    key == "id"
+                        || selected.iter().any(|s| s == key)
+                        || includes.iter().any(|i| i == key)
+                })
+                .collect(),
+        ),
+        other => other,
+    }
+}
@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::{
+    extract::{FromRequestParts, Query},
+    http::{HeaderValue, header, request::Parts},
+    response::Response,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    app::state::AppState,
+    application::translation::{
+        localize_content::LocalizeContentUseCase,
+        negotiate_language::{ContentLanguage, NegotiateLanguageUseCase},
+    },
+    interface::http::response::error_response,
+    shared::utils::jwt::Claims,
+};
+
+#[derive(Deserialize)]
+struct LangQuery {
+    lang: Option<String>,
+}
+
+/// Ngôn ngữ của request: `?lang=` > ngôn ngữ ưu tiên của user > `Accept-Language` > mặc định.
+/// `?lang=` không hợp lệ => 400
+pub struct RequestLanguage(pub ContentLanguage);
+
+#[async_trait]
+impl FromRequestParts<Arc<AppState>> for RequestLanguage {
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AppState>,
+    ) -> Result<Self, Self::Rejection> {
+        let lang = Query::<LangQuery>::try_from_uri(&parts.uri)
+            .ok()
+            .and_then(|q| q.0.lang);
+        let user_id = parts
+            .extensions
+            .get::<Claims>()
+            .and_then(|c| Uuid::parse_str(&c.sub).ok());
+        let accept_language = parts
+            .headers
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|v| v.to_str().ok());
+
+        NegotiateLanguageUseCase::new(
+            state.language_repo.clone(),
+            state.setting_repo.clone(),
+            state.user_profile_repo.clone(),
+        )
+        .execute(lang.as_deref(), user_id, accept_language)
+        .await
+        .map(RequestLanguage)
+        .map_err(error_response)
+    }
+}
+
+impl RequestLanguage {
+    /// Gắn `Content-Language` (và `Vary: Accept-Language`) vào response
+    pub fn apply(&self, mut response: Response) -> Response {
+        let headers = response.headers_mut();
+        if let Ok(value) = HeaderValue::from_str(&self.0.code) {
+            headers.insert(header::CONTENT_LANGUAGE, value);
+        }
+        headers.append(header::VARY, HeaderValue::from_static("accept-language"));
+        response
+    }
+}
+
+/// Ghép bản dịch theo ngôn ngữ của request cho các endpoint đọc nội dung
+pub fn localize_content(state: &AppState) -> LocalizeContentUseCase {
+    LocalizeContentUseCase::new(
+        state.post_translation_repo.clone(),
+        state.category_translation_repo.clone(),
+        state.tag_translation_repo.clone(),
+        state.banner_translation_repo.clone(),
+    )
+}
@@ -1,8 +1,13 @@
-use axum::{Json, http::StatusCode};
+use axum::{
+    Extension, Json,
+    http::{StatusCode, header},
+};
 use chrono::Utc;
 use serde::Serialize;
 use serde_json::Value;
 
+use crate::shared::error::{AppError, ErrorKind};
+
 #[derive(Serialize)]
 pub struct ApiResponse<T> {
     pub success: bool,
@@ -87,15 +92,41 @@ impl<T: Serialize> ApiResponse<T> {
         )
     }
 
+    /// Response 301/302 kèm header `Location`, body giữ format chung để client JSON vẫn đọc được
+    pub fn redirect(
+        status: StatusCode,
+        location: String,
+        data: T,
+    ) -> (StatusCode, [(header::HeaderName, String); 1], Json<Self>) {
+        (
+            status,
+            [(header::LOCATION, location)],
+            Json(Self {
+                success: true,
+                message: "Resource moved".to_string(),
+                data: Some(data),
+                error: None,
+                pagination: None,
+                timestamp: Utc::now().to_rfc3339(),
+                path: None,
+            }),
+        )
+    }
+
+    /// `message` là `AppError` thì mã message + tham số đi kèm response (extension) để
+    /// middleware `localize_errors` dịch theo ngôn ngữ của request
     pub fn error(
         status: StatusCode,
         code: String,
-        message: String,
+        message: impl Into<AppError>,
         details: Option<Value>,
         path: Option<String>,
-    ) -> (StatusCode, Json<ApiResponse<()>>) {
+    ) -> (StatusCode, Extension<AppError>, Json<ApiResponse<()>>) {
+        let error = message.into();
+        let message = error.message().to_string();
         (
             status,
+            Extension(error),
             Json(ApiResponse {
                 success: false,
                 message: message.clone(),
@@ -163,3 +194,71 @@ impl<T: Serialize> IntoResponse for ApiResponse<T> {
         (StatusCode::OK, Json(self)).into_response()
     }
 }
+
+/// HTTP status và `error.code` theo loại lỗi gán ở nơi phát sinh lỗi (không dựa vào message)
+pub fn error_status(error: &AppError) -> (StatusCode, &'static str) {
+    match error.kind() {
+        ErrorKind::Invalid => (StatusCode::BAD_REQUEST, "VALIDATION_ERROR"),
+        ErrorKind::Unauthorized => (StatusCode::UNAUTHORIZED, "UNAUTHORIZED"),
+        ErrorKind::Forbidden => (StatusCode::FORBIDDEN, "FORBIDDEN"),
+        ErrorKind::NotFound => (StatusCode::NOT_FOUND, "NOT_FOUND"),
+        ErrorKind::Conflict => (StatusCode::CONFLICT, "CONFLICT"),
+        ErrorKind::Internal => (StatusCode::INTERNAL_SERVER_ERROR, "INTERNAL_SERVER_ERROR"),
+    }
+}
+
+/// Response lỗi với status và `error.code` theo `error_status`
+pub fn error_response(error: AppError) -> Response {
+    let (status, code) = error_status(&error);
+    ApiResponse::<()>::error(status, code.to_string(), error, None, None).into_response()
+}
+
+/// Thay segment `old_slug` của path (segment cuối trùng, vd `/categories/{slug}/breadcrumbs`)
+/// bằng `slug`, giữ nguyên query string trừ các tham số trong `overrides` (được thay bằng giá trị mới)
+pub fn moved_location(
+    uri: &axum::http::Uri,
+    old_slug: &str,
+    slug: &str,
+    overrides: &[(&str, &str)],
+) -> String {
+    let mut segments: Vec<&str> = uri.path().split('/').collect();
+    let index = segments
+        .iter()
+        .rposition(|segment| *segment == old_slug)
+        .unwrap_or(segments.len() - 1);
+    segments[index] = slug;
+    let path = segments.join("/");
+    let mut params: Vec<String> = uri
+        .query()
+        .unwrap_or("")
+        .split('&')
+        .filter(|pair| {
+            let key = pair.split_once('=').map(|(key, _)| key).unwrap_or(pair);
+            !pair.is_empty() && !overrides.iter().any(|(name, _)| *name == key)
+        })
+        .map(str::to_string)
+        .collect();
+    params.extend(
+        overrides
+            .iter()
+            .map(|(name, value)| format!("{name}={}", encode_query_value(value))),
+    );
+    if params.is_empty() {
+        path
+    } else {
+        format!("{path}?{}", params.join("&"))
+    }
+}
+
+/// Percent-encode giá trị query (giữ nguyên ký tự unreserved theo RFC 3986)
+fn encode_query_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
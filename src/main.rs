@@ -1,10 +1,30 @@
 use crate::app::state::AppState;
 use crate::infrastructure::persistence::postgres::{
-    audit_log_repo::PgAuditLogRepository, banner_repo::PgBannerRepository,
-    category_repo::PgCategoryRepository, language_repo::PgLanguageRepository,
-    media_repo::PgMediaRepository, permission_repo::PgPermissionRepository,
-    post_repo::PgPostRepository, role_repo::PgRoleRepository, setting_repo::PgSettingRepository,
-    tag_repo::PgTagRepository, user_profile_repo::PgUserProfileRepository,
+    audit_log_repo::PgAuditLogRepository,
+    banner_repo::PgBannerRepository,
+    banner_translation_repo::PgBannerTranslationRepository,
+    category_repo::PgCategoryRepository,
+    category_translation_repo::PgCategoryTranslationRepository,
+    language_repo::PgLanguageRepository,
+    media_repo::PgMediaRepository,
+    permission_repo::PgPermissionRepository,
+    post_media_repo::PgPostMediaRepository,
+    post_repo::PgPostRepository,
+    post_review_repo::PgPostReviewRepository,
+    post_revision_repo::PgPostRevisionRepository,
+    post_translation_repo::PgPostTranslationRepository,
+    redirect_repo::PgRedirectRepository,
+    role_repo::PgRoleRepository,
+    seo_metadata_repo::PgSeoMetadataRepository,
+    setting_repo::PgSettingRepository,
+    sitemap_repo::PgSitemapRepository,
+    slug_redirect_repo::PgSlugRedirectRepository,
+    slug_repo::PgSlugRepository,
+    tag_repo::PgTagRepository,
+    tag_translation_repo::PgTagTranslationRepository,
+    translation_key_repo::{MessageBundleCache, PgTranslationKeyRepository},
+    translation_status_repo::PgTranslationStatusRepository,
+    user_profile_repo::PgUserProfileRepository,
     user_repo::PgUserRepository,
 };
 use crate::interface::http::handlers::audit_log_handler::list_audit_logs;
@@ -14,10 +34,21 @@ use crate::interface::http::handlers::banner_handler::{
     get_banner_by_key, list_active_banners, update_banner, update_banner_item,
 };
 use crate::interface::http::handlers::category_handler::{
-    bulk_delete_categories, create_category, delete_category, get_category, list_categories,
+    bulk_delete_categories, create_category, delete_category, get_category,
+    get_category_breadcrumbs, get_category_descendants, get_category_tree, list_categories,
     update_category,
 };
-use crate::interface::http::handlers::language_handler::{get_default_language, list_languages};
+use crate::interface::http::handlers::feed_handler::{
+    get_category_feed, get_site_feed, get_tag_feed,
+};
+use crate::interface::http::handlers::i18n_handler::{
+    create_translation_key, delete_translation_key, delete_translation_value, get_message_bundle,
+    get_translation_key, list_translation_keys, update_translation_key, upsert_translation_value,
+};
+use crate::interface::http::handlers::language_handler::{
+    create_language, delete_language, get_default_language, list_languages, set_default_language,
+    update_language,
+};
 use crate::interface::http::handlers::media_handler::{
     bulk_delete_media, delete_media, get_media, get_user_media, update_media, upload_media,
 };
@@ -25,26 +56,48 @@ use crate::interface::http::handlers::permission_handler::{
     create_permission, delete_permission, get_permission, get_permissions, update_permission,
 };
 use crate::interface::http::handlers::post_handler::{
-    bulk_delete_posts, create_post, delete_post, get_post_by_slug, list_posts, update_post,
+    append_post_media, assign_post_reviewers, attach_post_tags, bulk_delete_posts, create_post,
+    delete_post, detach_post_media, detach_post_tag, diff_post_revisions, get_post_by_slug,
+    get_post_media, get_post_review, get_post_revision, get_post_tags, list_awaiting_review_posts,
+    list_manage_posts, list_post_revisions, list_posts, list_posts_by_tag, replace_post_media,
+    replace_post_tags, restore_post_revision, review_post, search_posts, update_post,
+};
+use crate::interface::http::handlers::redirect_handler::{
+    create_redirect, delete_redirect, get_redirect, list_redirects, resolve_redirect,
+    update_redirect,
 };
 use crate::interface::http::handlers::role_handler::{
     assign_permission, create_role, delete_role, get_role, get_role_permissions, get_roles,
     revoke_permission, update_role,
 };
+use crate::interface::http::handlers::seo_handler::{
+    get_category_seo, get_post_seo, update_category_seo, update_post_seo,
+};
 use crate::interface::http::handlers::settings_handler::{
     get_setting as get_setting_handler, list_settings, update_setting as update_setting_handler,
 };
+use crate::interface::http::handlers::sitemap_handler::{get_sitemap_index, get_sitemap_page};
 use crate::interface::http::handlers::tag_handler::{
     bulk_delete_tags, create_tag, delete_tag, get_tag, list_tags, update_tag,
 };
+use crate::interface::http::handlers::translation_handler::{
+    delete_banner_translation, delete_category_translation, delete_post_translation,
+    delete_tag_translation, export_translations, get_banner_translation, get_category_translation,
+    get_post_translation, get_tag_translation, get_translation_coverage, import_translations,
+    list_banner_translations, list_category_translations, list_post_translations,
+    list_tag_translations, list_translation_queue, upsert_banner_translation,
+    upsert_category_translation, upsert_post_translation, upsert_tag_translation,
+};
 use crate::interface::http::handlers::user_handler::{
     assign_role, create_user, delete_user, get_user, get_users, revoke_role, update_user,
 };
 use crate::interface::http::handlers::user_profile_handler::{get_me_profile, update_me_profile};
 use crate::interface::http::middleware::auth::auth_middleware;
+use crate::interface::http::middleware::localize_errors::localize_errors;
+use crate::shared::sitemap_cache::SitemapCache;
 use axum::{
     Router, middleware,
-    routing::{get, patch, post},
+    routing::{delete, get, patch, post, put},
 };
 use std::sync::Arc;
 
@@ -58,8 +111,11 @@ mod shared;
 #[tokio::main]
 async fn main() {
     dotenvy::dotenv().ok();
+    // Log lỗi hệ thống (5xx) mà response không trả về cho client
+    tracing_subscriber::fmt::init();
 
     let db_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    crate::shared::utils::query::init_cursor_secret();
     let prefix_api = "/api/v1";
 
     let pool = sqlx::postgres::PgPoolOptions::new()
@@ -80,30 +136,103 @@ async fn main() {
     let media_repo = Arc::new(PgMediaRepository::new(pool.clone()))
         as Arc<dyn crate::domain::repositories::media_repository::MediaRepository>;
 
-    let setting_repo = Arc::new(PgSettingRepository::new(pool.clone()))
-        as Arc<dyn crate::domain::repositories::setting_repository::SettingRepository>;
-
     let banner_repo = Arc::new(PgBannerRepository::new(pool.clone()))
         as Arc<dyn crate::domain::repositories::banner_repository::BannerRepository>;
 
     let user_profile_repo = Arc::new(PgUserProfileRepository::new(pool.clone()))
         as Arc<dyn crate::domain::repositories::user_profile_repository::UserProfileRepository>;
 
-    let category_repo = Arc::new(PgCategoryRepository::new(pool.clone()))
+    let sitemap_cache = Arc::new(SitemapCache::new());
+
+    let setting_repo = Arc::new(PgSettingRepository::new(
+        pool.clone(),
+        sitemap_cache.clone(),
+    ))
+        as Arc<dyn crate::domain::repositories::setting_repository::SettingRepository>;
+
+    let category_repo = Arc::new(PgCategoryRepository::new(
+        pool.clone(),
+        sitemap_cache.clone(),
+    ))
         as Arc<dyn crate::domain::repositories::category_repository::CategoryRepository>;
 
-    let tag_repo = Arc::new(PgTagRepository::new(pool.clone()))
+    let tag_repo = Arc::new(PgTagRepository::new(pool.clone(), sitemap_cache.clone()))
         as Arc<dyn crate::domain::repositories::tag_repository::TagRepository>;
 
-    let post_repo = Arc::new(PgPostRepository::new(pool.clone()))
+    let post_repo = Arc::new(PgPostRepository::new(pool.clone(), sitemap_cache.clone()))
         as Arc<dyn crate::domain::repositories::post_repository::PostRepository>;
 
-    let language_repo = Arc::new(PgLanguageRepository::new(pool.clone()))
+    let post_revision_repo = Arc::new(PgPostRevisionRepository::new(pool.clone()))
+        as Arc<dyn crate::domain::repositories::post_revision_repository::PostRevisionRepository>;
+
+    let post_review_repo = Arc::new(PgPostReviewRepository::new(pool.clone()))
+        as Arc<dyn crate::domain::repositories::post_review_repository::PostReviewRepository>;
+
+    let post_media_repo = Arc::new(PgPostMediaRepository::new(pool.clone()))
+        as Arc<dyn crate::domain::repositories::post_media_repository::PostMediaRepository>;
+
+    let message_bundles = Arc::new(MessageBundleCache::new());
+    let language_repo = Arc::new(PgLanguageRepository::new(
+        pool.clone(),
+        sitemap_cache.clone(),
+        message_bundles.clone(),
+    ))
         as Arc<dyn crate::domain::repositories::language_repository::LanguageRepository>;
 
     let audit_log_repo = Arc::new(PgAuditLogRepository::new(pool.clone()))
         as Arc<dyn crate::domain::repositories::audit_log_repository::AuditLogRepository>;
 
+    let slug_repo = Arc::new(PgSlugRepository::new(pool.clone()))
+        as Arc<dyn crate::domain::repositories::slug_repository::SlugRepository>;
+
+    let slug_redirect_repo = Arc::new(PgSlugRedirectRepository::new(pool.clone()))
+        as Arc<dyn crate::domain::repositories::slug_redirect_repository::SlugRedirectRepository>;
+
+    let redirect_repo = Arc::new(PgRedirectRepository::new(pool.clone()))
+        as Arc<dyn crate::domain::repositories::redirect_repository::RedirectRepository>;
+
+    let seo_metadata_repo = Arc::new(PgSeoMetadataRepository::new(pool.clone()))
+        as Arc<dyn crate::domain::repositories::seo_metadata_repository::SeoMetadataRepository>;
+
+    let sitemap_repo = Arc::new(PgSitemapRepository::new(pool.clone()))
+        as Arc<dyn crate::domain::repositories::sitemap_repository::SitemapRepository>;
+
+    let post_translation_repo = Arc::new(PgPostTranslationRepository::new(
+        pool.clone(),
+        sitemap_cache.clone(),
+    ))
+        as Arc<
+            dyn crate::domain::repositories::post_translation_repository::PostTranslationRepository,
+        >;
+
+    let category_translation_repo = Arc::new(PgCategoryTranslationRepository::new(
+        pool.clone(),
+        sitemap_cache.clone(),
+    ))
+        as Arc<dyn crate::domain::repositories::category_translation_repository::CategoryTranslationRepository>;
+
+    let tag_translation_repo = Arc::new(PgTagTranslationRepository::new(
+        pool.clone(),
+        sitemap_cache.clone(),
+    ))
+        as Arc<
+            dyn crate::domain::repositories::tag_translation_repository::TagTranslationRepository,
+        >;
+
+    let banner_translation_repo = Arc::new(PgBannerTranslationRepository::new(pool.clone()))
+        as Arc<dyn crate::domain::repositories::banner_translation_repository::BannerTranslationRepository>;
+
+    let translation_status_repo = Arc::new(PgTranslationStatusRepository::new(pool.clone()))
+        as Arc<dyn crate::domain::repositories::translation_status_repository::TranslationStatusRepository>;
+
+    let translation_key_repo = Arc::new(PgTranslationKeyRepository::new(
+        pool.clone(),
+        message_bundles,
+    ))
+        as Arc<
+            dyn crate::domain::repositories::translation_key_repository::TranslationKeyRepository,
+        >;
+
     let state = Arc::new(AppState {
         user_repo,
         role_repo,
@@ -115,10 +244,27 @@ async fn main() {
         category_repo,
         tag_repo,
         post_repo,
+        post_revision_repo,
+        post_review_repo,
+        post_media_repo,
         language_repo,
         audit_log_repo,
+        slug_repo,
+        slug_redirect_repo,
+        redirect_repo,
+        seo_metadata_repo,
+        sitemap_repo,
+        post_translation_repo,
+        category_translation_repo,
+        tag_translation_repo,
+        banner_translation_repo,
+        translation_status_repo,
+        translation_key_repo,
+        sitemap_cache,
     });
 
+    crate::app::post_scheduler::spawn(state.clone());
+
     let app = Router::new()
         .route("/", get(|| async { "Rust API is running!" }))
         .nest(
@@ -171,6 +317,13 @@ async fn main() {
                 .route("/banners/key/:key", get(get_banner_by_key))
                 .route("/banners/:id", patch(update_banner).delete(delete_banner))
                 .route("/banners/bulk-delete", post(bulk_delete_banners))
+                .route("/banners/:id/translations", get(list_banner_translations))
+                .route(
+                    "/banners/:id/translations/:lang",
+                    get(get_banner_translation)
+                        .put(upsert_banner_translation)
+                        .delete(delete_banner_translation),
+                )
                 // Banner Items
                 .route("/banner-items", post(create_banner_item))
                 .route(
@@ -179,6 +332,7 @@ async fn main() {
                 )
                 // Categories & Tags & Posts (blog / content)
                 .route("/categories", get(list_categories).post(create_category))
+                .route("/categories/tree", get(get_category_tree))
                 .route(
                     "/categories/:slug",
                     get(get_category)
@@ -186,18 +340,118 @@ async fn main() {
                         .delete(delete_category),
                 )
                 .route("/categories/bulk-delete", post(bulk_delete_categories))
+                .route(
+                    "/categories/:slug/breadcrumbs",
+                    get(get_category_breadcrumbs),
+                )
+                .route(
+                    "/categories/:slug/descendants",
+                    get(get_category_descendants),
+                )
+                .route(
+                    "/categories/:slug/seo",
+                    get(get_category_seo).put(update_category_seo),
+                )
+                .route(
+                    "/categories/:slug/translations",
+                    get(list_category_translations),
+                )
+                .route(
+                    "/categories/:slug/translations/:lang",
+                    get(get_category_translation)
+                        .put(upsert_category_translation)
+                        .delete(delete_category_translation),
+                )
                 .route("/tags", get(list_tags).post(create_tag))
                 .route(
                     "/tags/:slug",
                     get(get_tag).patch(update_tag).delete(delete_tag),
                 )
                 .route("/tags/bulk-delete", post(bulk_delete_tags))
+                .route("/tags/:slug/posts", get(list_posts_by_tag))
+                .route("/tags/:slug/translations", get(list_tag_translations))
+                .route(
+                    "/tags/:slug/translations/:lang",
+                    get(get_tag_translation)
+                        .put(upsert_tag_translation)
+                        .delete(delete_tag_translation),
+                )
+                // Tiến độ dịch (khu vực biên tập nội dung)
+                .route(
+                    "/translations/coverage",
+                    get(get_translation_coverage).route_layer(middleware::from_fn_with_state(
+                        state.clone(),
+                        crate::interface::http::middleware::permission::require_editor_role,
+                    )),
+                )
+                .route(
+                    "/translations/queue",
+                    get(list_translation_queue).route_layer(middleware::from_fn_with_state(
+                        state.clone(),
+                        crate::interface::http::middleware::permission::require_editor_role,
+                    )),
+                )
+                .route(
+                    "/translations/export",
+                    get(export_translations).route_layer(middleware::from_fn_with_state(
+                        state.clone(),
+                        crate::interface::http::middleware::permission::require_editor_role,
+                    )),
+                )
+                .route(
+                    "/translations/import",
+                    post(import_translations).route_layer(middleware::from_fn_with_state(
+                        state.clone(),
+                        crate::interface::http::middleware::permission::require_editor_role,
+                    )),
+                )
                 .route("/posts", get(list_posts).post(create_post))
+                .route("/posts/search", get(search_posts))
+                .route(
+                    "/posts/manage",
+                    get(list_manage_posts).route_layer(middleware::from_fn_with_state(
+                        state.clone(),
+                        crate::interface::http::middleware::permission::require_editor_role,
+                    )),
+                )
+                .route("/posts/awaiting-review", get(list_awaiting_review_posts))
                 .route(
                     "/posts/:slug",
                     get(get_post_by_slug).patch(update_post).delete(delete_post),
                 )
                 .route("/posts/bulk-delete", post(bulk_delete_posts))
+                .route(
+                    "/posts/:slug/tags",
+                    get(get_post_tags)
+                        .put(replace_post_tags)
+                        .post(attach_post_tags),
+                )
+                .route("/posts/:slug/tags/:tag_slug", delete(detach_post_tag))
+                .route(
+                    "/posts/:slug/media",
+                    get(get_post_media)
+                        .put(replace_post_media)
+                        .post(append_post_media),
+                )
+                .route("/posts/:slug/media/:media_id", delete(detach_post_media))
+                .route("/posts/:slug/seo", get(get_post_seo).put(update_post_seo))
+                .route("/posts/:slug/translations", get(list_post_translations))
+                .route(
+                    "/posts/:slug/translations/:lang",
+                    get(get_post_translation)
+                        .put(upsert_post_translation)
+                        .delete(delete_post_translation),
+                )
+                .route("/posts/:slug/revisions", get(list_post_revisions))
+                .route("/posts/:slug/revisions/diff", get(diff_post_revisions))
+                .route("/posts/:slug/revisions/:number", get(get_post_revision))
+                .route(
+                    "/posts/:slug/revisions/:number/restore",
+                    post(restore_post_revision),
+                )
+                .route("/posts/:slug/review", get(get_post_review))
+                .route("/posts/:slug/reviewers", put(assign_post_reviewers))
+                .route("/posts/:slug/reviews", post(review_post))
                 // Languages
                 .route("/languages", get(list_languages))
                 .route("/languages/default", get(get_default_language))
@@ -207,6 +461,34 @@ async fn main() {
                     "/admin",
                     Router::new()
                         .route("/dashboard", get(|| async { "Admin Dashboard" }))
+                        .route("/redirects", get(list_redirects).post(create_redirect))
+                        .route(
+                            "/redirects/:id",
+                            get(get_redirect)
+                                .patch(update_redirect)
+                                .delete(delete_redirect),
+                        )
+                        .route("/languages", post(create_language))
+                        .route("/languages/default", put(set_default_language))
+                        .route(
+                            "/languages/:code",
+                            patch(update_language).delete(delete_language),
+                        )
+                        // Chuỗi giao diện (i18n message key)
+                        .route(
+                            "/i18n/keys",
+                            get(list_translation_keys).post(create_translation_key),
+                        )
+                        .route(
+                            "/i18n/keys/:id",
+                            get(get_translation_key)
+                                .patch(update_translation_key)
+                                .delete(delete_translation_key),
+                        )
+                        .route(
+                            "/i18n/keys/:id/values/:lang",
+                            put(upsert_translation_value).delete(delete_translation_value),
+                        )
                         .route_layer(middleware::from_fn_with_state(
                             state.clone(),
                             crate::interface::http::middleware::permission::require_admin_role,
@@ -216,6 +498,29 @@ async fn main() {
         )
         .route(&format!("{}/auth/login", prefix_api), post(login))
         .route(&format!("{}/auth/register", prefix_api), post(register))
+        .route(
+            &format!("{}/redirects/resolve", prefix_api),
+            get(resolve_redirect),
+        )
+        // Bundle chuỗi giao diện công khai cho frontend
+        .route(
+            &format!("{}/i18n/:lang/:namespace", prefix_api),
+            get(get_message_bundle),
+        )
+        // Feed công khai (RSS 2.0 / Atom / JSON Feed)
+        .route("/feed.xml", get(get_site_feed))
+        .route("/feed.atom", get(get_site_feed))
+        .route("/feed.json", get(get_site_feed))
+        .route("/categories/:slug/:feed", get(get_category_feed))
+        .route("/tags/:slug/:feed", get(get_tag_feed))
+        // Sitemap công khai
+        .route("/sitemap.xml", get(get_sitemap_index))
+        .route("/sitemaps/:file", get(get_sitemap_page))
+        // Lỗi trả về theo ngôn ngữ của request, kèm mã lỗi ổn định
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            localize_errors,
+        ))
         .with_state(state);
 
     let port = std::env::var("PORT").unwrap_or("4000".to_string());
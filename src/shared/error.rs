@@ -1 +1,101 @@
+use std::fmt;
 
+use serde_json::{Map, Value};
+
+/// Loại lỗi, quyết định HTTP status và `error.code` của response
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    Invalid,
+    Unauthorized,
+    Forbidden,
+    NotFound,
+    Conflict,
+    Internal,
+}
+
+/// Lỗi trả về từ use case: loại lỗi, message tiếng Anh kèm mã message ổn định và tham số,
+/// gán ngay tại nơi phát sinh lỗi (handler chọn HTTP status theo loại, middleware
+/// `localize_errors` dịch message theo ngôn ngữ request).
+///
+/// Lỗi chuyển từ `String` (lỗi DB, lỗi hệ thống) là `Internal` và không có mã
+#[derive(Debug, Clone)]
+pub struct AppError {
+    kind: ErrorKind,
+    message: String,
+    key: Option<&'static str>,
+    params: Map<String, Value>,
+}
+
+impl AppError {
+    /// `key`: mã lỗi trong namespace `errors` (`POST_NOT_FOUND`...)
+    pub fn new(kind: ErrorKind, key: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            key: Some(key),
+            params: Map::new(),
+        }
+    }
+
+    /// Thêm tham số nội suy (`{name}` trong message theo ngôn ngữ)
+    pub fn with(mut self, name: &str, value: impl Into<Value>) -> Self {
+        self.params.insert(name.to_string(), value.into());
+        self
+    }
+
+    /// `Invalid {field}: {reason}`
+    pub fn invalid(field: &str, reason: impl Into<String>) -> Self {
+        let reason = reason.into();
+        Self::new(
+            ErrorKind::Invalid,
+            "VALIDATION_ERROR",
+            format!("Invalid {field}: {reason}"),
+        )
+        .with("field", field)
+        .with("reason", reason)
+    }
+
+    /// `{resource} not found` với mã riêng của tài nguyên (`POST_NOT_FOUND`...)
+    pub fn not_found(key: &'static str, resource: &str) -> Self {
+        Self::new(ErrorKind::NotFound, key, format!("{resource} not found"))
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn key(&self) -> Option<&'static str> {
+        self.key
+    }
+
+    pub fn params(&self) -> &Map<String, Value> {
+        &self.params
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl From<String> for AppError {
+    fn from(message: String) -> Self {
+        Self {
+            kind: ErrorKind::Internal,
+            message,
+            key: None,
+            params: Map::new(),
+        }
+    }
+}
+
+impl From<&str> for AppError {
+    fn from(message: &str) -> Self {
+        message.to_string().into()
+    }
+}
@@ -1,2 +1,4 @@
 pub mod error;
+pub mod read_cache;
+pub mod sitemap_cache;
 pub mod utils;
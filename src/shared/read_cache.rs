@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Cache đọc trong bộ nhớ cho dữ liệu ít thay đổi nhưng được đọc ở mọi request
+/// (danh sách ngôn ngữ, setting). Repo sở hữu cache và xoá cache mỗi khi ghi.
+///
+/// Cùng cơ chế `generation` như `SitemapCache`: giá trị đọc trước lúc invalidate
+/// sẽ không được ghi vào cache.
+#[derive(Debug)]
+pub struct ReadCache<K, V> {
+    generation: AtomicU64,
+    entries: RwLock<HashMap<K, V>>,
+}
+
+impl<K, V> Default for ReadCache<K, V> {
+    fn default() -> Self {
+        Self {
+            generation: AtomicU64::new(0),
+            entries: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> ReadCache<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Giá trị trong cache, chưa có thì gọi `load` rồi lưu lại (lỗi thì không lưu)
+    pub async fn get_or_load<E, Fut>(&self, key: K, load: impl FnOnce() -> Fut) -> Result<V, E>
+    where
+        Fut: Future<Output = Result<V, E>>,
+    {
+        if let Some(value) = self.entries.read().ok().and_then(|e| e.get(&key).cloned()) {
+            return Ok(value);
+        }
+        let generation = self.generation.load(Ordering::Acquire);
+        let value = load().await?;
+        if let Ok(mut entries) = self.entries.write()
+            && self.generation.load(Ordering::Acquire) == generation
+        {
+            entries.insert(key, value.clone());
+        }
+        Ok(value)
+    }
+
+    pub fn invalidate(&self) {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        if let Ok(mut entries) = self.entries.write() {
+            entries.clear();
+        }
+    }
+
+    /// Invalidate khi thao tác ghi thành công, trả lại nguyên kết quả
+    pub fn invalidate_on_ok<T, E>(&self, result: Result<T, E>) -> Result<T, E> {
+        if result.is_ok() {
+            self.invalidate();
+        }
+        result
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
+
+use chrono::{NaiveDateTime, Utc};
+
+/// Cache XML sitemap trong bộ nhớ, bị xoá mỗi khi repo post/category/tag/setting ghi dữ liệu.
+/// Bản dựng còn hết hạn tại mốc `published_at`/`unpublish_at` kế tiếp (bài tự vào/ra sitemap
+/// theo thời gian mà không có thao tác ghi nào).
+///
+/// Mỗi lần invalidate tăng `generation`; bản dựng bắt đầu trước lúc invalidate
+/// sẽ không được ghi vào cache (tránh lưu lại dữ liệu cũ).
+#[derive(Debug, Default)]
+pub struct SitemapCache {
+    generation: AtomicU64,
+    entries: RwLock<HashMap<String, CachedSitemap>>,
+}
+
+#[derive(Debug)]
+struct CachedSitemap {
+    body: Arc<String>,
+    expires_at: Option<Instant>,
+}
+
+impl SitemapCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Thế hệ hiện tại, đọc trước khi dựng sitemap rồi truyền lại cho `put`
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    pub fn get(&self, key: &str) -> Option<Arc<String>> {
+        let entries = self.entries.read().ok()?;
+        let cached = entries.get(key)?;
+        if cached.expires_at.is_some_and(|at| at <= Instant::now()) {
+            return None;
+        }
+        Some(cached.body.clone())
+    }
+
+    /// Lưu bản dựng nếu cache chưa bị invalidate kể từ `generation`.
+    /// `expires_at` (UTC): mốc lịch kế tiếp làm thay đổi nội dung sitemap
+    pub fn put(
+        &self,
+        key: &str,
+        generation: u64,
+        body: String,
+        expires_at: Option<NaiveDateTime>,
+    ) -> Arc<String> {
+        let body = Arc::new(body);
+        let expires_at = expires_at
+            .map(|at| Instant::now() + (at - Utc::now().naive_utc()).to_std().unwrap_or_default());
+        if let Ok(mut entries) = self.entries.write()
+            && self.generation() == generation
+        {
+            entries.insert(
+                key.to_string(),
+                CachedSitemap {
+                    body: body.clone(),
+                    expires_at,
+                },
+            );
+        }
+        body
+    }
+
+    pub fn invalidate(&self) {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        if let Ok(mut entries) = self.entries.write() {
+            entries.clear();
+        }
+    }
+
+    /// Invalidate khi thao tác ghi thành công, trả lại nguyên kết quả
+    pub fn invalidate_on_ok<T, E>(&self, result: Result<T, E>) -> Result<T, E> {
+        if result.is_ok() {
+            self.invalidate();
+        }
+        result
+    }
+}
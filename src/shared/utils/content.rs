@@ -0,0 +1,322 @@
+use std::collections::{HashMap, HashSet};
+
+use pulldown_cmark::{Options, Parser, html};
+use serde::{Deserialize, Serialize};
+
+use crate::shared::utils::slug::slugify;
+
+/// Số từ đọc được mỗi phút, dùng để ước tính thời gian đọc
+pub const WORDS_PER_MINUTE: usize = 200;
+
+/// Độ dài tối đa (ký tự) của excerpt tự sinh
+pub const AUTO_EXCERPT_LENGTH: usize = 160;
+
+/// Các thẻ HTML được giữ lại sau sanitize, còn lại bị loại bỏ (giữ phần text bên trong)
+const ALLOWED_TAGS: &[&str] = &[
+    "a",
+    "b",
+    "blockquote",
+    "br",
+    "code",
+    "del",
+    "em",
+    "figcaption",
+    "figure",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "hr",
+    "i",
+    "img",
+    "li",
+    "ol",
+    "p",
+    "pre",
+    "s",
+    "strong",
+    "sub",
+    "sup",
+    "table",
+    "tbody",
+    "td",
+    "th",
+    "thead",
+    "tr",
+    "u",
+    "ul",
+];
+
+/// Thẻ block: khi trích text thì coi như khoảng trắng để không dính chữ
+const BLOCK_TAGS: &[&str] = &[
+    "blockquote",
+    "br",
+    "figcaption",
+    "figure",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "hr",
+    "img",
+    "li",
+    "ol",
+    "p",
+    "pre",
+    "table",
+    "tbody",
+    "td",
+    "th",
+    "thead",
+    "tr",
+    "ul",
+];
+
+/// 1 mục trong mục lục, `anchor` là `id` gắn vào thẻ heading
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TocEntry {
+    pub level: u8,
+    pub text: String,
+    pub anchor: String,
+}
+
+/// Markdown (CommonMark + bảng, gạch ngang) → HTML chưa sanitize
+pub fn markdown_to_html(markdown: &str) -> String {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+
+    let mut out = String::with_capacity(markdown.len() * 3 / 2);
+    html::push_html(&mut out, Parser::new_ext(markdown, options));
+    out
+}
+
+/// Text thuần → HTML: escape, đoạn tách bởi dòng trống, xuống dòng thành `<br>`
+pub fn plain_to_html(text: &str) -> String {
+    let normalized = text.replace("\r\n", "\n");
+    let mut out = String::with_capacity(normalized.len());
+    let mut paragraph: Vec<&str> = Vec::new();
+
+    fn flush(paragraph: &mut Vec<&str>, out: &mut String) {
+        if paragraph.is_empty() {
+            return;
+        }
+        let lines: Vec<String> = paragraph.iter().map(|l| escape_html(l)).collect();
+        out.push_str("<p>");
+        out.push_str(&lines.join("<br>\n"));
+        out.push_str("</p>\n");
+        paragraph.clear();
+    }
+
+    for line in normalized.lines() {
+        if line.trim().is_empty() {
+            flush(&mut paragraph, &mut out);
+        } else {
+            paragraph.push(line);
+        }
+    }
+    flush(&mut paragraph, &mut out);
+    out
+}
+
+/// Sanitize theo allow-list `ALLOWED_TAGS`: bỏ script/style/event handler,
+/// chỉ giữ URL http(s)/mailto, link thêm `rel="noopener noreferrer nofollow"`
+pub fn sanitize_html(raw: &str) -> String {
+    let tags: HashSet<&str> = ALLOWED_TAGS.iter().copied().collect();
+    let tag_attributes: HashMap<&str, HashSet<&str>> = HashMap::from([
+        ("a", HashSet::from(["href", "title"])),
+        (
+            "img",
+            HashSet::from(["src", "alt", "title", "width", "height"]),
+        ),
+        // `language-xxx` cho syntax highlight phía frontend
+        ("code", HashSet::from(["class"])),
+        ("td", HashSet::from(["colspan", "rowspan"])),
+        ("th", HashSet::from(["colspan", "rowspan"])),
+        ("ol", HashSet::from(["start"])),
+    ]);
+
+    ammonia::Builder::default()
+        .tags(tags)
+        .tag_attributes(tag_attributes)
+        .generic_attributes(HashSet::new())
+        .url_schemes(HashSet::from(["http", "https", "mailto"]))
+        .link_rel(Some("noopener noreferrer nofollow"))
+        .clean(raw)
+        .to_string()
+}
+
+/// Gắn `id` cho các heading của HTML đã sanitize và trả về mục lục.
+/// Anchor sinh từ text heading (bỏ dấu), trùng thì thêm hậu tố `-2`, `-3`, ...
+pub fn add_heading_anchors(sanitized: &str) -> (String, Vec<TocEntry>) {
+    let mut out = String::with_capacity(sanitized.len() + 64);
+    let mut toc = Vec::new();
+    let mut used: HashSet<String> = HashSet::new();
+    let mut rest = sanitized;
+
+    while let Some((start, level)) = find_heading_open(rest) {
+        let close = format!("</h{level}>");
+        let inner_start = start + 4;
+        let Some(inner_len) = rest[inner_start..].find(&close) else {
+            break;
+        };
+        let inner = &rest[inner_start..inner_start + inner_len];
+        let text = html_to_text(inner);
+
+        let base = match slugify(&text) {
+            s if s.is_empty() => "section".to_string(),
+            s => s,
+        };
+        let mut anchor = base.clone();
+        let mut n = 2;
+        while used.contains(&anchor) {
+            anchor = format!("{base}-{n}");
+            n += 1;
+        }
+        used.insert(anchor.clone());
+
+        out.push_str(&rest[..start]);
+        out.push_str(&format!("<h{level} id=\"{anchor}\">{inner}{close}"));
+        toc.push(TocEntry {
+            level,
+            text,
+            anchor,
+        });
+        rest = &rest[inner_start + inner_len + close.len()..];
+    }
+    out.push_str(rest);
+    (out, toc)
+}
+
+/// Vị trí thẻ mở `<hN>` (không thuộc tính, đúng format output của sanitizer)
+fn find_heading_open(html: &str) -> Option<(usize, u8)> {
+    let bytes = html.as_bytes();
+    let mut from = 0;
+    while let Some(pos) = html[from..].find("<h") {
+        let at = from + pos;
+        if let (Some(d), Some(b'>')) = (bytes.get(at + 2), bytes.get(at + 3))
+            && (b'1'..=b'6').contains(d)
+        {
+            return Some((at, d - b'0'));
+        }
+        from = at + 2;
+    }
+    None
+}
+
+/// Trích text từ HTML: bỏ thẻ (thẻ block thành khoảng trắng), decode entity, gộp khoảng trắng
+pub fn html_to_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(open) = rest.find('<') {
+        text.push_str(&rest[..open]);
+        let Some(close) = rest[open..].find('>') else {
+            rest = "";
+            break;
+        };
+        let tag = &rest[open + 1..open + close];
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        if BLOCK_TAGS.contains(&name.as_str()) {
+            text.push(' ');
+        }
+        rest = &rest[open + close + 1..];
+    }
+    text.push_str(rest);
+
+    decode_entities(&text)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn word_count(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+/// Số phút đọc (làm tròn lên), 0 khi không có nội dung
+pub fn reading_time_minutes(words: usize) -> usize {
+    words.div_ceil(WORDS_PER_MINUTE)
+}
+
+/// Cắt text tối đa `max_chars` ký tự tại ranh giới từ, thêm `…` nếu bị cắt
+pub fn derive_excerpt(text: &str, max_chars: usize) -> String {
+    let text = text.trim();
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(max_chars).collect();
+    let cut = match cut.rfind(char::is_whitespace) {
+        Some(pos) if pos > 0 => &cut[..pos],
+        _ => cut.as_str(),
+    };
+    let cut = cut.trim_end_matches(|c: char| c.is_whitespace() || ",.;:-–".contains(c));
+    format!("{cut}…")
+}
+
+pub fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            // Ký tự không hợp lệ trong XML 1.0 (feed/sitemap/XLIFF dùng chung hàm này)
+            '\t' | '\n' | '\r' => out.push(c),
+            c if c < '\u{20}' || c == '\u{FFFE}' || c == '\u{FFFF}' => {}
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        let tail = &rest[amp..];
+        let decoded = tail.find(';').filter(|end| *end <= 10).and_then(|end| {
+            let entity = &tail[1..end];
+            let c = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|n| n.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            c.map(|c| (c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &tail[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &tail[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
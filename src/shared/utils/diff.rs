@@ -0,0 +1,34 @@
+use serde::Serialize;
+use similar::{ChangeTag, TextDiff};
+
+/// 1 dòng trong diff theo dòng (line-level)
+#[derive(Debug, Clone, Serialize)]
+pub struct DiffLine {
+    /// `equal` | `insert` | `delete`
+    pub op: &'static str,
+    /// Số dòng (bắt đầu từ 1) ở bản cũ, `None` với dòng thêm mới
+    pub old_line: Option<usize>,
+    /// Số dòng (bắt đầu từ 1) ở bản mới, `None` với dòng bị xoá
+    pub new_line: Option<usize>,
+    pub text: String,
+}
+
+/// Diff 2 chuỗi theo dòng (bỏ qua khác biệt ký tự xuống dòng cuối chuỗi)
+pub fn line_diff(old: &str, new: &str) -> Vec<DiffLine> {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+
+    TextDiff::from_slices(&old_lines, &new_lines)
+        .iter_all_changes()
+        .map(|change| DiffLine {
+            op: match change.tag() {
+                ChangeTag::Equal => "equal",
+                ChangeTag::Insert => "insert",
+                ChangeTag::Delete => "delete",
+            },
+            old_line: change.old_index().map(|i| i + 1),
+            new_line: change.new_index().map(|i| i + 1),
+            text: change.value().to_string(),
+        })
+        .collect()
+}
@@ -0,0 +1,644 @@
+use serde_json::{Map, Value};
+
+/// Loại placeholder trong ICU MessageFormat
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IcuArgumentKind {
+    /// `{name}`
+    Simple,
+    /// `{n, number}`, `{d, date, short}`, `{t, time}`...
+    Formatted,
+    /// `{count, plural, one {..} other {..}}`
+    Plural,
+    /// `{pos, selectordinal, one {#st} other {#th}}`
+    SelectOrdinal,
+    /// `{gender, select, male {..} other {..}}`
+    Select,
+}
+
+impl IcuArgumentKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Simple => "simple",
+            Self::Formatted => "formatted",
+            Self::Plural => "plural",
+            Self::SelectOrdinal => "selectordinal",
+            Self::Select => "select",
+        }
+    }
+
+    /// Placeholder có nhánh (plural/select): bản dịch phải giữ đúng loại
+    pub fn is_branching(&self) -> bool {
+        matches!(self, Self::Plural | Self::SelectOrdinal | Self::Select)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IcuArgument {
+    pub name: String,
+    pub kind: IcuArgumentKind,
+}
+
+const PLURAL_CATEGORIES: &[&str] = &["zero", "one", "two", "few", "many", "other"];
+const FORMATTED_TYPES: &[&str] = &["number", "date", "time", "spellout", "ordinal", "duration"];
+
+/// Kiểm tra cú pháp ICU MessageFormat, trả về các placeholder (kể cả lồng trong plural/select).
+/// Plural/select bắt buộc có nhánh `other`; plural chỉ nhận `=N` hoặc category CLDR
+pub fn parse_icu_message(message: &str) -> Result<Vec<IcuArgument>, String> {
+    let mut parser = Parser {
+        chars: message.chars().collect(),
+        pos: 0,
+        arguments: Vec::new(),
+    };
+    parser.message(0, false)?;
+    Ok(parser.arguments)
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    arguments: Vec<IcuArgument>,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn read_while(&mut self, accept: impl Fn(char) -> bool) -> String {
+        let start = self.pos;
+        while self.peek().is_some_and(&accept) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    fn error(&self, message: &str) -> String {
+        format!("{message} at position {}", self.pos)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(c) => Err(self.error(&format!("expected '{expected}' but found '{c}'"))),
+            None => Err(self.error(&format!("expected '{expected}' but reached end of message"))),
+        }
+    }
+
+    /// Đọc text + placeholder tới `}` đóng nhánh (depth > 0) hoặc hết chuỗi
+    fn message(&mut self, depth: usize, in_plural: bool) -> Result<(), String> {
+        while let Some(c) = self.peek() {
+            match c {
+                '\'' => self.quoted(in_plural),
+                '{' => self.argument(depth)?,
+                '}' if depth > 0 => return Ok(()),
+                '}' => return Err(self.error("unmatched '}'")),
+                _ => self.pos += 1,
+            }
+        }
+        if depth > 0 {
+            return Err(self.error("missing '}'"));
+        }
+        Ok(())
+    }
+
+    /// `''` => dấu nháy; `'{...}'` => text thô; dấu nháy lẻ giữ nguyên
+    fn quoted(&mut self, in_plural: bool) {
+        match self.peek_at(1) {
+            Some('\'') => self.pos += 2,
+            Some('{' | '}') => self.skip_quoted(),
+            Some('#') if in_plural => self.skip_quoted(),
+            _ => self.pos += 1,
+        }
+    }
+
+    fn skip_quoted(&mut self) {
+        self.pos += 1;
+        while let Some(c) = self.peek() {
+            self.pos += 1;
+            if c == '\'' {
+                if self.peek() == Some('\'') {
+                    self.pos += 1;
+                } else {
+                    return;
+                }
+            }
+        }
+    }
+
+    fn argument(&mut self, depth: usize) -> Result<(), String> {
+        self.pos += 1;
+        self.skip_whitespace();
+        let name = self.read_while(|c| c.is_alphanumeric() || c == '_');
+        if name.is_empty() {
+            return Err(self.error("expected placeholder name"));
+        }
+        self.skip_whitespace();
+        match self.peek() {
+            Some('}') => {
+                self.pos += 1;
+                self.push(name, IcuArgumentKind::Simple);
+                Ok(())
+            }
+            Some(',') => {
+                self.pos += 1;
+                self.skip_whitespace();
+                let kind = self.read_while(|c| c.is_ascii_alphabetic());
+                match kind.as_str() {
+                    "plural" | "selectordinal" => {
+                        let kind = if kind == "plural" {
+                            IcuArgumentKind::Plural
+                        } else {
+                            IcuArgumentKind::SelectOrdinal
+                        };
+                        self.push(name.clone(), kind);
+                        self.expect(',')?;
+                        self.options(&name, kind, depth)
+                    }
+                    "select" => {
+                        self.push(name.clone(), IcuArgumentKind::Select);
+                        self.expect(',')?;
+                        self.options(&name, IcuArgumentKind::Select, depth)
+                    }
+                    t if FORMATTED_TYPES.contains(&t) => {
+                        self.push(name, IcuArgumentKind::Formatted);
+                        self.style()
+                    }
+                    "" => Err(self.error(&format!("expected type for placeholder {{{name}}}"))),
+                    t => Err(self.error(&format!("unknown type '{t}' for placeholder {{{name}}}"))),
+                }
+            }
+            Some(c) => Err(self.error(&format!("unexpected '{c}' in placeholder {{{name}}}"))),
+            None => Err(self.error(&format!("unclosed placeholder {{{name}}}"))),
+        }
+    }
+
+    /// Style của `number`/`date`/`time` (`, short`, `, ::currency/EUR`): không được chứa `{`
+    fn style(&mut self) -> Result<(), String> {
+        self.skip_whitespace();
+        if self.peek() == Some(',') {
+            self.pos += 1;
+            while let Some(c) = self.peek() {
+                match c {
+                    '\'' => self.quoted(false),
+                    '{' => return Err(self.error("unexpected '{' in placeholder style")),
+                    '}' => break,
+                    _ => self.pos += 1,
+                }
+            }
+        }
+        self.expect('}')
+    }
+
+    /// Các nhánh `selector {message}` của plural/select
+    fn options(&mut self, name: &str, kind: IcuArgumentKind, depth: usize) -> Result<(), String> {
+        let plural = kind != IcuArgumentKind::Select;
+        self.skip_whitespace();
+        if plural && self.chars[self.pos..].starts_with(&['o', 'f', 'f', 's', 'e', 't', ':']) {
+            self.pos += 7;
+            self.skip_whitespace();
+            if self.read_while(|c| c.is_ascii_digit()).is_empty() {
+                return Err(self.error("expected number after 'offset:'"));
+            }
+        }
+
+        let mut selectors: Vec<String> = Vec::new();
+        loop {
+            self.skip_whitespace();
+            let selector = match self.peek() {
+                Some('}') => {
+                    self.pos += 1;
+                    break;
+                }
+                None => return Err(self.error(&format!("unclosed placeholder {{{name}}}"))),
+                Some('=') if plural => {
+                    self.pos += 1;
+                    let value = self.read_while(|c| c.is_ascii_digit());
+                    if value.is_empty() {
+                        return Err(self.error("expected number after '='"));
+                    }
+                    format!("={value}")
+                }
+                Some(_) => {
+                    let selector = self.read_while(|c| c.is_alphanumeric() || c == '_' || c == '-');
+                    if selector.is_empty() {
+                        return Err(self.error(&format!(
+                            "expected {} selector in {{{name}}}",
+                            kind.as_str()
+                        )));
+                    }
+                    if plural && !PLURAL_CATEGORIES.contains(&selector.as_str()) {
+                        return Err(self.error(&format!(
+                            "invalid plural category '{selector}' in {{{name}}} \
+                             (expected zero, one, two, few, many, other or =N)"
+                        )));
+                    }
+                    selector
+                }
+            };
+            if selectors.contains(&selector) {
+                return Err(self.error(&format!("duplicate selector '{selector}' in {{{name}}}")));
+            }
+            self.expect('{')?;
+            self.message(depth + 1, plural)?;
+            self.pos += 1;
+            selectors.push(selector);
+        }
+
+        if !selectors.iter().any(|s| s == "other") {
+            return Err(format!(
+                "{} placeholder {{{name}}} is missing the 'other' case",
+                kind.as_str()
+            ));
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, name: String, kind: IcuArgumentKind) {
+        self.arguments.push(IcuArgument { name, kind });
+    }
+}
+
+/// Render message ICU với tham số theo ngôn ngữ `language` (mã BCP 47): plural/selectordinal
+/// chọn `=N` rồi category CLDR của số (`plural_category`) rồi `other`, `#` thay bằng số;
+/// select chọn theo giá trị chuỗi. Thiếu tham số thì giữ nguyên `{name}`
+pub fn format_icu_message(message: &str, params: &Map<String, Value>, language: &str) -> String {
+    let mut formatter = Formatter {
+        chars: message.chars().collect(),
+        pos: 0,
+        params,
+        language: language
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase(),
+    };
+    formatter.message(0, None)
+}
+
+struct Formatter<'a> {
+    chars: Vec<char>,
+    pos: usize,
+    params: &'a Map<String, Value>,
+    /// Subtag ngôn ngữ chính (`pt-BR` => `pt`)
+    language: String,
+}
+
+impl Formatter<'_> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn read_while(&mut self, accept: impl Fn(char) -> bool) -> String {
+        let start = self.pos;
+        while self.peek().is_some_and(&accept) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    /// Text tới `}` đóng nhánh (không tiêu thụ) hoặc hết chuỗi; `number` là giá trị của `#`
+    fn message(&mut self, depth: usize, number: Option<f64>) -> String {
+        let mut out = String::new();
+        while let Some(c) = self.peek() {
+            match c {
+                '\'' => self.quoted(&mut out, number.is_some()),
+                '{' => {
+                    let rendered = self.argument(depth);
+                    out.push_str(&rendered);
+                }
+                '}' if depth > 0 => break,
+                '#' if number.is_some() => {
+                    out.push_str(&format_number(number.unwrap_or_default()));
+                    self.pos += 1;
+                }
+                _ => {
+                    out.push(c);
+                    self.pos += 1;
+                }
+            }
+        }
+        out
+    }
+
+    fn quoted(&mut self, out: &mut String, in_plural: bool) {
+        match self.chars.get(self.pos + 1).copied() {
+            Some('\'') => {
+                out.push('\'');
+                self.pos += 2;
+            }
+            Some('{' | '}') => self.copy_quoted(out),
+            Some('#') if in_plural => self.copy_quoted(out),
+            _ => {
+                out.push('\'');
+                self.pos += 1;
+            }
+        }
+    }
+
+    fn copy_quoted(&mut self, out: &mut String) {
+        self.pos += 1;
+        while let Some(c) = self.peek() {
+            self.pos += 1;
+            if c == '\'' {
+                if self.peek() == Some('\'') {
+                    out.push('\'');
+                    self.pos += 1;
+                } else {
+                    return;
+                }
+            } else {
+                out.push(c);
+            }
+        }
+    }
+
+    fn argument(&mut self, depth: usize) -> String {
+        let start = self.pos;
+        self.pos += 1;
+        self.skip_whitespace();
+        let name = self.read_while(|c| c.is_alphanumeric() || c == '_');
+        self.skip_whitespace();
+        let value = self.params.get(&name);
+        if self.peek() == Some('}') {
+            self.pos += 1;
+            return value
+                .map(value_to_string)
+                .unwrap_or_else(|| format!("{{{name}}}"));
+        }
+        if self.peek() != Some(',') {
+            // Cú pháp hỏng (message đã được kiểm tra khi lưu): in nguyên phần còn lại
+            self.pos = self.chars.len();
+            return self.chars[start..].iter().collect();
+        }
+        self.pos += 1;
+        self.skip_whitespace();
+        let kind = self.read_while(|c| c.is_ascii_alphabetic());
+        match kind.as_str() {
+            "plural" | "selectordinal" | "select" => {
+                self.skip_whitespace();
+                if self.peek() == Some(',') {
+                    self.pos += 1;
+                }
+                self.options(&name, &kind, depth)
+            }
+            _ => {
+                // number/date/time...: bỏ qua style, in giá trị thô
+                while let Some(c) = self.peek() {
+                    self.pos += 1;
+                    if c == '}' {
+                        break;
+                    }
+                }
+                value
+                    .map(value_to_string)
+                    .unwrap_or_else(|| format!("{{{name}}}"))
+            }
+        }
+    }
+
+    fn options(&mut self, name: &str, kind: &str, depth: usize) -> String {
+        let plural = kind != "select";
+        let value = self.params.get(name);
+        let mut offset = 0.0;
+        self.skip_whitespace();
+        if plural && self.chars[self.pos..].starts_with(&['o', 'f', 'f', 's', 'e', 't', ':']) {
+            self.pos += 7;
+            self.skip_whitespace();
+            offset = self
+                .read_while(|c| c.is_ascii_digit())
+                .parse::<f64>()
+                .unwrap_or_default();
+        }
+        let number = value.and_then(value_to_number);
+
+        let mut branches: Vec<(String, String)> = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                None => break,
+                Some('}') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(_) => {}
+            }
+            let selector = self.read_while(|c| !c.is_whitespace() && c != '{' && c != '}');
+            self.skip_whitespace();
+            if self.peek() != Some('{') {
+                break;
+            }
+            self.pos += 1;
+            let branch = self.message(depth + 1, number.map(|n| n - offset).filter(|_| plural));
+            self.pos += 1;
+            branches.push((selector, branch));
+        }
+
+        let find = |selector: &str| {
+            branches
+                .iter()
+                .find(|(s, _)| s == selector)
+                .map(|(_, b)| b.clone())
+        };
+        let chosen = match (plural, number, value) {
+            (true, Some(n), _) => find(&format!("={}", format_number(n))).or_else(|| {
+                let category = if kind == "selectordinal" {
+                    ordinal_category(&self.language, n - offset)
+                } else {
+                    plural_category(&self.language, n - offset)
+                };
+                find(category)
+            }),
+            (false, _, Some(value)) => find(&value_to_string(value)),
+            _ => None,
+        };
+        chosen.or_else(|| find("other")).unwrap_or_default()
+    }
+}
+
+/// Category plural (cardinal) theo CLDR cho các họ ngôn ngữ phổ biến; ngôn ngữ không có
+/// trong bảng dùng quy tắc kiểu tiếng Anh (`one` khi số nguyên 1). Operand theo CLDR:
+/// `i` phần nguyên, `v` số chữ số thập phân
+fn plural_category(language: &str, n: f64) -> &'static str {
+    let i = n.abs().trunc() as u64;
+    let v = format_number(n.abs())
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len());
+    let integer = v == 0;
+    match language {
+        // Không phân biệt số ít/số nhiều
+        "vi" | "ja" | "zh" | "ko" | "th" | "id" | "ms" | "lo" | "km" | "my" | "yue" => "other",
+        "fr" | "pt" if i <= 1 => "one",
+        "fr" | "pt" => "other",
+        "ru" | "uk" | "be" if integer => match (i % 10, i % 100) {
+            (1, r) if r != 11 => "one",
+            (2..=4, r) if !(12..=14).contains(&r) => "few",
+            _ => "many",
+        },
+        "pl" if integer => match (i, i % 10, i % 100) {
+            (1, _, _) => "one",
+            (_, 2..=4, r) if !(12..=14).contains(&r) => "few",
+            _ => "many",
+        },
+        "cs" | "sk" if integer => match i {
+            1 => "one",
+            2..=4 => "few",
+            _ => "other",
+        },
+        "cs" | "sk" => "many",
+        "ar" if integer => match (i, i % 100) {
+            (0, _) => "zero",
+            (1, _) => "one",
+            (2, _) => "two",
+            (_, 3..=10) => "few",
+            (_, 11..=99) => "many",
+            _ => "other",
+        },
+        "he" if integer && i == 2 => "two",
+        "he" if (integer && i == 1) || (!integer && i == 0) => "one",
+        "ru" | "uk" | "be" | "pl" | "ar" | "he" => "other",
+        _ if integer && i == 1 => "one",
+        _ => "other",
+    }
+}
+
+/// Category của `selectordinal` theo CLDR (tiếng Anh: 1st/2nd/3rd/4th), mặc định `other`
+fn ordinal_category(language: &str, n: f64) -> &'static str {
+    if n.fract() != 0.0 {
+        return "other";
+    }
+    let n = n.abs() as u64;
+    match language {
+        "en" => match (n % 10, n % 100) {
+            (1, r) if r != 11 => "one",
+            (2, r) if r != 12 => "two",
+            (3, r) if r != 13 => "few",
+            _ => "other",
+        },
+        "fr" | "vi" | "ms" if n == 1 => "one",
+        "it" if matches!(n, 8 | 11 | 80 | 800) => "many",
+        _ => "other",
+    }
+}
+
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn value_to_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn format_number(n: f64) -> String {
+    if n.fract() == 0.0 && n.abs() < 1e15 {
+        format!("{}", n as i64)
+    } else {
+        n.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arg(name: &str, kind: IcuArgumentKind) -> IcuArgument {
+        IcuArgument {
+            name: name.to_string(),
+            kind,
+        }
+    }
+
+    #[test]
+    fn collects_simple_and_nested_placeholders() {
+        let args = parse_icu_message(
+            "{name} has {count, plural, offset:1 =0 {no posts} one {# post in {category}} other {# posts}}",
+        )
+        .unwrap();
+        assert_eq!(
+            args,
+            vec![
+                arg("name", IcuArgumentKind::Simple),
+                arg("count", IcuArgumentKind::Plural),
+                arg("category", IcuArgumentKind::Simple),
+            ]
+        );
+    }
+
+    #[test]
+    fn accepts_select_with_free_form_selectors() {
+        let args =
+            parse_icu_message("{gender, select, male {He} female {She} other {They}} replied")
+                .unwrap();
+        assert_eq!(args, vec![arg("gender", IcuArgumentKind::Select)]);
+    }
+
+    #[test]
+    fn quoted_braces_are_text() {
+        assert_eq!(parse_icu_message("Use '{name}' literally").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn plural_and_select_require_other() {
+        let err = parse_icu_message("{count, plural, one {# post}}").unwrap_err();
+        assert!(err.contains("missing the 'other' case"), "{err}");
+        let err = parse_icu_message("{gender, select, male {He}}").unwrap_err();
+        assert!(err.contains("select placeholder {gender}"), "{err}");
+    }
+
+    #[test]
+    fn plural_rejects_unknown_category() {
+        let err =
+            parse_icu_message("{count, plural, single {# post} other {# posts}}").unwrap_err();
+        assert!(err.contains("invalid plural category 'single'"), "{err}");
+    }
+
+    #[test]
+    fn rejects_duplicate_selectors() {
+        let err =
+            parse_icu_message("{gender, select, male {He} male {Him} other {They}}").unwrap_err();
+        assert!(err.contains("duplicate selector 'male'"), "{err}");
+        let err = parse_icu_message("{count, plural, =1 {a} =1 {b} other {c}}").unwrap_err();
+        assert!(err.contains("duplicate selector '=1'"), "{err}");
+    }
+
+    #[test]
+    fn rejects_broken_syntax() {
+        for message in [
+            "Hello {name",
+            "Hello name}",
+            "{}",
+            "{count, plural, one # post other {# posts}}",
+            "{count, bogus}",
+            "{n, number, {x}}",
+        ] {
+            assert!(parse_icu_message(message).is_err(), "{message}");
+        }
+    }
+}
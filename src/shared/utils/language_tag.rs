@@ -0,0 +1,157 @@
+/// Chuẩn hoá language tag theo BCP 47 (RFC 5646): `EN-us` → `en-US`, `zh-hant-tw` → `zh-Hant-TW`.
+/// `None` nếu tag sai cú pháp. Không hỗ trợ các tag "grandfathered" (`i-klingon`, ...)
+pub fn canonicalize_language_tag(tag: &str) -> Option<String> {
+    let subtags: Vec<&str> = tag.trim().split(['-', '_']).collect();
+    if subtags
+        .iter()
+        .any(|s| s.is_empty() || s.len() > 8 || !s.chars().all(|c| c.is_ascii_alphanumeric()))
+    {
+        return None;
+    }
+
+    let mut out: Vec<String> = Vec::with_capacity(subtags.len());
+    let mut rest = subtags.as_slice();
+
+    // language: mã ISO 639 2-3 chữ cái (subtag 5-8 ký tự chỉ dành cho ngôn ngữ đăng ký sau,
+    // hiện chưa có), có thể kèm tối đa 3 extlang
+    let (language, tail) = rest.split_first()?;
+    if !is_alpha(language) || !(2..=3).contains(&language.len()) {
+        return None;
+    }
+    out.push(language.to_ascii_lowercase());
+    rest = tail;
+    let mut extlangs = 0;
+    while let Some((s, tail)) = rest.split_first()
+        && s.len() == 3
+        && is_alpha(s)
+        && extlangs < 3
+    {
+        out.push(s.to_ascii_lowercase());
+        rest = tail;
+        extlangs += 1;
+    }
+
+    // script: 4 chữ cái, viết hoa chữ đầu
+    if let Some((s, tail)) = rest.split_first()
+        && s.len() == 4
+        && is_alpha(s)
+    {
+        out.push(title_case(s));
+        rest = tail;
+    }
+
+    // region: 2 chữ cái hoặc 3 chữ số
+    if let Some((s, tail)) = rest.split_first()
+        && ((s.len() == 2 && is_alpha(s)) || (s.len() == 3 && is_digit(s)))
+    {
+        out.push(s.to_ascii_uppercase());
+        rest = tail;
+    }
+
+    // variant: 5-8 ký tự, hoặc 4 ký tự bắt đầu bằng số; không lặp
+    while let Some((s, tail)) = rest.split_first()
+        && (s.len() >= 5 || (s.len() == 4 && s.starts_with(|c: char| c.is_ascii_digit())))
+    {
+        let variant = s.to_ascii_lowercase();
+        if out.contains(&variant) {
+            return None;
+        }
+        out.push(variant);
+        rest = tail;
+    }
+
+    // extension (`u-ca-buddhist`) và private use (`x-...`)
+    let mut singletons: Vec<char> = Vec::new();
+    while let Some((s, tail)) = rest.split_first() {
+        if s.len() != 1 {
+            return None;
+        }
+        let singleton = s.to_ascii_lowercase().chars().next()?;
+        let min_len = if singleton == 'x' { 1 } else { 2 };
+        let count = tail
+            .iter()
+            .take_while(|t| t.len() >= min_len && (singleton == 'x' || t.len() > 1))
+            .count();
+        if count == 0 || singletons.contains(&singleton) {
+            return None;
+        }
+        singletons.push(singleton);
+        out.push(singleton.to_string());
+        out.extend(tail[..count].iter().map(|t| t.to_ascii_lowercase()));
+        rest = &tail[count..];
+        if singleton == 'x' && !rest.is_empty() {
+            return None;
+        }
+    }
+
+    Some(out.join("-"))
+}
+
+fn is_alpha(s: &str) -> bool {
+    s.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+fn is_digit(s: &str) -> bool {
+    s.chars().all(|c| c.is_ascii_digit())
+}
+
+fn title_case(s: &str) -> String {
+    let lower = s.to_ascii_lowercase();
+    let mut chars = lower.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canonical(tag: &str) -> Option<String> {
+        canonicalize_language_tag(tag)
+    }
+
+    #[test]
+    fn normalizes_case_of_each_subtag() {
+        assert_eq!(canonical("EN-us").as_deref(), Some("en-US"));
+        assert_eq!(canonical("zh-hant-tw").as_deref(), Some("zh-Hant-TW"));
+        assert_eq!(canonical(" VI ").as_deref(), Some("vi"));
+    }
+
+    #[test]
+    fn accepts_underscore_separator() {
+        assert_eq!(canonical("pt_br").as_deref(), Some("pt-BR"));
+    }
+
+    #[test]
+    fn keeps_numeric_region_variants_and_extensions() {
+        assert_eq!(canonical("ES-419").as_deref(), Some("es-419"));
+        assert_eq!(canonical("de-CH-1996").as_deref(), Some("de-CH-1996"));
+        assert_eq!(
+            canonical("th-TH-U-CA-Buddhist").as_deref(),
+            Some("th-TH-u-ca-buddhist")
+        );
+        assert_eq!(
+            canonical("EN-latn-X-Private").as_deref(),
+            Some("en-Latn-x-private")
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_tags() {
+        for tag in [
+            "",
+            "e",
+            "english",
+            "en--US",
+            "en-US!",
+            "en-US-u",
+            "de-1996-1996",
+            "en-a-bc-a-de",
+            "toolongsubtag",
+        ] {
+            assert_eq!(canonical(tag), None, "{tag}");
+        }
+    }
+}
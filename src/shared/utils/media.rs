@@ -0,0 +1,39 @@
+use std::collections::BTreeMap;
+
+/// Prefix URL public của file upload khi không cấu hình `MEDIA_BASE_URL`
+pub const DEFAULT_MEDIA_BASE_URL: &str = "/uploads";
+
+/// Các biến thể ảnh mặc định `tên:chiều rộng` khi không cấu hình `MEDIA_VARIANTS`
+pub const DEFAULT_MEDIA_VARIANTS: &str = "thumbnail:320,medium:768,large:1280";
+
+/// URL public của media từ `file_path` lưu trong DB (URL tuyệt đối giữ nguyên)
+pub fn media_url(file_path: &str) -> String {
+    if file_path.starts_with("http://") || file_path.starts_with("https://") {
+        return file_path.to_string();
+    }
+    let base = std::env::var("MEDIA_BASE_URL").unwrap_or_else(|_| DEFAULT_MEDIA_BASE_URL.into());
+    format!(
+        "{}/{}",
+        base.trim_end_matches('/'),
+        file_path.trim_start_matches('/')
+    )
+}
+
+/// URL các biến thể resize (`?w=`) của ảnh, media không phải ảnh thì rỗng
+pub fn media_variants(media_type: &str, url: &str) -> BTreeMap<String, String> {
+    if media_type != "images" {
+        return BTreeMap::new();
+    }
+    let spec = std::env::var("MEDIA_VARIANTS").unwrap_or_else(|_| DEFAULT_MEDIA_VARIANTS.into());
+    let separator = if url.contains('?') { '&' } else { '?' };
+
+    spec.split(',')
+        .filter_map(|item| {
+            let (name, width) = item.split_once(':')?;
+            let width: u32 = width.trim().parse().ok()?;
+            let name = name.trim();
+            (!name.is_empty() && width > 0)
+                .then(|| (name.to_string(), format!("{url}{separator}w={width}")))
+        })
+        .collect()
+}
@@ -1,3 +1,9 @@
+pub mod content;
+pub mod diff;
 pub mod hash;
+pub mod icu_message;
 pub mod jwt;
+pub mod language_tag;
+pub mod media;
 pub mod query;
+pub mod slug;
@@ -1,7 +1,13 @@
 use base64::{Engine as _, engine::general_purpose};
 use chrono::{DateTime, NaiveDateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::sync::OnceLock;
 use uuid::Uuid;
 
+use crate::shared::error::{AppError, ErrorKind};
+
 // Re-export DTOs từ application để không phá vỡ các import hiện có
 pub use crate::application::common::list_params::{ListParams, PaginatedResult};
 
@@ -27,10 +33,45 @@ impl SortDirection {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NullsOrder {
+    First,
+    Last,
+}
+
+impl NullsOrder {
+    /// Mặc định giống Postgres: ASC -> NULLS LAST, DESC -> NULLS FIRST
+    pub fn default_for(dir: SortDirection) -> Self {
+        match dir {
+            SortDirection::Asc => NullsOrder::Last,
+            SortDirection::Desc => NullsOrder::First,
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "nulls_first" | "first" => Some(NullsOrder::First),
+            "nulls_last" | "last" => Some(NullsOrder::Last),
+            _ => None,
+        }
+    }
+
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            NullsOrder::First => "NULLS FIRST",
+            NullsOrder::Last => "NULLS LAST",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FieldType {
     Text,
     Timestamp,
+    /// Cột kiểu enum Postgres, giữ tên type để cast giá trị cursor (vd: `user_status`)
+    Enum(&'static str),
+    /// Cột số nguyên (INT/BIGINT), giá trị cursor encode dạng `CursorValue::Text`
+    Integer,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -47,49 +88,198 @@ pub enum BindValue {
     I64(i64),
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct SortKey {
+    pub field: FieldInfo,
+    pub dir: SortDirection,
+    pub nulls: NullsOrder,
+}
+
 #[derive(Debug, Clone)]
 pub struct BuiltQuery {
     pub sql: String,
     pub binds: Vec<BindValue>,
     pub limit: i64,
-    pub sort_field: &'static str,
-    #[allow(dead_code)]
-    pub sort_dir: SortDirection,
+    pub sort_keys: Vec<SortKey>,
+    /// Chuỗi chuẩn hoá của sort keys, nhúng vào cursor để phát hiện đổi sort giữa các trang
+    pub sort_signature: String,
+    /// Hash của search/filter cố định, nhúng vào cursor để phát hiện đổi filter giữa các trang
+    pub filter_hash: String,
+}
+
+/// Giá trị của 1 sort key trên bản ghi cuối trang, `None` nghĩa là NULL.
+#[derive(Debug, Clone)]
+pub enum CursorValue {
+    Text(Option<String>),
+    Timestamp(Option<NaiveDateTime>),
+    /// Field không có arm trong `cursor_value` (thiếu khi thêm vào `allowed_fields`)
+    Unsupported,
+}
+
+/// Entity cần implement trait này để query builder encode được cursor trang sau.
+pub trait CursorRow {
+    fn cursor_id(&self) -> Uuid;
+    fn cursor_value(&self, field: &str) -> CursorValue;
+}
+
+/// Tăng khi đổi format payload, cursor cũ sẽ bị từ chối (400) thay vì trả sai trang.
+const CURSOR_VERSION: u8 = 1;
+
+/// Lỗi cursor (handler map sang 400 `INVALID_CURSOR`)
+fn invalid_cursor(reason: impl Into<String>) -> AppError {
+    let reason = reason.into();
+    AppError::new(
+        ErrorKind::Invalid,
+        "INVALID_CURSOR",
+        format!("Invalid cursor: {reason}"),
+    )
+    .with("reason", reason)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CursorPayload {
+    v: u8,
+    sort: String,
+    filter: String,
+    values: Vec<Option<String>>,
+    id: Uuid,
 }
 
 #[derive(Debug, Clone)]
 pub struct CursorData {
-    pub sort_raw: String,
+    pub values: Vec<Option<String>>,
     pub id: Uuid,
 }
 
-fn decode_cursor(cursor: &str) -> Result<CursorData, String> {
-    let decoded = general_purpose::STANDARD
-        .decode(cursor)
-        .map_err(|e| format!("Invalid cursor: {e}"))?;
-    let decoded_str =
-        String::from_utf8(decoded).map_err(|e| format!("Invalid cursor utf8: {e}"))?;
-    let mut parts = decoded_str.splitn(2, '|');
-    let sort_raw = parts
-        .next()
-        .ok_or_else(|| "Cursor missing sort value".to_string())?
-        .to_string();
-    let id_str = parts
-        .next()
-        .ok_or_else(|| "Cursor missing id value".to_string())?;
-    let id = Uuid::parse_str(id_str).map_err(|e| format!("Invalid cursor id: {e}"))?;
-    Ok(CursorData { sort_raw, id })
+pub fn is_cursor_error(error: &AppError) -> bool {
+    error.key() == Some("INVALID_CURSOR")
+}
+
+static CURSOR_SECRET: OnceLock<String> = OnceLock::new();
+
+/// Đọc secret ký cursor 1 lần lúc khởi động: thiếu biến môi trường thì dừng ngay,
+/// không để panic giữa request
+pub fn init_cursor_secret() {
+    let secret = std::env::var("CURSOR_SECRET")
+        .or_else(|_| std::env::var("JWT_SECRET"))
+        .expect("CURSOR_SECRET or JWT_SECRET must be set");
+    CURSOR_SECRET.get_or_init(|| secret);
+}
+
+fn cursor_secret() -> &'static str {
+    CURSOR_SECRET
+        .get()
+        .map(String::as_str)
+        .expect("init_cursor_secret must be called at startup")
+}
+
+fn sign(data: &[u8]) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(cursor_secret().as_bytes())
+        .expect("HMAC accepts keys of any size");
+    mac.update(data);
+    mac
 }
 
-pub fn encode_cursor_text(value: &str, id: Uuid) -> String {
-    let raw = format!("{value}|{id}");
-    general_purpose::STANDARD.encode(raw.as_bytes())
+fn hash_hex(data: &str) -> String {
+    Sha256::digest(data.as_bytes())
+        .iter()
+        .take(8)
+        .map(|b| format!("{b:02x}"))
+        .collect()
 }
 
-pub fn encode_cursor_ts(value: NaiveDateTime, id: Uuid) -> String {
-    let dt: DateTime<Utc> = DateTime::<Utc>::from_naive_utc_and_offset(value, Utc);
-    let raw = format!("{}|{id}", dt.to_rfc3339());
-    general_purpose::STANDARD.encode(raw.as_bytes())
+/// Cursor = base64url(payload JSON) + "." + base64url(HMAC-SHA256(payload)).
+fn encode_cursor(payload: &CursorPayload) -> String {
+    let raw = serde_json::to_vec(payload).unwrap_or_default();
+    let body = general_purpose::URL_SAFE_NO_PAD.encode(raw);
+    let signature =
+        general_purpose::URL_SAFE_NO_PAD.encode(sign(body.as_bytes()).finalize().into_bytes());
+    format!("{body}.{signature}")
+}
+
+fn decode_cursor(cursor: &str, sort: &str, filter: &str) -> Result<CursorData, AppError> {
+    let malformed = || invalid_cursor("malformed or tampered cursor");
+    let (body, signature) = cursor.split_once('.').ok_or_else(malformed)?;
+    let signature = general_purpose::URL_SAFE_NO_PAD
+        .decode(signature)
+        .map_err(|_| malformed())?;
+    sign(body.as_bytes())
+        .verify_slice(&signature)
+        .map_err(|_| malformed())?;
+
+    let raw = general_purpose::URL_SAFE_NO_PAD
+        .decode(body)
+        .map_err(|_| malformed())?;
+    let payload: CursorPayload = serde_json::from_slice(&raw).map_err(|_| malformed())?;
+
+    if payload.v != CURSOR_VERSION {
+        return Err(invalid_cursor(format!(
+            "cursor version {} is no longer supported, restart from the first page",
+            payload.v
+        )));
+    }
+    if payload.sort != sort {
+        return Err(invalid_cursor(format!(
+            "cursor was issued for sort_by={}, restart from the first page",
+            payload.sort
+        )));
+    }
+    if payload.filter != filter {
+        return Err(invalid_cursor(
+            "cursor was issued for different search/filter params, restart from the first page",
+        ));
+    }
+
+    Ok(CursorData {
+        values: payload.values,
+        id: payload.id,
+    })
+}
+
+impl BuiltQuery {
+    /// Cursor cho trang tiếp theo (chỉ có khi trang hiện tại đầy `limit`).
+    /// Field sort không có arm trong `cursor_value` => lỗi, tránh cursor NULL làm lặp/bỏ sót dòng
+    pub fn next_cursor<T: CursorRow>(&self, items: &[T]) -> Result<Option<String>, AppError> {
+        let Some(first) = items.first() else {
+            return Ok(None);
+        };
+        if let Some(key) = self
+            .sort_keys
+            .iter()
+            .find(|k| matches!(first.cursor_value(k.field.name), CursorValue::Unsupported))
+        {
+            return Err(format!(
+                "Sort field `{}` has no cursor value mapping",
+                key.field.name
+            )
+            .into());
+        }
+
+        if items.len() as i64 != self.limit {
+            return Ok(None);
+        }
+        let Some(last) = items.last() else {
+            return Ok(None);
+        };
+        let values = self
+            .sort_keys
+            .iter()
+            .map(|k| match last.cursor_value(k.field.name) {
+                CursorValue::Text(t) => t,
+                CursorValue::Timestamp(ts) => ts.map(|value| {
+                    DateTime::<Utc>::from_naive_utc_and_offset(value, Utc).to_rfc3339()
+                }),
+                CursorValue::Unsupported => None,
+            })
+            .collect();
+        Ok(Some(encode_cursor(&CursorPayload {
+            v: CURSOR_VERSION,
+            sort: self.sort_signature.clone(),
+            filter: self.filter_hash.clone(),
+            values,
+            id: last.cursor_id(),
+        })))
+    }
 }
 
 fn sanitize_fields<'a>(
@@ -122,7 +312,7 @@ pub fn build_query(
     default_sort: &'static str,
     default_dir: SortDirection,
     default_search_fields: &[&str],
-) -> Result<BuiltQuery, String> {
+) -> Result<BuiltQuery, AppError> {
     build_query_with_seed(
         base_sql,
         params,
@@ -145,7 +335,7 @@ pub fn build_query_with_seed(
     default_search_fields: &[&str],
     base_filters: &[(&str, BindValue)],
     start_index: i32,
-) -> Result<BuiltQuery, String> {
+) -> Result<BuiltQuery, AppError> {
     let mut binds: Vec<BindValue> = base_filters.iter().map(|(_, b)| b.clone()).collect();
     let mut clauses: Vec<String> = base_filters.iter().map(|(c, _)| c.to_string()).collect();
     let mut idx: i32 = start_index;
@@ -171,80 +361,46 @@ pub fn build_query_with_seed(
         }
     }
 
-    // sort parse
-    let (sort_field, sort_dir) = if let Some(sort_raw) = params.sort_by.as_ref() {
-        let mut parts = sort_raw.splitn(2, ':');
-        let field = parts.next().unwrap_or(default_sort);
-        let dir = parts.next().unwrap_or_else(|| default_dir.as_sql());
-        let dir = SortDirection::from_str(dir);
-        if let Some(info) = allowed_fields
-            .iter()
-            .find(|f| f.name.eq_ignore_ascii_case(field))
-        {
-            (info, dir)
-        } else {
-            (
-                allowed_fields
-                    .iter()
-                    .find(|f| f.name == default_sort)
-                    .ok_or_else(|| "default sort field not in allowed_fields".to_string())?,
-                default_dir,
+    // sort parse: "field:dir[:nulls_first|nulls_last],field2:dir2"
+    let sort_keys = parse_sort_keys(
+        params.sort_by.as_deref(),
+        allowed_fields,
+        default_sort,
+        default_dir,
+    )?;
+
+    let sort_signature = sort_keys
+        .iter()
+        .map(|k| {
+            format!(
+                "{}:{}:{}",
+                k.field.name,
+                k.dir.as_sql().to_lowercase(),
+                match k.nulls {
+                    NullsOrder::First => "nulls_first",
+                    NullsOrder::Last => "nulls_last",
+                }
             )
-        }
-    } else {
-        (
-            allowed_fields
-                .iter()
-                .find(|f| f.name == default_sort)
-                .ok_or_else(|| "default sort field not in allowed_fields".to_string())?,
-            default_dir,
-        )
-    };
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    let filter_hash = hash_hex(&format!(
+        "{:?}|{:?}|{:?}",
+        base_filters
+            .iter()
+            .map(|(c, b)| format!("{c}={b:?}"))
+            .collect::<Vec<_>>(),
+        params.search.as_deref().unwrap_or_default(),
+        search_fields.iter().map(|f| f.name).collect::<Vec<_>>()
+    ));
 
     // cursor (bỏ qua nếu chuỗi rỗng)
     if let Some(cursor) = params.cursor.as_ref().filter(|s| !s.is_empty()) {
-        let decoded = decode_cursor(cursor)?;
-        match sort_field.field_type {
-            FieldType::Text => {
-                idx += 1;
-                let idx_sort = idx;
-                idx += 1;
-                let idx_id = idx;
-                let cmp = match sort_dir {
-                    SortDirection::Asc => ">",
-                    SortDirection::Desc => "<",
-                };
-                clauses.push(format!(
-                    "({sort_col}, id) {cmp} (${}, ${})",
-                    idx_sort,
-                    idx_id,
-                    sort_col = sort_field.name
-                ));
-                binds.push(BindValue::Text(decoded.sort_raw));
-                binds.push(BindValue::Uuid(decoded.id));
-            }
-            FieldType::Timestamp => {
-                let dt = DateTime::parse_from_rfc3339(&decoded.sort_raw)
-                    .map_err(|e| format!("Invalid cursor datetime: {e}"))?
-                    .naive_utc();
-                idx += 1;
-                let idx_sort = idx;
-                idx += 1;
-                let idx_id = idx;
-                let cmp = match sort_dir {
-                    SortDirection::Asc => ">",
-                    SortDirection::Desc => "<",
-                };
-                clauses.push(format!(
-                    "({sort_col}, id) {cmp} (${}, ${})",
-                    idx_sort,
-                    idx_id,
-                    sort_col = sort_field.name
-                ));
-                binds.push(BindValue::Timestamp(dt));
-                binds.push(BindValue::Uuid(decoded.id));
-            }
+        let decoded = decode_cursor(cursor, &sort_signature, &filter_hash)?;
+        if decoded.values.len() != sort_keys.len() {
+            return Err(invalid_cursor("sort keys mismatch"));
         }
+        clauses.push(keyset_clause(&sort_keys, decoded, &mut idx, &mut binds)?);
     }
 
     let where_sql = if clauses.is_empty() {
@@ -255,12 +411,13 @@ pub fn build_query_with_seed(
         format!("{prefix}{}", clauses.join(" AND "))
     };
 
-    let order_sql = format!(
-        " ORDER BY {} {}, id {}",
-        sort_field.name,
-        sort_dir.as_sql(),
-        sort_dir.as_sql()
-    );
+    // id luôn là tie-breaker cuối, cùng chiều với sort key đầu tiên
+    let mut order_parts = sort_keys
+        .iter()
+        .map(|k| format!("{} {} {}", k.field.name, k.dir.as_sql(), k.nulls.as_sql()))
+        .collect::<Vec<_>>();
+    order_parts.push(format!("id {}", sort_keys[0].dir.as_sql()));
+    let order_sql = format!(" ORDER BY {}", order_parts.join(", "));
 
     let limit = params.limit.unwrap_or(20).clamp(1, 100);
     idx += 1;
@@ -272,7 +429,315 @@ pub fn build_query_with_seed(
         sql,
         binds,
         limit,
-        sort_field: sort_field.name,
-        sort_dir,
+        sort_keys,
+        sort_signature,
+        filter_hash,
     })
 }
+
+fn parse_sort_keys(
+    sort_by: Option<&str>,
+    allowed_fields: &[FieldInfo],
+    default_sort: &'static str,
+    default_dir: SortDirection,
+) -> Result<Vec<SortKey>, String> {
+    let mut keys: Vec<SortKey> = Vec::new();
+    for part in sort_by
+        .unwrap_or_default()
+        .split(',')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+    {
+        let mut segments = part.splitn(3, ':');
+        let field = segments.next().unwrap_or_default();
+        let Some(info) = allowed_fields
+            .iter()
+            .find(|f| f.name.eq_ignore_ascii_case(field))
+        else {
+            continue;
+        };
+        if keys.iter().any(|k| k.field.name == info.name) {
+            continue;
+        }
+        let dir = segments
+            .next()
+            .map(SortDirection::from_str)
+            .unwrap_or(default_dir);
+        let nulls = segments
+            .next()
+            .and_then(NullsOrder::parse)
+            .unwrap_or_else(|| NullsOrder::default_for(dir));
+        keys.push(SortKey {
+            field: *info,
+            dir,
+            nulls,
+        });
+    }
+
+    if keys.is_empty() {
+        let info = allowed_fields
+            .iter()
+            .find(|f| f.name == default_sort)
+            .ok_or_else(|| "default sort field not in allowed_fields".to_string())?;
+        keys.push(SortKey {
+            field: *info,
+            dir: default_dir,
+            nulls: NullsOrder::default_for(default_dir),
+        });
+    }
+
+    Ok(keys)
+}
+
+/// Sinh điều kiện keyset dạng mở rộng để hỗ trợ nhiều chiều sort khác nhau và NULL:
+/// `(k1 > v1) OR (k1 = v1 AND k2 < v2) OR ... OR (k1 = v1 AND ... AND id > last_id)`.
+fn keyset_clause(
+    sort_keys: &[SortKey],
+    cursor: CursorData,
+    idx: &mut i32,
+    binds: &mut Vec<BindValue>,
+) -> Result<String, AppError> {
+    // placeholder cho từng giá trị (None = NULL, không cần bind)
+    let mut placeholders: Vec<Option<String>> = Vec::with_capacity(sort_keys.len());
+    for (key, raw) in sort_keys.iter().zip(cursor.values) {
+        let Some(raw) = raw else {
+            placeholders.push(None);
+            continue;
+        };
+        *idx += 1;
+        let placeholder = match key.field.field_type {
+            FieldType::Text => {
+                binds.push(BindValue::Text(raw));
+                format!("${idx}")
+            }
+            FieldType::Enum(type_name) => {
+                binds.push(BindValue::Text(raw));
+                format!("${idx}::{type_name}")
+            }
+            FieldType::Integer => {
+                let n = raw
+                    .parse::<i64>()
+                    .map_err(|e| invalid_cursor(format!("bad integer value ({e})")))?;
+                binds.push(BindValue::I64(n));
+                format!("${idx}")
+            }
+            FieldType::Timestamp => {
+                let dt = DateTime::parse_from_rfc3339(&raw)
+                    .map_err(|e| invalid_cursor(format!("bad datetime value ({e})")))?
+                    .naive_utc();
+                binds.push(BindValue::Timestamp(dt));
+                format!("${idx}")
+            }
+        };
+        placeholders.push(Some(placeholder));
+    }
+
+    *idx += 1;
+    binds.push(BindValue::Uuid(cursor.id));
+    let id_cmp = match sort_keys[0].dir {
+        SortDirection::Asc => ">",
+        SortDirection::Desc => "<",
+    };
+    let id_after = format!("id {id_cmp} ${idx}");
+
+    let mut terms: Vec<String> = Vec::new();
+    let mut equals: Vec<String> = Vec::new();
+    for (key, placeholder) in sort_keys.iter().zip(placeholders.iter()) {
+        let col = key.field.name;
+        let cmp = match key.dir {
+            SortDirection::Asc => ">",
+            SortDirection::Desc => "<",
+        };
+        let after = match (placeholder, key.nulls) {
+            // NULL đứng cuối: không còn gì "sau" một giá trị NULL
+            (None, NullsOrder::Last) => None,
+            (None, NullsOrder::First) => Some(format!("{col} IS NOT NULL")),
+            (Some(p), NullsOrder::Last) => Some(format!("({col} {cmp} {p} OR {col} IS NULL)")),
+            (Some(p), NullsOrder::First) => Some(format!("{col} {cmp} {p}")),
+        };
+        if let Some(after) = after {
+            let mut parts = equals.clone();
+            parts.push(after);
+            terms.push(format!("({})", parts.join(" AND ")));
+        }
+        equals.push(match placeholder {
+            Some(p) => format!("{col} = {p}"),
+            None => format!("{col} IS NULL"),
+        });
+    }
+    equals.push(id_after);
+    terms.push(format!("({})", equals.join(" AND ")));
+
+    Ok(format!("({})", terms.join(" OR ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TITLE: FieldInfo = FieldInfo {
+        name: "title",
+        field_type: FieldType::Text,
+    };
+    const PUBLISHED_AT: FieldInfo = FieldInfo {
+        name: "published_at",
+        field_type: FieldType::Timestamp,
+    };
+
+    fn key(field: FieldInfo, dir: SortDirection, nulls: NullsOrder) -> SortKey {
+        SortKey { field, dir, nulls }
+    }
+
+    fn clause(sort_keys: &[SortKey], values: Vec<Option<&str>>) -> (String, Vec<BindValue>) {
+        let cursor = CursorData {
+            values: values.into_iter().map(|v| v.map(str::to_string)).collect(),
+            id: Uuid::nil(),
+        };
+        let mut idx = 0;
+        let mut binds = Vec::new();
+        let sql = keyset_clause(sort_keys, cursor, &mut idx, &mut binds).unwrap();
+        (sql, binds)
+    }
+
+    fn init_secret() {
+        CURSOR_SECRET.get_or_init(|| "test-cursor-secret".to_string());
+    }
+
+    fn payload(v: u8, sort: &str) -> CursorPayload {
+        CursorPayload {
+            v,
+            sort: sort.to_string(),
+            filter: "f".to_string(),
+            values: vec![Some("b".to_string()), None],
+            id: Uuid::nil(),
+        }
+    }
+
+    fn decode_reason(cursor: &str, sort: &str, filter: &str) -> String {
+        let err = decode_cursor(cursor, sort, filter).unwrap_err();
+        assert!(is_cursor_error(&err));
+        err.params()["reason"].as_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn nulls_last_value_also_matches_trailing_nulls() {
+        let (sql, binds) = clause(
+            &[key(TITLE, SortDirection::Asc, NullsOrder::Last)],
+            vec![Some("b")],
+        );
+        assert_eq!(
+            sql,
+            "(((title > $1 OR title IS NULL)) OR (title = $1 AND id > $2))"
+        );
+        assert_eq!(binds.len(), 2);
+    }
+
+    #[test]
+    fn nulls_first_value_skips_nulls() {
+        let (sql, _) = clause(
+            &[key(TITLE, SortDirection::Desc, NullsOrder::First)],
+            vec![Some("b")],
+        );
+        assert_eq!(sql, "((title < $1) OR (title = $1 AND id < $2))");
+    }
+
+    #[test]
+    fn null_cursor_value_with_nulls_last_only_continues_within_nulls() {
+        let (sql, binds) = clause(
+            &[key(TITLE, SortDirection::Asc, NullsOrder::Last)],
+            vec![None],
+        );
+        assert_eq!(sql, "((title IS NULL AND id > $1))");
+        assert_eq!(binds.len(), 1);
+    }
+
+    #[test]
+    fn null_cursor_value_with_nulls_first_moves_on_to_values() {
+        let (sql, _) = clause(
+            &[key(TITLE, SortDirection::Desc, NullsOrder::First)],
+            vec![None],
+        );
+        assert_eq!(sql, "((title IS NOT NULL) OR (title IS NULL AND id < $1))");
+    }
+
+    #[test]
+    fn multi_column_expansion_carries_equalities_forward() {
+        let (sql, binds) = clause(
+            &[
+                key(PUBLISHED_AT, SortDirection::Desc, NullsOrder::First),
+                key(TITLE, SortDirection::Asc, NullsOrder::Last),
+            ],
+            vec![None, Some("b")],
+        );
+        assert_eq!(
+            sql,
+            "((published_at IS NOT NULL) \
+             OR (published_at IS NULL AND (title > $1 OR title IS NULL)) \
+             OR (published_at IS NULL AND title = $1 AND id < $2))"
+        );
+        assert_eq!(binds.len(), 2);
+    }
+
+    #[test]
+    fn bad_timestamp_in_cursor_is_invalid_cursor() {
+        let cursor = CursorData {
+            values: vec![Some("yesterday".to_string())],
+            id: Uuid::nil(),
+        };
+        let err = keyset_clause(
+            &[key(PUBLISHED_AT, SortDirection::Desc, NullsOrder::First)],
+            cursor,
+            &mut 0,
+            &mut Vec::new(),
+        )
+        .unwrap_err();
+        assert!(is_cursor_error(&err));
+    }
+
+    #[test]
+    fn cursor_round_trips() {
+        init_secret();
+        let cursor = encode_cursor(&payload(CURSOR_VERSION, "title:asc:nulls_last"));
+        let decoded = decode_cursor(&cursor, "title:asc:nulls_last", "f").unwrap();
+        assert_eq!(decoded.values, vec![Some("b".to_string()), None]);
+        assert_eq!(decoded.id, Uuid::nil());
+    }
+
+    #[test]
+    fn tampered_cursor_is_rejected() {
+        init_secret();
+        let cursor = encode_cursor(&payload(CURSOR_VERSION, "title:asc:nulls_last"));
+        let (_, signature) = cursor.split_once('.').unwrap();
+        let mut forged = payload(CURSOR_VERSION, "title:asc:nulls_last");
+        forged.values = vec![Some("z".to_string()), None];
+        let forged_body =
+            general_purpose::URL_SAFE_NO_PAD.encode(serde_json::to_vec(&forged).unwrap());
+
+        for bad in [
+            format!("{forged_body}.{signature}"),
+            cursor.replace('.', ""),
+            format!("{cursor}x"),
+        ] {
+            let reason = decode_reason(&bad, "title:asc:nulls_last", "f");
+            assert!(reason.contains("tampered"), "{reason}");
+        }
+    }
+
+    #[test]
+    fn old_cursor_version_is_rejected() {
+        init_secret();
+        let cursor = encode_cursor(&payload(CURSOR_VERSION - 1, "title:asc:nulls_last"));
+        let reason = decode_reason(&cursor, "title:asc:nulls_last", "f");
+        assert!(reason.contains("version"), "{reason}");
+    }
+
+    #[test]
+    fn cursor_from_other_sort_or_filter_is_rejected() {
+        init_secret();
+        let cursor = encode_cursor(&payload(CURSOR_VERSION, "title:asc:nulls_last"));
+        let reason = decode_reason(&cursor, "title:desc:nulls_first", "f");
+        assert!(reason.contains("sort_by=title:asc:nulls_last"), "{reason}");
+        let reason = decode_reason(&cursor, "title:asc:nulls_last", "other");
+        assert!(reason.contains("search/filter"), "{reason}");
+    }
+}
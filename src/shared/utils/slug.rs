@@ -0,0 +1,67 @@
+use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
+
+/// Độ dài tối đa của slug (không tính hậu tố `-2`, `-3`, ...)
+pub const MAX_SLUG_LENGTH: usize = 120;
+
+/// Chuyển chuỗi bất kỳ thành slug ASCII: bỏ dấu tiếng Việt, chữ thường,
+/// ký tự khác chữ/số thành `-` ("Chào mừng đến với Rust!" → "chao-mung-den-voi-rust")
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    let mut pending_dash = false;
+
+    for c in text.nfd().filter(|c| !is_combining_mark(*c)) {
+        let c = match c {
+            'đ' | 'Đ' => 'd',
+            _ => c.to_ascii_lowercase(),
+        };
+        if c.is_ascii_alphanumeric() {
+            if pending_dash && !slug.is_empty() {
+                slug.push('-');
+            }
+            pending_dash = false;
+            slug.push(c);
+        } else {
+            pending_dash = true;
+        }
+    }
+
+    if slug.len() > MAX_SLUG_LENGTH {
+        slug.truncate(MAX_SLUG_LENGTH);
+        let trimmed = slug.trim_end_matches('-').len();
+        slug.truncate(trimmed);
+    }
+    slug
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_vietnamese_diacritics() {
+        assert_eq!(slugify("Chào mừng"), "chao-mung");
+        assert_eq!(slugify("Chào mừng đến với Rust!"), "chao-mung-den-voi-rust");
+    }
+
+    #[test]
+    fn maps_d_with_stroke() {
+        assert_eq!(slugify("Đà Nẵng đẹp"), "da-nang-dep");
+    }
+
+    #[test]
+    fn collapses_and_trims_separators() {
+        assert_eq!(slugify("  --Hello,   World--  "), "hello-world");
+    }
+
+    #[test]
+    fn empty_or_symbol_only_input_gives_empty_slug() {
+        assert_eq!(slugify(""), "");
+        assert_eq!(slugify("!!! ???"), "");
+    }
+
+    #[test]
+    fn truncates_without_trailing_dash() {
+        let slug = slugify(&format!("{} b", "a".repeat(MAX_SLUG_LENGTH - 1)));
+        assert_eq!(slug, "a".repeat(MAX_SLUG_LENGTH - 1));
+    }
+}